@@ -360,7 +360,7 @@ export default abstract class UploadCommandBase extends ContentDirectoryCommandB
           `Are you sure you want to continue?`
       )
       return createType<StorageAssets, 'StorageAssets'>('StorageAssets', {
-        expected_data_size_fee: feePerMB,
+        expected_data_size_fee: totalStorageFee,
         object_creation_list: resolvedAssets.map((a) => a.parameters),
       })
     }
@@ -61,16 +61,18 @@ export default class DevBatchUpload extends AccountsCommandBase {
         const dataHash = new ContentHash().update(dataObject).digest()
         batch.push([
           api.tx.sudo.sudo(
-            api.tx.storage.sudoUploadDataObjects({
-              deletionPrizeSourceAccountId: sudoKey,
-              objectCreationList: [
-                {
-                  Size: dataObject.byteLength,
-                  IpfsContentId: dataHash,
-                },
-              ],
-              expectedDataSizeFee: dataFee,
-              bagId: new BagIdParserService(bagId).parse(),
+            api.tx.storage.executeStorageAdministrativeAction({
+              UploadDataObjects: {
+                deletionPrizeSourceAccountId: sudoKey,
+                objectCreationList: [
+                  {
+                    Size: dataObject.byteLength,
+                    IpfsContentId: dataHash,
+                  },
+                ],
+                expectedDataSizeFee: dataFee.muln(Math.ceil(dataObject.byteLength / 1024 / 1024)),
+                bagId: new BagIdParserService(bagId).parse(),
+              },
             })
           ),
           dataObject,
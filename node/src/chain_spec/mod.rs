@@ -33,7 +33,7 @@ use sp_runtime::Perbill;
 use node_runtime::{
     membership, wasm_binary_unwrap, AuthorityDiscoveryConfig, BabeConfig, Balance, BalancesConfig,
     ContentConfig, ForumConfig, GrandpaConfig, ImOnlineConfig, MembersConfig, SessionConfig,
-    SessionKeys, Signature, StakerStatus, StakingConfig, SudoConfig, SystemConfig,
+    SessionKeys, Signature, StakerStatus, StakingConfig, StorageConfig, SudoConfig, SystemConfig,
 };
 
 // Exported to be used by chain-spec-builder
@@ -308,6 +308,7 @@ pub fn testnet_genesis(
                 max_auction_whitelist_length: 100,
             }
         }),
+        storage: Some(StorageConfig {}),
     }
 }
 
@@ -30,7 +30,10 @@
 
 #![warn(missing_docs)]
 
-use node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
+use node_runtime::{
+    opaque::Block, AccountId, Balance, BlockNumber, DataObjectId, Hash, Index, Runtime,
+    StorageBucketId,
+};
 use sc_consensus_babe::{Config, Epoch};
 use sc_consensus_babe_rpc::BabeRpcHandler;
 use sc_consensus_epochs::SharedEpochChanges;
@@ -114,6 +117,19 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_storage_rpc::StorageRuntimeApi<
+        Block,
+        node_runtime::storage::BagId<Runtime>,
+        node_runtime::storage::DynamicBagId<Runtime>,
+        DataObjectId,
+        node_runtime::storage::DataObject<Balance>,
+        StorageBucketId,
+        node_runtime::storage::DistributionBucketId<Runtime>,
+        node_runtime::storage::Voucher,
+        node_runtime::storage::DataObjectCreationParameters,
+        node_runtime::storage::UploadFeeQuote<Balance>,
+        BlockNumber,
+    >,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
@@ -121,6 +137,7 @@ where
     B: sc_client_api::Backend<Block> + Send + Sync + 'static,
     B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashFor<Block>>,
 {
+    use pallet_storage_rpc::{Storage, StorageApi};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
     use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
@@ -155,6 +172,7 @@ where
     io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(
         client.clone(),
     )));
+    io.extend_with(StorageApi::to_delegate(Storage::new(client.clone())));
     io.extend_with(sc_consensus_babe_rpc::BabeApi::to_delegate(
         BabeRpcHandler::new(
             client,
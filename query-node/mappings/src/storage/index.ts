@@ -1,7 +1,7 @@
 /*
 eslint-disable @typescript-eslint/naming-convention
 */
-import { EventContext, StoreContext } from '@joystream/hydra-common'
+import { DatabaseManager, EventContext, StoreContext } from '@joystream/hydra-common'
 import { Storage } from '../../generated/types/storage'
 import {
   DistributionBucket,
@@ -20,6 +20,8 @@ import {
   GeoCoordinates,
 } from 'query-node/dist/model'
 import BN from 'bn.js'
+import { BTreeSet } from '@polkadot/types'
+import { BagId, DataObjectId } from '@joystream/types/augment/all'
 import { getById, inconsistentState } from '../common'
 import {
   processDistributionBucketFamilyMetadata,
@@ -237,8 +239,12 @@ export async function storage_PendingDataObjectsAccepted({ event, store }: Event
   )
 }
 
-export async function storage_DataObjectsMoved({ event, store }: EventContext & StoreContext): Promise<void> {
-  const [srcBagId, destBagId, dataObjectIds] = new Storage.DataObjectsMovedEvent(event).params
+async function moveDataObjects(
+  store: DatabaseManager,
+  srcBagId: BagId,
+  destBagId: BagId,
+  dataObjectIds: BTreeSet<DataObjectId>
+): Promise<void> {
   const dataObjects = await getDataObjectsInBag(store, srcBagId, dataObjectIds)
   const destBag = await getBag(store, destBagId)
   await Promise.all(
@@ -249,6 +255,16 @@ export async function storage_DataObjectsMoved({ event, store }: EventContext &
   )
 }
 
+export async function storage_DataObjectsMoved({ event, store }: EventContext & StoreContext): Promise<void> {
+  const [srcBagId, destBagId, dataObjectIds] = new Storage.DataObjectsMovedEvent(event).params
+  await moveDataObjects(store, srcBagId, destBagId, dataObjectIds)
+}
+
+export async function storage_DataObjectsMovedByOwner({ event, store }: EventContext & StoreContext): Promise<void> {
+  const [, srcBagId, destBagId, dataObjectIds] = new Storage.DataObjectsMovedByOwnerEvent(event).params
+  await moveDataObjects(store, srcBagId, destBagId, dataObjectIds)
+}
+
 export async function storage_DataObjectsDeleted({ event, store }: EventContext & StoreContext): Promise<void> {
   const [, bagId, dataObjectIds] = new Storage.DataObjectsDeletedEvent(event).params
   const dataObjects = await getDataObjectsInBag(store, bagId, dataObjectIds)
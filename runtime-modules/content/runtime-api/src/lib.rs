@@ -0,0 +1,20 @@
+//! Runtime API definition for the content directory pallet.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+// Internal Substrate warning (decl_runtime_apis).
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Content directory state queries for off-chain services (e.g. distributor nodes).
+    pub trait ContentApi<ChannelId, MemberId>
+    where
+        ChannelId: Codec,
+        MemberId: Codec,
+    {
+        /// Whether the member holds an active subscription to the channel.
+        fn is_subscribed(channel_id: ChannelId, member_id: MemberId) -> bool;
+    }
+}
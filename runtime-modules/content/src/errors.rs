@@ -46,6 +46,9 @@ decl_error! {
         /// Operation cannot be perfomed with this Actor
         ActorNotAuthorized,
 
+        /// Collaborator is not allowed to perform this action
+        CollaboratorPermissionMissing,
+
         /// A Channel or Video Category does not exist.
         CategoryDoesNotExist,
 
@@ -265,5 +268,32 @@ decl_error! {
         /// Reward account is none
         RewardAccountNotFoundInChannel,
 
+        /// More video asset slots provided than assets uploaded
+        VideoAssetSlotsExceedUploadedAssets,
+
+        /// Video asset slot provided more than once
+        DuplicateVideoAssetSlot,
+
+        /// Subtitle or rendition video asset slot key is too long
+        VideoAssetSlotKeyTooLong,
+
+        /// Channel has no subscription plan
+        SubscriptionPlanDoesNotExist,
+
+        /// Subscription period must be greater than zero
+        InvalidSubscriptionPeriod,
+
+        /// Subscription price must be greater than zero
+        InvalidSubscriptionPrice,
+
+        /// At least one subscription period must be paid
+        ZeroSubscriptionPeriods,
+
+        /// Subscription plan price differs from the expected one
+        SubscriptionPriceChanged,
+
+        /// Some of the assets are already scheduled for deletion
+        AssetsPendingDeletion,
+
     }
 }
@@ -8,6 +8,7 @@
 mod tests;
 use core::marker::PhantomData;
 mod errors;
+mod migrations;
 mod nft;
 mod permissions;
 mod types;
@@ -16,6 +17,7 @@ use sp_std::cmp::max;
 use sp_std::mem::size_of;
 
 pub use errors::*;
+pub use migrations::*;
 pub use nft::*;
 pub use permissions::*;
 pub use types::*;
@@ -36,7 +38,9 @@ use frame_support::{
     decl_event, decl_module, decl_storage,
     dispatch::{DispatchError, DispatchResult},
     ensure,
+    storage::IterableStorageDoubleMap,
     traits::{Currency, ExistenceRequirement, Get, ReservableCurrency},
+    weights::Weight,
     Parameter,
 };
 
@@ -49,10 +53,13 @@ use sp_arithmetic::{
     Perbill,
 };
 use sp_runtime::{
-    traits::{AccountIdConversion, Hash, MaybeSerializeDeserialize, Member},
+    traits::{AccountIdConversion, Hash, MaybeSerializeDeserialize, Member, SaturatedConversion},
     ModuleId,
 };
-use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+use sp_std::{
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    vec::Vec,
+};
 /// Module configuration trait for Content Directory Module
 pub trait Trait:
     frame_system::Trait
@@ -126,6 +133,19 @@ pub trait Trait:
 
     /// Channel migrated in each block during migration
     type ChannelsMigrationsEachBlock: Get<u64>;
+
+    /// Data objects deleted in each block while processing pending assets deletions
+    type AssetsDeletionsEachBlock: Get<u64>;
+
+    /// Failed attempts after which a pending assets deletion is dropped
+    type MaxAssetsDeletionAttempts: Get<u32>;
+
+    /// Channel subscription expirations processed in each block (blocks without expirations
+    /// are counted too), the remaining ones are processed in the next blocks
+    type SubscriptionExpirationsEachBlock: Get<u64>;
+
+    /// Max length of the subtitle and rendition video asset slot keys
+    type MaxVideoAssetSlotKeyLength: Get<u32>;
 }
 
 decl_storage! {
@@ -179,6 +199,52 @@ decl_storage! {
 
         pub VideoMigration get(fn video_migration) config(): VideoMigrationConfig<T>;
 
+        /// Storage layout version, used to select the migrations to run on runtime upgrade
+        pub StorageVersion get(fn storage_version) build(|_| ContentStorageVersion::LATEST):
+        ContentStorageVersion;
+
+        /// Progress of the channel collaborators migration
+        pub ChannelCollaboratorsMigration get(fn channel_collaborators_migration):
+        ChannelMigrationConfig<T>;
+
+        /// Progress of the video assets migration
+        pub VideoAssetsMigration get(fn video_assets_migration): VideoMigrationConfig<T>;
+
+        /// Assets deletions waiting to be processed in `on_initialize`
+        pub PendingAssetsDeletionById get(fn pending_assets_deletion_by_id):
+        map hasher(blake2_128_concat) u64 => AssetsDeletion<T>;
+
+        /// Id of the next pending assets deletion to be processed
+        pub CurrentAssetsDeletionId get(fn current_assets_deletion_id): u64;
+
+        /// Id assigned to the next scheduled assets deletion
+        pub NextAssetsDeletionId get(fn next_assets_deletion_id): u64;
+
+        /// Data objects included in the pending assets deletions
+        pub PendingAssetsDeletionObjects get(fn pending_assets_deletion_objects):
+        map hasher(blake2_128_concat) DataObjectId<T> => ();
+
+        /// Videos by the data objects assigned to their asset slots
+        pub VideoByAsset get(fn video_by_asset):
+        map hasher(blake2_128_concat) DataObjectId<T> => Option<T::VideoId>;
+
+        /// Subscription plan offered by the channel
+        pub ChannelSubscriptionPlan get(fn channel_subscription_plan):
+        map hasher(blake2_128_concat) T::ChannelId => Option<SubscriptionPlan<T>>;
+
+        /// Block at which the member subscription to the channel expires
+        pub ChannelSubscriptionExpiry get(fn channel_subscription_expiry):
+        double_map hasher(blake2_128_concat) T::ChannelId,
+        hasher(blake2_128_concat) T::MemberId => Option<T::BlockNumber>;
+
+        /// Channel subscriptions indexed by expiration block, removed in `on_initialize`
+        pub ChannelSubscriptionsExpiringAt get(fn channel_subscriptions_expiring_at):
+        double_map hasher(twox_64_concat) T::BlockNumber,
+        hasher(blake2_128_concat) (T::ChannelId, T::MemberId) => ();
+
+        /// Next block of the channel subscription expirations to be processed in `on_initialize`
+        pub SubscriptionExpirationsCursor get(fn subscription_expirations_cursor): T::BlockNumber;
+
         pub Commitment get(fn commitment): <T as frame_system::Trait>::Hash;
 
         pub MaxRewardAllowed get(fn max_reward_allowed) config(): BalanceOf<T>;
@@ -243,6 +309,18 @@ decl_module! {
         /// Exports const -  max number of curators per group
         const MaxNumberOfCuratorsPerGroup: MaxNumber = T::MaxNumberOfCuratorsPerGroup::get();
 
+        /// Exports const -  max number of data objects deleted in each block
+        const AssetsDeletionsEachBlock: u64 = T::AssetsDeletionsEachBlock::get();
+
+        /// Exports const -  failed attempts after which a pending assets deletion is dropped
+        const MaxAssetsDeletionAttempts: u32 = T::MaxAssetsDeletionAttempts::get();
+
+        /// Exports const -  max number of channel subscription expirations processed in each block
+        const SubscriptionExpirationsEachBlock: u64 = T::SubscriptionExpirationsEachBlock::get();
+
+        /// Exports const -  max length of the subtitle and rendition video asset slot keys
+        const MaxVideoAssetSlotKeyLength: u32 = T::MaxVideoAssetSlotKeyLength::get();
+
         // ======
         // Next set of extrinsics can only be invoked by lead.
         // ======
@@ -373,7 +451,7 @@ decl_module! {
             Self::deposit_event(RawEvent::CuratorRemoved(curator_group_id, curator_id));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = Module::<T>::create_channel_weight(&params)]
         pub fn create_channel(
             origin,
             actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -398,7 +476,7 @@ decl_module! {
 
             // ensure collaborator & moderator member ids are valid
             Self::validate_member_set(&params.moderators)?;
-            Self::validate_member_set(&params.collaborators)?;
+            Self::validate_member_set(&params.collaborators.keys().cloned().collect())?;
 
             let upload_params = params.assets.as_ref().map(|assets| {
                 Self::construct_upload_parameters(
@@ -475,7 +553,7 @@ decl_module! {
         }
 
         // Include Option<AccountId> in ChannelUpdateParameters to update reward_account
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = Module::<T>::update_channel_weight(&params)]
         pub fn update_channel(
             origin,
             actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -491,6 +569,7 @@ decl_module! {
                 &sender,
                 &actor,
                 &channel,
+                &Self::channel_update_permissions(&params),
             )?;
 
             // maybe update the reward account if actor is not a collaborator
@@ -503,7 +582,7 @@ decl_module! {
             if let Some(new_collabs) = params.collaborators.as_ref() {
                 ensure_actor_can_manage_collaborators::<T>(&sender, &channel.owner, &actor)?;
                 // ensure collaborator member ids are valid
-                Self::validate_member_set(new_collabs)?;
+                Self::validate_member_set(&new_collabs.keys().cloned().collect())?;
 
                 channel.collaborators = new_collabs.clone();
             }
@@ -519,6 +598,8 @@ decl_module! {
             }
 
             if !params.assets_to_remove.is_empty() {
+                Self::ensure_assets_not_pending_deletion(&params.assets_to_remove)?;
+
                 Storage::<T>::can_delete_data_objects(
                     &Self::bag_id_for_channel(&channel_id),
                     &params.assets_to_remove
@@ -540,6 +621,8 @@ decl_module! {
             }
 
             if !params.assets_to_remove.is_empty() {
+                Self::remove_video_assets(&params.assets_to_remove);
+
                 Storage::<T>::delete_data_objects(
                     sender,
                     Self::bag_id_for_channel(&channel_id),
@@ -609,25 +692,16 @@ decl_module! {
                 // == MUTATION SAFE ==
                 //
 
-                // remove specified assets from storage
-                if !assets_to_remove.is_empty() {
-                    Storage::<T>::delete_data_objects(
-                        sender.clone(),
-                        Self::bag_id_for_channel(&channel_id),
-                        assets_to_remove.clone(),
-                    )?;
-                }
-
-                // delete channel dynamic bag
-                Storage::<T>::delete_dynamic_bag(
-                    sender,
-                    dyn_bag,
-                )?;
+                // schedule assets and channel bag removal: performed in `on_initialize`
+                Self::schedule_assets_deletion(channel_id, sender, assets_to_remove, true, 0);
             }
 
             // remove channel from on chain state
             ChannelById::<T>::remove(channel_id);
 
+            // existing subscriptions are left to expire
+            ChannelSubscriptionPlan::<T>::remove(channel_id);
+
             // deposit event
             Self::deposit_event(RawEvent::ChannelDeleted(actor, channel_id));
 
@@ -724,7 +798,7 @@ decl_module! {
             Self::deposit_event(RawEvent::ChannelCategoryDeleted(actor, category_id));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = Module::<T>::create_video_weight(&params)]
         pub fn create_video(
             origin,
             actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -740,8 +814,11 @@ decl_module! {
                 &sender,
                 &actor,
                 &channel,
+                &[ChannelCollaboratorPermission::AddVideo],
             )?;
 
+            Self::ensure_valid_video_asset_slots(params.assets.as_ref(), &params.asset_slots)?;
+
             // next video id
             let video_id = NextVideoId::<T>::get();
 
@@ -750,14 +827,19 @@ decl_module! {
             //
 
             // upload to storage: check is performed beforehand in the extrinsics so storage state won't be endangered
-            if let Some(upload_assets) = params.assets.as_ref() {
+            let uploaded_assets = if let Some(upload_assets) = params.assets.as_ref() {
                 let params = Self::construct_upload_parameters(
                     upload_assets,
                     &channel_id,
                     &sender
                 );
-                Storage::<T>::upload_data_objects(params)?;
-            }
+                Storage::<T>::upload_data_objects(params)?
+            } else {
+                Vec::new()
+            };
+
+            // ids assigned to the uploaded assets in their slots
+            let video_assets = Self::video_asset_slots_ids(&params.asset_slots, &uploaded_assets);
 
             // create the video struct
             let video: Video<T> = VideoRecord {
@@ -768,8 +850,13 @@ decl_module! {
                 video_post_id:  None,
                 /// Newly created video has no nft
                 nft_status: None,
+                assets: video_assets,
             };
 
+            for data_object_id in video.assets.values() {
+                VideoByAsset::<T>::insert(data_object_id, video_id);
+            }
+
             // add it to the onchain state
             VideoById::<T>::insert(video_id, video);
 
@@ -786,7 +873,7 @@ decl_module! {
 
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = Module::<T>::update_video_weight(&params)]
         pub fn update_video(
             origin,
             actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -805,6 +892,12 @@ decl_module! {
                 &sender,
                 &actor,
                 &channel,
+                &Self::video_update_permissions(&params),
+            )?;
+
+            Self::ensure_valid_video_asset_slots(
+                params.assets_to_upload.as_ref(),
+                &params.asset_slots,
             )?;
 
             if let Some(upload_assets) = params.assets_to_upload.as_ref() {
@@ -816,10 +909,15 @@ decl_module! {
                 Storage::<T>::can_upload_data_objects(&params)?;
             }
 
-            if !params.assets_to_remove.is_empty() {
+            // assets replaced in their slots are removed along with the requested ones
+            let assets_to_remove = Self::video_assets_to_remove(&video, &params);
+
+            if !assets_to_remove.is_empty() {
+                Self::ensure_assets_not_pending_deletion(&assets_to_remove)?;
+
                 Storage::<T>::can_delete_data_objects(
                     &Self::bag_id_for_channel(&channel_id),
-                    &params.assets_to_remove,
+                    &assets_to_remove,
                 )?;
             }
 
@@ -827,23 +925,44 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            if let Some(upload_assets) = params.assets_to_upload.as_ref() {
+            let uploaded_assets = if let Some(upload_assets) = params.assets_to_upload.as_ref() {
                 let params = Self::construct_upload_parameters(
                     upload_assets,
                     &channel_id,
                     &sender
                 );
-                Storage::<T>::upload_data_objects(params)?;
+                Storage::<T>::upload_data_objects(params)?
+            } else {
+                Vec::new()
+            };
+
+            // ids assigned to the uploaded assets in their slots
+            let uploaded_video_assets =
+                Self::video_asset_slots_ids(&params.asset_slots, &uploaded_assets);
+
+            for data_object_id in uploaded_video_assets.values() {
+                VideoByAsset::<T>::insert(data_object_id, video_id);
             }
 
-            if !params.assets_to_remove.is_empty() {
+            let video_assets = video
+                .assets
+                .into_iter()
+                .filter(|(_, data_object_id)| !assets_to_remove.contains(data_object_id))
+                .chain(uploaded_video_assets)
+                .collect();
+
+            if !assets_to_remove.is_empty() {
+                Self::remove_video_assets(&assets_to_remove);
+
                 Storage::<T>::delete_data_objects(
                     sender,
                     Self::bag_id_for_channel(&channel_id),
-                    params.assets_to_remove.clone(),
+                    assets_to_remove,
                 )?;
             }
 
+            VideoById::<T>::mutate(video_id, |video| video.assets = video_assets);
+
             Self::deposit_event(RawEvent::VideoUpdated(actor, video_id, params));
         }
 
@@ -867,6 +986,7 @@ decl_module! {
                 &sender,
                 &actor,
                 &channel,
+                &[ChannelCollaboratorPermission::DeleteVideo],
             )?;
 
             // ensure video can be removed
@@ -875,6 +995,13 @@ decl_module! {
             // Ensure nft for this video have not been issued
             video.ensure_nft_is_not_issued::<T>()?;
 
+            // assets in the video slots are removed along with the requested ones
+            let assets_to_remove: BTreeSet<_> =
+                Self::channel_bag_assets(&channel_id, video.assets.values().cloned())
+                    .union(&assets_to_remove)
+                    .cloned()
+                    .collect();
+
             if !assets_to_remove.is_empty() {
                 Storage::<T>::can_delete_data_objects(
                     &Self::bag_id_for_channel(&channel_id),
@@ -882,16 +1009,6 @@ decl_module! {
                 )?;
             }
 
-            // TODO: Solve #now
-            // If video is on storage, remove it
-            // if let Some(data_objects_id_set) = video.maybe_data_objects_id_set {
-            //     Storage::<T>::delete_data_objects(
-            //         channel.deletion_prize_source_account_id,
-            //         Self::bag_id_for_channel(&channel_id),
-            //         data_objects_id_set,
-            //     )?;
-            // }
-
             // bloat bond logic: channel owner is refunded
             video.video_post_id.as_ref().map(
                 |video_post_id| Self::video_deletion_refund_logic(&sender, &video_id, &video_post_id)
@@ -901,12 +1018,11 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            // schedule assets removal: performed in `on_initialize`
             if !assets_to_remove.is_empty() {
-                Storage::<T>::delete_data_objects(
-                    sender,
-                    Self::bag_id_for_channel(&channel_id),
-                    assets_to_remove.clone()
-                )?;
+                Self::remove_video_assets(&assets_to_remove);
+
+                Self::schedule_assets_deletion(channel_id, sender, assets_to_remove, false, 0);
             }
 
             // Remove video
@@ -1160,7 +1276,7 @@ decl_module! {
 
             // ensure channel is valid
             let video = Self::ensure_video_validity(&params.video_reference)?;
-            let owner = ChannelById::<T>::get(video.in_channel).owner;
+            let channel = ChannelById::<T>::get(video.in_channel);
 
             match params.post_type {
                 VideoPostType::<T>::Comment(parent_id) => {
@@ -1171,7 +1287,7 @@ decl_module! {
                 VideoPostType::<T>::Description => ensure_actor_authorized_to_add_video_post::<T>(
                     &sender,
                     &actor,
-                    &owner
+                    &channel
                 )?
             };
 
@@ -1233,7 +1349,7 @@ decl_module! {
                 VideoPostType::<T>::Description => ensure_actor_authorized_to_edit_video_post::<T>(
                     &sender,
                     &actor,
-                    &channel.owner
+                    &channel
                 )?,
                 VideoPostType::<T>::Comment(_) => ensure_actor_authorized_to_edit_comment::<T>(
                     &sender,
@@ -1390,10 +1506,13 @@ decl_module! {
                 ));
         }
 
-        fn on_initialize(_n: T::BlockNumber) -> frame_support::weights::Weight {
-            Self::perform_video_migration();
-            Self::perform_channel_migration();
-            10_000_000 // TODO: adjust Weight
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            Self::perform_migrations();
+            let assets_deletions_weight = Self::perform_pending_assets_deletions();
+            let subscriptions_weight = Self::expire_channel_subscriptions(n);
+            Self::BASE_WEIGHT
+                .saturating_add(assets_deletions_weight)
+                .saturating_add(subscriptions_weight)
         }
 
         #[weight = 10_000_000] // TODO: adjust Weight
@@ -1459,6 +1578,90 @@ decl_module! {
             Self::deposit_event(RawEvent::MinCashoutUpdated(amount));
         }
 
+        /// Set or remove the channel subscription plan.
+        /// Existing subscriptions are kept until they expire.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn update_channel_subscription_plan(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            plan: Option<SubscriptionPlan<T>>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let channel = Self::ensure_channel_validity(&channel_id)?;
+
+            ensure_actor_can_manage_reward_account::<T>(&sender, &channel.owner, &actor)?;
+
+            if let Some(plan) = plan.as_ref() {
+                // subscriptions are paid to the channel reward account
+                ensure!(channel.reward_account.is_some(), Error::<T>::RewardAccountIsNotSet);
+                ensure!(!plan.period.is_zero(), Error::<T>::InvalidSubscriptionPeriod);
+                // free subscriptions would let anyone fill the expirations queue at no cost
+                ensure!(!plan.price.is_zero(), Error::<T>::InvalidSubscriptionPrice);
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match plan.as_ref() {
+                Some(plan) => ChannelSubscriptionPlan::<T>::insert(channel_id, plan),
+                None => ChannelSubscriptionPlan::<T>::remove(channel_id),
+            }
+
+            Self::deposit_event(RawEvent::ChannelSubscriptionPlanUpdated(actor, channel_id, plan));
+        }
+
+        /// Subscribe a member to a channel for a number of plan periods, paid to the channel
+        /// reward account minus the platform fee. An active subscription is extended.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn subscribe_to_channel(
+            origin,
+            member_id: T::MemberId,
+            channel_id: T::ChannelId,
+            periods: u32,
+            expected_price: CurrencyOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_member_auth_success::<T>(&sender, &member_id)?;
+
+            let channel = Self::ensure_channel_validity(&channel_id)?;
+
+            let plan = Self::ensure_subscription_plan_exists(&channel_id)?;
+
+            ensure!(plan.price == expected_price, Error::<T>::SubscriptionPriceChanged);
+            ensure!(periods > 0, Error::<T>::ZeroSubscriptionPeriods);
+
+            let reward_account = channel.reward_account.ok_or(Error::<T>::RewardAccountIsNotSet)?;
+
+            let amount = plan.price.saturating_mul(periods.into());
+            Self::ensure_sufficient_free_balance(&sender, amount)?;
+
+            // an active subscription is extended from its expiration
+            let now = <frame_system::Module<T>>::block_number();
+            let current_expiry = Self::channel_subscription_expiry(channel_id, member_id);
+            let expires_at = current_expiry
+                .filter(|expires_at| *expires_at > now)
+                .unwrap_or(now)
+                .saturating_add(plan.period.saturating_mul(periods.into()));
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::complete_payment(channel_id, None, amount, sender, Some(reward_account), false);
+
+            if let Some(current_expiry) = current_expiry {
+                ChannelSubscriptionsExpiringAt::<T>::remove(current_expiry, (channel_id, member_id));
+            }
+
+            ChannelSubscriptionExpiry::<T>::insert(channel_id, member_id, expires_at);
+            ChannelSubscriptionsExpiringAt::<T>::insert(expires_at, (channel_id, member_id), ());
+
+            Self::deposit_event(RawEvent::ChannelSubscribed(member_id, channel_id, expires_at, amount));
+        }
+
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn issue_nft(
             origin,
@@ -1482,7 +1685,12 @@ decl_module! {
             // Ensure channel exists, retrieve channel owner
             let channel = Self::ensure_channel_validity(&channel_id)?;
 
-            ensure_actor_authorized_to_update_channel_assets::<T>(&sender, &actor, &channel)?;
+            ensure_actor_authorized_to_update_channel_assets::<T>(
+                &sender,
+                &actor,
+                &channel,
+                &[ChannelCollaboratorPermission::IssueNft],
+            )?;
 
             // The content owner will be..
             let nft_owner = if let Some(to) = to {
@@ -2055,81 +2263,296 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
-    /// Migrate Videos
-    fn perform_video_migration() {
-        let MigrationConfigRecord {
-            current_id,
-            final_id,
-        } = <VideoMigration<T>>::get();
-
-        if current_id < final_id {
-            // perform migration procedure
-            let next_id = sp_std::cmp::min(
-                current_id + T::VideosMigrationsEachBlock::get().into(),
-                final_id,
+    // Base weight of the content extrinsics, the storage operations are added on top of it.
+    const BASE_WEIGHT: Weight = 10_000_000; // TODO: adjust weight
+
+    // Weight of the uploaded assets.
+    fn upload_assets_weight(assets: Option<&StorageAssets<T>>) -> Weight {
+        assets.map_or(0, |assets| {
+            Storage::<T>::upload_data_objects_weight(
+                assets.object_creation_list.len().saturated_into(),
+            )
+        })
+    }
+
+    // Weight of the removed assets.
+    fn delete_assets_weight(assets_number: usize) -> Weight {
+        if assets_number == 0 {
+            return 0;
+        }
+
+        Storage::<T>::delete_data_objects_weight(assets_number.saturated_into())
+    }
+
+    // Calculates weight for create_channel extrinsic: assumes the channel bag is created.
+    fn create_channel_weight(params: &ChannelCreationParameters<T>) -> Weight {
+        Self::BASE_WEIGHT
+            .saturating_add(Storage::<T>::create_dynamic_bag_weight())
+            .saturating_add(Self::upload_assets_weight(params.assets.as_ref()))
+    }
+
+    // Calculates weight for update_channel extrinsic.
+    fn update_channel_weight(params: &ChannelUpdateParameters<T>) -> Weight {
+        Self::BASE_WEIGHT
+            .saturating_add(Self::upload_assets_weight(params.assets_to_upload.as_ref()))
+            .saturating_add(Self::delete_assets_weight(params.assets_to_remove.len()))
+    }
+
+    // Calculates weight for create_video extrinsic.
+    fn create_video_weight(params: &VideoCreationParameters<T>) -> Weight {
+        Self::BASE_WEIGHT.saturating_add(Self::upload_assets_weight(params.assets.as_ref()))
+    }
+
+    // Calculates weight for update_video extrinsic: every asset slot is assumed to replace an
+    // existing asset.
+    fn update_video_weight(params: &VideoUpdateParameters<T>) -> Weight {
+        let assets_to_remove = params
+            .assets_to_remove
+            .len()
+            .saturating_add(params.asset_slots.len());
+
+        Self::BASE_WEIGHT
+            .saturating_add(Self::upload_assets_weight(params.assets_to_upload.as_ref()))
+            .saturating_add(Self::delete_assets_weight(assets_to_remove))
+    }
+
+    /// Whether the member holds an active subscription to the channel
+    pub fn is_subscribed(channel_id: &T::ChannelId, member_id: &T::MemberId) -> bool {
+        let now = <frame_system::Module<T>>::block_number();
+
+        ChannelById::<T>::contains_key(channel_id)
+            && Self::channel_subscription_expiry(channel_id, member_id)
+                .map_or(false, |expires_at| expires_at > now)
+    }
+
+    fn ensure_subscription_plan_exists(
+        channel_id: &T::ChannelId,
+    ) -> Result<SubscriptionPlan<T>, Error<T>> {
+        Self::channel_subscription_plan(channel_id).ok_or(Error::<T>::SubscriptionPlanDoesNotExist)
+    }
+
+    /// Remove at most `SubscriptionExpirationsEachBlock` expired channel subscriptions, starting
+    /// from the block saved by the cursor. Returns the weight of the processed expirations.
+    fn expire_channel_subscriptions(now: T::BlockNumber) -> Weight {
+        let cursor = SubscriptionExpirationsCursor::<T>::get();
+        let mut block = if cursor.is_zero() { now } else { cursor };
+        let mut budget = T::SubscriptionExpirationsEachBlock::get();
+        let mut weight = T::DbWeight::get().reads(1);
+
+        while budget > 0 && block <= now {
+            let expired = ChannelSubscriptionsExpiringAt::<T>::iter_prefix(block)
+                .map(|(subscription, _)| subscription)
+                .take(budget.saturated_into())
+                .collect::<Vec<_>>();
+            let expired_number = expired.len() as u64;
+            weight = weight.saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(expired_number.saturating_add(1), 2 * expired_number),
             );
 
-            //
-            // == MUTATION SAFE ==
-            //
+            // a block without remaining expirations is processed too
+            let block_processed = expired_number < budget;
+            budget = budget.saturating_sub(expired_number.max(1));
 
-            // clear maps: (iterator are lazy and do nothing unless consumed)
-            for id in current_id.into()..next_id.into() {
-                <VideoById<T>>::remove(T::VideoId::from(id));
+            for (channel_id, member_id) in expired {
+                ChannelSubscriptionsExpiringAt::<T>::remove(block, (channel_id, member_id));
+                ChannelSubscriptionExpiry::<T>::remove(channel_id, member_id);
+                Self::deposit_event(RawEvent::ChannelSubscriptionExpired(channel_id, member_id));
             }
 
-            // edit the current id
-            <VideoMigration<T>>::mutate(|value| value.current_id = next_id);
+            if block_processed {
+                block = block.saturating_add(One::one());
+            }
         }
-    }
 
-    /// Migrate Channels
-    fn perform_channel_migration() {
-        let MigrationConfigRecord {
-            current_id,
-            final_id,
-        } = <ChannelMigration<T>>::get();
-
-        if current_id < final_id {
-            // perform migration procedure
-            let next_id = sp_std::cmp::min(
-                current_id + T::ChannelsMigrationsEachBlock::get().into(),
-                final_id,
-            );
+        if block != cursor {
+            SubscriptionExpirationsCursor::<T>::put(block);
+            weight = weight.saturating_add(T::DbWeight::get().writes(1));
+        }
 
-            //
-            // == MUTATION SAFE ==
-            //
+        weight
+    }
 
-            // clear maps: (iterator are lazy and do nothing unless consumed)
-            for id in current_id.into()..next_id.into() {
-                <ChannelById<T>>::remove(T::ChannelId::from(id));
+    /// Clear the video asset slots holding the data objects (removed from the storage or
+    /// scheduled for removal).
+    pub fn remove_video_assets(data_object_ids: &BTreeSet<DataObjectId<T>>) {
+        for data_object_id in data_object_ids.iter() {
+            if let Some(video_id) = VideoByAsset::<T>::take(data_object_id) {
+                VideoById::<T>::mutate(video_id, |video| {
+                    video.assets = sp_std::mem::take(&mut video.assets)
+                        .into_iter()
+                        .filter(|(_, id)| id != data_object_id)
+                        .collect();
+                });
             }
+        }
+    }
+
+    /// Queue channel bag objects for deletion. Objects are removed in `on_initialize`.
+    fn schedule_assets_deletion(
+        channel_id: T::ChannelId,
+        deletion_prize_account_id: T::AccountId,
+        assets: BTreeSet<DataObjectId<T>>,
+        delete_bag: bool,
+        failed_attempts: u32,
+    ) {
+        // assets already scheduled by another deletion are left to it
+        let assets: BTreeSet<_> = assets
+            .into_iter()
+            .filter(|id| !PendingAssetsDeletionObjects::<T>::contains_key(id))
+            .collect();
+
+        if assets.is_empty() && !delete_bag {
+            return;
+        }
 
-            // edit the current id
-            <ChannelMigration<T>>::mutate(|value| value.current_id = next_id);
+        for id in assets.iter() {
+            PendingAssetsDeletionObjects::<T>::insert(id, ());
         }
+
+        let deletion_id = NextAssetsDeletionId::get();
+
+        PendingAssetsDeletionById::<T>::insert(
+            deletion_id,
+            AssetsDeletionRecord {
+                channel_id,
+                deletion_prize_account_id,
+                assets,
+                delete_bag,
+                failed_attempts,
+            },
+        );
+
+        NextAssetsDeletionId::put(deletion_id.saturating_add(1));
     }
 
-    /// Ensure Channel Migration Finished
+    /// Schedule the failed assets deletion again or drop it once `MaxAssetsDeletionAttempts` is
+    /// reached. Objects of the dropped deletion are kept in the storage.
+    fn retry_assets_deletion(
+        deletion: &AssetsDeletion<T>,
+        assets: BTreeSet<DataObjectId<T>>,
+        delete_bag: bool,
+    ) {
+        let failed_attempts = deletion.failed_attempts.saturating_add(1);
+
+        if failed_attempts >= T::MaxAssetsDeletionAttempts::get() {
+            Self::deposit_event(RawEvent::PendingAssetsDeletionFailed(
+                deletion.channel_id,
+                assets,
+                delete_bag,
+            ));
+        } else {
+            Self::schedule_assets_deletion(
+                deletion.channel_id,
+                deletion.deletion_prize_account_id.clone(),
+                assets,
+                delete_bag,
+                failed_attempts,
+            );
+        }
+    }
 
-    /// Ensure Video Migration Finished
-    fn is_migration_done() -> bool {
-        let MigrationConfigRecord {
-            current_id,
-            final_id,
-        } = <VideoMigration<T>>::get();
+    /// Delete at most `AssetsDeletionsEachBlock` data objects from the pending assets deletions.
+    /// Deletion prizes are paid once each batch is removed. Failed batches and bag deletions are
+    /// scheduled again at the end of the queue until `MaxAssetsDeletionAttempts` is reached.
+    /// Returns the weight of the performed deletions.
+    fn perform_pending_assets_deletions() -> Weight {
+        let mut budget = T::AssetsDeletionsEachBlock::get();
+        let mut weight: Weight = 0;
+        let mut deletion_id = CurrentAssetsDeletionId::get();
+        let next_deletion_id = NextAssetsDeletionId::get();
+
+        while budget > 0 && deletion_id < next_deletion_id {
+            let mut deletion = PendingAssetsDeletionById::<T>::get(deletion_id);
+            let bag_id = Self::bag_id_for_channel(&deletion.channel_id);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            if !deletion.assets.is_empty() {
+                let batch = deletion
+                    .assets
+                    .iter()
+                    .take(budget.saturated_into())
+                    .cloned()
+                    .collect::<BTreeSet<_>>();
+
+                deletion.assets = deletion.assets.difference(&batch).cloned().collect();
+                budget = budget.saturating_sub(batch.len() as u64);
+
+                for id in batch.iter() {
+                    PendingAssetsDeletionObjects::<T>::remove(id);
+                }
 
-        let video_migration_done = current_id == final_id;
+                // objects already removed by other means are skipped
+                let batch = batch
+                    .into_iter()
+                    .filter(|id| storage::DataObjectsById::<T>::contains_key(&bag_id, id))
+                    .collect::<BTreeSet<_>>();
+                weight = weight.saturating_add(
+                    T::DbWeight::get().reads_writes(batch.len() as u64, batch.len() as u64),
+                );
 
-        let MigrationConfigRecord {
-            current_id,
-            final_id,
-        } = <ChannelMigration<T>>::get();
+                if !batch.is_empty() {
+                    weight = weight.saturating_add(Self::delete_assets_weight(batch.len()));
+
+                    if Storage::<T>::delete_data_objects(
+                        deletion.deletion_prize_account_id.clone(),
+                        bag_id,
+                        batch.clone(),
+                    )
+                    .is_ok()
+                    {
+                        Self::deposit_event(RawEvent::PendingAssetsDeleted(
+                            deletion.channel_id,
+                            batch,
+                        ));
+                    } else {
+                        Self::retry_assets_deletion(&deletion, batch, false);
+                    }
+                }
+            }
+
+            if !deletion.assets.is_empty() {
+                PendingAssetsDeletionById::<T>::insert(deletion_id, deletion);
+                break;
+            }
+
+            if deletion.delete_bag {
+                budget = budget.saturating_sub(1);
+                weight = weight.saturating_add(Storage::<T>::delete_dynamic_bag_weight());
+                let dyn_bag =
+                    DynamicBagIdType::<T::MemberId, T::ChannelId>::Channel(deletion.channel_id);
+
+                if Storage::<T>::delete_dynamic_bag(
+                    deletion.deletion_prize_account_id.clone(),
+                    dyn_bag,
+                )
+                .is_ok()
+                {
+                    Self::deposit_event(RawEvent::ChannelBagDeleted(deletion.channel_id));
+                } else {
+                    // the bag might still hold the objects of a failed batch scheduled before
+                    Self::retry_assets_deletion(&deletion, BTreeSet::new(), true);
+                }
+            }
 
-        let channel_migration_done = current_id == final_id;
+            PendingAssetsDeletionById::<T>::remove(deletion_id);
+            deletion_id = deletion_id.saturating_add(1);
+        }
+
+        CurrentAssetsDeletionId::put(deletion_id);
+
+        weight
+    }
 
-        return video_migration_done && channel_migration_done;
+    // Ensures that none of the assets is scheduled for deletion.
+    fn ensure_assets_not_pending_deletion(assets: &BTreeSet<DataObjectId<T>>) -> DispatchResult {
+        ensure!(
+            !assets
+                .iter()
+                .any(|id| PendingAssetsDeletionObjects::<T>::contains_key(id)),
+            Error::<T>::AssetsPendingDeletion
+        );
+        Ok(())
     }
 
     /// Ensure `CuratorGroup` under given id exists
@@ -2308,6 +2731,107 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    // Ensure each slot is assigned to an uploaded asset and no slot is assigned twice
+    fn ensure_valid_video_asset_slots(
+        assets: Option<&StorageAssets<T>>,
+        asset_slots: &[VideoAssetSlot],
+    ) -> DispatchResult {
+        let uploaded_assets_number = assets.map_or(0, |assets| assets.object_creation_list.len());
+        ensure!(
+            asset_slots.len() <= uploaded_assets_number,
+            Error::<T>::VideoAssetSlotsExceedUploadedAssets
+        );
+
+        let unique_slots = asset_slots.iter().collect::<BTreeSet<_>>();
+        ensure!(
+            unique_slots.len() == asset_slots.len(),
+            Error::<T>::DuplicateVideoAssetSlot
+        );
+
+        let max_key_length = T::MaxVideoAssetSlotKeyLength::get() as usize;
+        ensure!(
+            asset_slots.iter().all(|slot| match slot {
+                VideoAssetSlot::Subtitle(key) | VideoAssetSlot::Rendition(key) => {
+                    key.len() <= max_key_length
+                }
+                VideoAssetSlot::Media | VideoAssetSlot::Thumbnail => true,
+            }),
+            Error::<T>::VideoAssetSlotKeyTooLong
+        );
+
+        Ok(())
+    }
+
+    // Ids of the uploaded assets by slot: the n-th slot is assigned to the n-th uploaded asset.
+    fn video_asset_slots_ids(
+        asset_slots: &[VideoAssetSlot],
+        uploaded_assets: &[DataObjectId<T>],
+    ) -> BTreeMap<VideoAssetSlot, DataObjectId<T>> {
+        asset_slots
+            .iter()
+            .cloned()
+            .zip(uploaded_assets.iter().cloned())
+            .collect()
+    }
+
+    // Assets removed by the video update: the requested ones and the ones replaced in their slots
+    fn video_assets_to_remove(
+        video: &Video<T>,
+        params: &VideoUpdateParameters<T>,
+    ) -> BTreeSet<DataObjectId<T>> {
+        let replaced_assets = params
+            .asset_slots
+            .iter()
+            .filter_map(|slot| video.assets.get(slot).cloned());
+
+        Self::channel_bag_assets(&video.in_channel, replaced_assets)
+            .union(&params.assets_to_remove)
+            .cloned()
+            .collect()
+    }
+
+    // Assets still stored in the channel bag: they may have been removed with a channel update
+    fn channel_bag_assets(
+        channel_id: &T::ChannelId,
+        assets: impl Iterator<Item = DataObjectId<T>>,
+    ) -> BTreeSet<DataObjectId<T>> {
+        let bag_id = Self::bag_id_for_channel(channel_id);
+
+        assets
+            .filter(|data_object_id| {
+                storage::DataObjectsById::<T>::contains_key(&bag_id, data_object_id)
+            })
+            .collect()
+    }
+
+    // Collaborator permissions required by the channel update
+    fn channel_update_permissions(
+        params: &ChannelUpdateParameters<T>,
+    ) -> Vec<ChannelCollaboratorPermission> {
+        let mut permissions = Vec::new();
+        if params.new_meta.is_some() {
+            permissions.push(ChannelCollaboratorPermission::UpdateMetadata);
+        }
+        if params.assets_to_upload.is_some() || !params.assets_to_remove.is_empty() {
+            permissions.push(ChannelCollaboratorPermission::ManageAssets);
+        }
+        permissions
+    }
+
+    // Collaborator permissions required by the video update
+    fn video_update_permissions(
+        params: &VideoUpdateParameters<T>,
+    ) -> Vec<ChannelCollaboratorPermission> {
+        let mut permissions = Vec::new();
+        if params.new_meta.is_some() || params.enable_comments.is_some() {
+            permissions.push(ChannelCollaboratorPermission::UpdateMetadata);
+        }
+        if params.assets_to_upload.is_some() || !params.assets_to_remove.is_empty() {
+            permissions.push(ChannelCollaboratorPermission::ManageAssets);
+        }
+        permissions
+    }
+
     fn validate_member_set(members: &BTreeSet<T::MemberId>) -> DispatchResult {
         // check if all members are valid
         let res = members
@@ -2332,13 +2856,6 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
-
-    // Reset Videos and Channels on runtime upgrade but preserving next ids and categories.
-    pub fn on_runtime_upgrade() {
-        // setting final index triggers migration
-        <VideoMigration<T>>::mutate(|config| config.final_id = <NextVideoId<T>>::get());
-        <ChannelMigration<T>>::mutate(|config| config.final_id = <NextChannelId<T>>::get());
-    }
 }
 
 decl_event!(
@@ -2383,6 +2900,8 @@ decl_event!(
         ModeratorSet = BTreeSet<<T as MembershipTypes>::MemberId>,
         Hash = <T as frame_system::Trait>::Hash,
         IsExtended = bool,
+        BlockNumber = <T as frame_system::Trait>::BlockNumber,
+        SubscriptionPlan = SubscriptionPlan<T>,
     {
         // Curators
         CuratorGroupCreated(CuratorGroupId),
@@ -2484,6 +3003,9 @@ decl_event!(
         ),
         PersonDeleted(ContentActor, PersonId),
         ChannelDeleted(ContentActor, ChannelId),
+        PendingAssetsDeleted(ChannelId, BTreeSet<DataObjectId>),
+        ChannelBagDeleted(ChannelId),
+        PendingAssetsDeletionFailed(ChannelId, BTreeSet<DataObjectId>, bool),
 
         // VideoPosts & Replies
         VideoPostCreated(VideoPost, VideoPostId),
@@ -2498,6 +3020,16 @@ decl_event!(
         ChannelRewardUpdated(Balance, ChannelId),
         MaxRewardUpdated(Balance),
         MinCashoutUpdated(Balance),
+
+        // Channel subscriptions
+        ChannelSubscriptionPlanUpdated(ContentActor, ChannelId, Option<SubscriptionPlan>),
+        ChannelSubscribed(
+            MemberId,
+            ChannelId,
+            BlockNumber, /* expires at */
+            CurrencyAmount,
+        ),
+        ChannelSubscriptionExpired(ChannelId, MemberId),
         // NFT auction
         AuctionStarted(ContentActor, VideoId, AuctionParams),
         NftIssued(
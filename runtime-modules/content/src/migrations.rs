@@ -0,0 +1,277 @@
+use crate::*;
+use frame_support::{storage::unhashed, StorageMap, StorageValue};
+
+impl<T: Trait> Module<T> {
+    /// Start the migration steps required by the stored storage version.
+    /// Large maps are migrated over multiple blocks in `on_initialize`.
+    pub fn on_runtime_upgrade() {
+        let version = StorageVersion::get();
+
+        if version < ContentStorageVersion::V1 {
+            // the reset removes every video and channel: nothing left to migrate
+            Self::start_videos_and_channels_reset();
+        } else {
+            if version < ContentStorageVersion::V2 {
+                Self::start_channel_collaborators_migration();
+            }
+
+            if version < ContentStorageVersion::V3 {
+                Self::start_video_assets_migration();
+            }
+        }
+
+        StorageVersion::put(ContentStorageVersion::LATEST);
+    }
+
+    // Reset Videos and Channels preserving next ids and categories.
+    fn start_videos_and_channels_reset() {
+        // setting final index triggers migration
+        <VideoMigration<T>>::mutate(|config| config.final_id = <NextVideoId<T>>::get());
+        <ChannelMigration<T>>::mutate(|config| config.final_id = <NextChannelId<T>>::get());
+    }
+
+    // Migrate channel collaborators to permission scoped collaborators.
+    fn start_channel_collaborators_migration() {
+        // channel ids start at 1
+        <ChannelCollaboratorsMigration<T>>::put(MigrationConfigRecord {
+            current_id: T::ChannelId::one(),
+            final_id: <NextChannelId<T>>::get(),
+        });
+    }
+
+    // Migrate videos to typed asset slots.
+    fn start_video_assets_migration() {
+        // video ids start at 1
+        <VideoAssetsMigration<T>>::put(MigrationConfigRecord {
+            current_id: T::VideoId::one(),
+            final_id: <NextVideoId<T>>::get(),
+        });
+    }
+
+    /// Make progress with the multi block migrations
+    pub(crate) fn perform_migrations() {
+        Self::perform_video_migration();
+        Self::perform_channel_migration();
+        Self::perform_channel_collaborators_migration();
+        Self::perform_video_assets_migration();
+    }
+
+    /// Migrate Videos
+    fn perform_video_migration() {
+        let MigrationConfigRecord {
+            current_id,
+            final_id,
+        } = <VideoMigration<T>>::get();
+
+        if current_id < final_id {
+            // perform migration procedure
+            let next_id = sp_std::cmp::min(
+                current_id + T::VideosMigrationsEachBlock::get().into(),
+                final_id,
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // clear maps: (iterator are lazy and do nothing unless consumed)
+            for id in current_id.into()..next_id.into() {
+                <VideoById<T>>::remove(T::VideoId::from(id));
+            }
+
+            // edit the current id
+            <VideoMigration<T>>::mutate(|value| value.current_id = next_id);
+        }
+    }
+
+    /// Migrate Channels
+    fn perform_channel_migration() {
+        let MigrationConfigRecord {
+            current_id,
+            final_id,
+        } = <ChannelMigration<T>>::get();
+
+        if current_id < final_id {
+            // perform migration procedure
+            let next_id = sp_std::cmp::min(
+                current_id + T::ChannelsMigrationsEachBlock::get().into(),
+                final_id,
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // clear maps: (iterator are lazy and do nothing unless consumed)
+            for id in current_id.into()..next_id.into() {
+                <ChannelById<T>>::remove(T::ChannelId::from(id));
+            }
+
+            // edit the current id
+            <ChannelMigration<T>>::mutate(|value| value.current_id = next_id);
+        }
+    }
+
+    /// Migrate channel collaborators: every existing collaborator keeps full rights.
+    fn perform_channel_collaborators_migration() {
+        let MigrationConfigRecord {
+            current_id,
+            final_id,
+        } = <ChannelCollaboratorsMigration<T>>::get();
+
+        if current_id < final_id {
+            let next_id = sp_std::cmp::min(
+                current_id + T::ChannelsMigrationsEachBlock::get().into(),
+                final_id,
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for id in current_id.into()..next_id.into() {
+                let channel_id = T::ChannelId::from(id);
+                let key = <ChannelById<T>>::hashed_key_for(channel_id);
+
+                // channels removed meanwhile are skipped
+                if let Some(channel) = unhashed::get::<ChannelV0<T>>(&key) {
+                    <ChannelById<T>>::insert(channel_id, channel.migrate());
+                }
+            }
+
+            <ChannelCollaboratorsMigration<T>>::mutate(|value| value.current_id = next_id);
+        }
+    }
+
+    /// Migrate video assets: existing videos start with no typed assets.
+    fn perform_video_assets_migration() {
+        let MigrationConfigRecord {
+            current_id,
+            final_id,
+        } = <VideoAssetsMigration<T>>::get();
+
+        if current_id < final_id {
+            let next_id = sp_std::cmp::min(
+                current_id + T::VideosMigrationsEachBlock::get().into(),
+                final_id,
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for id in current_id.into()..next_id.into() {
+                let video_id = T::VideoId::from(id);
+                let key = <VideoById<T>>::hashed_key_for(video_id);
+
+                // videos removed meanwhile are skipped
+                if let Some(video) = unhashed::get::<VideoV0<T>>(&key) {
+                    <VideoById<T>>::insert(video_id, video.migrate());
+                }
+            }
+
+            <VideoAssetsMigration<T>>::mutate(|value| value.current_id = next_id);
+        }
+    }
+
+    /// Ensure Video, Channel, Channel Collaborators and Video Assets Migrations Finished
+    pub(crate) fn is_migration_done() -> bool {
+        let video_migration_done = Self::is_migration_config_done(<VideoMigration<T>>::get());
+        let channel_migration_done = Self::is_migration_config_done(<ChannelMigration<T>>::get());
+        let collaborators_migration_done =
+            Self::is_migration_config_done(<ChannelCollaboratorsMigration<T>>::get());
+        let video_assets_migration_done =
+            Self::is_migration_config_done(<VideoAssetsMigration<T>>::get());
+
+        video_migration_done
+            && channel_migration_done
+            && collaborators_migration_done
+            && video_assets_migration_done
+    }
+
+    fn is_migration_config_done<Id: PartialEq>(config: MigrationConfigRecord<Id>) -> bool {
+        config.current_id == config.final_id
+    }
+}
+
+// Try-runtime style checks: run them around `on_runtime_upgrade` and the multi block migrations.
+#[cfg(any(feature = "try-runtime", test))]
+impl<T: Trait> Module<T> {
+    /// Checks the storage can be migrated from its stored version.
+    pub fn pre_upgrade_checks() -> Result<(), &'static str> {
+        if !Self::is_migration_done() {
+            return Err("A migration is still in progress");
+        }
+
+        if StorageVersion::get() < ContentStorageVersion::V2 {
+            let all_channels_decode = Self::existing_channel_keys()
+                .iter()
+                .all(|key| unhashed::get::<ChannelV0<T>>(key).is_some());
+
+            if !all_channels_decode {
+                return Err("Channel cannot be decoded with the pre-V2 layout");
+            }
+        }
+
+        if StorageVersion::get() < ContentStorageVersion::V3 {
+            let all_videos_decode = Self::existing_video_keys()
+                .iter()
+                .all(|key| unhashed::get::<VideoV0<T>>(key).is_some());
+
+            if !all_videos_decode {
+                return Err("Video cannot be decoded with the pre-V3 layout");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the storage once the migrations started by `on_runtime_upgrade` are done.
+    pub fn post_upgrade_checks() -> Result<(), &'static str> {
+        if StorageVersion::get() != ContentStorageVersion::LATEST {
+            return Err("Storage version was not updated");
+        }
+
+        if !Self::is_migration_done() {
+            return Err("A migration is still in progress");
+        }
+
+        let all_channels_decode = Self::existing_channel_keys()
+            .iter()
+            .all(|key| unhashed::get::<Channel<T>>(key).is_some());
+
+        if !all_channels_decode {
+            return Err("Channel cannot be decoded with the latest layout");
+        }
+
+        let all_videos_decode = Self::existing_video_keys()
+            .iter()
+            .all(|key| unhashed::get::<Video<T>>(key).is_some());
+
+        if !all_videos_decode {
+            return Err("Video cannot be decoded with the latest layout");
+        }
+
+        Ok(())
+    }
+
+    // Storage keys of the existing channels: does not decode the values
+    fn existing_channel_keys() -> Vec<Vec<u8>> {
+        let final_id: u64 = <NextChannelId<T>>::get().into();
+
+        (0..final_id)
+            .map(|id| <ChannelById<T>>::hashed_key_for(T::ChannelId::from(id)))
+            .filter(|key| unhashed::exists(key))
+            .collect()
+    }
+
+    // Storage keys of the existing videos: does not decode the values
+    fn existing_video_keys() -> Vec<Vec<u8>> {
+        let final_id: u64 = <NextVideoId<T>>::get().into();
+
+        (0..final_id)
+            .map(|id| <VideoById<T>>::hashed_key_for(T::VideoId::from(id)))
+            .filter(|key| unhashed::exists(key))
+            .collect()
+    }
+}
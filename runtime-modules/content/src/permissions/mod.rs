@@ -214,29 +214,39 @@ pub fn ensure_actor_authorized_to_update_channel_assets<T: Trait>(
     sender: &T::AccountId,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
     channel: &Channel<T>,
+    required_permissions: &[ChannelCollaboratorPermission],
 ) -> DispatchResult {
     // Only owner of a channel can update and delete channel assets.
     // Lead can update and delete curator group owned channel assets.
+    // Collaborators can perform the actions allowed by their permission set.
     ensure_actor_auth_success::<T>(&sender, actor)?;
     match actor {
         ContentActor::Lead => ensure_channel_is_owned_by_curators::<T>(&channel.owner),
         ContentActor::Curator(..) => ensure_actor_is_channel_owner::<T>(actor, &channel.owner),
         ContentActor::Member(member_id) => {
-            let is_collaborator = ensure_member_is_collaborator::<T>(member_id, channel);
+            let is_collaborator =
+                ensure_member_is_collaborator::<T>(member_id, channel, required_permissions);
             let is_owner = ensure_actor_is_channel_owner::<T>(actor, &channel.owner);
             is_owner.or(is_collaborator)
         }
     }
 }
 
-// ensure member id is in the channel collaborator set
+// ensure member id is in the channel collaborators with all the required permissions
 pub fn ensure_member_is_collaborator<T: Trait>(
     member_id: &T::MemberId,
     channel: &Channel<T>,
+    required_permissions: &[ChannelCollaboratorPermission],
 ) -> DispatchResult {
+    let permissions = channel
+        .collaborators
+        .get(member_id)
+        .ok_or(Error::<T>::ActorNotAuthorized)?;
     ensure!(
-        channel.collaborators.contains(member_id),
-        Error::<T>::ActorNotAuthorized
+        required_permissions
+            .iter()
+            .all(|permission| permissions.contains(permission)),
+        Error::<T>::CollaboratorPermissionMissing
     );
     Ok(())
 }
@@ -364,20 +374,20 @@ pub fn ensure_actor_authorized_to_add_comment<T: Trait>(
 pub fn ensure_actor_authorized_to_add_video_post<T: Trait>(
     sender: &T::AccountId,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-    owner: &ChannelOwner<T::MemberId, T::CuratorGroupId>,
+    channel: &Channel<T>,
 ) -> DispatchResult {
     ensure_actor_auth_success::<T>(sender, actor)?;
-    ensure_actor_is_channel_owner::<T>(actor, owner)
+    ensure_actor_can_manage_video_posts::<T>(actor, channel)
 }
 
 // Ensure actor can edit a video post description
 pub fn ensure_actor_authorized_to_edit_video_post<T: Trait>(
     sender: &T::AccountId,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-    owner: &ChannelOwner<T::MemberId, T::CuratorGroupId>,
+    channel: &Channel<T>,
 ) -> DispatchResult {
     ensure_actor_auth_success::<T>(sender, actor)?;
-    ensure_actor_is_channel_owner::<T>(actor, owner)
+    ensure_actor_can_manage_video_posts::<T>(actor, channel)
 }
 
 // Ensure actor can edit a post comment text
@@ -415,7 +425,25 @@ pub fn ensure_actor_authorized_to_remove_video_post<T: Trait>(
     channel: &Channel<T>,
 ) -> DispatchResult {
     ensure_actor_auth_success::<T>(sender, actor)?;
-    ensure_actor_is_channel_owner::<T>(actor, &channel.owner)
+    ensure_actor_can_manage_video_posts::<T>(actor, channel)
+}
+
+// Ensure actor is the channel owner or a collaborator allowed to manage video posts
+pub fn ensure_actor_can_manage_video_posts<T: Trait>(
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> DispatchResult {
+    let is_owner = ensure_actor_is_channel_owner::<T>(actor, &channel.owner);
+    if let ContentActor::Member(member_id) = actor {
+        let is_collaborator = ensure_member_is_collaborator::<T>(
+            member_id,
+            channel,
+            &[ChannelCollaboratorPermission::ManageVideoPosts],
+        );
+        is_owner.or(is_collaborator)
+    } else {
+        is_owner
+    }
 }
 
 // Ensure actor is a moderator
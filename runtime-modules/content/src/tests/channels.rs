@@ -4,7 +4,9 @@ use super::curators;
 use super::fixtures::*;
 use super::mock::*;
 use crate::*;
+use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_ok};
+use storage::ModuleAccount;
 
 #[test]
 fn channel_censoring() {
@@ -20,7 +22,7 @@ fn channel_censoring() {
                 assets: None,
                 meta: None,
                 reward_account: None,
-                collaborators: BTreeSet::new(),
+                collaborators: BTreeMap::new(),
                 moderators: BTreeSet::new(),
             }
         ));
@@ -98,7 +100,7 @@ fn channel_censoring() {
                 assets: None,
                 meta: None,
                 reward_account: None,
-                collaborators: BTreeSet::new(),
+                collaborators: BTreeMap::new(),
                 moderators: BTreeSet::new(),
             }
         ));
@@ -250,6 +252,49 @@ fn unsuccessful_channel_creation_with_invalid_expected_data_size_fee() {
     })
 }
 
+#[test]
+fn channel_creation_expected_data_size_fee_uses_channel_bag_fee_schedule() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        let channel_bag_fee = 10;
+        assert_ok!(Storage::<Test>::update_data_size_fee_schedule(
+            frame_system::RawOrigin::Root.into(),
+            storage::DataSizeFeeCategory::Dynamic(storage::DynamicBagType::Channel),
+            Some(storage::DataSizeFeeSchedule {
+                per_megabyte_fee: channel_bag_fee,
+                object_class_fees: Default::default(),
+            }),
+        ));
+
+        CreateChannelFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .with_assets(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_objects_helper(),
+            })
+            .call_and_assert(Err(storage::Error::<Test>::DataSizeFeeChanged.into()));
+
+        assert_ok!(Content::create_channel(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            ChannelCreationParameters::<Test> {
+                assets: Some(StorageAssets::<Test> {
+                    expected_data_size_fee: channel_bag_fee,
+                    object_creation_list: create_data_objects_helper(),
+                }),
+                meta: None,
+                reward_account: None,
+                collaborators: BTreeMap::new(),
+                moderators: BTreeSet::new(),
+            },
+        ));
+    })
+}
+
 #[test]
 fn unsuccessful_channel_creation_with_insufficient_balance() {
     with_default_mock_builder(|| {
@@ -281,6 +326,9 @@ fn unsuccessful_channel_creation_with_no_bucket_with_sufficient_size_available()
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: STORAGE_BUCKET_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: vec![1u8],
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
                 }],
             })
             .call_and_assert(Err(
@@ -307,6 +355,9 @@ fn unsuccessful_channel_creation_with_no_bucket_with_sufficient_number_available
                     .map(|_| DataObjectCreationParameters {
                         size: 1,
                         ipfs_content_id: vec![1u8],
+                        content_root: None,
+                        parts: Vec::new(),
+                        class: None,
                     })
                     .collect(),
             })
@@ -330,6 +381,9 @@ fn unsuccessful_channel_creation_with_data_limits_exceeded() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: VOUCHER_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: vec![1u8],
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
                 }],
             })
             .call_and_assert(Err(storage::Error::<Test>::MaxDataObjectSizeExceeded.into()));
@@ -344,7 +398,11 @@ fn successful_channel_creation_with_collaborators_set() {
         CreateChannelFixture::default()
             .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
             .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
-            .with_collaborators(vec![COLLABORATOR_MEMBER_ID].into_iter().collect())
+            .with_collaborators(
+                vec![(COLLABORATOR_MEMBER_ID, ChannelCollaboratorPermission::all())]
+                    .into_iter()
+                    .collect(),
+            )
             .call_and_assert(Ok(()));
 
         let default_curator_group_id = curators::add_curator_to_new_group(DEFAULT_CURATOR_ID);
@@ -354,7 +412,11 @@ fn successful_channel_creation_with_collaborators_set() {
                 default_curator_group_id,
                 DEFAULT_CURATOR_ID,
             ))
-            .with_collaborators(vec![COLLABORATOR_MEMBER_ID].into_iter().collect())
+            .with_collaborators(
+                vec![(COLLABORATOR_MEMBER_ID, ChannelCollaboratorPermission::all())]
+                    .into_iter()
+                    .collect(),
+            )
             .call_and_assert(Ok(()));
     })
 }
@@ -366,7 +428,14 @@ fn unsuccessful_channel_creation_with_invalid_collaborators_set() {
         CreateChannelFixture::default()
             .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
             .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
-            .with_collaborators(vec![COLLABORATOR_MEMBER_ID + 100].into_iter().collect())
+            .with_collaborators(
+                vec![(
+                    COLLABORATOR_MEMBER_ID + 100,
+                    ChannelCollaboratorPermission::all(),
+                )]
+                .into_iter()
+                .collect(),
+            )
             .call_and_assert(Err(Error::<Test>::InvalidMemberProvided.into()));
     })
 }
@@ -503,6 +572,43 @@ fn successful_channel_update_with_assets_removed_by_collaborator() {
     })
 }
 
+#[test]
+fn unsuccessful_channel_update_with_assets_uploaded_by_collaborator_without_permission() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        increase_account_balance_helper(COLLABORATOR_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        let channel_id = Content::next_channel_id();
+        CreateChannelFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .with_collaborators(
+                vec![(
+                    COLLABORATOR_MEMBER_ID,
+                    vec![ChannelCollaboratorPermission::UpdateMetadata]
+                        .into_iter()
+                        .collect(),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .call_and_assert(Ok(()));
+
+        UpdateChannelFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .with_channel_id(channel_id)
+            .with_assets_to_upload(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_objects_helper(),
+            })
+            .call_and_assert(Err(Error::<Test>::CollaboratorPermissionMissing.into()));
+    })
+}
+
 #[test]
 fn successful_channel_update_with_assets_uploaded_by_member() {
     with_default_mock_builder(|| {
@@ -846,7 +952,7 @@ fn successful_channel_update_with_collaborators_set_updated_by_member() {
         UpdateChannelFixture::default()
             .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
             .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
-            .with_collaborators(BTreeSet::new())
+            .with_collaborators(BTreeMap::new())
             .call_and_assert(Ok(()));
     })
 }
@@ -863,7 +969,7 @@ fn unsuccessful_channel_update_with_collaborators_set_updated_by_unauthorized_me
         UpdateChannelFixture::default()
             .with_sender(UNAUTHORIZED_MEMBER_ACCOUNT_ID)
             .with_actor(ContentActor::Member(UNAUTHORIZED_MEMBER_ID))
-            .with_collaborators(BTreeSet::new())
+            .with_collaborators(BTreeMap::new())
             .call_and_assert(Err(Error::<Test>::ActorNotAuthorized.into()));
     })
 }
@@ -884,7 +990,7 @@ fn successful_channel_update_with_collaborators_set_updated_by_curator() {
                 default_curator_group_id,
                 DEFAULT_CURATOR_ID,
             ))
-            .with_collaborators(BTreeSet::new())
+            .with_collaborators(BTreeMap::new())
             .call_and_assert(Ok(()));
     })
 }
@@ -906,7 +1012,7 @@ fn unsuccessful_channel_update_with_collaborators_set_updated_by_unauthorized_cu
                 unauthorized_curator_group_id,
                 UNAUTHORIZED_CURATOR_ID,
             ))
-            .with_collaborators(BTreeSet::new())
+            .with_collaborators(BTreeMap::new())
             .call_and_assert(Err(Error::<Test>::ActorNotAuthorized.into()));
     })
 }
@@ -923,7 +1029,7 @@ fn unsuccessful_channel_update_with_collaborators_set_updated_by_collaborator()
         UpdateChannelFixture::default()
             .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
             .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
-            .with_collaborators(BTreeSet::new())
+            .with_collaborators(BTreeMap::new())
             .call_and_assert(Err(Error::<Test>::ActorNotAuthorized.into()));
     })
 }
@@ -940,7 +1046,7 @@ fn unsuccessful_member_channel_update_with_collaborators_set_updated_by_lead() {
         UpdateChannelFixture::default()
             .with_sender(LEAD_ACCOUNT_ID)
             .with_actor(ContentActor::Lead)
-            .with_collaborators(BTreeSet::new())
+            .with_collaborators(BTreeMap::new())
             .call_and_assert(Err(Error::<Test>::ActorNotAuthorized.into()));
     })
 }
@@ -957,7 +1063,7 @@ fn successful_curator_channel_update_with_collaborators_set_updated_by_lead() {
         UpdateChannelFixture::default()
             .with_sender(LEAD_ACCOUNT_ID)
             .with_actor(ContentActor::Lead)
-            .with_collaborators(BTreeSet::new())
+            .with_collaborators(BTreeMap::new())
             .call_and_assert(Ok(()));
     })
 }
@@ -974,7 +1080,7 @@ fn unsuccessful_curator_channel_update_with_collaborators_set_updated_by_invalid
         UpdateChannelFixture::default()
             .with_sender(LEAD_ACCOUNT_ID + 100)
             .with_actor(ContentActor::Lead)
-            .with_collaborators(BTreeSet::new())
+            .with_collaborators(BTreeMap::new())
             .call_and_assert(Err(Error::<Test>::LeadAuthFailed.into()));
     })
 }
@@ -1141,6 +1247,9 @@ fn unsuccessful_channel_update_with_data_limits_exceeded() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: VOUCHER_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: vec![1u8],
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
                 }],
             })
             .call_and_assert(Err(storage::Error::<Test>::MaxDataObjectSizeExceeded.into()));
@@ -1178,7 +1287,14 @@ fn unsuccessful_channel_update_with_invalid_collaborators_set() {
         UpdateChannelFixture::default()
             .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
             .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
-            .with_collaborators(vec![COLLABORATOR_MEMBER_ID + 100].into_iter().collect())
+            .with_collaborators(
+                vec![(
+                    COLLABORATOR_MEMBER_ID + 100,
+                    ChannelCollaboratorPermission::all(),
+                )]
+                .into_iter()
+                .collect(),
+            )
             .call_and_assert(Err(Error::<Test>::InvalidMemberProvided.into()));
     })
 }
@@ -1242,6 +1358,9 @@ fn unsuccessful_channel_update_with_no_bucket_with_sufficient_object_size_limit(
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: STORAGE_BUCKET_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: vec![1u8],
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
                 }],
             })
             .call_and_assert(Err(
@@ -1274,6 +1393,9 @@ fn unsuccessful_channel_update_with_no_bucket_with_sufficient_object_number_limi
                     .map(|_| DataObjectCreationParameters {
                         size: 1,
                         ipfs_content_id: vec![1u8],
+                        content_root: None,
+                        parts: Vec::new(),
+                        class: None,
                     })
                     .collect(),
             })
@@ -1494,6 +1616,177 @@ fn unsuccessful_channel_deletion_with_invalid_bag_size() {
     })
 }
 
+#[test]
+fn successful_channel_deletion_with_assets_removed_over_multiple_blocks() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel();
+
+        let channel_id = Content::next_channel_id() - 1;
+        let bag_id = Content::bag_id_for_channel(&channel_id);
+
+        // ensure deletion cannot be completed within a single block
+        assert!(DATA_OBJECTS_NUMBER > ASSETS_DELETIONS_PER_BLOCK);
+
+        assert_ok!(Content::delete_channel(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id,
+            DATA_OBJECTS_NUMBER,
+        ));
+
+        // channel is gone, assets are still in storage
+        assert!(!ChannelById::<Test>::contains_key(channel_id));
+        assert_eq!(
+            storage::Bags::<Test>::get(&bag_id).objects_number,
+            DATA_OBJECTS_NUMBER
+        );
+
+        // first batch
+        run_to_block(2);
+        assert_eq!(
+            storage::Bags::<Test>::get(&bag_id).objects_number,
+            DATA_OBJECTS_NUMBER - ASSETS_DELETIONS_PER_BLOCK
+        );
+        assert!(Content::current_assets_deletion_id() < Content::next_assets_deletion_id());
+
+        run_to_pending_assets_deletions_end();
+
+        assert!(!storage::Bags::<Test>::contains_key(&bag_id));
+        assert!(!PendingAssetsDeletionById::<Test>::contains_key(
+            Content::current_assets_deletion_id() - 1
+        ));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelBagDeleted(channel_id))
+        );
+    })
+}
+
+#[test]
+fn channel_deletion_skips_assets_already_pending_deletion() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        let channel_id = Content::next_channel_id() - 1;
+        let video_id = Content::next_video_id() - 1;
+        let bag_id = Content::bag_id_for_channel(&channel_id);
+        let assets = Storage::<Test>::get_data_objects_id(&bag_id);
+        let video_assets: BTreeSet<_> = assets.iter().take(2).cloned().collect();
+
+        assert_ok!(Content::delete_video(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+            video_assets.clone(),
+        ));
+
+        // assets pending deletion cannot be removed by other means
+        UpdateChannelFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .with_channel_id(channel_id)
+            .with_assets_to_remove(video_assets.clone())
+            .call_and_assert(Err(Error::<Test>::AssetsPendingDeletion.into()));
+
+        assert_ok!(Content::delete_channel(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id,
+            assets.len() as u64,
+        ));
+
+        let channel_deletion =
+            Content::pending_assets_deletion_by_id(Content::next_assets_deletion_id() - 1);
+        assert!(channel_deletion.assets.is_disjoint(&video_assets));
+        assert_eq!(
+            channel_deletion.assets.len(),
+            assets.len() - video_assets.len()
+        );
+
+        run_to_pending_assets_deletions_end();
+
+        assert!(!storage::Bags::<Test>::contains_key(&bag_id));
+        assert!(!assets
+            .iter()
+            .any(|id| PendingAssetsDeletionObjects::<Test>::contains_key(id)));
+    })
+}
+
+#[test]
+fn failing_pending_assets_deletion_is_dropped_after_max_attempts() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel();
+
+        let channel_id = Content::next_channel_id() - 1;
+        let bag_id = Content::bag_id_for_channel(&channel_id);
+        let assets = Storage::<Test>::get_data_objects_id(&bag_id);
+
+        assert_ok!(Content::delete_channel(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id,
+            assets.len() as u64,
+        ));
+
+        // deletion prizes can't be paid: every deletion attempt fails
+        let storage_treasury = storage::StorageTreasury::<Test>::module_account_id();
+        Balances::<Test>::make_free_balance_be(&storage_treasury, 0);
+
+        run_to_pending_assets_deletions_end();
+
+        let mut dropped_assets = BTreeSet::new();
+        let mut dropped_bag_deletions = 0;
+        for record in System::events() {
+            match record.event {
+                MetaEvent::content(RawEvent::PendingAssetsDeletionFailed(
+                    failed_channel_id,
+                    failed_assets,
+                    delete_bag,
+                )) => {
+                    assert_eq!(failed_channel_id, channel_id);
+                    dropped_assets.extend(failed_assets);
+                    if delete_bag {
+                        dropped_bag_deletions += 1;
+                    }
+                }
+                MetaEvent::content(RawEvent::PendingAssetsDeleted(..))
+                | MetaEvent::content(RawEvent::ChannelBagDeleted(..)) => {
+                    panic!("Unexpected assets deletion")
+                }
+                _ => {}
+            }
+        }
+
+        assert_eq!(dropped_assets, assets);
+        assert_eq!(dropped_bag_deletions, 1);
+
+        // objects and bag are kept in the storage, nothing is left in the queue
+        assert_eq!(
+            storage::Bags::<Test>::get(&bag_id).objects_number,
+            assets.len() as u64
+        );
+        assert!(!assets
+            .iter()
+            .any(|id| PendingAssetsDeletionObjects::<Test>::contains_key(id)));
+        assert_eq!(
+            Content::current_assets_deletion_id(),
+            Content::next_assets_deletion_id()
+        );
+    })
+}
+
 #[test]
 fn unsuccessful_channel_creation_with_invalid_moderator_set() {
     with_default_mock_builder(|| {
@@ -25,7 +25,7 @@ impl CreateChannelFixture {
                 assets: None,
                 meta: None,
                 reward_account: None,
-                collaborators: BTreeSet::new(),
+                collaborators: BTreeMap::new(),
                 moderators: BTreeSet::new(),
             },
         }
@@ -49,7 +49,10 @@ impl CreateChannelFixture {
         }
     }
 
-    pub fn with_collaborators(self, collaborators: BTreeSet<MemberId>) -> Self {
+    pub fn with_collaborators(
+        self,
+        collaborators: BTreeMap<MemberId, ChannelCollaboratorPermissions>,
+    ) -> Self {
         Self {
             params: ChannelCreationParameters::<Test> {
                 collaborators: collaborators,
@@ -174,6 +177,7 @@ impl CreateVideoFixture {
                 assets: None,
                 meta: None,
                 enable_comments: true,
+                asset_slots: Vec::new(),
             },
             channel_id: ChannelId::one(), // channel index starts at 1
         }
@@ -201,6 +205,16 @@ impl CreateVideoFixture {
         }
     }
 
+    pub fn with_asset_slots(self, asset_slots: Vec<VideoAssetSlot>) -> Self {
+        Self {
+            params: VideoCreationParameters::<Test> {
+                asset_slots,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = Origin::signed(self.sender.clone());
         let balance_pre = Balances::<Test>::usable_balance(self.sender);
@@ -253,13 +267,26 @@ impl CreateVideoFixture {
 
                 assert_eq!(
                     balance_pre.saturating_sub(balance_post),
-                    bag_deletion_prize.saturating_add(objects_deletion_prize),
+                    bag_deletion_prize
+                        .saturating_add(objects_deletion_prize)
+                        .saturating_add(assets.expected_data_size_fee),
                 );
 
                 assert!((beg_obj_id..end_obj_id).all(|id| {
                     storage::DataObjectsById::<Test>::contains_key(&channel_bag_id, id)
                 }));
             }
+
+            // slots are assigned in upload order
+            assert_eq!(
+                Content::video_by_id(video_id).assets,
+                self.params
+                    .asset_slots
+                    .iter()
+                    .cloned()
+                    .zip(beg_obj_id..end_obj_id)
+                    .collect::<BTreeMap<_, _>>()
+            );
         } else {
             assert!(!VideoById::<Test>::contains_key(&video_id));
 
@@ -331,7 +358,10 @@ impl UpdateChannelFixture {
         }
     }
 
-    pub fn with_collaborators(self, collaborators: BTreeSet<MemberId>) -> Self {
+    pub fn with_collaborators(
+        self,
+        collaborators: BTreeMap<MemberId, ChannelCollaboratorPermissions>,
+    ) -> Self {
         Self {
             params: ChannelUpdateParameters::<Test> {
                 collaborators: Some(collaborators),
@@ -476,6 +506,7 @@ impl UpdateVideoFixture {
                 assets_to_remove: BTreeSet::new(),
                 enable_comments: None,
                 new_meta: None,
+                asset_slots: Vec::new(),
             },
         }
     }
@@ -511,6 +542,17 @@ impl UpdateVideoFixture {
             ..self
         }
     }
+
+    pub fn with_asset_slots(self, asset_slots: Vec<VideoAssetSlot>) -> Self {
+        Self {
+            params: VideoUpdateParameters::<Test> {
+                asset_slots,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = Origin::signed(self.sender.clone());
         let balance_pre = Balances::<Test>::usable_balance(self.sender);
@@ -518,6 +560,15 @@ impl UpdateVideoFixture {
         let bag_id_for_channel = Content::bag_id_for_channel(&video_pre.in_channel);
         let beg_obj_id = storage::NextDataObjectId::<Test>::get();
 
+        // assets replaced in their slots are removed too
+        let assets_to_remove = self
+            .params
+            .asset_slots
+            .iter()
+            .filter_map(|slot| video_pre.assets.get(slot).cloned())
+            .chain(self.params.assets_to_remove.iter().cloned())
+            .collect::<BTreeSet<_>>();
+
         let deletion_prize_deposited =
             self.params
                 .assets_to_upload
@@ -533,9 +584,8 @@ impl UpdateVideoFixture {
                         })
                 });
 
-        let deletion_prize_withdrawn = if !self.params.assets_to_remove.is_empty() {
-            self.params
-                .assets_to_remove
+        let deletion_prize_withdrawn = if !assets_to_remove.is_empty() {
+            assets_to_remove
                 .iter()
                 .fold(BalanceOf::<Test>::zero(), |acc, obj_id| {
                     acc + storage::DataObjectsById::<Test>::get(&bag_id_for_channel, obj_id)
@@ -580,9 +630,21 @@ impl UpdateVideoFixture {
                     }));
                 }
 
-                assert!(!self.params.assets_to_remove.iter().any(|obj_id| {
+                assert!(!assets_to_remove.iter().any(|obj_id| {
                     storage::DataObjectsById::<Test>::contains_key(&bag_id_for_channel, obj_id)
                 }));
+
+                // removed assets leave their slots, uploaded assets take theirs
+                assert!(video_post
+                    .assets
+                    .values()
+                    .all(|obj_id| !assets_to_remove.contains(obj_id)));
+                assert!(self
+                    .params
+                    .asset_slots
+                    .iter()
+                    .zip(beg_obj_id..end_obj_id)
+                    .all(|(slot, obj_id)| video_post.assets.get(slot) == Some(&obj_id)));
             }
             Err(err) => {
                 assert_eq!(video_pre, video_post);
@@ -673,6 +735,12 @@ impl DeleteChannelFixture {
                     ))
                 );
 
+                // assets are removed lazily
+                assert_eq!(balance_pre, balance_post);
+                assert!(!<ChannelById<Test>>::contains_key(&self.channel_id));
+                run_to_pending_assets_deletions_end();
+                let balance_post = Balances::<Test>::usable_balance(self.sender);
+
                 let deletion_prize = bag_deletion_prize.saturating_add(objects_deletion_prize);
 
                 assert_eq!(balance_post.saturating_sub(balance_pre), deletion_prize,);
@@ -1025,8 +1093,16 @@ impl DeleteVideoFixture {
         let balance_pre = Balances::<Test>::usable_balance(self.sender);
         let video_pre = <VideoById<Test>>::get(&self.video_id);
         let channel_bag_id = Content::bag_id_for_channel(&video_pre.in_channel);
+
+        // assets in the video slots are removed too
+        let assets_to_remove = video_pre
+            .assets
+            .values()
+            .cloned()
+            .chain(self.assets_to_remove.iter().cloned())
+            .collect::<BTreeSet<_>>();
         let deletion_prize =
-            self.assets_to_remove
+            assets_to_remove
                 .iter()
                 .fold(BalanceOf::<Test>::zero(), |acc, obj_id| {
                     acc + storage::DataObjectsById::<Test>::get(&channel_bag_id, obj_id)
@@ -1051,9 +1127,15 @@ impl DeleteVideoFixture {
                     MetaEvent::content(RawEvent::VideoDeleted(self.actor.clone(), self.video_id,))
                 );
 
+                assert!(!<VideoById<Test>>::contains_key(&self.video_id));
+
+                // assets are removed lazily
+                run_to_pending_assets_deletions_end();
+                let balance_post = Balances::<Test>::usable_balance(self.sender);
+
                 assert_eq!(balance_post.saturating_sub(balance_pre), deletion_prize);
 
-                assert!(!self.assets_to_remove.iter().any(|obj_id| {
+                assert!(!assets_to_remove.iter().any(|obj_id| {
                     storage::DataObjectsById::<Test>::contains_key(&channel_bag_id, obj_id)
                 }));
 
@@ -1366,6 +1448,9 @@ pub fn create_data_object_candidates_helper(
         .map(|_| DataObjectCreationParameters {
             size: DEFAULT_OBJECT_SIZE,
             ipfs_content_id: vec![1u8],
+            content_root: None,
+            parts: Vec::new(),
+            class: None,
         })
         .collect()
 }
@@ -1407,7 +1492,11 @@ pub fn create_default_member_owned_channel() {
             object_creation_list: create_data_objects_helper(),
         })
         .with_reward_account(DEFAULT_MEMBER_ACCOUNT_ID)
-        .with_collaborators(vec![COLLABORATOR_MEMBER_ID].into_iter().collect())
+        .with_collaborators(
+            vec![(COLLABORATOR_MEMBER_ID, ChannelCollaboratorPermission::all())]
+                .into_iter()
+                .collect(),
+        )
         .with_moderators(vec![DEFAULT_MODERATOR_ID].into_iter().collect())
         .call_and_assert(Ok(()));
 }
@@ -1422,7 +1511,11 @@ pub fn create_default_curator_owned_channel() {
             object_creation_list: create_data_objects_helper(),
         })
         .with_reward_account(DEFAULT_CURATOR_ACCOUNT_ID)
-        .with_collaborators(vec![COLLABORATOR_MEMBER_ID].into_iter().collect())
+        .with_collaborators(
+            vec![(COLLABORATOR_MEMBER_ID, ChannelCollaboratorPermission::all())]
+                .into_iter()
+                .collect(),
+        )
         .with_moderators(vec![DEFAULT_MODERATOR_ID].into_iter().collect())
         .call_and_assert(Ok(()));
 }
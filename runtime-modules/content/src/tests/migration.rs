@@ -8,6 +8,7 @@ fn assert_video_and_channel_existrinsics_with(result: DispatchResult) {
         assets: None,
         meta: None,
         enable_comments: true,
+        asset_slots: Vec::new(),
     };
 
     // attempt to create valid channel if result is ok, otherwise id does not matter
@@ -32,7 +33,7 @@ fn assert_video_and_channel_existrinsics_with(result: DispatchResult) {
                 assets: None,
                 meta: Some(vec![]),
                 reward_account: None,
-                collaborators: BTreeSet::new(),
+                collaborators: BTreeMap::new(),
                 moderators: BTreeSet::new(),
             },
         ),
@@ -73,6 +74,7 @@ fn assert_video_and_channel_existrinsics_with(result: DispatchResult) {
                 new_meta: Some(vec![]),
                 assets_to_remove: BTreeSet::new(),
                 enable_comments: None,
+                asset_slots: Vec::new(),
             },
         ),
         result
@@ -137,6 +139,9 @@ fn migration_test() {
         println!("last migration block:\t{:?}", last_migration_block);
         assert!(last_migration_block > START_MIGRATION_AT_BLOCK);
 
+        // storage layout before versioning was introduced
+        StorageVersion::put(ContentStorageVersion::V0);
+
         // triggering migration
         Content::on_runtime_upgrade();
 
@@ -163,3 +168,138 @@ fn migration_test() {
         assert_video_and_channel_existrinsics_with(Ok(()));
     })
 }
+
+#[test]
+fn storage_version_updated_on_runtime_upgrade() {
+    with_default_mock_builder(|| {
+        run_to_block(START_MIGRATION_AT_BLOCK);
+
+        // storage layout before versioning was introduced
+        StorageVersion::put(ContentStorageVersion::V0);
+        assert_eq!(Content::pre_upgrade_checks(), Ok(()));
+
+        Content::on_runtime_upgrade();
+        assert_eq!(Content::storage_version(), ContentStorageVersion::LATEST);
+
+        // running the upgrade again does not start any migration
+        run_to_block(START_MIGRATION_AT_BLOCK + 1);
+        let channel_migration = Content::channel_migration();
+        Content::on_runtime_upgrade();
+        assert_eq!(Content::channel_migration(), channel_migration);
+        assert_eq!(Content::post_upgrade_checks(), Ok(()));
+    })
+}
+
+#[test]
+fn channel_collaborators_migration() {
+    with_default_mock_builder(|| {
+        run_to_block(START_MIGRATION_AT_BLOCK);
+
+        // videos and channels reset already performed
+        StorageVersion::put(ContentStorageVersion::V1);
+
+        let old_channel = ChannelV0::<Test> {
+            collaborators: vec![COLLABORATOR_MEMBER_ID].into_iter().collect(),
+            moderators: vec![DEFAULT_MODERATOR_ID].into_iter().collect(),
+            ..Default::default()
+        };
+
+        // store channels with the pre-migration layout
+        let channels_number = 3 * CHANNEL_MIGRATIONS_PER_BLOCK;
+        for _ in 0..channels_number {
+            let channel_id = Content::next_channel_id();
+            frame_support::storage::unhashed::put(
+                &ChannelById::<Test>::hashed_key_for(channel_id),
+                &old_channel,
+            );
+            NextChannelId::<Test>::put(channel_id + 1);
+        }
+
+        assert_eq!(Content::pre_upgrade_checks(), Ok(()));
+        Content::on_runtime_upgrade();
+
+        // migration happens over multiple blocks
+        assert!(!Content::is_migration_done());
+        run_to_block(START_MIGRATION_AT_BLOCK + 1);
+        assert!(!Content::is_migration_done());
+        assert!(Content::post_upgrade_checks().is_err());
+
+        run_to_block(START_MIGRATION_AT_BLOCK + 3);
+        assert!(Content::is_migration_done());
+        assert_eq!(Content::post_upgrade_checks(), Ok(()));
+
+        // channels are migrated, not removed
+        assert_eq!(ChannelById::<Test>::iter().count() as u64, channels_number);
+        ChannelById::<Test>::iter().for_each(|(_, channel)| {
+            assert_eq!(
+                channel.collaborators,
+                vec![(COLLABORATOR_MEMBER_ID, ChannelCollaboratorPermission::all())]
+                    .into_iter()
+                    .collect()
+            );
+            assert_eq!(channel.moderators, old_channel.moderators);
+        });
+    })
+}
+
+#[test]
+fn runtime_upgrade_at_latest_storage_version_runs_no_migration() {
+    with_default_mock_builder(|| {
+        run_to_block(START_MIGRATION_AT_BLOCK);
+
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_initial_storage_buckets_helper();
+        create_default_member_owned_channels_with_videos();
+        let channels_number = ChannelById::<Test>::iter().count();
+
+        // genesis sets the latest storage version
+        assert_eq!(Content::storage_version(), ContentStorageVersion::LATEST);
+
+        Content::on_runtime_upgrade();
+
+        assert!(Content::is_migration_done());
+        assert_eq!(ChannelById::<Test>::iter().count(), channels_number);
+        assert_video_and_channel_existrinsics_with(Ok(()));
+    })
+}
+
+#[test]
+fn video_assets_migration() {
+    with_default_mock_builder(|| {
+        run_to_block(START_MIGRATION_AT_BLOCK);
+
+        // channel collaborators already migrated
+        StorageVersion::put(ContentStorageVersion::V2);
+
+        let old_video = VideoV0::<Test> {
+            in_channel: ChannelId::one(),
+            enable_comments: true,
+            ..Default::default()
+        };
+
+        // store videos with the pre-migration layout
+        let videos_number = 2 * VIDEO_MIGRATIONS_PER_BLOCK;
+        for _ in 0..videos_number {
+            let video_id = Content::next_video_id();
+            frame_support::storage::unhashed::put(
+                &VideoById::<Test>::hashed_key_for(video_id),
+                &old_video,
+            );
+            NextVideoId::<Test>::put(video_id + 1);
+        }
+
+        assert_eq!(Content::pre_upgrade_checks(), Ok(()));
+        Content::on_runtime_upgrade();
+        assert!(!Content::is_migration_done());
+
+        run_to_block(START_MIGRATION_AT_BLOCK + 2);
+        assert!(Content::is_migration_done());
+        assert_eq!(Content::post_upgrade_checks(), Ok(()));
+
+        // videos are migrated with no typed assets
+        assert_eq!(VideoById::<Test>::iter().count() as u64, videos_number);
+        VideoById::<Test>::iter().for_each(|(_, video)| {
+            assert_eq!(video, old_video.clone().migrate());
+        });
+    })
+}
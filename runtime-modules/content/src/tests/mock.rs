@@ -65,6 +65,10 @@ pub const DEFAULT_OBJECT_SIZE: u64 = 5;
 pub const DATA_OBJECTS_NUMBER: u64 = 10;
 pub const VIDEO_MIGRATIONS_PER_BLOCK: u64 = 2;
 pub const CHANNEL_MIGRATIONS_PER_BLOCK: u64 = 1;
+pub const ASSETS_DELETIONS_PER_BLOCK: u64 = 4;
+pub const SUBSCRIPTION_EXPIRATIONS_PER_BLOCK: u64 = 2;
+pub const MAX_ASSETS_DELETION_ATTEMPTS: u32 = 3;
+pub const MAX_VIDEO_ASSET_SLOT_KEY_LENGTH: u32 = 8;
 pub const MIGRATION_BLOCKS: u64 = 4;
 
 pub const OUTSTANDING_VIDEOS: u64 = MIGRATION_BLOCKS * VIDEO_MIGRATIONS_PER_BLOCK;
@@ -281,11 +285,34 @@ parameter_types! {
         storage::StorageBucketsPerBagValueConstraint {min: 3, max_min_diff: 7};
     pub const InitialStorageBucketsNumberForDynamicBag: u64 = 3;
     pub const MaxRandomIterationNumber: u64 = 3;
+    pub const MaxStorageBucketPickerCandidates: u64 = 10;
+    pub const MaxDistributionBucketPickerCandidates: u64 = 10;
     pub const DefaultMemberDynamicBagNumberOfStorageBuckets: u64 = 3;
     pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u64 = 4;
     pub const DistributionBucketsPerBagValueConstraint: storage::DistributionBucketsPerBagValueConstraint =
     storage::StorageBucketsPerBagValueConstraint {min: 3, max_min_diff: 7};
     pub const MaxDataObjectSize: u64 = VOUCHER_OBJECTS_SIZE_LIMIT;
+    pub const MaxReReplicatedBagsPerBlock: u64 = 0;
+    pub const StorageChallengePeriod: u64 = 0;
+    pub const MaxStorageChallengesPerBlock: u64 = 0;
+    pub const StorageChallengeResponsePeriod: u64 = 0;
+    pub const ChallengeChunkSize: u64 = 1024;
+    pub const MissedStorageChallengesSlashingThreshold: u64 = 0;
+    pub const MissedStorageChallengesSlashingPenalty: u64 = 0;
+    pub const InitialBagRentPeriod: u64 = 0;
+    pub const StorageRentGracePeriod: u64 = 0;
+    pub const MaxGarbageCollectedDataObjectsPerBlock: u64 = 0;
+    pub const MaxBagRentExpirationsPerBlock: u64 = 0;
+    pub const MaxBlacklistExpirationsPerBlock: u64 = 0;
+    pub const MaxRegionTagLength: u32 = 32;
+    pub const OperatorRewardPeriod: u64 = 0;
+    pub const MaxOperatorRewardBucketsPerBlock: u64 = 0;
+    pub const MaxDataObjectPartsNumber: u64 = 10;
+    pub const MaxDrainedBagsPerBlock: u64 = 0;
+    pub const DistributionHealthBeaconPeriod: u64 = 0;
+    pub const MaxDistributionHealthBeaconsPerBlock: u64 = 0;
+    pub const MissedDistributionHealthBeaconsThreshold: u64 = 0;
+    pub const MaxHealthBeaconBucketsPerBlock: u64 = 0;
 }
 
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: u64 = 100001;
@@ -314,13 +341,38 @@ impl storage::Trait for Test {
     type DefaultChannelDynamicBagNumberOfStorageBuckets =
         DefaultChannelDynamicBagNumberOfStorageBuckets;
     type Randomness = CollectiveFlip;
+    type StorageBucketsSelectionPolicy = storage::StorageBucketPicker<Test>;
     type MaxRandomIterationNumber = MaxRandomIterationNumber;
+    type MaxStorageBucketPickerCandidates = MaxStorageBucketPickerCandidates;
+    type MaxDistributionBucketPickerCandidates = MaxDistributionBucketPickerCandidates;
     type MaxDistributionBucketFamilyNumber = MaxDistributionBucketFamilyNumber;
     type DistributionBucketsPerBagValueConstraint = DistributionBucketsPerBagValueConstraint;
     type MaxNumberOfPendingInvitationsPerDistributionBucket =
         MaxNumberOfPendingInvitationsPerDistributionBucket;
     type ContentId = u64;
     type MaxDataObjectSize = MaxDataObjectSize;
+    type MaxReReplicatedBagsPerBlock = MaxReReplicatedBagsPerBlock;
+    type StorageChallengePeriod = StorageChallengePeriod;
+    type MaxStorageChallengesPerBlock = MaxStorageChallengesPerBlock;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type ChallengeChunkSize = ChallengeChunkSize;
+    type MissedStorageChallengesSlashingThreshold = MissedStorageChallengesSlashingThreshold;
+    type MissedStorageChallengesSlashingPenalty = MissedStorageChallengesSlashingPenalty;
+    type InitialBagRentPeriod = InitialBagRentPeriod;
+    type StorageRentGracePeriod = StorageRentGracePeriod;
+    type MaxGarbageCollectedDataObjectsPerBlock = MaxGarbageCollectedDataObjectsPerBlock;
+    type MaxBagRentExpirationsPerBlock = MaxBagRentExpirationsPerBlock;
+    type MaxBlacklistExpirationsPerBlock = MaxBlacklistExpirationsPerBlock;
+    type MaxRegionTagLength = MaxRegionTagLength;
+    type OperatorRewardPeriod = OperatorRewardPeriod;
+    type MaxOperatorRewardBucketsPerBlock = MaxOperatorRewardBucketsPerBlock;
+    type MaxDataObjectPartsNumber = MaxDataObjectPartsNumber;
+    type MaxDrainedBagsPerBlock = MaxDrainedBagsPerBlock;
+    type DistributionHealthBeaconPeriod = DistributionHealthBeaconPeriod;
+    type MaxDistributionHealthBeaconsPerBlock = MaxDistributionHealthBeaconsPerBlock;
+    type MissedDistributionHealthBeaconsThreshold = MissedDistributionHealthBeaconsThreshold;
+    type MaxHealthBeaconBucketsPerBlock = MaxHealthBeaconBucketsPerBlock;
+    type WeightInfo = ();
 
     fn ensure_storage_working_group_leader_origin(origin: Self::Origin) -> DispatchResult {
         let account_id = ensure_signed(origin)?;
@@ -353,6 +405,20 @@ impl storage::Trait for Test {
         }
     }
 
+    fn slash_storage_worker_stake(worker_id: &u64, _penalty: u64) -> DispatchResult {
+        Self::ensure_storage_worker_exists(worker_id)
+    }
+
+    fn storage_working_group_budget() -> u64 {
+        0
+    }
+
+    fn pay_storage_worker_reward(worker_id: &u64, _reward: u64) -> DispatchResult {
+        Self::ensure_storage_worker_exists(worker_id)
+    }
+
+    fn fund_storage_working_group_budget(_amount: u64) {}
+
     fn ensure_distribution_working_group_leader_origin(origin: Self::Origin) -> DispatchResult {
         let account_id = ensure_signed(origin)?;
 
@@ -385,6 +451,69 @@ impl storage::Trait for Test {
             Ok(())
         }
     }
+
+    fn distribution_working_group_budget() -> u64 {
+        0
+    }
+
+    fn pay_distribution_worker_reward(worker_id: &u64, _reward: u64) -> DispatchResult {
+        Self::ensure_distribution_worker_exists(worker_id)
+    }
+
+    fn is_storage_owner_account(
+        owner: &common::storage::StorageObjectOwner<u64, u64>,
+        account_id: &u64,
+    ) -> bool {
+        match owner {
+            common::storage::StorageObjectOwner::Member(member_id) => {
+                <Test as ContentActorAuthenticator>::is_member(member_id, account_id)
+            }
+            common::storage::StorageObjectOwner::Channel(channel_id) => {
+                match ChannelById::<Test>::get(channel_id).owner {
+                    ChannelOwner::Member(member_id) => {
+                        <Test as ContentActorAuthenticator>::is_member(&member_id, account_id)
+                    }
+                    ChannelOwner::CuratorGroup(_) => {
+                        <Test as ContentActorAuthenticator>::is_lead(account_id)
+                    }
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn ensure_working_group_leader_origin(
+        origin: Self::Origin,
+        working_group: common::working_group::WorkingGroup,
+    ) -> DispatchResult {
+        let account_id = ensure_signed(origin)?;
+
+        let leader_account_id = match working_group {
+            common::working_group::WorkingGroup::Storage => STORAGE_WG_LEADER_ACCOUNT_ID,
+            common::working_group::WorkingGroup::Distribution => DISTRIBUTION_WG_LEADER_ACCOUNT_ID,
+            _ => return Err(DispatchError::BadOrigin),
+        };
+
+        if account_id != leader_account_id {
+            Err(DispatchError::BadOrigin)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn on_data_objects_removed(
+        _bag_id: &storage::BagId<Self>,
+        data_object_ids: &BTreeSet<storage::DataObjectId<Self>>,
+    ) {
+        Content::remove_video_assets(data_object_ids)
+    }
+
+    fn is_data_object_in_use(
+        _bag_id: &storage::BagId<Self>,
+        data_object_id: &storage::DataObjectId<Self>,
+    ) -> bool {
+        crate::VideoByAsset::<Test>::contains_key(data_object_id)
+    }
 }
 
 // Anyone can upload and delete without restriction
@@ -401,6 +530,10 @@ parameter_types! {
     pub const BloatBondCap: u32 = 1000;
     pub const VideosMigrationsEachBlock: u64 = VIDEO_MIGRATIONS_PER_BLOCK;
     pub const ChannelsMigrationsEachBlock: u64 = CHANNEL_MIGRATIONS_PER_BLOCK;
+    pub const AssetsDeletionsEachBlock: u64 = ASSETS_DELETIONS_PER_BLOCK;
+    pub const SubscriptionExpirationsEachBlock: u64 = SUBSCRIPTION_EXPIRATIONS_PER_BLOCK;
+    pub const MaxAssetsDeletionAttempts: u32 = MAX_ASSETS_DELETION_ATTEMPTS;
+    pub const MaxVideoAssetSlotKeyLength: u32 = MAX_VIDEO_ASSET_SLOT_KEY_LENGTH;
 }
 
 impl Trait for Test {
@@ -464,6 +597,14 @@ impl Trait for Test {
     type VideosMigrationsEachBlock = VideosMigrationsEachBlock;
 
     type ChannelsMigrationsEachBlock = ChannelsMigrationsEachBlock;
+
+    type AssetsDeletionsEachBlock = AssetsDeletionsEachBlock;
+
+    type SubscriptionExpirationsEachBlock = SubscriptionExpirationsEachBlock;
+
+    type MaxAssetsDeletionAttempts = MaxAssetsDeletionAttempts;
+
+    type MaxVideoAssetSlotKeyLength = MaxVideoAssetSlotKeyLength;
 }
 
 // #[derive (Default)]
@@ -602,6 +743,13 @@ pub fn run_to_block(n: u64) {
     }
 }
 
+// runs blocks until all pending assets deletions have been processed
+pub fn run_to_pending_assets_deletions_end() {
+    while Content::current_assets_deletion_id() < Content::next_assets_deletion_id() {
+        run_to_block(System::block_number() + 1);
+    }
+}
+
 pub fn assert_event(tested_event: MetaEvent, number_of_events_after_call: usize) {
     // Ensure  runtime events length is equal to expected number of events after call
     assert_eq!(System::events().len(), number_of_events_after_call);
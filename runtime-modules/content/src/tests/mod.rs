@@ -8,4 +8,5 @@ mod migration;
 mod mock;
 mod nft;
 mod posts;
+mod subscriptions;
 mod videos;
@@ -0,0 +1,382 @@
+#![cfg(test)]
+
+use super::fixtures::*;
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_err, assert_ok};
+
+const SUBSCRIPTION_PRICE: u64 = 100;
+const SUBSCRIPTION_PERIOD: u64 = 10;
+
+fn default_subscription_plan() -> SubscriptionPlan<Test> {
+    SubscriptionPlanRecord {
+        price: SUBSCRIPTION_PRICE,
+        period: SUBSCRIPTION_PERIOD,
+    }
+}
+
+fn setup_channel_with_subscription_plan() -> ChannelId {
+    create_initial_storage_buckets_helper();
+    increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+    create_default_member_owned_channel();
+
+    let channel_id = NextChannelId::<Test>::get() - 1;
+
+    assert_ok!(Content::update_channel_subscription_plan(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        channel_id,
+        Some(default_subscription_plan()),
+    ));
+
+    channel_id
+}
+
+#[test]
+fn successful_channel_subscription_plan_update() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+
+        assert_eq!(
+            Content::channel_subscription_plan(channel_id),
+            Some(default_subscription_plan())
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelSubscriptionPlanUpdated(
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                channel_id,
+                Some(default_subscription_plan()),
+            ))
+        );
+
+        // plan removal
+        assert_ok!(Content::update_channel_subscription_plan(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id,
+            None,
+        ));
+        assert_eq!(Content::channel_subscription_plan(channel_id), None);
+    })
+}
+
+#[test]
+fn unsuccessful_channel_subscription_plan_update_by_collaborator() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+
+        assert_err!(
+            Content::update_channel_subscription_plan(
+                Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(COLLABORATOR_MEMBER_ID),
+                channel_id,
+                None,
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+    })
+}
+
+#[test]
+fn unsuccessful_channel_subscription_plan_update_with_zero_period() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+
+        assert_err!(
+            Content::update_channel_subscription_plan(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                channel_id,
+                Some(SubscriptionPlanRecord {
+                    period: 0,
+                    ..default_subscription_plan()
+                }),
+            ),
+            Error::<Test>::InvalidSubscriptionPeriod
+        );
+    })
+}
+
+#[test]
+fn unsuccessful_channel_subscription_plan_update_with_zero_price() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+
+        assert_err!(
+            Content::update_channel_subscription_plan(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                channel_id,
+                Some(SubscriptionPlanRecord {
+                    price: 0,
+                    ..default_subscription_plan()
+                }),
+            ),
+            Error::<Test>::InvalidSubscriptionPrice
+        );
+    })
+}
+
+#[test]
+fn successful_channel_subscription() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+        let reward_account = ChannelById::<Test>::get(channel_id).reward_account.unwrap();
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, 2 * SUBSCRIPTION_PRICE);
+        let reward_account_balance_pre = Balances::<Test>::free_balance(reward_account);
+
+        assert_ok!(Content::subscribe_to_channel(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            channel_id,
+            2,
+            SUBSCRIPTION_PRICE,
+        ));
+
+        let expires_at = 1 + 2 * SUBSCRIPTION_PERIOD;
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelSubscribed(
+                SECOND_MEMBER_ID,
+                channel_id,
+                expires_at,
+                2 * SUBSCRIPTION_PRICE,
+            ))
+        );
+        assert!(Content::is_subscribed(&channel_id, &SECOND_MEMBER_ID));
+
+        // subscriber paid, channel received the price minus the platform fee
+        assert_eq!(Balances::<Test>::free_balance(SECOND_MEMBER_ACCOUNT_ID), 0);
+        assert_eq!(
+            Balances::<Test>::free_balance(reward_account) - reward_account_balance_pre,
+            2 * SUBSCRIPTION_PRICE - Content::platform_fee_percentage() * (2 * SUBSCRIPTION_PRICE)
+        );
+    })
+}
+
+#[test]
+fn channel_subscription_expires() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, SUBSCRIPTION_PRICE);
+
+        assert_ok!(Content::subscribe_to_channel(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            channel_id,
+            1,
+            SUBSCRIPTION_PRICE,
+        ));
+
+        run_to_block(SUBSCRIPTION_PERIOD);
+        assert!(Content::is_subscribed(&channel_id, &SECOND_MEMBER_ID));
+
+        run_to_block(1 + SUBSCRIPTION_PERIOD);
+        assert!(!Content::is_subscribed(&channel_id, &SECOND_MEMBER_ID));
+        assert_eq!(
+            Content::channel_subscription_expiry(channel_id, SECOND_MEMBER_ID),
+            None
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::ChannelSubscriptionExpired(
+                channel_id,
+                SECOND_MEMBER_ID
+            ))
+        );
+    })
+}
+
+#[test]
+fn channel_subscription_expirations_are_processed_in_bounded_batches() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+        let subscribers = vec![
+            (DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID),
+            (SECOND_MEMBER_ACCOUNT_ID, SECOND_MEMBER_ID),
+            (COLLABORATOR_MEMBER_ACCOUNT_ID, COLLABORATOR_MEMBER_ID),
+        ];
+        assert!(subscribers.len() as u64 > SUBSCRIPTION_EXPIRATIONS_PER_BLOCK);
+
+        for (account_id, member_id) in subscribers.iter() {
+            increase_account_balance_helper(*account_id, SUBSCRIPTION_PRICE);
+
+            assert_ok!(Content::subscribe_to_channel(
+                Origin::signed(*account_id),
+                *member_id,
+                channel_id,
+                1,
+                SUBSCRIPTION_PRICE,
+            ));
+        }
+
+        let expires_at = 1 + SUBSCRIPTION_PERIOD;
+        run_to_block(expires_at);
+
+        let remaining_expirations = subscribers
+            .iter()
+            .filter(|(_, member_id)| {
+                Content::channel_subscription_expiry(channel_id, member_id).is_some()
+            })
+            .count() as u64;
+        assert_eq!(
+            remaining_expirations,
+            subscribers.len() as u64 - SUBSCRIPTION_EXPIRATIONS_PER_BLOCK
+        );
+        assert_eq!(Content::subscription_expirations_cursor(), expires_at);
+
+        // the remaining expiration is processed in the next block
+        run_to_block(expires_at + 1);
+
+        for (_, member_id) in subscribers.iter() {
+            assert_eq!(
+                Content::channel_subscription_expiry(channel_id, member_id),
+                None
+            );
+            assert!(!Content::is_subscribed(&channel_id, member_id));
+        }
+        assert_eq!(Content::subscription_expirations_cursor(), expires_at + 2);
+    })
+}
+
+#[test]
+fn active_channel_subscription_is_extended() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, 2 * SUBSCRIPTION_PRICE);
+
+        assert_ok!(Content::subscribe_to_channel(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            channel_id,
+            1,
+            SUBSCRIPTION_PRICE,
+        ));
+
+        run_to_block(5);
+
+        assert_ok!(Content::subscribe_to_channel(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            channel_id,
+            1,
+            SUBSCRIPTION_PRICE,
+        ));
+
+        // extended from the previous expiration, not from the current block
+        let expires_at = 1 + 2 * SUBSCRIPTION_PERIOD;
+        assert_eq!(
+            Content::channel_subscription_expiry(channel_id, SECOND_MEMBER_ID),
+            Some(expires_at)
+        );
+
+        // previous expiration no longer applies
+        run_to_block(1 + SUBSCRIPTION_PERIOD);
+        assert!(Content::is_subscribed(&channel_id, &SECOND_MEMBER_ID));
+
+        run_to_block(expires_at);
+        assert!(!Content::is_subscribed(&channel_id, &SECOND_MEMBER_ID));
+    })
+}
+
+#[test]
+fn unsuccessful_channel_subscription_without_plan() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel();
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, SUBSCRIPTION_PRICE);
+
+        assert_err!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                NextChannelId::<Test>::get() - 1,
+                1,
+                SUBSCRIPTION_PRICE,
+            ),
+            Error::<Test>::SubscriptionPlanDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn unsuccessful_channel_subscription_with_changed_price() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, SUBSCRIPTION_PRICE);
+
+        assert_err!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                channel_id,
+                1,
+                SUBSCRIPTION_PRICE - 1,
+            ),
+            Error::<Test>::SubscriptionPriceChanged
+        );
+    })
+}
+
+#[test]
+fn unsuccessful_channel_subscription_with_insufficient_balance() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, SUBSCRIPTION_PRICE);
+
+        assert_err!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                channel_id,
+                2,
+                SUBSCRIPTION_PRICE,
+            ),
+            Error::<Test>::InsufficientBalance
+        );
+    })
+}
+
+#[test]
+fn unsuccessful_channel_subscription_with_member_auth_failure() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let channel_id = setup_channel_with_subscription_plan();
+
+        assert_err!(
+            Content::subscribe_to_channel(
+                Origin::signed(UNAUTHORIZED_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                channel_id,
+                1,
+                SUBSCRIPTION_PRICE,
+            ),
+            Error::<Test>::MemberAuthFailed
+        );
+    })
+}
@@ -187,6 +187,39 @@ fn successful_video_creation_by_collaborator() {
     })
 }
 
+#[test]
+fn unsuccessful_video_creation_by_collaborator_without_permission() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        increase_account_balance_helper(COLLABORATOR_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        let channel_id = Content::next_channel_id();
+        CreateChannelFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .with_collaborators(
+                vec![(
+                    COLLABORATOR_MEMBER_ID,
+                    vec![ChannelCollaboratorPermission::ManageVideoPosts]
+                        .into_iter()
+                        .collect(),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .call_and_assert(Ok(()));
+
+        CreateVideoFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .with_channel_id(channel_id)
+            .call_and_assert(Err(Error::<Test>::CollaboratorPermissionMissing.into()));
+    })
+}
+
 #[test]
 fn successful_video_creation_by_lead() {
     with_default_mock_builder(|| {
@@ -419,6 +452,57 @@ fn unsuccessful_video_creation_with_invalid_expected_data_size_fee() {
     })
 }
 
+#[test]
+fn successful_video_creation_with_media_and_thumbnail_priced_by_object_class() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel();
+
+        let media_fee = 100;
+        let thumbnail_fee = 10;
+        assert_ok!(Storage::<Test>::update_data_size_fee_schedule(
+            frame_system::RawOrigin::Root.into(),
+            storage::DataSizeFeeCategory::Dynamic(storage::DynamicBagType::Channel),
+            Some(storage::DataSizeFeeSchedule {
+                per_megabyte_fee: 1,
+                object_class_fees: vec![
+                    (storage::DataObjectClass::Media, media_fee),
+                    (storage::DataObjectClass::Thumbnail, thumbnail_fee),
+                ]
+                .into_iter()
+                .collect(),
+            }),
+        ));
+
+        let classes = vec![
+            storage::DataObjectClass::Media,
+            storage::DataObjectClass::Thumbnail,
+        ];
+        let object_creation_list = create_data_object_candidates_helper(1, 2)
+            .into_iter()
+            .zip(classes)
+            .map(|(object_params, class)| DataObjectCreationParameters {
+                class: Some(class),
+                ..object_params
+            })
+            .collect::<Vec<_>>();
+
+        // The media and the thumbnail are charged with their class fees in the same upload.
+        CreateVideoFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .with_assets(StorageAssets::<Test> {
+                expected_data_size_fee: media_fee + thumbnail_fee,
+                object_creation_list,
+            })
+            .with_asset_slots(vec![VideoAssetSlot::Media, VideoAssetSlot::Thumbnail])
+            .call_and_assert(Ok(()));
+    })
+}
+
 #[test]
 fn unsuccessful_video_creation_with_insufficient_balance() {
     with_default_mock_builder(|| {
@@ -457,6 +541,9 @@ fn unsuccessful_video_creation_due_to_bucket_having_insufficient_objects_size_le
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: STORAGE_BUCKET_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: vec![1u8],
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
                 }],
             })
             .call_and_assert(Err(
@@ -489,6 +576,9 @@ fn unsuccessful_video_creation_due_to_bucket_having_insufficient_objects_number_
                     .map(|_| DataObjectCreationParameters {
                         size: 1,
                         ipfs_content_id: vec![1u8],
+                        content_root: None,
+                        parts: Vec::new(),
+                        class: None,
                     })
                     .collect(),
             })
@@ -515,6 +605,9 @@ fn unsuccessful_video_creation_with_max_object_size_limits_exceeded() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: VOUCHER_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: vec![1u8],
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
                 }],
             })
             .call_and_assert(Err(storage::Error::<Test>::MaxDataObjectSizeExceeded.into()));
@@ -863,6 +956,9 @@ fn unsuccessful_video_update_due_to_bucket_having_insufficient_objects_size_left
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: STORAGE_BUCKET_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: vec![1u8],
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
                 }],
             })
             .call_and_assert(Err(
@@ -893,6 +989,9 @@ fn unsuccessful_video_update_due_to_bucket_having_insufficient_objects_number_le
                     .map(|_| DataObjectCreationParameters {
                         size: 1,
                         ipfs_content_id: vec![1u8],
+                        content_root: None,
+                        parts: Vec::new(),
+                        class: None,
                     })
                     .collect(),
             })
@@ -917,6 +1016,9 @@ fn unsuccessful_video_update_with_max_object_size_limits_exceeded() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: <Test as storage::Trait>::MaxDataObjectSize::get() + 1,
                     ipfs_content_id: vec![1u8],
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
                 }],
             })
             .call_and_assert(Err(storage::Error::<Test>::MaxDataObjectSizeExceeded.into()));
@@ -1168,3 +1270,232 @@ fn unsuccessful_video_deletion_with_invalid_object_ids() {
             .call_and_assert(Err(storage::Error::<Test>::DataObjectDoesntExist.into()));
     })
 }
+
+fn default_video_asset_slots() -> Vec<VideoAssetSlot> {
+    vec![
+        VideoAssetSlot::Media,
+        VideoAssetSlot::Thumbnail,
+        VideoAssetSlot::Subtitle(b"en".to_vec()),
+        VideoAssetSlot::Rendition(b"720p".to_vec()),
+        VideoAssetSlot::Rendition(b"1080p".to_vec()),
+    ]
+}
+
+fn create_default_member_owned_channel_with_video_asset_slots() {
+    create_default_member_owned_channel();
+
+    CreateVideoFixture::default()
+        .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+        .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+        .with_assets(StorageAssets::<Test> {
+            expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+            object_creation_list: create_data_objects_helper(),
+        })
+        .with_asset_slots(default_video_asset_slots())
+        .with_channel_id(NextChannelId::<Test>::get() - 1)
+        .call_and_assert(Ok(()));
+}
+
+#[test]
+fn successful_video_creation_with_asset_slots() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video_asset_slots();
+
+        let video = Content::video_by_id(VideoId::one());
+        assert_eq!(
+            video.assets.keys().cloned().collect::<BTreeSet<_>>(),
+            default_video_asset_slots().into_iter().collect()
+        );
+    })
+}
+
+#[test]
+fn unsuccessful_video_creation_with_more_asset_slots_than_assets() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel();
+
+        CreateVideoFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .with_assets(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_object_candidates_helper(1, 1),
+            })
+            .with_asset_slots(default_video_asset_slots())
+            .call_and_assert(Err(
+                Error::<Test>::VideoAssetSlotsExceedUploadedAssets.into()
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_video_creation_with_duplicate_asset_slots() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel();
+
+        CreateVideoFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .with_assets(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_objects_helper(),
+            })
+            .with_asset_slots(vec![VideoAssetSlot::Media, VideoAssetSlot::Media])
+            .call_and_assert(Err(Error::<Test>::DuplicateVideoAssetSlot.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_video_creation_with_asset_slot_key_too_long() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel();
+
+        let key = vec![b'a'; MAX_VIDEO_ASSET_SLOT_KEY_LENGTH as usize + 1];
+
+        CreateVideoFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .with_assets(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_objects_helper(),
+            })
+            .with_asset_slots(vec![VideoAssetSlot::Subtitle(key)])
+            .call_and_assert(Err(Error::<Test>::VideoAssetSlotKeyTooLong.into()));
+    })
+}
+
+#[test]
+fn successful_video_update_replacing_one_rendition() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video_asset_slots();
+
+        let rendition = VideoAssetSlot::Rendition(b"720p".to_vec());
+        let video_pre = Content::video_by_id(VideoId::one());
+        let channel_bag_id = Content::bag_id_for_channel(&video_pre.in_channel);
+        let replaced_asset = video_pre.assets[&rendition];
+
+        UpdateVideoFixture::default()
+            .with_assets_to_upload(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_object_candidates_helper(1, 1),
+            })
+            .with_asset_slots(vec![rendition.clone()])
+            .call_and_assert(Ok(()));
+
+        // only the replaced rendition is removed from the bag
+        let video_post = Content::video_by_id(VideoId::one());
+        assert!(!storage::DataObjectsById::<Test>::contains_key(
+            &channel_bag_id,
+            replaced_asset
+        ));
+        assert!(video_pre
+            .assets
+            .iter()
+            .filter(|(slot, _)| **slot != rendition)
+            .all(|(slot, obj_id)| {
+                storage::DataObjectsById::<Test>::contains_key(&channel_bag_id, obj_id)
+                    && video_post.assets.get(slot) == Some(obj_id)
+            }));
+    })
+}
+
+#[test]
+fn successful_video_update_with_slot_asset_removal() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video_asset_slots();
+
+        let subtitle = VideoAssetSlot::Subtitle(b"en".to_vec());
+        let subtitle_asset = Content::video_by_id(VideoId::one()).assets[&subtitle];
+
+        UpdateVideoFixture::default()
+            .with_assets_to_remove(vec![subtitle_asset].into_iter().collect())
+            .call_and_assert(Ok(()));
+
+        assert!(!Content::video_by_id(VideoId::one())
+            .assets
+            .contains_key(&subtitle));
+        assert_eq!(Content::video_by_asset(subtitle_asset), None);
+    })
+}
+
+#[test]
+fn video_asset_slots_cleared_on_assets_removed_by_storage() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video_asset_slots();
+
+        let media_asset = Content::video_by_id(VideoId::one()).assets[&VideoAssetSlot::Media];
+        assert_eq!(Content::video_by_asset(media_asset), Some(VideoId::one()));
+
+        // eg.: garbage collected on the channel bag storage rent expiration
+        <Test as storage::Trait>::on_data_objects_removed(
+            &Content::bag_id_for_channel(&(NextChannelId::<Test>::get() - 1)),
+            &vec![media_asset].into_iter().collect(),
+        );
+
+        assert!(!Content::video_by_id(VideoId::one())
+            .assets
+            .contains_key(&VideoAssetSlot::Media));
+        assert_eq!(Content::video_by_asset(media_asset), None);
+    })
+}
+
+#[test]
+fn unsuccessful_video_update_with_duplicate_asset_slots() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video_asset_slots();
+
+        UpdateVideoFixture::default()
+            .with_assets_to_upload(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_objects_helper(),
+            })
+            .with_asset_slots(vec![VideoAssetSlot::Thumbnail, VideoAssetSlot::Thumbnail])
+            .call_and_assert(Err(Error::<Test>::DuplicateVideoAssetSlot.into()));
+    })
+}
+
+#[test]
+fn successful_video_deletion_with_slot_assets_removal() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video_asset_slots();
+
+        // slot assets are removed without being listed
+        DeleteVideoFixture::default().call_and_assert(Ok(()));
+    })
+}
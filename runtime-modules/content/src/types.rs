@@ -15,7 +15,7 @@ pub enum NewAsset<ContentParameters> {
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
 pub struct MigrationConfigRecord<NumericId> {
-    // at each block the videos/channels removed will be those with id in the
+    // at each block the videos/channels migrated will be those with id in the
     // half open range [current_id, final_id).
     // when migration is triggered final_id will be updated
     // when migration is performed current_id will be updated
@@ -26,6 +26,57 @@ pub struct MigrationConfigRecord<NumericId> {
 pub type VideoMigrationConfig<T> = MigrationConfigRecord<<T as Trait>::VideoId>;
 pub type ChannelMigrationConfig<T> = MigrationConfigRecord<<T as storage::Trait>::ChannelId>;
 
+/// Storage layout versions of the content pallet.
+/// On runtime upgrade the migrations of every version newer than the stored one are run,
+/// then the stored version is bumped to `ContentStorageVersion::LATEST`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum ContentStorageVersion {
+    /// Layout before storage versioning was introduced
+    V0,
+    /// Videos and channels reset, preserving next ids and categories
+    V1,
+    /// Channel collaborators with permission scopes
+    V2,
+    /// Videos with typed asset slots
+    V3,
+}
+
+impl ContentStorageVersion {
+    /// Version of the current storage layout
+    pub const LATEST: Self = Self::V3;
+}
+
+// Default trait implemented only because its used in a StorageValue.
+impl Default for ContentStorageVersion {
+    fn default() -> Self {
+        Self::V0
+    }
+}
+
+/// Data objects of a channel bag scheduled for removal. Pending deletions are
+/// processed in `on_initialize` in order of their id, a bounded number of objects each block.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct AssetsDeletionRecord<ChannelId, AccountId, DataObjectId: Ord> {
+    /// Channel whose bag contains the objects
+    pub channel_id: ChannelId,
+    /// Account receiving the deletion prizes
+    pub deletion_prize_account_id: AccountId,
+    /// Objects not deleted yet
+    pub assets: BTreeSet<DataObjectId>,
+    /// Whether the channel bag must be deleted once all objects are removed
+    pub delete_bag: bool,
+    /// Failed attempts of the deletion, it's dropped after `MaxAssetsDeletionAttempts` failures
+    pub failed_attempts: u32,
+}
+
+pub type AssetsDeletion<T> = AssetsDeletionRecord<
+    <T as storage::Trait>::ChannelId,
+    <T as frame_system::Trait>::AccountId,
+    DataObjectId<T>,
+>;
+
 /// The owner of a channel, is the authorized "actor" that can update
 /// or delete or transfer a channel and its contents.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -81,8 +132,8 @@ pub struct ChannelRecord<MemberId: Ord, CuratorGroupId, AccountId, Balance> {
     pub is_censored: bool,
     /// Reward account where revenue is sent if set.
     pub reward_account: Option<AccountId>,
-    /// collaborator set
-    pub collaborators: BTreeSet<MemberId>,
+    /// collaborators with their permission sets
+    pub collaborators: BTreeMap<MemberId, ChannelCollaboratorPermissions>,
     /// moderator set
     pub moderators: BTreeSet<MemberId>,
     /// Cumulative cashout
@@ -110,6 +161,85 @@ pub type Channel<T> = ChannelRecord<
     BalanceOf<T>,
 >;
 
+/// Channel record layout used before collaborator permission scopes were introduced.
+/// Only kept for decoding existing channels during the storage migration.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ChannelRecordV0<MemberId: Ord, CuratorGroupId, AccountId, Balance> {
+    pub owner: ChannelOwner<MemberId, CuratorGroupId>,
+    pub num_videos: u64,
+    pub is_censored: bool,
+    pub reward_account: Option<AccountId>,
+    pub collaborators: BTreeSet<MemberId>,
+    pub moderators: BTreeSet<MemberId>,
+    pub cumulative_payout_earned: Balance,
+}
+
+impl<MemberId: Ord, CuratorGroupId, AccountId, Balance>
+    ChannelRecordV0<MemberId, CuratorGroupId, AccountId, Balance>
+{
+    /// Convert to the current channel record: existing collaborators are granted every permission,
+    /// preserving the rights they had before the migration.
+    pub fn migrate(self) -> ChannelRecord<MemberId, CuratorGroupId, AccountId, Balance> {
+        ChannelRecord {
+            owner: self.owner,
+            num_videos: self.num_videos,
+            is_censored: self.is_censored,
+            reward_account: self.reward_account,
+            collaborators: self
+                .collaborators
+                .into_iter()
+                .map(|member_id| (member_id, ChannelCollaboratorPermission::all()))
+                .collect(),
+            moderators: self.moderators,
+            cumulative_payout_earned: self.cumulative_payout_earned,
+        }
+    }
+}
+
+pub type ChannelV0<T> = ChannelRecordV0<
+    <T as common::MembershipTypes>::MemberId,
+    <T as ContentActorAuthenticator>::CuratorGroupId,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+>;
+
+/// Actions a channel collaborator can be allowed to perform on behalf of the channel owner.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum ChannelCollaboratorPermission {
+    /// Create videos in the channel
+    AddVideo,
+    /// Update channel and video metadata and video comments settings
+    UpdateMetadata,
+    /// Upload and remove channel and video assets
+    ManageAssets,
+    /// Delete videos of the channel
+    DeleteVideo,
+    /// Issue NFTs for channel videos
+    IssueNft,
+    /// Create, edit and delete video description posts
+    ManageVideoPosts,
+}
+
+impl ChannelCollaboratorPermission {
+    /// Set containing every permission
+    pub fn all() -> ChannelCollaboratorPermissions {
+        [
+            Self::AddVideo,
+            Self::UpdateMetadata,
+            Self::ManageAssets,
+            Self::DeleteVideo,
+            Self::IssueNft,
+            Self::ManageVideoPosts,
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    }
+}
+
+pub type ChannelCollaboratorPermissions = BTreeSet<ChannelCollaboratorPermission>;
+
 /// A request to buy a channel by a new ChannelOwner.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
@@ -145,8 +275,8 @@ pub struct ChannelCreationParametersRecord<StorageAssets, AccountId, MemberId: O
     pub meta: Option<Vec<u8>>,
     /// optional reward account
     pub reward_account: Option<AccountId>,
-    /// initial collaborator set
-    pub collaborators: BTreeSet<MemberId>,
+    /// initial collaborators with their permission sets
+    pub collaborators: BTreeMap<MemberId, ChannelCollaboratorPermissions>,
     /// initial moderator set
     pub moderators: BTreeSet<MemberId>,
 }
@@ -170,8 +300,8 @@ pub struct ChannelUpdateParametersRecord<StorageAssets, AccountId, DataObjectId:
     pub reward_account: Option<Option<AccountId>>,
     /// assets to be removed from channel
     pub assets_to_remove: BTreeSet<DataObjectId>,
-    /// collaborator set
-    pub collaborators: Option<BTreeSet<MemberId>>,
+    /// If set, collaborators with their permission sets
+    pub collaborators: Option<BTreeMap<MemberId, ChannelCollaboratorPermissions>>,
 }
 
 pub type ChannelUpdateParameters<T> = ChannelUpdateParametersRecord<
@@ -224,6 +354,9 @@ pub type StorageAssets<T> = StorageAssetsRecord<BalanceOf<T>>;
 pub struct VideoCreationParametersRecord<StorageAssets> {
     /// Asset collection for the video
     pub assets: Option<StorageAssets>,
+    /// Slots of the uploaded assets: the n-th slot is assigned to the n-th uploaded asset.
+    /// Assets without a slot are not tracked by the video.
+    pub asset_slots: Vec<VideoAssetSlot>,
     /// Metadata for the video.
     pub meta: Option<Vec<u8>>,
     /// Comments enabled or not
@@ -244,6 +377,9 @@ pub struct VideoUpdateParametersRecord<StorageAssets, DataObjectId: Ord> {
     pub assets_to_remove: BTreeSet<DataObjectId>,
     /// If set enable/disable comments to video
     pub enable_comments: Option<bool>,
+    /// Slots of the uploaded assets: the n-th slot is assigned to the n-th uploaded asset.
+    /// The asset previously in the slot is removed from the channel.
+    pub asset_slots: Vec<VideoAssetSlot>,
 }
 
 pub type VideoUpdateParameters<T> = VideoUpdateParametersRecord<StorageAssets<T>, DataObjectId<T>>;
@@ -251,7 +387,7 @@ pub type VideoUpdateParameters<T> = VideoUpdateParametersRecord<StorageAssets<T>
 /// A video which belongs to a channel. A video may be part of a series or playlist.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct VideoRecord<ChannelId, SeriesId, VideoPostId, OwnedNFT> {
+pub struct VideoRecord<ChannelId, SeriesId, VideoPostId, OwnedNFT, DataObjectId> {
     pub in_channel: ChannelId,
     pub in_series: Option<SeriesId>,
     /// Whether the curators have censored the video or not.
@@ -262,6 +398,8 @@ pub struct VideoRecord<ChannelId, SeriesId, VideoPostId, OwnedNFT> {
     pub video_post_id: Option<VideoPostId>,
     /// Whether nft for this video have been issued.
     pub nft_status: Option<OwnedNFT>,
+    /// Channel bag data objects of the video, by role.
+    pub assets: BTreeMap<VideoAssetSlot, DataObjectId>,
 }
 
 pub type Video<T> = VideoRecord<
@@ -269,8 +407,74 @@ pub type Video<T> = VideoRecord<
     <T as Trait>::SeriesId,
     <T as Trait>::VideoPostId,
     Nft<T>,
+    DataObjectId<T>,
+>;
+
+/// Role of a data object among the assets of a video.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum VideoAssetSlot {
+    /// Main media of the video
+    Media,
+    /// Video thumbnail
+    Thumbnail,
+    /// Subtitles track, keyed by language code
+    Subtitle(Vec<u8>),
+    /// Alternate rendition of the media (e.g. a lower resolution), keyed by label
+    Rendition(Vec<u8>),
+}
+
+/// Video record layout used before typed asset slots were introduced.
+/// Only kept for decoding existing videos during the storage migration.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct VideoRecordV0<ChannelId, SeriesId, VideoPostId, OwnedNFT> {
+    pub in_channel: ChannelId,
+    pub in_series: Option<SeriesId>,
+    pub is_censored: bool,
+    pub enable_comments: bool,
+    pub video_post_id: Option<VideoPostId>,
+    pub nft_status: Option<OwnedNFT>,
+}
+
+impl<ChannelId, SeriesId, VideoPostId, OwnedNFT>
+    VideoRecordV0<ChannelId, SeriesId, VideoPostId, OwnedNFT>
+{
+    /// Convert to the current video record: existing assets are left untyped.
+    pub fn migrate<DataObjectId>(
+        self,
+    ) -> VideoRecord<ChannelId, SeriesId, VideoPostId, OwnedNFT, DataObjectId> {
+        VideoRecord {
+            in_channel: self.in_channel,
+            in_series: self.in_series,
+            is_censored: self.is_censored,
+            enable_comments: self.enable_comments,
+            video_post_id: self.video_post_id,
+            nft_status: self.nft_status,
+            assets: BTreeMap::new(),
+        }
+    }
+}
+
+pub type VideoV0<T> = VideoRecordV0<
+    <T as storage::Trait>::ChannelId,
+    <T as Trait>::SeriesId,
+    <T as Trait>::VideoPostId,
+    Nft<T>,
 >;
 
+/// Paid subscription plan offered by a channel.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct SubscriptionPlanRecord<Balance, BlockNumber> {
+    /// Price paid for each subscription period
+    pub price: Balance,
+    /// Subscription period length in blocks
+    pub period: BlockNumber,
+}
+
+pub type SubscriptionPlan<T> =
+    SubscriptionPlanRecord<CurrencyOf<T>, <T as frame_system::Trait>::BlockNumber>;
+
 /// Information about the plyalist being created.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
@@ -541,8 +745,8 @@ pub type PullPayment<T> = PullPaymentElement<
     <T as frame_system::Trait>::Hash,
 >;
 
-impl<ChannelId: Clone, SeriesId: Clone, VideoPostId: Clone, OwnedNFT: Clone>
-    VideoRecord<ChannelId, SeriesId, VideoPostId, OwnedNFT>
+impl<ChannelId: Clone, SeriesId: Clone, VideoPostId: Clone, OwnedNFT: Clone, DataObjectId>
+    VideoRecord<ChannelId, SeriesId, VideoPostId, OwnedNFT, DataObjectId>
 {
     /// Ensure nft is not issued
     pub fn ensure_nft_is_not_issued<T: Trait>(&self) -> DispatchResult {
@@ -101,6 +101,7 @@ impl common::membership::MembershipTypes for Runtime {
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const MaxDeputyLeads: u32 = 2;
     pub const LockId: [u8; 8] = [9; 8];
     pub const InviteMemberLockId: [u8; 8] = [9; 8];
     pub const StakingCandidateLockId: [u8; 8] = [10; 8];
@@ -115,6 +116,7 @@ pub type ForumWorkingGroupInstance = working_group::Instance1;
 impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingAccountValidator = membership::Module<Runtime>;
     type StakingHandler = staking_handler::StakingManager<Self, LockId>;
     type MemberOriginValidator = ();
@@ -224,6 +226,9 @@ impl working_group::WeightInfo for Weights {
     fn leave_role(_: u32) -> u64 {
         unimplemented!()
     }
+    fn set_deputy_lead() -> u64 {
+        unimplemented!()
+    }
 }
 
 impl membership::WeightInfo for Weights {
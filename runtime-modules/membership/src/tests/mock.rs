@@ -107,6 +107,7 @@ impl common::membership::MembershipTypes for Test {
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const MaxDeputyLeads: u32 = 2;
     pub const LockId: LockIdentifier = [9; 8];
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const ReferralCutMaximumPercent: u8 = 50;
@@ -118,6 +119,7 @@ parameter_types! {
 impl working_group::Trait<MembershipWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = staking_handler::StakingManager<Self, LockId>;
     type StakingAccountValidator = Membership;
     type MemberOriginValidator = ();
@@ -228,6 +230,9 @@ impl working_group::WeightInfo for Weights {
     fn leave_role(_: u32) -> u64 {
         unimplemented!()
     }
+    fn set_deputy_lead() -> u64 {
+        unimplemented!()
+    }
 }
 
 // impl WeightInfo for () {
@@ -14,8 +14,13 @@ use membership::Module as Membership;
 use proposals_discussion::Module as Discussion;
 use proposals_engine::Module as Engine;
 use sp_runtime::traits::One;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::convert::TryInto;
 use sp_std::prelude::*;
+use storage::{
+    DataObjectClass, DataSizeFeeCategory, DataSizeFeeSchedule, DynamicBagIdType, DynamicBagType,
+    StorageAdministrativeActionRecord,
+};
 
 const SEED: u32 = 0;
 const MAX_BYTES: u32 = 16384;
@@ -807,6 +812,106 @@ benchmarks! {
         );
     }
 
+    create_proposal_update_data_size_fee_schedule {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let fee: BalanceOf<T> = One::one();
+        let schedule = DataSizeFeeSchedule {
+            per_megabyte_fee: fee,
+            object_class_fees: vec![
+                (DataObjectClass::Media, fee),
+                (DataObjectClass::Thumbnail, fee),
+                (DataObjectClass::Metadata, fee),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        let proposal_details = ProposalDetails::UpdateDataSizeFeeSchedule(
+            DataSizeFeeCategory::Dynamic(DynamicBagType::Channel),
+            Some(schedule),
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_storage_administrative_action {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::StorageAdministrativeAction(
+            StorageAdministrativeActionRecord::CreateDynamicBag(
+                DynamicBagIdType::Member(member_id),
+                None,
+            ),
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_set_working_group_deputy_lead {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let permissions: BTreeSet<working_group::DeputyLeadPermission> = vec![
+            working_group::DeputyLeadPermission::FillOpening,
+            working_group::DeputyLeadPermission::CancelOpening,
+            working_group::DeputyLeadPermission::UpdateRewardAmount,
+            working_group::DeputyLeadPermission::SpendFromBudget,
+            working_group::DeputyLeadPermission::SetStatusText,
+        ]
+        .into_iter()
+        .collect();
+
+        let proposal_details = ProposalDetails::SetWorkingGroupDeputyLead(
+            working_group::WorkerId::<T>::zero(),
+            permissions,
+            WorkingGroup::Forum,
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
 }
 
 #[cfg(test)]
@@ -986,4 +1091,25 @@ mod tests {
             assert_ok!(test_benchmark_create_proposal_veto_proposal::<Test>());
         });
     }
+
+    #[test]
+    fn test_create_proposal_update_data_size_fee_schedule() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_update_data_size_fee_schedule::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_storage_administrative_action() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_storage_administrative_action::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_set_working_group_deputy_lead() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_set_working_group_deputy_lead::<Test>());
+        });
+    }
 }
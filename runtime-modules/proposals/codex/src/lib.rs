@@ -26,6 +26,7 @@
 //! - [common](../substrate_common_module/index.html)
 //! - [staking](../substrate_staking_module/index.html)
 //! - [working_group](../substrate_working_group_module/index.html)
+//! - [storage](../substrate_storage_module/index.html)
 //!
 //! ### Notes
 //! The module uses [ProposalEncoder](./trait.ProposalEncoder.html) to encode the proposal using its
@@ -61,6 +62,7 @@ use proposals_discussion::ThreadMode;
 use proposals_engine::{
     BalanceOf, ProposalCreationParameters, ProposalObserver, ProposalParameters,
 };
+use storage::StorageAdministrativeActionRecord;
 pub use types::{
     CreateOpeningParameters, FillOpeningParameters, GeneralProposalParams, ProposalDetails,
     ProposalDetailsOf, ProposalEncoder, TerminateRoleParameters,
@@ -101,6 +103,9 @@ pub trait WeightInfo {
     fn create_proposal_lock_blog_post(t: u32, d: u32) -> Weight;
     fn create_proposal_unlock_blog_post(t: u32, d: u32) -> Weight;
     fn create_proposal_veto_proposal(t: u32, d: u32) -> Weight;
+    fn create_proposal_update_data_size_fee_schedule(t: u32, d: u32) -> Weight;
+    fn create_proposal_storage_administrative_action(t: u32, d: u32) -> Weight;
+    fn create_proposal_set_working_group_deputy_lead(t: u32, d: u32) -> Weight;
 }
 
 type WeightInfoCodex<T> = <T as Trait>::WeightInfo;
@@ -111,6 +116,7 @@ pub trait Trait:
     + proposals_engine::Trait
     + proposals_discussion::Trait
     + common::membership::MembershipTypes
+    + common::StorageOwnership
     + staking::Trait
     + proposals_engine::Trait
 {
@@ -246,6 +252,21 @@ pub trait Trait:
 
     /// `Veto Proposal` proposal parameters
     type VetoProposalProposalParameters: Get<ProposalParameters<Self::BlockNumber, BalanceOf<Self>>>;
+
+    /// `Update Data Size Fee Schedule` proposal parameters
+    type UpdateDataSizeFeeScheduleProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Storage Administrative Action` proposal parameters
+    type StorageAdministrativeActionProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Set Working Group Deputy Lead` proposal parameters
+    type SetWorkingGroupDeputyLeadProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
 }
 
 /// Specialized alias of GeneralProposalParams
@@ -332,6 +353,10 @@ decl_error! {
 
         /// Repeated account in 'Funding Request' proposal.
         InvalidFundingRequestProposalRepeatedAccount,
+
+        /// Invalid 'Storage Administrative Action' proposal parameter - the deletion prize and
+        /// the data size fee should be paid from the proposer account.
+        InvalidStorageAdministrativeActionFundingAccount,
     }
 }
 
@@ -444,6 +469,18 @@ decl_module! {
         const VetoProposalProposalParameters:
             ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::VetoProposalProposalParameters::get();
 
+        const UpdateDataSizeFeeScheduleProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> =
+                T::UpdateDataSizeFeeScheduleProposalParameters::get();
+
+        const StorageAdministrativeActionProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> =
+                T::StorageAdministrativeActionProposalParameters::get();
+
+        const SetWorkingGroupDeputyLeadProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> =
+                T::SetWorkingGroupDeputyLeadProposalParameters::get();
+
 
         /// Create a proposal, the type of proposal depends on the `proposal_details` variant
         ///
@@ -482,6 +519,8 @@ decl_module! {
                     general_proposal_parameters.member_id
                 )?;
 
+            Self::ensure_proposal_funding_account(&proposal_details, &account_id)?;
+
             <proposals_engine::Module<T>>::ensure_create_proposal_parameters_are_valid(
                 &proposal_parameters,
                 &general_proposal_parameters.title,
@@ -646,6 +685,41 @@ impl<T: Trait> Module<T> {
             ProposalDetails::VetoProposal(..) => {
                 // Note: No checks for this proposal for now
             }
+            ProposalDetails::UpdateDataSizeFeeSchedule(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::StorageAdministrativeAction(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::SetWorkingGroupDeputyLead(..) => {
+                // Note: No checks for this proposal for now
+            }
+        }
+
+        Ok(())
+    }
+
+    // Ensures the proposal execution is funded from the proposer account.
+    fn ensure_proposal_funding_account(
+        details: &ProposalDetailsOf<T>,
+        account_id: &T::AccountId,
+    ) -> DispatchResult {
+        if let ProposalDetails::StorageAdministrativeAction(action) = details {
+            let funding_account_id = match action {
+                StorageAdministrativeActionRecord::UploadDataObjects(params) => {
+                    Some(&params.deletion_prize_source_account_id)
+                }
+                StorageAdministrativeActionRecord::CreateDynamicBag(_, deletion_prize) => {
+                    deletion_prize.as_ref().map(|prize| &prize.account_id)
+                }
+            };
+
+            ensure!(
+                funding_account_id.map_or(true, |funding_account_id| {
+                    funding_account_id == account_id
+                }),
+                Error::<T>::InvalidStorageAdministrativeActionFundingAccount
+            );
         }
 
         Ok(())
@@ -711,6 +785,15 @@ impl<T: Trait> Module<T> {
             ProposalDetails::LockBlogPost(..) => T::LockBlogPostProposalParameters::get(),
             ProposalDetails::UnlockBlogPost(..) => T::UnlockBlogPostProposalParameters::get(),
             ProposalDetails::VetoProposal(..) => T::VetoProposalProposalParameters::get(),
+            ProposalDetails::UpdateDataSizeFeeSchedule(..) => {
+                T::UpdateDataSizeFeeScheduleProposalParameters::get()
+            }
+            ProposalDetails::StorageAdministrativeAction(..) => {
+                T::StorageAdministrativeActionProposalParameters::get()
+            }
+            ProposalDetails::SetWorkingGroupDeputyLead(..) => {
+                T::SetWorkingGroupDeputyLeadProposalParameters::get()
+            }
         }
     }
 
@@ -878,6 +961,24 @@ impl<T: Trait> Module<T> {
                 )
                 .saturated_into()
             }
+            ProposalDetails::UpdateDataSizeFeeSchedule(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_data_size_fee_schedule(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::StorageAdministrativeAction(..) => {
+                WeightInfoCodex::<T>::create_proposal_storage_administrative_action(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::SetWorkingGroupDeputyLead(..) => {
+                WeightInfoCodex::<T>::create_proposal_set_working_group_deputy_lead(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
         }
     }
 }
@@ -79,6 +79,12 @@ impl common::membership::MembershipTypes for Test {
     type ActorId = u64;
 }
 
+impl common::StorageOwnership for Test {
+    type ChannelId = u64;
+    type ContentId = u64;
+    type DataObjectTypeId = u64;
+}
+
 // Weights info stub
 pub struct Weights;
 impl membership::WeightInfo for Weights {
@@ -382,6 +388,7 @@ pub type MembershipWorkingGroupInstance = working_group::Instance4;
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
+    pub const MaxDeputyLeads: u32 = 10;
     pub const LockId1: [u8; 8] = [1; 8];
     pub const LockId2: [u8; 8] = [2; 8];
     pub const MinimumApplicationStake: u32 = 50;
@@ -392,6 +399,7 @@ pub struct WorkingGroupWeightInfo;
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId1>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
@@ -469,11 +477,15 @@ impl working_group::WeightInfo for WorkingGroupWeightInfo {
     fn leave_role(_: u32) -> Weight {
         0
     }
+    fn set_deputy_lead() -> Weight {
+        0
+    }
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
@@ -487,6 +499,7 @@ impl working_group::Trait<StorageWorkingGroupInstance> for Test {
 impl working_group::Trait<ForumWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = staking_handler::StakingManager<Self, LockId2>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
@@ -500,6 +513,7 @@ impl working_group::Trait<ForumWorkingGroupInstance> for Test {
 impl working_group::Trait<MembershipWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
@@ -621,6 +635,9 @@ impl crate::Trait for Test {
     type LockBlogPostProposalParameters = DefaultProposalParameters;
     type UnlockBlogPostProposalParameters = DefaultProposalParameters;
     type VetoProposalProposalParameters = DefaultProposalParameters;
+    type UpdateDataSizeFeeScheduleProposalParameters = DefaultProposalParameters;
+    type StorageAdministrativeActionProposalParameters = DefaultProposalParameters;
+    type SetWorkingGroupDeputyLeadProposalParameters = DefaultProposalParameters;
 }
 
 parameter_types! {
@@ -884,6 +901,15 @@ impl crate::WeightInfo for () {
     fn create_proposal_veto_proposal(_: u32, _: u32) -> Weight {
         0
     }
+    fn create_proposal_update_data_size_fee_schedule(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_storage_administrative_action(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_set_working_group_deputy_lead(_: u32, _: u32) -> Weight {
+        0
+    }
 }
 
 impl ProposalEncoder<Test> for () {
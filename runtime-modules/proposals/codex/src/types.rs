@@ -3,13 +3,15 @@
 use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
 use common::working_group::WorkingGroup;
 use common::BalanceKind;
 use common::FundingRequestParameters;
 
-use working_group::StakePolicy;
+use storage::{DataSizeFeeCategory, DataSizeFeeSchedule, StorageAdministrativeActionRecord};
+use working_group::{DeputyLeadPermission, StakePolicy};
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Trait> {
@@ -26,12 +28,24 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     working_group::OpeningId,
     blog::PostId,
     <T as proposals_engine::Trait>::ProposalId,
+    common::MemberId<T>,
+    <T as common::StorageOwnership>::ChannelId,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
-pub enum ProposalDetails<Balance, BlockNumber, AccountId, WorkerId, OpeningId, PostId, ProposalId> {
+pub enum ProposalDetails<
+    Balance,
+    BlockNumber,
+    AccountId,
+    WorkerId,
+    OpeningId,
+    PostId,
+    ProposalId,
+    MemberId,
+    ChannelId,
+> {
     /// The signal of the `Signal` proposal
     Signal(Vec<u8>),
 
@@ -111,10 +125,45 @@ pub enum ProposalDetails<Balance, BlockNumber, AccountId, WorkerId, OpeningId, P
 
     /// `Veto Proposal` proposal
     VetoProposal(ProposalId),
+
+    /// `Update Data Size Fee Schedule` proposal:
+    /// Sets or removes the storage data size fee schedule of the bag category.
+    UpdateDataSizeFeeSchedule(DataSizeFeeCategory, Option<DataSizeFeeSchedule<Balance>>),
+
+    /// `Storage Administrative Action` proposal:
+    /// Uploads data objects or creates a dynamic bag on behalf of the governance. The deletion
+    /// prize and the data size fee are paid from the proposer account.
+    StorageAdministrativeAction(
+        StorageAdministrativeActionRecord<MemberId, ChannelId, AccountId, Balance>,
+    ),
+
+    /// `Set Working Group Deputy Lead` proposal:
+    /// Appoints the working group deputy lead or removes it with the empty permission set.
+    SetWorkingGroupDeputyLead(WorkerId, BTreeSet<DeputyLeadPermission>, WorkingGroup),
 }
 
-impl<Balance, BlockNumber, AccountId, WorkerId, OpeningId, PostId, ProposalId> Default
-    for ProposalDetails<Balance, BlockNumber, AccountId, WorkerId, OpeningId, PostId, ProposalId>
+impl<
+        Balance,
+        BlockNumber,
+        AccountId,
+        WorkerId,
+        OpeningId,
+        PostId,
+        ProposalId,
+        MemberId,
+        ChannelId,
+    > Default
+    for ProposalDetails<
+        Balance,
+        BlockNumber,
+        AccountId,
+        WorkerId,
+        OpeningId,
+        PostId,
+        ProposalId,
+        MemberId,
+        ChannelId,
+    >
 {
     fn default() -> Self {
         ProposalDetails::Signal(b"invalid proposal details".to_vec())
@@ -0,0 +1,228 @@
+//! Node RPC for the storage pallet: exposes the `StorageApi` runtime API queries.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+pub use storage_runtime_api::StorageApi as StorageRuntimeApi;
+
+/// Error code of the failed runtime API call.
+const RUNTIME_ERROR: i64 = 1;
+
+/// Storage bag and bucket queries for the storage and distribution nodes.
+#[rpc]
+pub trait StorageApi<
+    BlockHash,
+    BagId,
+    DataObjectId,
+    DataObject,
+    StorageBucketId,
+    DistributionBucketId,
+    Voucher,
+    DataObjectCreationParameters,
+    UploadFeeQuote,
+>
+{
+    /// Page of the bags stored by the storage bucket.
+    #[rpc(name = "storage_storageBucketBags")]
+    fn storage_bucket_bags(
+        &self,
+        storage_bucket_id: StorageBucketId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<BagId>>;
+
+    /// Page of the bags distributed by the distribution bucket.
+    #[rpc(name = "storage_distributionBucketBags")]
+    fn distribution_bucket_bags(
+        &self,
+        distribution_bucket_id: DistributionBucketId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<BagId>>;
+
+    /// Page of the bag data objects with their accepted status.
+    #[rpc(name = "storage_bagDataObjects")]
+    fn bag_data_objects(
+        &self,
+        bag_id: BagId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(DataObjectId, DataObject)>>;
+
+    /// Voucher of the storage bucket (`None` for the non-existing bucket).
+    #[rpc(name = "storage_storageBucketVoucher")]
+    fn storage_bucket_voucher(
+        &self,
+        storage_bucket_id: StorageBucketId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Voucher>>;
+
+    /// Fees of the prospective upload of the data objects to the bag.
+    #[rpc(name = "storage_uploadFeeQuote")]
+    fn upload_fee_quote(
+        &self,
+        bag_id: BagId,
+        object_creation_list: Vec<DataObjectCreationParameters>,
+        at: Option<BlockHash>,
+    ) -> Result<UploadFeeQuote>;
+}
+
+/// Implementation of the storage RPC over the `StorageApi` runtime API.
+pub struct Storage<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> Storage<C, B> {
+    /// Creates the storage RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// Converts the runtime API call error to the RPC error.
+fn runtime_error_into_rpc_error(error: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Storage runtime API call failed.".into(),
+        data: Some(format!("{:?}", error).into()),
+    }
+}
+
+impl<
+        C,
+        Block,
+        BagId,
+        DynamicBagId,
+        DataObjectId,
+        DataObject,
+        StorageBucketId,
+        DistributionBucketId,
+        Voucher,
+        DataObjectCreationParameters,
+        UploadFeeQuote,
+        BlockNumber,
+    >
+    StorageApi<
+        <Block as BlockT>::Hash,
+        BagId,
+        DataObjectId,
+        DataObject,
+        StorageBucketId,
+        DistributionBucketId,
+        Voucher,
+        DataObjectCreationParameters,
+        UploadFeeQuote,
+    > for Storage<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: StorageRuntimeApi<
+        Block,
+        BagId,
+        DynamicBagId,
+        DataObjectId,
+        DataObject,
+        StorageBucketId,
+        DistributionBucketId,
+        Voucher,
+        DataObjectCreationParameters,
+        UploadFeeQuote,
+        BlockNumber,
+    >,
+    BagId: Codec,
+    DynamicBagId: Codec,
+    DataObjectId: Codec,
+    DataObject: Codec,
+    StorageBucketId: Codec,
+    DistributionBucketId: Codec,
+    Voucher: Codec,
+    DataObjectCreationParameters: Codec,
+    UploadFeeQuote: Codec,
+    BlockNumber: Codec,
+{
+    fn storage_bucket_bags(
+        &self,
+        storage_bucket_id: StorageBucketId,
+        offset: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<BagId>> {
+        self.client
+            .runtime_api()
+            .storage_bucket_bags(&self.block_id(at), storage_bucket_id, offset, limit)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn distribution_bucket_bags(
+        &self,
+        distribution_bucket_id: DistributionBucketId,
+        offset: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<BagId>> {
+        self.client
+            .runtime_api()
+            .distribution_bucket_bags(&self.block_id(at), distribution_bucket_id, offset, limit)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn bag_data_objects(
+        &self,
+        bag_id: BagId,
+        offset: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(DataObjectId, DataObject)>> {
+        self.client
+            .runtime_api()
+            .bag_data_objects(&self.block_id(at), bag_id, offset, limit)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn storage_bucket_voucher(
+        &self,
+        storage_bucket_id: StorageBucketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<Voucher>> {
+        self.client
+            .runtime_api()
+            .storage_bucket_voucher(&self.block_id(at), storage_bucket_id)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn upload_fee_quote(
+        &self,
+        bag_id: BagId,
+        object_creation_list: Vec<DataObjectCreationParameters>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<UploadFeeQuote> {
+        self.client
+            .runtime_api()
+            .upload_fee_quote(&self.block_id(at), bag_id, object_creation_list)
+            .map_err(runtime_error_into_rpc_error)
+    }
+}
+
+impl<C, Block> Storage<C, Block>
+where
+    Block: BlockT,
+    C: HeaderBackend<Block>,
+{
+    // Requested block or the best block by default.
+    fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
@@ -0,0 +1,84 @@
+//! Runtime API definition for the storage pallet.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+// Internal Substrate warning (decl_runtime_apis).
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Storage state queries for off-chain services (e.g. storage nodes).
+    pub trait StorageApi<
+        BagId,
+        DynamicBagId,
+        DataObjectId,
+        DataObject,
+        StorageBucketId,
+        DistributionBucketId,
+        Voucher,
+        DataObjectCreationParameters,
+        UploadFeeQuote,
+        BlockNumber,
+    >
+    where
+        BagId: Codec,
+        DynamicBagId: Codec,
+        DataObjectId: Codec,
+        DataObject: Codec,
+        StorageBucketId: Codec,
+        DistributionBucketId: Codec,
+        Voucher: Codec,
+        DataObjectCreationParameters: Codec,
+        UploadFeeQuote: Codec,
+        BlockNumber: Codec,
+    {
+        /// Dynamic bags with less active storage buckets than required by the dynamic bag
+        /// creation policy.
+        fn under_replicated_bags() -> Vec<DynamicBagId>;
+
+        /// Last prepaid block of the dynamic bags subject to the storage rent.
+        fn bags_rent_paid_through() -> Vec<(DynamicBagId, BlockNumber)>;
+
+        /// Data objects of the bag matching the blacklisted content ID.
+        fn blacklisted_data_objects(cid: Vec<u8>, bag_id: BagId) -> Vec<DataObjectId>;
+
+        /// Page of the data objects of all bags matching the blacklisted content ID.
+        fn blacklisted_data_objects_page(
+            cid: Vec<u8>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(BagId, DataObjectId)>;
+
+        /// Page of the bags stored by the storage bucket.
+        fn storage_bucket_bags(
+            storage_bucket_id: StorageBucketId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<BagId>;
+
+        /// Page of the bags distributed by the distribution bucket.
+        fn distribution_bucket_bags(
+            distribution_bucket_id: DistributionBucketId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<BagId>;
+
+        /// Page of the bag data objects with their accepted status.
+        fn bag_data_objects(
+            bag_id: BagId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(DataObjectId, DataObject)>;
+
+        /// Voucher of the storage bucket (`None` for the non-existing bucket).
+        fn storage_bucket_voucher(storage_bucket_id: StorageBucketId) -> Option<Voucher>;
+
+        /// Fees of the prospective upload of the data objects to the bag.
+        fn upload_fee_quote(
+            bag_id: BagId,
+            object_creation_list: Vec<DataObjectCreationParameters>,
+        ) -> UploadFeeQuote;
+    }
+}
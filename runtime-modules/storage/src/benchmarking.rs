@@ -1,47 +1,2067 @@
 #![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use balances::Module as Balances;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::storage::{StorageDoubleMap, StorageMap, StorageValue};
+use frame_support::traits::Currency;
+use frame_system::Module as System;
+use frame_system::{EventRecord, RawOrigin};
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+use working_group::{CurrentLead, Worker, WorkerById};
 
-//TODO: implement benchmarks after merging with the Olympia release.
+use crate::Module as Storage;
 
-use frame_benchmarking::benchmarks;
-use frame_support::storage::StorageMap;
-use frame_system::RawOrigin;
-use sp_std::boxed::Box;
-use sp_std::vec;
-use sp_std::vec::Vec;
+// We create this trait because we need to be compatible with the runtime
+// in the mock for tests. In that case we need to be able to have `account_id == id`.
+// In the case of `AccountId32` we use the method provided by `frame_benchmarking` to get an
+// AccountId.
+pub trait CreateAccountId {
+    fn create_account_id(id: u32) -> Self;
+}
+
+impl CreateAccountId for u64 {
+    fn create_account_id(id: u32) -> Self {
+        id.into()
+    }
+}
+
+impl CreateAccountId for sp_core::crypto::AccountId32 {
+    fn create_account_id(id: u32) -> Self {
+        account::<Self>("default", id, SEED)
+    }
+}
+
+// The storage working group instance alias.
+pub type StorageWorkingGroupInstance = working_group::Instance2;
+
+// The distribution working group instance alias.
+pub type DistributionWorkingGroupInstance = working_group::Instance9;
+
+const SEED: u32 = 0;
+const MAX_BYTES: u32 = 16384;
+const MAX_OBJECTS: u32 = 100;
+const MAX_CONTENT_MERKLE_PROOF_LENGTH: u32 = 16;
+const VOUCHER_LIMIT: u64 = 1_000_000;
+
+// Account and worker ids match the ones authorized by the pallet mock.
+const STORAGE_WG_LEADER_ACCOUNT_ID: u32 = 100001;
+const DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID: u32 = 100002;
+const DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID: u32 = 100003;
+const DISTRIBUTION_WG_LEADER_ACCOUNT_ID: u32 = 100004;
+const LEADER_WORKER_ID: u64 = 0;
+const DEFAULT_STORAGE_PROVIDER_ID: u64 = 10;
+const DEFAULT_DISTRIBUTION_PROVIDER_ID: u64 = 12;
+const DEFAULT_MEMBER_ACCOUNT_ID: u32 = 101;
+const DEFAULT_MEMBER_ID: u64 = 100;
+const DEFAULT_CHANNEL_ID: u64 = 100;
+
+fn assert_last_event<T: Trait>(generic_event: <T as Trait>::Event) {
+    let events = System::<T>::events();
+    let system_event: <T as frame_system::Trait>::Event = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+fn insert_worker<T, I>(account_id: T::AccountId, worker_id: WorkerId<T>)
+where
+    T: Trait + working_group::Trait<I>,
+    I: frame_support::traits::Instance,
+{
+    let worker = Worker::<T>::new(
+        &Default::default(),
+        &account_id,
+        &account_id,
+        &account_id,
+        Zero::zero(),
+        None,
+        System::<T>::block_number(),
+    );
+
+    WorkerById::<T, I>::insert(worker_id, worker);
+}
+
+fn insert_leader<T, I>(account_id: T::AccountId)
+where
+    T: Trait + working_group::Trait<I>,
+    I: frame_support::traits::Instance,
+{
+    let leader_id: WorkerId<T> = LEADER_WORKER_ID.saturated_into();
+
+    insert_worker::<T, I>(account_id, leader_id);
+
+    CurrentLead::<T, I>::put(leader_id);
+}
+
+fn storage_lead<T>() -> T::AccountId
+where
+    T: Trait + working_group::Trait<StorageWorkingGroupInstance>,
+    T::AccountId: CreateAccountId,
+{
+    let account_id = T::AccountId::create_account_id(STORAGE_WG_LEADER_ACCOUNT_ID);
+
+    insert_leader::<T, StorageWorkingGroupInstance>(account_id.clone());
+
+    account_id
+}
+
+fn storage_provider<T>() -> (T::AccountId, WorkerId<T>)
+where
+    T: Trait + working_group::Trait<StorageWorkingGroupInstance>,
+    T::AccountId: CreateAccountId,
+{
+    let account_id = T::AccountId::create_account_id(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID);
+    let worker_id: WorkerId<T> = DEFAULT_STORAGE_PROVIDER_ID.saturated_into();
+
+    insert_worker::<T, StorageWorkingGroupInstance>(account_id.clone(), worker_id);
+
+    (account_id, worker_id)
+}
+
+fn distribution_lead<T>() -> T::AccountId
+where
+    T: Trait + working_group::Trait<DistributionWorkingGroupInstance>,
+    T::AccountId: CreateAccountId,
+{
+    let account_id = T::AccountId::create_account_id(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
+
+    insert_leader::<T, DistributionWorkingGroupInstance>(account_id.clone());
+
+    account_id
+}
+
+fn distribution_provider<T>() -> (T::AccountId, WorkerId<T>)
+where
+    T: Trait + working_group::Trait<DistributionWorkingGroupInstance>,
+    T::AccountId: CreateAccountId,
+{
+    let account_id = T::AccountId::create_account_id(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID);
+    let worker_id: WorkerId<T> = DEFAULT_DISTRIBUTION_PROVIDER_ID.saturated_into();
+
+    insert_worker::<T, DistributionWorkingGroupInstance>(account_id.clone(), worker_id);
+
+    (account_id, worker_id)
+}
+
+fn funded_account<T: Trait>() -> T::AccountId
+where
+    T::AccountId: CreateAccountId,
+{
+    let account_id = T::AccountId::create_account_id(DEFAULT_MEMBER_ACCOUNT_ID);
+
+    let _ = Balances::<T>::make_free_balance_be(&account_id, BalanceOf::<T>::max_value());
+
+    account_id
+}
+
+fn max_storage_buckets_per_bag<T: Trait>() -> u32 {
+    T::StorageBucketsPerBagValueConstraint::get()
+        .max()
+        .saturated_into()
+}
+
+fn max_distribution_buckets_per_bag<T: Trait>() -> u32 {
+    T::DistributionBucketsPerBagValueConstraint::get()
+        .max()
+        .saturated_into()
+}
+
+// Content ID of the given length unique for the provided id.
+fn create_cid(id: u32) -> Cid {
+    let mut cid = id.to_be_bytes().to_vec();
+    cid.resize(46, 1u8);
+
+    cid
+}
+
+fn create_storage_buckets<T: Trait>(
+    lead_account_id: &T::AccountId,
+    number: u32,
+) -> BTreeSet<T::StorageBucketId> {
+    Storage::<T>::update_storage_buckets_voucher_max_limits(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        VOUCHER_LIMIT,
+        VOUCHER_LIMIT,
+    )
+    .unwrap();
+
+    (0..number)
+        .map(|_| {
+            let bucket_id = Storage::<T>::next_storage_bucket_id();
+
+            Storage::<T>::create_storage_bucket(
+                RawOrigin::Signed(lead_account_id.clone()).into(),
+                None,
+                true,
+                VOUCHER_LIMIT,
+                VOUCHER_LIMIT,
+            )
+            .unwrap();
+
+            bucket_id
+        })
+        .collect()
+}
+
+fn assign_storage_buckets<T: Trait>(
+    lead_account_id: &T::AccountId,
+    bag_id: BagId<T>,
+    buckets: BTreeSet<T::StorageBucketId>,
+) {
+    Storage::<T>::update_storage_buckets_per_bag_limit(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        max_storage_buckets_per_bag::<T>().into(),
+    )
+    .unwrap();
+
+    Storage::<T>::update_storage_buckets_for_bag(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        bag_id,
+        buckets,
+        BTreeSet::new(),
+    )
+    .unwrap();
+}
+
+fn create_distribution_buckets<T: Trait>(
+    lead_account_id: &T::AccountId,
+    number: u32,
+) -> (
+    T::DistributionBucketFamilyId,
+    BTreeSet<T::DistributionBucketIndex>,
+) {
+    let family_id = Storage::<T>::next_distribution_bucket_family_id();
+
+    Storage::<T>::create_distribution_bucket_family(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+    )
+    .unwrap();
+
+    let bucket_indices = (0..number)
+        .map(|_| {
+            let bucket_index = Storage::<T>::distribution_bucket_family_by_id(family_id)
+                .next_distribution_bucket_index;
+
+            Storage::<T>::create_distribution_bucket(
+                RawOrigin::Signed(lead_account_id.clone()).into(),
+                family_id,
+                true,
+            )
+            .unwrap();
+
+            bucket_index
+        })
+        .collect();
+
+    (family_id, bucket_indices)
+}
 
-use crate::{Call, Module, StorageBucketById, Trait};
+fn create_distribution_bucket<T: Trait>(lead_account_id: &T::AccountId) -> DistributionBucketId<T> {
+    let (family_id, bucket_indices) = create_distribution_buckets::<T>(lead_account_id, 1);
+
+    DistributionBucketId::<T> {
+        distribution_bucket_family_id: family_id,
+        distribution_bucket_index: *bucket_indices.iter().next().unwrap(),
+    }
+}
+
+fn upload_objects<T: Trait>(
+    account_id: &T::AccountId,
+    bag_id: BagId<T>,
+    number: u32,
+) -> BTreeSet<T::DataObjectId>
+where
+    T::AccountId: CreateAccountId,
+{
+    let first_object_id = Storage::<T>::next_data_object_id();
+
+    let params = UploadParameters::<T> {
+        bag_id,
+        object_creation_list: (0..number)
+            .map(|id| DataObjectCreationParameters {
+                size: 1,
+                ipfs_content_id: create_cid(id),
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
+            })
+            .collect(),
+        deletion_prize_source_account_id: account_id.clone(),
+        expected_data_size_fee: Storage::<T>::data_object_per_mega_byte_fee(),
+    };
+
+    Storage::<T>::upload_data_objects(params).unwrap();
+
+    (0..number)
+        .map(|id| first_object_id + id.saturated_into())
+        .collect()
+}
+
+fn create_dynamic_bag_policy<T: Trait>(
+    storage_lead_account_id: &T::AccountId,
+    distribution_lead_account_id: &T::AccountId,
+    storage_buckets_number: u32,
+    distribution_buckets_number: u32,
+) {
+    create_storage_buckets::<T>(storage_lead_account_id, storage_buckets_number);
+
+    Storage::<T>::update_number_of_storage_buckets_in_dynamic_bag_creation_policy(
+        RawOrigin::Signed(storage_lead_account_id.clone()).into(),
+        DynamicBagType::Member,
+        storage_buckets_number.into(),
+    )
+    .unwrap();
+
+    let (family_id, _) =
+        create_distribution_buckets::<T>(distribution_lead_account_id, distribution_buckets_number);
+
+    let mut families = BTreeMap::new();
+    families.insert(family_id, distribution_buckets_number);
+
+    Storage::<T>::update_families_in_dynamic_bag_creation_policy(
+        RawOrigin::Signed(distribution_lead_account_id.clone()).into(),
+        DynamicBagType::Member,
+        families,
+    )
+    .unwrap();
+}
 
 benchmarks! {
     where_clause {
-        where T: balances::Trait,
-              T: membership::Trait,
-              T: Trait,
+        where T: Trait,
+              T: working_group::Trait<StorageWorkingGroupInstance>,
+              T: working_group::Trait<DistributionWorkingGroupInstance>,
+              T::AccountId: CreateAccountId,
     }
     _{ }
 
+    delete_storage_bucket {
+        let lead_account_id = storage_lead::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id)
+    verify {
+        assert!(!StorageBucketById::<T>::contains_key(bucket_id));
+        assert_last_event::<T>(RawEvent::StorageBucketDeleted(bucket_id).into());
+    }
+
+    update_uploading_blocked_status {
+        let lead_account_id = storage_lead::<T>();
+    }: _ (RawOrigin::Signed(lead_account_id), true)
+    verify {
+        assert!(Storage::<T>::uploading_blocked());
+        assert_last_event::<T>(RawEvent::UploadingBlockStatusUpdated(true).into());
+    }
+
+    update_data_size_fee {
+        let lead_account_id = storage_lead::<T>();
+        let fee: BalanceOf<T> = One::one();
+    }: _ (RawOrigin::Signed(lead_account_id), fee)
+    verify {
+        assert_eq!(Storage::<T>::data_object_per_mega_byte_fee(), fee);
+        assert_last_event::<T>(RawEvent::DataObjectPerMegabyteFeeUpdated(fee).into());
+    }
+
+    update_data_size_fee_schedule {
+        let lead_account_id = storage_lead::<T>();
+        let category = DataSizeFeeCategory::Dynamic(DynamicBagType::Channel);
+        let fee: BalanceOf<T> = One::one();
+        let schedule = DataSizeFeeSchedule {
+            per_megabyte_fee: fee,
+            object_class_fees: vec![
+                (DataObjectClass::Media, fee),
+                (DataObjectClass::Thumbnail, fee),
+                (DataObjectClass::Metadata, fee),
+            ]
+            .into_iter()
+            .collect(),
+        };
+    }: _ (RawOrigin::Signed(lead_account_id), category.clone(), Some(schedule.clone()))
+    verify {
+        assert_eq!(Storage::<T>::data_size_fee_schedule(&category), Some(schedule.clone()));
+        assert_last_event::<T>(
+            RawEvent::DataSizeFeeScheduleUpdated(category, Some(schedule)).into()
+        );
+    }
+
+    update_storage_rent {
+        let lead_account_id = storage_lead::<T>();
+        let rent: BalanceOf<T> = One::one();
+    }: _ (RawOrigin::Signed(lead_account_id), rent)
+    verify {
+        assert_eq!(Storage::<T>::data_object_per_mega_byte_per_block_rent(), rent);
+        assert_last_event::<T>(RawEvent::StorageRentUpdated(rent).into());
+    }
+
+    update_data_object_reference_fee {
+        let lead_account_id = storage_lead::<T>();
+        let fee: Option<BalanceOf<T>> = Some(One::one());
+    }: _ (RawOrigin::Signed(lead_account_id), fee)
+    verify {
+        assert_eq!(Storage::<T>::data_object_reference_fee(), fee);
+        assert_last_event::<T>(RawEvent::DataObjectReferenceFeeUpdated(fee).into());
+    }
+
+    update_dynamic_bag_quota {
+        let lead_account_id = storage_lead::<T>();
+        let quota = Some(BagQuota {
+            objects_limit: 1000,
+            size_limit: 1000000,
+        });
+    }: _ (RawOrigin::Signed(lead_account_id), DynamicBagType::Member, quota)
+    verify {
+        assert_eq!(Storage::<T>::dynamic_bag_quota(DynamicBagType::Member), quota);
+        assert_last_event::<T>(
+            RawEvent::DynamicBagQuotaUpdated(DynamicBagType::Member, quota).into()
+        );
+    }
+
+    set_bag_quota_override {
+        let lead_account_id = storage_lead::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+        let quota = Some(BagQuota {
+            objects_limit: 1000,
+            size_limit: 1000000,
+        });
+    }: _ (RawOrigin::Signed(lead_account_id), bag_id.clone(), quota)
+    verify {
+        assert_eq!(Storage::<T>::bag_quota_override(&bag_id), quota);
+        assert_last_event::<T>(RawEvent::BagQuotaOverrideUpdated(bag_id, quota).into());
+    }
+
+    update_storage_operator_reward_rates {
+        let lead_account_id = storage_lead::<T>();
+        let rates = OperatorRewardRates::<BalanceOf<T>> {
+            per_megabyte: One::one(),
+            per_bag: One::one(),
+        };
+    }: _ (RawOrigin::Signed(lead_account_id), rates)
+    verify {
+        assert_eq!(Storage::<T>::storage_operator_reward_rates(), rates);
+        assert_last_event::<T>(RawEvent::StorageOperatorRewardRatesUpdated(rates).into());
+    }
+
+    top_up_bag_rent {
+        let lead_account_id = storage_lead::<T>();
+        let account_id = funded_account::<T>();
+
+        Storage::<T>::update_storage_rent(
+            RawOrigin::Signed(lead_account_id).into(),
+            One::one(),
+        ).unwrap();
+
+        // The new bag gets the initial prepaid period: the top-up reschedules its expiration.
+        let dynamic_bag_id = DynamicBagId::<T>::Member(Default::default());
+        Storage::<T>::create_dynamic_bag(dynamic_bag_id.clone(), None).unwrap();
+
+        upload_objects::<T>(&account_id, dynamic_bag_id.clone().into(), 1);
+
+        let blocks: T::BlockNumber = 10u32.into();
+        let paid_through = Storage::<T>::bag_rent_paid_through(&dynamic_bag_id).unwrap() + blocks;
+    }: _ (RawOrigin::Signed(account_id), dynamic_bag_id.clone(), blocks)
+    verify {
+        assert_eq!(
+            Storage::<T>::bag_rent_paid_through(&dynamic_bag_id),
+            Some(paid_through)
+        );
+    }
+
+    update_storage_buckets_per_bag_limit {
+        let lead_account_id = storage_lead::<T>();
+        let new_limit: u64 = max_storage_buckets_per_bag::<T>().into();
+    }: _ (RawOrigin::Signed(lead_account_id), new_limit)
+    verify {
+        assert_eq!(Storage::<T>::storage_buckets_per_bag_limit(), new_limit);
+        assert_last_event::<T>(RawEvent::StorageBucketsPerBagLimitUpdated(new_limit).into());
+    }
+
+    update_storage_buckets_voucher_max_limits {
+        let lead_account_id = storage_lead::<T>();
+    }: _ (RawOrigin::Signed(lead_account_id), VOUCHER_LIMIT, VOUCHER_LIMIT)
+    verify {
+        assert_eq!(Storage::<T>::voucher_max_objects_size_limit(), VOUCHER_LIMIT);
+        assert_eq!(Storage::<T>::voucher_max_objects_number_limit(), VOUCHER_LIMIT);
+    }
+
+    update_number_of_storage_buckets_in_dynamic_bag_creation_policy {
+        let lead_account_id = storage_lead::<T>();
+        let number_of_storage_buckets: u64 = max_storage_buckets_per_bag::<T>().into();
+    }: _ (RawOrigin::Signed(lead_account_id), DynamicBagType::Channel, number_of_storage_buckets)
+    verify {
+        assert_eq!(
+            Storage::<T>::get_dynamic_bag_creation_policy(DynamicBagType::Channel)
+                .number_of_storage_buckets,
+            number_of_storage_buckets
+        );
+    }
+
+    update_blacklist {
+        let i in 0 .. T::BlacklistSizeLimit::get().saturated_into();
+
+        let j in 0 .. T::BlacklistSizeLimit::get().saturated_into();
+
+        let k in 0 .. MAX_BYTES;
+
+        let lead_account_id = storage_lead::<T>();
+
+        // `k` bytes in total spread over the hashes
+        let cid_length = (k / (i + j).max(1)) as usize;
+        let create_cid = |id: u32| {
+            let mut cid = create_cid(id);
+            cid.resize(cid_length.max(cid.len()), 0);
+            cid
+        };
+
+        let remove_hashes = (0..i).map(create_cid).collect::<BTreeSet<_>>();
+        for cid in remove_hashes.iter() {
+            Blacklist::<T>::insert(cid, BlacklistEntry::default());
+        }
+        CurrentBlacklistSize::put(u64::from(i));
+
+        let add_hashes = (i..i + j).map(create_cid).collect::<BTreeSet<_>>();
+    }: _ (RawOrigin::Signed(lead_account_id), remove_hashes.clone(), add_hashes.clone())
+    verify {
+        assert_eq!(Storage::<T>::current_blacklist_size(), u64::from(j));
+        assert_last_event::<T>(RawEvent::UpdateBlacklist(remove_hashes, add_hashes).into());
+    }
+
+    set_blacklist_entry {
+        let i in 0 .. MAX_BYTES;
+
+        let lead_account_id = storage_lead::<T>();
+        let cid = create_cid(0);
+
+        // Rescheduling the existing expiration is the worst case.
+        Storage::<T>::set_blacklist_entry(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            cid.clone(),
+            BlacklistEntry {
+                rationale: Vec::new(),
+                expires_at: Some(System::<T>::block_number() + 10u32.into()),
+            },
+        ).unwrap();
+
+        let entry = BlacklistEntry {
+            rationale: vec![1u8; i as usize],
+            expires_at: Some(System::<T>::block_number() + 20u32.into()),
+        };
+    }: _ (RawOrigin::Signed(lead_account_id), cid.clone(), entry.clone())
+    verify {
+        assert_eq!(Storage::<T>::blacklist(&cid), entry);
+        assert_last_event::<T>(RawEvent::BlacklistEntrySet(cid, entry).into());
+    }
+
+    purge_blacklisted_data_objects {
+        let i in 1 .. MAX_OBJECTS;
+
+        let lead_account_id = storage_lead::<T>();
+        let account_id = funded_account::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+        let cid = create_cid(0);
+
+        let buckets =
+            create_storage_buckets::<T>(&lead_account_id, max_storage_buckets_per_bag::<T>());
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), buckets);
+
+        let first_object_id = Storage::<T>::next_data_object_id();
+        Storage::<T>::upload_data_objects(UploadParameters::<T> {
+            bag_id: bag_id.clone(),
+            object_creation_list: (0..i)
+                .map(|_| DataObjectCreationParameters {
+                    size: 1,
+                    ipfs_content_id: cid.clone(),
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
+                })
+                .collect(),
+            deletion_prize_source_account_id: account_id.clone(),
+            expected_data_size_fee: Storage::<T>::data_object_per_mega_byte_fee(),
+        }).unwrap();
+        let objects = (0..i)
+            .map(|id| first_object_id + id.saturated_into())
+            .collect::<BTreeSet<_>>();
+
+        Storage::<T>::update_blacklist(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            BTreeSet::new(),
+            vec![cid.clone()].into_iter().collect(),
+        ).unwrap();
+    }: _ (
+        RawOrigin::Signed(lead_account_id),
+        cid.clone(),
+        bag_id.clone(),
+        objects.clone()
+    )
+    verify {
+        assert_eq!(Storage::<T>::bag(&bag_id).objects_number, 0);
+        assert_last_event::<T>(
+            RawEvent::BlacklistedDataObjectsPurged(cid, bag_id, objects).into()
+        );
+    }
+
     create_storage_bucket {
-        let account_id: T::AccountId = Default::default();
+        let lead_account_id = storage_lead::<T>();
+        let (_, provider_id) = storage_provider::<T>();
 
+        Storage::<T>::update_storage_buckets_voucher_max_limits(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            VOUCHER_LIMIT,
+            VOUCHER_LIMIT,
+        ).unwrap();
 
-    }: _ (RawOrigin::Signed(account_id), None, false, 0, 0)
+        let bucket_id = Storage::<T>::next_storage_bucket_id();
+    }: _ (RawOrigin::Signed(lead_account_id), Some(provider_id), true, VOUCHER_LIMIT, VOUCHER_LIMIT)
     verify {
-        let storage_bucket_id: T::StorageBucketId = Default::default();
+        assert!(StorageBucketById::<T>::contains_key(bucket_id));
+        assert_last_event::<T>(
+            RawEvent::StorageBucketCreated(
+                bucket_id,
+                Some(provider_id),
+                true,
+                VOUCHER_LIMIT,
+                VOUCHER_LIMIT,
+            ).into()
+        );
+    }
+
+    update_storage_buckets_for_bag {
+        let i in 1 .. max_storage_buckets_per_bag::<T>();
+
+        let j in 1 .. max_storage_buckets_per_bag::<T>();
 
-        assert!(StorageBucketById::<T>::contains_key(storage_bucket_id));
+        let lead_account_id = storage_lead::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+
+        let remove_buckets = create_storage_buckets::<T>(&lead_account_id, j);
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), remove_buckets.clone());
+
+        let add_buckets = create_storage_buckets::<T>(&lead_account_id, i);
+    }: _ (
+        RawOrigin::Signed(lead_account_id),
+        bag_id.clone(),
+        add_buckets.clone(),
+        remove_buckets.clone()
+    )
+    verify {
+        assert_eq!(Storage::<T>::bag(&bag_id).stored_by, add_buckets);
+        assert_last_event::<T>(
+            RawEvent::StorageBucketsUpdatedForBag(bag_id, add_buckets, remove_buckets).into()
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::tests::mocks::{build_test_externalities, Test};
-    use frame_support::assert_ok;
+    cancel_storage_bucket_operator_invite {
+        let lead_account_id = storage_lead::<T>();
+        let (_, provider_id) = storage_provider::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
 
-    #[ignore] // until enabling the benchmarking for the pallet
-    #[test]
-    fn create_storage_bucket() {
-        build_test_externalities().execute_with(|| {
-            assert_ok!(test_benchmark_create_storage_bucket::<Test>());
+        Storage::<T>::invite_storage_bucket_operator(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id,
+            provider_id,
+        ).unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id)
+    verify {
+        assert_eq!(
+            Storage::<T>::storage_bucket_by_id(bucket_id).operator_status,
+            StorageBucketOperatorStatus::Missing
+        );
+        assert_last_event::<T>(RawEvent::StorageBucketInvitationCancelled(bucket_id).into());
+    }
+
+    invite_storage_bucket_operator {
+        let lead_account_id = storage_lead::<T>();
+        let (_, provider_id) = storage_provider::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id, provider_id)
+    verify {
+        assert_eq!(
+            Storage::<T>::storage_bucket_by_id(bucket_id).operator_status,
+            StorageBucketOperatorStatus::InvitedStorageWorker(provider_id)
+        );
+        assert_last_event::<T>(
+            RawEvent::StorageBucketOperatorInvited(bucket_id, provider_id).into()
+        );
+    }
+
+    remove_storage_bucket_operator {
+        let lead_account_id = storage_lead::<T>();
+        let (provider_account_id, provider_id) = storage_provider::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+
+        Storage::<T>::invite_storage_bucket_operator(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id,
+            provider_id,
+        ).unwrap();
+
+        Storage::<T>::accept_storage_bucket_invitation(
+            RawOrigin::Signed(provider_account_id.clone()).into(),
+            provider_id,
+            bucket_id,
+            provider_account_id,
+        ).unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id)
+    verify {
+        assert_eq!(
+            Storage::<T>::storage_bucket_by_id(bucket_id).operator_status,
+            StorageBucketOperatorStatus::Missing
+        );
+        assert_last_event::<T>(RawEvent::StorageBucketOperatorRemoved(bucket_id).into());
+    }
+
+    update_storage_bucket_status {
+        let lead_account_id = storage_lead::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id, false)
+    verify {
+        assert!(!Storage::<T>::storage_bucket_by_id(bucket_id).accepting_new_bags);
+        assert_last_event::<T>(RawEvent::StorageBucketStatusUpdated(bucket_id, false).into());
+    }
+
+    start_storage_bucket_draining {
+        let lead_account_id = storage_lead::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id)
+    verify {
+        assert!(!Storage::<T>::storage_bucket_by_id(bucket_id).accepting_new_bags);
+        assert!(DrainingStorageBuckets::<T>::contains_key(bucket_id));
+        assert_last_event::<T>(RawEvent::StorageBucketDrainingStarted(bucket_id).into());
+    }
+
+    cancel_storage_bucket_draining {
+        let lead_account_id = storage_lead::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+
+        Storage::<T>::start_storage_bucket_draining(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id,
+        ).unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id)
+    verify {
+        assert!(!DrainingStorageBuckets::<T>::contains_key(bucket_id));
+        assert_last_event::<T>(RawEvent::StorageBucketDrainingCanceled(bucket_id).into());
+    }
+
+    set_storage_bucket_voucher_limits {
+        let lead_account_id = storage_lead::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+        let new_limit = VOUCHER_LIMIT / 2;
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id, new_limit, new_limit)
+    verify {
+        let voucher = Storage::<T>::storage_bucket_by_id(bucket_id).voucher;
+
+        assert_eq!(voucher.size_limit, new_limit);
+        assert_eq!(voucher.objects_limit, new_limit);
+    }
+
+    accept_storage_bucket_invitation {
+        let lead_account_id = storage_lead::<T>();
+        let (provider_account_id, provider_id) = storage_provider::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+
+        Storage::<T>::invite_storage_bucket_operator(
+            RawOrigin::Signed(lead_account_id).into(),
+            bucket_id,
+            provider_id,
+        ).unwrap();
+    }: _ (
+        RawOrigin::Signed(provider_account_id.clone()),
+        provider_id,
+        bucket_id,
+        provider_account_id.clone()
+    )
+    verify {
+        assert_eq!(
+            Storage::<T>::storage_bucket_by_id(bucket_id).operator_status,
+            StorageBucketOperatorStatus::StorageWorker(provider_id, provider_account_id.clone())
+        );
+        assert_last_event::<T>(
+            RawEvent::StorageBucketInvitationAccepted(
+                bucket_id,
+                provider_id,
+                provider_account_id
+            ).into()
+        );
+    }
+
+    set_storage_operator_metadata {
+        let i in 0 .. MAX_BYTES;
+
+        let lead_account_id = storage_lead::<T>();
+        let (provider_account_id, provider_id) = storage_provider::<T>();
+        let bucket_id = *create_storage_buckets::<T>(&lead_account_id, 1).iter().next().unwrap();
+
+        Storage::<T>::invite_storage_bucket_operator(
+            RawOrigin::Signed(lead_account_id).into(),
+            bucket_id,
+            provider_id,
+        ).unwrap();
+
+        Storage::<T>::accept_storage_bucket_invitation(
+            RawOrigin::Signed(provider_account_id.clone()).into(),
+            provider_id,
+            bucket_id,
+            provider_account_id.clone(),
+        ).unwrap();
+
+        let metadata = vec![0u8; i as usize];
+    }: _ (RawOrigin::Signed(provider_account_id), provider_id, bucket_id, metadata.clone())
+    verify {
+        assert_last_event::<T>(
+            RawEvent::StorageOperatorMetadataSet(bucket_id, provider_id, metadata).into()
+        );
+    }
+
+    accept_pending_data_objects {
+        let i in 1 .. MAX_OBJECTS;
+
+        let lead_account_id = storage_lead::<T>();
+        let (provider_account_id, provider_id) = storage_provider::<T>();
+        let account_id = funded_account::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+
+        let buckets = create_storage_buckets::<T>(&lead_account_id, 1);
+        let bucket_id = *buckets.iter().next().unwrap();
+
+        Storage::<T>::invite_storage_bucket_operator(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id,
+            provider_id,
+        ).unwrap();
+
+        Storage::<T>::accept_storage_bucket_invitation(
+            RawOrigin::Signed(provider_account_id.clone()).into(),
+            provider_id,
+            bucket_id,
+            provider_account_id.clone(),
+        ).unwrap();
+
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), buckets);
+
+        let data_objects = upload_objects::<T>(&account_id, bag_id.clone(), i);
+    }: _ (
+        RawOrigin::Signed(provider_account_id),
+        provider_id,
+        bucket_id,
+        bag_id.clone(),
+        data_objects.clone()
+    )
+    verify {
+        for data_object_id in data_objects.iter() {
+            assert!(Storage::<T>::data_object_by_id(&bag_id, data_object_id).accepted);
+        }
+    }
+
+    accept_pending_data_object_parts {
+        let i in 1 .. T::MaxDataObjectPartsNumber::get().saturated_into();
+
+        let lead_account_id = storage_lead::<T>();
+        let (provider_account_id, provider_id) = storage_provider::<T>();
+        let account_id = funded_account::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+
+        let buckets = create_storage_buckets::<T>(&lead_account_id, 1);
+        let bucket_id = *buckets.iter().next().unwrap();
+
+        Storage::<T>::invite_storage_bucket_operator(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id,
+            provider_id,
+        ).unwrap();
+
+        Storage::<T>::accept_storage_bucket_invitation(
+            RawOrigin::Signed(provider_account_id.clone()).into(),
+            provider_id,
+            bucket_id,
+            provider_account_id.clone(),
+        ).unwrap();
+
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), buckets);
+
+        // The object is accepted with the last part (worst case).
+        let parts_number = T::MaxDataObjectPartsNumber::get();
+        let data_object_id = Storage::<T>::next_data_object_id();
+        Storage::<T>::upload_data_objects(UploadParameters::<T> {
+            bag_id: bag_id.clone(),
+            object_creation_list: vec![DataObjectCreationParameters {
+                size: parts_number,
+                ipfs_content_id: create_cid(0),
+                content_root: None,
+                parts: vec![[1u8; 32]; parts_number.saturated_into()],
+                class: None,
+            }],
+            deletion_prize_source_account_id: account_id,
+            expected_data_size_fee: Storage::<T>::data_object_per_mega_byte_fee(),
+        }).unwrap();
+
+        let held_parts_number: u64 = parts_number - u64::from(i);
+        <DataObjectPartsByBucket<T>>::insert(
+            data_object_id,
+            bucket_id,
+            (0..held_parts_number).collect::<BTreeSet<_>>(),
+        );
+
+        let parts = (held_parts_number..parts_number).collect::<BTreeSet<_>>();
+    }: _ (
+        RawOrigin::Signed(provider_account_id),
+        provider_id,
+        bucket_id,
+        bag_id.clone(),
+        data_object_id,
+        parts
+    )
+    verify {
+        assert!(Storage::<T>::data_object_by_id(&bag_id, data_object_id).accepted);
+    }
+
+    respond_to_storage_challenge {
+        let i in 1 .. MAX_CONTENT_MERKLE_PROOF_LENGTH;
+
+        let j in 1 .. T::ChallengeChunkSize::get().saturated_into();
+
+        let lead_account_id = storage_lead::<T>();
+        let (provider_account_id, provider_id) = storage_provider::<T>();
+        let account_id = funded_account::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+
+        let buckets = create_storage_buckets::<T>(&lead_account_id, 1);
+        let bucket_id = *buckets.iter().next().unwrap();
+
+        Storage::<T>::invite_storage_bucket_operator(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id,
+            provider_id,
+        ).unwrap();
+
+        Storage::<T>::accept_storage_bucket_invitation(
+            RawOrigin::Signed(provider_account_id.clone()).into(),
+            provider_id,
+            bucket_id,
+            provider_account_id.clone(),
+        ).unwrap();
+
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), buckets);
+
+        let data_object_id = *upload_objects::<T>(&account_id, bag_id.clone(), 1)
+            .iter()
+            .next()
+            .unwrap();
+
+        // Content with 2^i chunks: the Merkle proof contains i hashes. The challenged last chunk
+        // has j bytes.
+        let chunk_size = T::ChallengeChunkSize::get();
+        let chunks_number = 1u64 << i;
+        let chunk = vec![1u8; j as usize];
+        let full_chunk = vec![1u8; chunk_size as usize];
+        let mut leaves = vec![content_chunk_hash(&full_chunk); chunks_number as usize];
+        let chunk_index = chunks_number - 1;
+        leaves[chunk_index as usize] = content_chunk_hash(&chunk);
+        let proof = content_merkle_proof(&leaves, chunk_index);
+
+        DataObjectsById::<T>::mutate(&bag_id, data_object_id, |data_object| {
+            data_object.accepted = true;
+            data_object.size = chunk_index * chunk_size + j as u64;
+            data_object.content_root = Some(content_merkle_root(&leaves));
+        });
+
+        StorageChallenges::<T>::insert(bucket_id, StorageChallengeRecord {
+            worker_id: provider_id,
+            bag_id,
+            data_object_id,
+            chunk_index,
+            expires_at: System::<T>::block_number(),
+        });
+    }: _ (
+        RawOrigin::Signed(provider_account_id),
+        provider_id,
+        bucket_id,
+        chunk,
+        proof
+    )
+    verify {
+        assert!(Storage::<T>::storage_challenge(&bucket_id).is_none());
+    }
+
+    create_distribution_bucket_family {
+        let lead_account_id = distribution_lead::<T>();
+        let family_id = Storage::<T>::next_distribution_bucket_family_id();
+    }: _ (RawOrigin::Signed(lead_account_id))
+    verify {
+        assert!(DistributionBucketFamilyById::<T>::contains_key(family_id));
+        assert_last_event::<T>(RawEvent::DistributionBucketFamilyCreated(family_id).into());
+    }
+
+    delete_distribution_bucket_family {
+        let lead_account_id = distribution_lead::<T>();
+        let (family_id, _) = create_distribution_buckets::<T>(&lead_account_id, 0);
+    }: _ (RawOrigin::Signed(lead_account_id), family_id)
+    verify {
+        assert!(!DistributionBucketFamilyById::<T>::contains_key(family_id));
+        assert_last_event::<T>(RawEvent::DistributionBucketFamilyDeleted(family_id).into());
+    }
+
+    create_distribution_bucket {
+        let lead_account_id = distribution_lead::<T>();
+        let (family_id, _) = create_distribution_buckets::<T>(&lead_account_id, 0);
+        let bucket_id = DistributionBucketId::<T> {
+            distribution_bucket_family_id: family_id,
+            distribution_bucket_index: Storage::<T>::distribution_bucket_family_by_id(family_id)
+                .next_distribution_bucket_index,
+        };
+    }: _ (RawOrigin::Signed(lead_account_id), family_id, true)
+    verify {
+        assert!(DistributionBucketByFamilyIdById::<T>::contains_key(
+            bucket_id.distribution_bucket_family_id,
+            bucket_id.distribution_bucket_index
+        ));
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketCreated(family_id, true, bucket_id).into()
+        );
+    }
+
+    update_distribution_bucket_status {
+        let lead_account_id = distribution_lead::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id.clone(), false)
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketStatusUpdated(bucket_id, false).into()
+        );
+    }
+
+    delete_distribution_bucket {
+        let lead_account_id = distribution_lead::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id.clone())
+    verify {
+        assert!(!DistributionBucketByFamilyIdById::<T>::contains_key(
+            bucket_id.distribution_bucket_family_id,
+            bucket_id.distribution_bucket_index
+        ));
+        assert_last_event::<T>(RawEvent::DistributionBucketDeleted(bucket_id).into());
+    }
+
+    update_distribution_buckets_for_bag {
+        let i in 1 .. max_distribution_buckets_per_bag::<T>();
+
+        let j in 1 .. max_distribution_buckets_per_bag::<T>();
+
+        let lead_account_id = distribution_lead::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+
+        let (family_id, bucket_indices) =
+            create_distribution_buckets::<T>(&lead_account_id, i + j);
+
+        let remove_buckets_indices = bucket_indices
+            .iter()
+            .take(j as usize)
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        let add_buckets_indices = bucket_indices
+            .difference(&remove_buckets_indices)
+            .cloned()
+            .collect::<BTreeSet<_>>();
+
+        Storage::<T>::update_distribution_buckets_per_bag_limit(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            max_distribution_buckets_per_bag::<T>().into(),
+        ).unwrap();
+
+        Storage::<T>::update_distribution_buckets_for_bag(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bag_id.clone(),
+            family_id,
+            remove_buckets_indices.clone(),
+            BTreeSet::new(),
+        ).unwrap();
+    }: _ (
+        RawOrigin::Signed(lead_account_id),
+        bag_id.clone(),
+        family_id,
+        add_buckets_indices.clone(),
+        remove_buckets_indices.clone()
+    )
+    verify {
+        assert_eq!(Storage::<T>::bag(&bag_id).distributed_by.len(), i as usize);
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketsUpdatedForBag(
+                bag_id,
+                family_id,
+                add_buckets_indices,
+                remove_buckets_indices
+            ).into()
+        );
+    }
+
+    update_distribution_buckets_per_bag_limit {
+        let lead_account_id = distribution_lead::<T>();
+        let new_limit: u64 = max_distribution_buckets_per_bag::<T>().into();
+    }: _ (RawOrigin::Signed(lead_account_id), new_limit)
+    verify {
+        assert_eq!(Storage::<T>::distribution_buckets_per_bag_limit(), new_limit);
+        assert_last_event::<T>(RawEvent::DistributionBucketsPerBagLimitUpdated(new_limit).into());
+    }
+
+    update_distribution_bucket_mode {
+        let lead_account_id = distribution_lead::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id.clone(), false)
+    verify {
+        assert_last_event::<T>(RawEvent::DistributionBucketModeUpdated(bucket_id, false).into());
+    }
+
+    update_families_in_dynamic_bag_creation_policy {
+        let i in 1 .. T::MaxDistributionBucketFamilyNumber::get().saturated_into();
+
+        let lead_account_id = distribution_lead::<T>();
+
+        let families = (0..i)
+            .map(|_| {
+                let (family_id, _) = create_distribution_buckets::<T>(&lead_account_id, 0);
+
+                (family_id, 1u32)
+            })
+            .collect::<BTreeMap<_, _>>();
+    }: _ (RawOrigin::Signed(lead_account_id), DynamicBagType::Channel, families.clone())
+    verify {
+        assert_eq!(
+            Storage::<T>::get_dynamic_bag_creation_policy(DynamicBagType::Channel).families,
+            families
+        );
+    }
+
+    update_regions_in_dynamic_bag_creation_policy {
+        let i in 1 .. max_distribution_buckets_per_bag::<T>();
+
+        let lead_account_id = distribution_lead::<T>();
+
+        Storage::<T>::update_distribution_buckets_per_bag_limit(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            max_distribution_buckets_per_bag::<T>().into(),
+        ).unwrap();
+
+        let regions = (0..i)
+            .map(|idx| idx.to_be_bytes().to_vec())
+            .collect::<BTreeSet<_>>();
+    }: _ (RawOrigin::Signed(lead_account_id), DynamicBagType::Channel, regions.clone())
+    verify {
+        assert_eq!(
+            Storage::<T>::get_dynamic_bag_creation_policy(DynamicBagType::Channel).regions,
+            regions
+        );
+    }
+
+    invite_distribution_bucket_operator {
+        let lead_account_id = distribution_lead::<T>();
+        let (_, provider_id) = distribution_provider::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id.clone(), provider_id)
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketOperatorInvited(bucket_id, provider_id).into()
+        );
+    }
+
+    cancel_distribution_bucket_operator_invite {
+        let lead_account_id = distribution_lead::<T>();
+        let (_, provider_id) = distribution_provider::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+
+        Storage::<T>::invite_distribution_bucket_operator(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id.clone(),
+            provider_id,
+        ).unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id.clone(), provider_id)
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketInvitationCancelled(bucket_id, provider_id).into()
+        );
+    }
+
+    remove_distribution_bucket_operator {
+        let lead_account_id = distribution_lead::<T>();
+        let (provider_account_id, provider_id) = distribution_provider::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+
+        Storage::<T>::invite_distribution_bucket_operator(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id.clone(),
+            provider_id,
+        ).unwrap();
+
+        Storage::<T>::accept_distribution_bucket_invitation(
+            RawOrigin::Signed(provider_account_id).into(),
+            provider_id,
+            bucket_id.clone(),
+        ).unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id.clone(), provider_id)
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketOperatorRemoved(bucket_id, provider_id).into()
+        );
+    }
+
+    set_distribution_bucket_family_metadata {
+        let i in 0 .. MAX_BYTES;
+
+        let lead_account_id = distribution_lead::<T>();
+        let (family_id, _) = create_distribution_buckets::<T>(&lead_account_id, 0);
+        let metadata = vec![0u8; i as usize];
+    }: _ (RawOrigin::Signed(lead_account_id), family_id, metadata.clone())
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketFamilyMetadataSet(family_id, metadata).into()
+        );
+    }
+
+    set_distribution_bucket_family_region {
+        let i in 1 .. T::MaxRegionTagLength::get();
+
+        let lead_account_id = distribution_lead::<T>();
+        let (family_id, _) = create_distribution_buckets::<T>(&lead_account_id, 0);
+        let region = Some(vec![0u8; i as usize]);
+    }: _ (RawOrigin::Signed(lead_account_id), family_id, region.clone())
+    verify {
+        assert_eq!(Storage::<T>::distribution_bucket_family_by_id(family_id).region, region);
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketFamilyRegionUpdated(family_id, region).into()
+        );
+    }
+
+    set_distribution_bucket_region {
+        let i in 1 .. T::MaxRegionTagLength::get();
+
+        let lead_account_id = distribution_lead::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+        let region = Some(vec![0u8; i as usize]);
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id.clone(), region.clone())
+    verify {
+        assert_eq!(Storage::<T>::distribution_bucket_region(&bucket_id), region);
+        assert_last_event::<T>(RawEvent::DistributionBucketRegionUpdated(bucket_id, region).into());
+    }
+
+    update_distribution_operator_reward_rates {
+        let lead_account_id = distribution_lead::<T>();
+        let rates = OperatorRewardRates::<BalanceOf<T>> {
+            per_megabyte: One::one(),
+            per_bag: One::one(),
+        };
+    }: _ (RawOrigin::Signed(lead_account_id), rates)
+    verify {
+        assert_eq!(Storage::<T>::distribution_operator_reward_rates(), rates);
+        assert_last_event::<T>(RawEvent::DistributionOperatorRewardRatesUpdated(rates).into());
+    }
+
+    accept_distribution_bucket_invitation {
+        let lead_account_id = distribution_lead::<T>();
+        let (provider_account_id, provider_id) = distribution_provider::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+
+        Storage::<T>::invite_distribution_bucket_operator(
+            RawOrigin::Signed(lead_account_id).into(),
+            bucket_id.clone(),
+            provider_id,
+        ).unwrap();
+    }: _ (RawOrigin::Signed(provider_account_id), provider_id, bucket_id.clone())
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketInvitationAccepted(provider_id, bucket_id).into()
+        );
+    }
+
+    set_distribution_operator_metadata {
+        let i in 0 .. MAX_BYTES;
+
+        let lead_account_id = distribution_lead::<T>();
+        let (provider_account_id, provider_id) = distribution_provider::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+
+        Storage::<T>::invite_distribution_bucket_operator(
+            RawOrigin::Signed(lead_account_id).into(),
+            bucket_id.clone(),
+            provider_id,
+        ).unwrap();
+
+        Storage::<T>::accept_distribution_bucket_invitation(
+            RawOrigin::Signed(provider_account_id.clone()).into(),
+            provider_id,
+            bucket_id.clone(),
+        ).unwrap();
+
+        let metadata = vec![0u8; i as usize];
+    }: _ (RawOrigin::Signed(provider_account_id), provider_id, bucket_id.clone(), metadata.clone())
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketMetadataSet(provider_id, bucket_id, metadata).into()
+        );
+    }
+
+    report_distribution_operator_health {
+        let lead_account_id = distribution_lead::<T>();
+        let (provider_account_id, provider_id) = distribution_provider::<T>();
+        let bucket_id = create_distribution_bucket::<T>(&lead_account_id);
+
+        Storage::<T>::invite_distribution_bucket_operator(
+            RawOrigin::Signed(lead_account_id).into(),
+            bucket_id.clone(),
+            provider_id,
+        ).unwrap();
+
+        Storage::<T>::accept_distribution_bucket_invitation(
+            RawOrigin::Signed(provider_account_id.clone()).into(),
+            provider_id,
+            bucket_id.clone(),
+        ).unwrap();
+
+        let bytes_served = 1_000_000u64;
+        let uptime = 3600u64;
+    }: _ (
+        RawOrigin::Signed(provider_account_id),
+        provider_id,
+        bucket_id.clone(),
+        bytes_served,
+        uptime
+    )
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DistributionOperatorHealthReported(
+                bucket_id,
+                provider_id,
+                bytes_served,
+                uptime,
+            ).into()
+        );
+    }
+
+    upload_data_objects {
+        let i in 1 .. MAX_OBJECTS;
+
+        let lead_account_id = storage_lead::<T>();
+        let account_id = funded_account::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+
+        let buckets =
+            create_storage_buckets::<T>(&lead_account_id, max_storage_buckets_per_bag::<T>());
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), buckets);
+
+        let params = UploadParameters::<T> {
+            bag_id: bag_id.clone(),
+            object_creation_list: (0..i)
+                .map(|id| DataObjectCreationParameters {
+                    size: 1,
+                    ipfs_content_id: create_cid(id),
+                    content_root: None,
+                    parts: Vec::new(),
+                    class: None,
+                })
+                .collect(),
+            deletion_prize_source_account_id: account_id,
+            expected_data_size_fee: Storage::<T>::data_object_per_mega_byte_fee(),
+        };
+    }: {
+        Storage::<T>::upload_data_objects(params)?;
+    }
+    verify {
+        assert_eq!(Storage::<T>::bag(&bag_id).objects_number, u64::from(i));
+    }
+
+    move_data_objects {
+        let i in 1 .. MAX_OBJECTS;
+
+        let lead_account_id = storage_lead::<T>();
+        let account_id = funded_account::<T>();
+        let src_bag_id: BagId<T> = StaticBagId::Council.into();
+        let dest_bag_id: BagId<T> = StaticBagId::WorkingGroup(WorkingGroup::Storage).into();
+
+        let buckets =
+            create_storage_buckets::<T>(&lead_account_id, max_storage_buckets_per_bag::<T>());
+        assign_storage_buckets::<T>(&lead_account_id, src_bag_id.clone(), buckets.clone());
+        assign_storage_buckets::<T>(&lead_account_id, dest_bag_id.clone(), buckets);
+
+        let objects = upload_objects::<T>(&account_id, src_bag_id.clone(), i);
+    }: {
+        Storage::<T>::move_data_objects(src_bag_id.clone(), dest_bag_id.clone(), objects)?;
+    }
+    verify {
+        assert_eq!(Storage::<T>::bag(&src_bag_id).objects_number, 0);
+        assert_eq!(Storage::<T>::bag(&dest_bag_id).objects_number, u64::from(i));
+    }
+
+    move_bag_data_objects {
+        let i in 1 .. MAX_OBJECTS;
+
+        let lead_account_id = storage_lead::<T>();
+        let account_id = funded_account::<T>();
+
+        // Bag owner ids match the ones authorized by the pallet mock.
+        let src_dynamic_bag_id =
+            DynamicBagId::<T>::Member(DEFAULT_MEMBER_ID.saturated_into());
+        let dest_dynamic_bag_id =
+            DynamicBagId::<T>::Channel(DEFAULT_CHANNEL_ID.saturated_into());
+
+        Storage::<T>::create_dynamic_bag(src_dynamic_bag_id.clone(), None).unwrap();
+        Storage::<T>::create_dynamic_bag(dest_dynamic_bag_id.clone(), None).unwrap();
+
+        let src_bag_id: BagId<T> = src_dynamic_bag_id.into();
+        let dest_bag_id: BagId<T> = dest_dynamic_bag_id.into();
+
+        let buckets =
+            create_storage_buckets::<T>(&lead_account_id, max_storage_buckets_per_bag::<T>());
+        assign_storage_buckets::<T>(&lead_account_id, src_bag_id.clone(), buckets.clone());
+        assign_storage_buckets::<T>(&lead_account_id, dest_bag_id.clone(), buckets);
+
+        let objects = upload_objects::<T>(&account_id, src_bag_id.clone(), i);
+    }: _ (
+        RawOrigin::Signed(account_id),
+        src_bag_id.clone(),
+        dest_bag_id.clone(),
+        objects
+    )
+    verify {
+        assert_eq!(Storage::<T>::bag(&src_bag_id).objects_number, 0);
+        assert_eq!(Storage::<T>::bag(&dest_bag_id).objects_number, u64::from(i));
+    }
+
+    delete_data_objects {
+        let i in 1 .. MAX_OBJECTS;
+
+        let lead_account_id = storage_lead::<T>();
+        let account_id = funded_account::<T>();
+        let bag_id: BagId<T> = StaticBagId::Council.into();
+
+        let buckets =
+            create_storage_buckets::<T>(&lead_account_id, max_storage_buckets_per_bag::<T>());
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), buckets);
+
+        let objects = upload_objects::<T>(&account_id, bag_id.clone(), i);
+    }: {
+        Storage::<T>::delete_data_objects(account_id, bag_id.clone(), objects)?;
+    }
+    verify {
+        assert_eq!(Storage::<T>::bag(&bag_id).objects_number, 0);
+    }
+
+    upload_working_group_data_objects {
+        let i in 1 .. MAX_OBJECTS;
+
+        let lead_account_id = storage_lead::<T>();
+        let _ = Balances::<T>::make_free_balance_be(&lead_account_id, BalanceOf::<T>::max_value());
+        let working_group = WorkingGroup::Storage;
+        let bag_id: BagId<T> = StaticBagId::WorkingGroup(working_group).into();
+
+        let buckets =
+            create_storage_buckets::<T>(&lead_account_id, max_storage_buckets_per_bag::<T>());
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), buckets);
+
+        let object_creation_list = (0..i)
+            .map(|id| DataObjectCreationParameters {
+                size: 1,
+                ipfs_content_id: create_cid(id),
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
+            })
+            .collect::<Vec<_>>();
+    }: _ (
+        RawOrigin::Signed(lead_account_id),
+        working_group,
+        object_creation_list,
+        Storage::<T>::data_object_per_mega_byte_fee()
+    )
+    verify {
+        assert_eq!(Storage::<T>::bag(&bag_id).objects_number, u64::from(i));
+    }
+
+    delete_working_group_data_objects {
+        let i in 1 .. MAX_OBJECTS;
+
+        let lead_account_id = storage_lead::<T>();
+        let _ = Balances::<T>::make_free_balance_be(&lead_account_id, BalanceOf::<T>::max_value());
+        let working_group = WorkingGroup::Storage;
+        let bag_id: BagId<T> = StaticBagId::WorkingGroup(working_group).into();
+
+        let buckets =
+            create_storage_buckets::<T>(&lead_account_id, max_storage_buckets_per_bag::<T>());
+        assign_storage_buckets::<T>(&lead_account_id, bag_id.clone(), buckets);
+
+        let objects = upload_objects::<T>(&lead_account_id, bag_id.clone(), i);
+    }: _ (RawOrigin::Signed(lead_account_id), working_group, objects)
+    verify {
+        assert_eq!(Storage::<T>::bag(&bag_id).objects_number, 0);
+    }
+
+    create_dynamic_bag {
+        let i in 1 .. max_storage_buckets_per_bag::<T>();
+
+        let j in 1 .. max_distribution_buckets_per_bag::<T>();
+
+        let storage_lead_account_id = storage_lead::<T>();
+        let distribution_lead_account_id = distribution_lead::<T>();
+
+        create_dynamic_bag_policy::<T>(
+            &storage_lead_account_id,
+            &distribution_lead_account_id,
+            i,
+            j,
+        );
+
+        let dynamic_bag_id = DynamicBagId::<T>::Member(Default::default());
+    }: {
+        Storage::<T>::create_dynamic_bag(dynamic_bag_id.clone(), None)?;
+    }
+    verify {
+        let bag = Storage::<T>::bag(BagId::<T>::from(dynamic_bag_id));
+
+        assert_eq!(bag.stored_by.len(), i as usize);
+        assert_eq!(bag.distributed_by.len(), j as usize);
+    }
+
+    delete_dynamic_bag {
+        let i in 1 .. max_storage_buckets_per_bag::<T>();
+
+        let j in 1 .. max_distribution_buckets_per_bag::<T>();
+
+        let storage_lead_account_id = storage_lead::<T>();
+        let distribution_lead_account_id = distribution_lead::<T>();
+        let account_id = funded_account::<T>();
+
+        create_dynamic_bag_policy::<T>(
+            &storage_lead_account_id,
+            &distribution_lead_account_id,
+            i,
+            j,
+        );
+
+        let dynamic_bag_id = DynamicBagId::<T>::Member(Default::default());
+
+        Storage::<T>::create_dynamic_bag(dynamic_bag_id.clone(), None).unwrap();
+    }: {
+        Storage::<T>::delete_dynamic_bag(account_id, dynamic_bag_id.clone())?;
+    }
+    verify {
+        assert!(!Bags::<T>::contains_key(BagId::<T>::from(dynamic_bag_id)));
+    }
+
+    drain_storage_buckets {
+        let i in 1 .. T::MaxDrainedBagsPerBlock::get().saturated_into();
+
+        let lead_account_id = storage_lead::<T>();
+
+        // Bags are created without storage buckets.
+        let bag_ids = (0..i)
+            .map(|id| BagId::<T>::from(DynamicBagId::<T>::Member(id.saturated_into())))
+            .collect::<Vec<_>>();
+
+        for bag_id in bag_ids.iter() {
+            if let BagId::<T>::Dynamic(dynamic_bag_id) = bag_id {
+                Storage::<T>::create_dynamic_bag(dynamic_bag_id.clone(), None).unwrap();
+            }
+        }
+
+        let buckets = create_storage_buckets::<T>(&lead_account_id, 2);
+        let mut bucket_ids = buckets.iter();
+        let draining_bucket_id = *bucket_ids.next().unwrap();
+
+        for bag_id in bag_ids.iter() {
+            assign_storage_buckets::<T>(
+                &lead_account_id,
+                bag_id.clone(),
+                iter::once(draining_bucket_id).collect(),
+            );
+        }
+
+        Storage::<T>::start_storage_bucket_draining(
+            RawOrigin::Signed(lead_account_id).into(),
+            draining_bucket_id,
+        ).unwrap();
+    }: {
+        Storage::<T>::drain_storage_buckets();
+    }
+    verify {
+        assert!(!StorageBucketById::<T>::contains_key(draining_bucket_id));
+        assert!(!DrainingStorageBuckets::<T>::contains_key(draining_bucket_id));
+    }
+
+    re_replicate_bags {
+        let i in 1 .. T::MaxReReplicatedBagsPerBlock::get().saturated_into();
+
+        let lead_account_id = storage_lead::<T>();
+        let buckets_number = max_storage_buckets_per_bag::<T>();
+
+        Storage::<T>::update_number_of_storage_buckets_in_dynamic_bag_creation_policy(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            DynamicBagType::Member,
+            buckets_number.into(),
+        )
+        .unwrap();
+
+        Storage::<T>::update_storage_buckets_per_bag_limit(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            buckets_number.into(),
+        )
+        .unwrap();
+
+        // Bags are created without storage buckets: all of them become under-replicated.
+        let dynamic_bag_ids = (0..i)
+            .map(|id| DynamicBagId::<T>::Member(id.saturated_into()))
+            .collect::<Vec<_>>();
+
+        for dynamic_bag_id in dynamic_bag_ids.iter() {
+            Storage::<T>::create_dynamic_bag(dynamic_bag_id.clone(), None).unwrap();
+        }
+
+        create_storage_buckets::<T>(&lead_account_id, buckets_number);
+    }: {
+        Storage::<T>::re_replicate_bags();
+    }
+    verify {
+        assert!(Storage::<T>::under_replicated_bags().is_empty());
+
+        for dynamic_bag_id in dynamic_bag_ids {
+            let bag = Storage::<T>::bag(BagId::<T>::from(dynamic_bag_id));
+
+            assert_eq!(bag.stored_by.len(), buckets_number as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mocks::{build_test_externalities, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn delete_storage_bucket() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_delete_storage_bucket::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_uploading_blocked_status() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_uploading_blocked_status::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_data_size_fee() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_data_size_fee::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_data_size_fee_schedule() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_data_size_fee_schedule::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_storage_rent() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_storage_rent::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_data_object_reference_fee() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_data_object_reference_fee::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_dynamic_bag_quota() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_dynamic_bag_quota::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_bag_quota_override() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_bag_quota_override::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_storage_operator_reward_rates() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_storage_operator_reward_rates::<Test>());
+        });
+    }
+
+    #[test]
+    fn top_up_bag_rent() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_top_up_bag_rent::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_storage_buckets_per_bag_limit() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_storage_buckets_per_bag_limit::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_storage_buckets_voucher_max_limits() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_storage_buckets_voucher_max_limits::<
+                Test,
+            >());
+        });
+    }
+
+    #[test]
+    fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(
+                test_benchmark_update_number_of_storage_buckets_in_dynamic_bag_creation_policy::<
+                    Test,
+                >()
+            );
+        });
+    }
+
+    #[test]
+    fn update_blacklist() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_blacklist::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_blacklist_entry() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_blacklist_entry::<Test>());
+        });
+    }
+
+    #[test]
+    fn purge_blacklisted_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_purge_blacklisted_data_objects::<Test>());
+        });
+    }
+
+    #[test]
+    fn create_storage_bucket() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_create_storage_bucket::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_storage_buckets_for_bag() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_storage_buckets_for_bag::<Test>());
+        });
+    }
+
+    #[test]
+    fn cancel_storage_bucket_operator_invite() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_cancel_storage_bucket_operator_invite::<Test>());
+        });
+    }
+
+    #[test]
+    fn invite_storage_bucket_operator() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_invite_storage_bucket_operator::<Test>());
+        });
+    }
+
+    #[test]
+    fn remove_storage_bucket_operator() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_remove_storage_bucket_operator::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_storage_bucket_status() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_storage_bucket_status::<Test>());
+        });
+    }
+
+    #[test]
+    fn start_storage_bucket_draining() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_start_storage_bucket_draining::<Test>());
+        });
+    }
+
+    #[test]
+    fn cancel_storage_bucket_draining() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_cancel_storage_bucket_draining::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_storage_bucket_voucher_limits() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_storage_bucket_voucher_limits::<Test>());
+        });
+    }
+
+    #[test]
+    fn accept_storage_bucket_invitation() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_accept_storage_bucket_invitation::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_storage_operator_metadata() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_storage_operator_metadata::<Test>());
+        });
+    }
+
+    #[test]
+    fn accept_pending_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_accept_pending_data_objects::<Test>());
+        });
+    }
+
+    #[test]
+    fn accept_pending_data_object_parts() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_accept_pending_data_object_parts::<Test>());
+        });
+    }
+
+    #[test]
+    fn respond_to_storage_challenge() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_respond_to_storage_challenge::<Test>());
+        });
+    }
+
+    #[test]
+    fn create_distribution_bucket_family() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_create_distribution_bucket_family::<Test>());
+        });
+    }
+
+    #[test]
+    fn delete_distribution_bucket_family() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_delete_distribution_bucket_family::<Test>());
+        });
+    }
+
+    #[test]
+    fn create_distribution_bucket() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_create_distribution_bucket::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_distribution_bucket_status() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_distribution_bucket_status::<Test>());
+        });
+    }
+
+    #[test]
+    fn delete_distribution_bucket() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_delete_distribution_bucket::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_distribution_buckets_for_bag() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_distribution_buckets_for_bag::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_distribution_buckets_per_bag_limit() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_distribution_buckets_per_bag_limit::<
+                Test,
+            >());
+        });
+    }
+
+    #[test]
+    fn update_distribution_bucket_mode() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_distribution_bucket_mode::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_families_in_dynamic_bag_creation_policy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_families_in_dynamic_bag_creation_policy::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_regions_in_dynamic_bag_creation_policy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_regions_in_dynamic_bag_creation_policy::<Test>());
+        });
+    }
+
+    #[test]
+    fn invite_distribution_bucket_operator() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_invite_distribution_bucket_operator::<Test>());
+        });
+    }
+
+    #[test]
+    fn cancel_distribution_bucket_operator_invite() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_cancel_distribution_bucket_operator_invite::<
+                Test,
+            >());
+        });
+    }
+
+    #[test]
+    fn remove_distribution_bucket_operator() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_remove_distribution_bucket_operator::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_distribution_bucket_family_metadata() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_distribution_bucket_family_metadata::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_distribution_bucket_family_region() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_distribution_bucket_family_region::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_distribution_bucket_region() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_distribution_bucket_region::<Test>());
+        });
+    }
+
+    #[test]
+    fn update_distribution_operator_reward_rates() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_distribution_operator_reward_rates::<
+                Test,
+            >());
+        });
+    }
+
+    #[test]
+    fn accept_distribution_bucket_invitation() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_accept_distribution_bucket_invitation::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_distribution_operator_metadata() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_distribution_operator_metadata::<Test>());
+        });
+    }
+
+    #[test]
+    fn report_distribution_operator_health() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_report_distribution_operator_health::<Test>());
+        });
+    }
+
+    #[test]
+    fn upload_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_upload_data_objects::<Test>());
+        });
+    }
+
+    #[test]
+    fn upload_working_group_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_upload_working_group_data_objects::<Test>());
+        });
+    }
+
+    #[test]
+    fn delete_working_group_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_delete_working_group_data_objects::<Test>());
+        });
+    }
+
+    #[test]
+    fn move_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_move_data_objects::<Test>());
+        });
+    }
+
+    #[test]
+    fn move_bag_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_move_bag_data_objects::<Test>());
+        });
+    }
+
+    #[test]
+    fn delete_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_delete_data_objects::<Test>());
+        });
+    }
+
+    #[test]
+    fn create_dynamic_bag() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_create_dynamic_bag::<Test>());
+        });
+    }
+
+    #[test]
+    fn delete_dynamic_bag() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_delete_dynamic_bag::<Test>());
+        });
+    }
+
+    #[test]
+    fn drain_storage_buckets() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_drain_storage_buckets::<Test>());
+        });
+    }
+
+    #[test]
+    fn re_replicate_bags() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_re_replicate_bags::<Test>());
         });
     }
 }
@@ -0,0 +1,174 @@
+use frame_support::dispatch::DispatchError;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{ensure, IterableStorageDoubleMap, StorageDoubleMap, StorageMap, StorageValue};
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::vec::Vec;
+
+use crate::{
+    Bag, BagId, BagUpdate, BalanceOf, Blacklist, BlacklistExpirations, BlacklistExpirationsCursor,
+    Cid, CurrentBlacklistSize, DataObjectsById, Error, Module, OperationType, RawEvent, Trait,
+    VoucherUpdate,
+};
+
+// Blacklist entries with the rationale and the optional expiry. Expiring entries are scheduled in
+// `BlacklistExpirations` and removed from the blacklist starting from the expiry block (at most
+// `MaxBlacklistExpirationsPerBlock` per block). Blacklisting prevents new uploads only: the already
+// uploaded data objects with the blacklisted content ID are found off-chain (see
+// `blacklisted_data_objects` and `blacklisted_data_objects_page`) and purged by the storage lead.
+// Deletion prizes of the purged data objects are forfeited. Purged data objects are reported to
+// the runtime (see `Trait::on_data_objects_removed`), so the content references to them are
+// cleared.
+
+impl<T: Trait> Module<T> {
+    // Removes the expired blacklist entries. Processes at most `MaxBlacklistExpirationsPerBlock`
+    // expirations per block from the persisted cursor, the skipped expirations are processed in the
+    // next blocks.
+    pub(crate) fn process_blacklist_expirations(now: T::BlockNumber) -> Weight {
+        // the cursor
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        let cursor = Self::blacklist_expirations_cursor();
+        let mut block = if cursor.is_zero() { now } else { cursor };
+        let mut steps_budget = T::MaxBlacklistExpirationsPerBlock::get();
+
+        while block <= now && steps_budget > 0 {
+            let expired_cids = <BlacklistExpirations<T>>::iter_prefix(block)
+                .map(|(cid, _)| cid)
+                .take(steps_budget.saturated_into())
+                .collect::<Vec<_>>();
+
+            let expirations_number: u64 = expired_cids.len().saturated_into();
+            reads = reads
+                .saturating_add(expirations_number.saturating_mul(2))
+                .saturating_add(1);
+            writes = writes.saturating_add(expirations_number.saturating_mul(3));
+
+            // The block without (remaining) expirations is processed too.
+            let block_processed = expirations_number < steps_budget;
+            steps_budget = steps_budget.saturating_sub(expirations_number.max(1));
+
+            for cid in expired_cids {
+                <BlacklistExpirations<T>>::remove(block, &cid);
+                <Blacklist<T>>::remove(&cid);
+                CurrentBlacklistSize::mutate(|size| *size = size.saturating_sub(1));
+
+                Self::deposit_event(RawEvent::BlacklistEntryExpired(cid));
+            }
+
+            if block_processed {
+                block = block.saturating_add(One::one());
+            }
+        }
+
+        if block != cursor {
+            writes = writes.saturating_add(1);
+
+            <BlacklistExpirationsCursor<T>>::put(block);
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    // Reschedules the blacklist entry expiration.
+    pub(crate) fn set_blacklist_entry_expiration(cid: &Cid, expires_at: Option<T::BlockNumber>) {
+        Self::clear_blacklist_entry_expiration(cid);
+
+        if let Some(expires_at) = expires_at {
+            <BlacklistExpirations<T>>::insert(expires_at, cid, ());
+        }
+    }
+
+    // Removes the scheduled blacklist entry expiration.
+    pub(crate) fn clear_blacklist_entry_expiration(cid: &Cid) {
+        if let Some(expires_at) = Self::blacklist(cid).expires_at {
+            <BlacklistExpirations<T>>::remove(expires_at, cid);
+        }
+    }
+
+    // Validates the purged data objects: they must exist and match the blacklist entry.
+    pub(crate) fn validate_purge_blacklisted_data_objects_params(
+        cid: &Cid,
+        bag_id: &BagId<T>,
+        data_object_ids: &BTreeSet<T::DataObjectId>,
+    ) -> Result<BagUpdate<BalanceOf<T>>, DispatchError> {
+        ensure!(
+            <Blacklist<T>>::contains_key(cid),
+            Error::<T>::BlacklistEntryDoesntExist
+        );
+
+        ensure!(
+            !data_object_ids.is_empty(),
+            Error::<T>::DataObjectIdParamsAreEmpty
+        );
+
+        let mut bag_change = BagUpdate::<BalanceOf<T>>::default();
+
+        for data_object_id in data_object_ids.iter() {
+            let data_object = Self::ensure_data_object_exists(bag_id, data_object_id)?;
+
+            ensure!(
+                data_object.ipfs_content_id == *cid,
+                Error::<T>::DataObjectIsNotBlacklisted
+            );
+
+            bag_change.add_object(data_object.size, data_object.deletion_prize);
+        }
+
+        Ok(bag_change)
+    }
+
+    // Removes the data objects of the bag, releases the storage bucket vouchers and notifies the
+    // runtime about the removed data objects.
+    pub(crate) fn purge_data_objects(
+        bag_id: &BagId<T>,
+        bag: &Bag<T>,
+        data_object_ids: &BTreeSet<T::DataObjectId>,
+        voucher_update: &VoucherUpdate,
+    ) {
+        let removed_data_objects = data_object_ids
+            .iter()
+            .map(|data_object_id| Self::take_data_object(bag_id, data_object_id))
+            .collect::<Vec<_>>();
+        let content_references =
+            Self::data_objects_content_references(bag_id, &removed_data_objects);
+
+        Self::change_storage_bucket_vouchers_for_bag(
+            bag_id,
+            bag,
+            voucher_update,
+            &content_references,
+            OperationType::Decrease,
+        );
+
+        Self::release_shared_content(&content_references);
+
+        T::on_data_objects_removed(bag_id, data_object_ids);
+    }
+
+    /// Returns the data objects of the bag matching the blacklisted content ID.
+    pub fn blacklisted_data_objects(cid: &Cid, bag_id: &BagId<T>) -> Vec<T::DataObjectId> {
+        <DataObjectsById<T>>::iter_prefix(bag_id)
+            .filter(|(_, data_object)| data_object.ipfs_content_id == *cid)
+            .map(|(data_object_id, _)| data_object_id)
+            .collect()
+    }
+
+    /// Returns the page of the data objects of all bags matching the blacklisted content ID. The
+    /// offset and the limit apply to the matching data objects, all data objects are scanned.
+    pub fn blacklisted_data_objects_page(
+        cid: &Cid,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(BagId<T>, T::DataObjectId)> {
+        <DataObjectsById<T>>::iter()
+            .filter(|(_, _, data_object)| data_object.ipfs_content_id == *cid)
+            .map(|(bag_id, data_object_id, _)| (bag_id, data_object_id))
+            .skip(offset.saturated_into())
+            .take(limit.saturated_into())
+            .collect()
+    }
+}
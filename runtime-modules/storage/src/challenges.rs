@@ -0,0 +1,395 @@
+use codec::Encode;
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::traits::{Get, Randomness};
+use frame_support::weights::Weight;
+use frame_support::{ensure, StorageDoubleMap, StorageMap, StorageValue};
+use sp_runtime::traits::{BlakeTwo256, Hash as HashT, One, Saturating, Zero};
+use sp_runtime::SaturatedConversion;
+use sp_std::vec::Vec;
+
+use crate::{
+    ContentHash, DataObjectsById, Error, MissedStorageChallenges, Module, RawEvent, StorageBucket,
+    StorageBucketBags, StorageBucketOperatorStatus, StorageChallenge, StorageChallengeCursor,
+    StorageChallengeRecord, StorageChallenges, Trait, WorkerId,
+};
+
+// The storage challenges prove that the storage operators keep the data objects of their buckets.
+// The uploader commits to the content with the Merkle root over the content chunk hashes. Each
+// challenge period the storage bucket operator gets a random chunk of a random data object to
+// prove with the chunk itself and its Merkle proof (sibling hashes from the leaf to the root).
+// Tree levels with an odd node number promote the last node to the next level unchanged.
+
+/// Hash of the data object content chunk (Merkle tree leaf).
+pub fn content_chunk_hash(chunk: &[u8]) -> ContentHash {
+    BlakeTwo256::hash(chunk).to_fixed_bytes()
+}
+
+/// Number of the content chunks (Merkle tree leaves) for the data object size. Empty content
+/// has a single empty chunk.
+pub fn content_chunks_number(size: u64, chunk_size: u64) -> u64 {
+    let chunk_size = chunk_size.max(1);
+
+    (size.saturating_add(chunk_size - 1) / chunk_size).max(1)
+}
+
+/// Merkle root over the content chunk hashes.
+pub fn content_merkle_root(leaves: &[ContentHash]) -> ContentHash {
+    let mut level = leaves.to_vec();
+
+    if level.is_empty() {
+        return content_chunk_hash(&[]);
+    }
+
+    while level.len() > 1 {
+        level = next_merkle_tree_level(&level);
+    }
+
+    level[0]
+}
+
+/// Merkle proof (sibling hashes from the leaf to the root) for the content chunk.
+pub fn content_merkle_proof(leaves: &[ContentHash], chunk_index: u64) -> Vec<ContentHash> {
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    let mut index: usize = chunk_index.saturated_into();
+
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+
+        level = next_merkle_tree_level(&level);
+        index /= 2;
+    }
+
+    proof
+}
+
+/// Verifies the content chunk against the Merkle root with the Merkle proof.
+pub fn verify_content_merkle_proof(
+    chunk: &[u8],
+    chunk_index: u64,
+    chunks_number: u64,
+    proof: &[ContentHash],
+    root: &ContentHash,
+) -> bool {
+    if chunk_index >= chunks_number {
+        return false;
+    }
+
+    let mut hash = content_chunk_hash(chunk);
+    let mut index = chunk_index;
+    let mut level_length = chunks_number;
+    let mut siblings = proof.iter();
+
+    while level_length > 1 {
+        if index % 2 == 1 {
+            match siblings.next() {
+                Some(sibling) => hash = merkle_node_hash(sibling, &hash),
+                None => return false,
+            }
+        } else if index + 1 < level_length {
+            match siblings.next() {
+                Some(sibling) => hash = merkle_node_hash(&hash, sibling),
+                None => return false,
+            }
+        }
+
+        index /= 2;
+        level_length = (level_length + 1) / 2;
+    }
+
+    siblings.next().is_none() && hash == *root
+}
+
+// Hash of the Merkle tree node.
+fn merkle_node_hash(left: &ContentHash, right: &ContentHash) -> ContentHash {
+    let mut data = left.to_vec();
+    data.extend_from_slice(right);
+
+    BlakeTwo256::hash(&data).to_fixed_bytes()
+}
+
+// Next (parent) Merkle tree level. An odd last node is promoted unchanged.
+fn next_merkle_tree_level(level: &[ContentHash]) -> Vec<ContentHash> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => merkle_node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!("chunks(2) yields one or two elements"),
+        })
+        .collect()
+}
+
+impl<T: Trait> Module<T> {
+    // Runs the storage challenge rounds: each storage challenge period starts a round over the
+    // storage bucket IDs unless the previous round is still in progress. At most
+    // `MaxStorageChallengesPerBlock` storage bucket IDs are processed per block.
+    pub(crate) fn process_storage_challenges(now: T::BlockNumber) -> Weight {
+        let period = T::StorageChallengePeriod::get();
+
+        if period.is_zero() {
+            return 0;
+        }
+
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        let mut storage_bucket_id = match Self::storage_challenge_cursor() {
+            Some(storage_bucket_id) => storage_bucket_id,
+            None if (now % period).is_zero() => Zero::zero(),
+            None => return T::DbWeight::get().reads(reads),
+        };
+
+        let response_period = T::StorageChallengeResponsePeriod::get().min(period);
+        let expires_at = now.saturating_add(response_period);
+        let next_storage_bucket_id = Self::next_storage_bucket_id();
+        reads = reads.saturating_add(1);
+
+        let mut processed: u64 = 0;
+        while processed < T::MaxStorageChallengesPerBlock::get()
+            && storage_bucket_id < next_storage_bucket_id
+        {
+            let (bucket_reads, bucket_writes) =
+                Self::challenge_storage_bucket(storage_bucket_id, now, expires_at);
+            reads = reads.saturating_add(bucket_reads);
+            writes = writes.saturating_add(bucket_writes);
+
+            storage_bucket_id += One::one();
+            processed += 1;
+        }
+
+        writes = writes.saturating_add(1);
+        if storage_bucket_id < next_storage_bucket_id {
+            <StorageChallengeCursor<T>>::put(storage_bucket_id);
+        } else {
+            <StorageChallengeCursor<T>>::kill();
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    // Registers the expired outstanding challenge of the storage bucket as missed and issues
+    // a new challenge. The outstanding challenge that can still be answered is kept.
+    // Returns the number of the storage reads and writes.
+    fn challenge_storage_bucket(
+        storage_bucket_id: T::StorageBucketId,
+        now: T::BlockNumber,
+        expires_at: T::BlockNumber,
+    ) -> (u64, u64) {
+        let mut reads: u64 = 2;
+        let mut writes: u64 = 0;
+
+        if let Some(challenge) = Self::storage_challenge(storage_bucket_id) {
+            if challenge.expires_at > now {
+                return (reads, writes);
+            }
+
+            <StorageChallenges<T>>::remove(storage_bucket_id);
+            reads = reads.saturating_add(3);
+            writes = writes.saturating_add(1);
+
+            if Self::storage_challenge_is_relevant(&storage_bucket_id, &challenge) {
+                reads = reads.saturating_add(1);
+                writes = writes.saturating_add(2);
+
+                Self::register_missed_storage_challenge(storage_bucket_id, challenge.worker_id);
+            }
+        }
+
+        let bucket = match Self::ensure_storage_bucket_exists(&storage_bucket_id) {
+            Ok(bucket) => bucket,
+            Err(_) => return (reads, writes),
+        };
+
+        let (challenge, challenge_reads) =
+            Self::create_storage_challenge(&storage_bucket_id, &bucket, expires_at);
+        reads = reads.saturating_add(challenge_reads);
+
+        if let Some(challenge) = challenge {
+            writes = writes.saturating_add(1);
+
+            <StorageChallenges<T>>::insert(storage_bucket_id, challenge.clone());
+
+            Self::deposit_event(RawEvent::StorageChallengeIssued(
+                storage_bucket_id,
+                challenge.worker_id,
+                challenge.bag_id,
+                challenge.data_object_id,
+                challenge.chunk_index,
+                challenge.expires_at,
+            ));
+        }
+
+        (reads, writes)
+    }
+
+    // Picks a random content chunk of a random accepted data object stored by the bucket.
+    // Returns the challenge (if any) and the number of the storage reads.
+    fn create_storage_challenge(
+        storage_bucket_id: &T::StorageBucketId,
+        bucket: &StorageBucket<T>,
+        expires_at: T::BlockNumber,
+    ) -> (Option<StorageChallenge<T>>, u64) {
+        let worker_id = match bucket.operator_status {
+            StorageBucketOperatorStatus::StorageWorker(worker_id, _) => worker_id,
+            _ => return (None, 0),
+        };
+
+        let bags_number = Self::storage_bucket_bags_number(storage_bucket_id);
+        if bags_number == 0 {
+            return (None, 1);
+        }
+
+        let seed = T::Randomness::random(&storage_bucket_id.encode());
+        let bag_index = Self::random_index(seed.as_ref(), bags_number);
+        let bag_id = Self::storage_bucket_bag_by_index(storage_bucket_id, bag_index);
+
+        let objects_number = Self::bag_data_objects_number(&bag_id);
+        if objects_number == 0 {
+            return (None, 3);
+        }
+
+        let seed = T::Randomness::random(seed.as_ref());
+        let object_index = Self::random_index(seed.as_ref(), objects_number);
+        let data_object_id = Self::bag_data_object_by_index(&bag_id, object_index);
+        let data_object = Self::data_object_by_id(&bag_id, &data_object_id);
+        let reads = 5;
+
+        if !data_object.accepted || data_object.content_root.is_none() {
+            return (None, reads);
+        }
+
+        let chunks_number = content_chunks_number(data_object.size, T::ChallengeChunkSize::get());
+        let seed = T::Randomness::random(seed.as_ref());
+        let chunk_index = Self::random_index(seed.as_ref(), chunks_number);
+
+        let challenge = StorageChallengeRecord {
+            worker_id,
+            bag_id,
+            data_object_id,
+            chunk_index,
+            expires_at,
+        };
+
+        (Some(challenge), reads)
+    }
+
+    // Checks whether the unanswered challenge can be held against the storage operator: the bucket
+    // still stores the challenged data object and it's operated by the challenged worker.
+    fn storage_challenge_is_relevant(
+        storage_bucket_id: &T::StorageBucketId,
+        challenge: &StorageChallenge<T>,
+    ) -> bool {
+        let operated_by_challenged_worker = Self::ensure_storage_bucket_exists(storage_bucket_id)
+            .map_or(false, |bucket| match bucket.operator_status {
+                StorageBucketOperatorStatus::StorageWorker(worker_id, _) => {
+                    worker_id == challenge.worker_id
+                }
+                _ => false,
+            });
+
+        operated_by_challenged_worker
+            && <StorageBucketBags<T>>::contains_key(storage_bucket_id, &challenge.bag_id)
+            && <DataObjectsById<T>>::contains_key(&challenge.bag_id, &challenge.data_object_id)
+    }
+
+    // Increments the missed storage challenges number and slashes the storage operator
+    // on reaching the threshold.
+    fn register_missed_storage_challenge(
+        storage_bucket_id: T::StorageBucketId,
+        worker_id: WorkerId<T>,
+    ) {
+        let missed_challenges =
+            Self::missed_storage_challenges(storage_bucket_id).saturating_add(1);
+
+        Self::deposit_event(RawEvent::StorageChallengeMissed(
+            storage_bucket_id,
+            worker_id,
+            missed_challenges,
+        ));
+
+        let threshold = T::MissedStorageChallengesSlashingThreshold::get();
+
+        if threshold == 0 || missed_challenges < threshold {
+            <MissedStorageChallenges<T>>::insert(storage_bucket_id, missed_challenges);
+
+            return;
+        }
+
+        <MissedStorageChallenges<T>>::remove(storage_bucket_id);
+
+        let penalty = T::MissedStorageChallengesSlashingPenalty::get();
+
+        // The worker could have left the working group meanwhile.
+        if T::slash_storage_worker_stake(&worker_id, penalty).is_ok() {
+            Self::deposit_event(RawEvent::StorageOperatorSlashed(
+                storage_bucket_id,
+                worker_id,
+                penalty,
+            ));
+        }
+    }
+
+    // Removes the outstanding storage challenge and the missed challenges record.
+    pub(crate) fn clear_storage_challenges(storage_bucket_id: &T::StorageBucketId) {
+        <StorageChallenges<T>>::remove(storage_bucket_id);
+        <MissedStorageChallenges<T>>::remove(storage_bucket_id);
+    }
+
+    // Ensures the outstanding storage challenge exists and its response period hasn't expired.
+    pub(crate) fn ensure_storage_challenge_can_be_answered(
+        storage_bucket_id: &T::StorageBucketId,
+    ) -> Result<StorageChallenge<T>, DispatchError> {
+        let challenge = Self::storage_challenge(storage_bucket_id)
+            .ok_or(Error::<T>::StorageChallengeDoesntExist)?;
+
+        ensure!(
+            <frame_system::Module<T>>::block_number() <= challenge.expires_at,
+            Error::<T>::StorageChallengeExpired
+        );
+
+        Ok(challenge)
+    }
+
+    // Verifies the content chunk and its Merkle proof against the challenged data object.
+    pub(crate) fn validate_storage_challenge_response(
+        challenge: &StorageChallenge<T>,
+        chunk: &[u8],
+        proof: &[ContentHash],
+    ) -> DispatchResult {
+        let data_object =
+            Self::ensure_data_object_exists(&challenge.bag_id, &challenge.data_object_id)?;
+
+        let content_root = data_object
+            .content_root
+            .ok_or(Error::<T>::InvalidStorageChallengeResponse)?;
+
+        let chunk_size = T::ChallengeChunkSize::get().max(1);
+        let chunks_number = content_chunks_number(data_object.size, chunk_size);
+        let chunk_offset = challenge.chunk_index.saturating_mul(chunk_size);
+        let expected_chunk_length = data_object
+            .size
+            .saturating_sub(chunk_offset)
+            .min(chunk_size);
+
+        ensure!(
+            chunk.len() as u64 == expected_chunk_length,
+            Error::<T>::InvalidStorageChallengeResponse
+        );
+
+        ensure!(
+            verify_content_merkle_proof(
+                chunk,
+                challenge.chunk_index,
+                chunks_number,
+                proof,
+                &content_root,
+            ),
+            Error::<T>::InvalidStorageChallengeResponse
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,272 @@
+use frame_support::{IterableStorageDoubleMap, StorageDoubleMap, StorageMap};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_runtime::SaturatedConversion;
+
+use crate::{
+    BagContentReferences, BagId, BagUpdate, BalanceOf, ContentReferences, DataObject,
+    DataObjectCreationParameters, Module, OperationType, RawEvent, SharedDataObjectRecord,
+    SharedDataObjects, StorageBucketById, StorageBucketContentReferences, Trait, VoucherUpdate,
+};
+
+// Content-addressed deduplication of the data objects. It's enabled by the data object
+// reference fee. Data objects accepted while the deduplication is enabled are registered in the
+// shared content index by their content ID with the reference counting. Uploading the already
+// stored content (the same content ID and size) is charged with the reference fee instead of the
+// data size fee, pending data objects are not the stored content. Storage buckets
+// count the size of the shared content once: the deduplicated data objects are tracked per bag
+// and per storage bucket. Data objects of the bag with the same content ID are interchangeable:
+// the deduplicated ones are removed first.
+
+impl<T: Trait> Module<T> {
+    // Returns true if the content deduplication is enabled.
+    pub(crate) fn content_deduplication_enabled() -> bool {
+        Self::data_object_reference_fee().is_some()
+    }
+
+    // Returns true if the uploaded data object is already stored: the accepted content with the
+    // same content ID and size is registered (if the content deduplication is enabled). The data
+    // objects with the size mismatch are uploaded as the new content.
+    pub(crate) fn is_stored_shared_content(object_params: &DataObjectCreationParameters) -> bool {
+        if !Self::content_deduplication_enabled() {
+            return false;
+        }
+
+        Self::shared_data_object(&object_params.ipfs_content_id)
+            .map_or(false, |record| record.size == object_params.size)
+    }
+
+    // Calculates the data size fee for the new content and the reference fee for the already
+    // stored content.
+    pub(crate) fn calculate_upload_storage_fee(
+        bag_change: &BagUpdate<BalanceOf<T>>,
+    ) -> BalanceOf<T> {
+        let reference_fee =
+            Self::calculate_reference_fee(bag_change.referenced_objects.objects_number);
+
+        bag_change.data_size_fee.saturating_add(reference_fee)
+    }
+
+    // Calculates the reference fee for the data objects with the already stored content.
+    pub(crate) fn calculate_reference_fee(objects_number: u64) -> BalanceOf<T> {
+        Self::data_object_reference_fee()
+            .unwrap_or_else(Zero::zero)
+            .saturating_mul(objects_number.saturated_into())
+    }
+
+    // Registers the accepted data objects of the bag in the shared content index (if the content
+    // deduplication is enabled). Pending data objects are counted by the storage buckets in full,
+    // the registered ones release the size of the content already stored by the storage bucket.
+    pub(crate) fn register_accepted_shared_content(
+        bag_id: &BagId<T>,
+        data_objects: &[DataObject<BalanceOf<T>>],
+    ) {
+        if !Self::content_deduplication_enabled() {
+            return;
+        }
+
+        let mut content_references = ContentReferences::new();
+        let mut registered_objects = VoucherUpdate::default();
+
+        for data_object in data_objects.iter() {
+            let registered = <SharedDataObjects>::mutate(&data_object.ipfs_content_id, |record| {
+                let record = record.get_or_insert_with(|| SharedDataObjectRecord {
+                    size: data_object.size,
+                    references: 0,
+                });
+
+                // The size mismatch keeps the data object as the new content.
+                if record.size != data_object.size {
+                    return false;
+                }
+
+                record.references = record.references.saturating_add(1);
+
+                true
+            });
+
+            if registered {
+                let references = content_references
+                    .entry(data_object.ipfs_content_id.clone())
+                    .or_default();
+
+                *references = references.saturating_add(1);
+
+                registered_objects.objects_total_size = registered_objects
+                    .objects_total_size
+                    .saturating_add(data_object.size);
+            }
+        }
+
+        if content_references.is_empty() {
+            return;
+        }
+
+        Self::change_bag_content_references(bag_id, &content_references, OperationType::Increase);
+
+        for bucket_id in Self::bag(bag_id).stored_by.iter() {
+            let stored_size = Self::storage_bucket_voucher_update(
+                bucket_id,
+                &registered_objects,
+                &content_references,
+                OperationType::Increase,
+            )
+            .objects_total_size;
+
+            Self::change_storage_bucket_content_references(
+                bucket_id,
+                &content_references,
+                OperationType::Increase,
+            );
+
+            <StorageBucketById<T>>::mutate(bucket_id, |bucket| {
+                bucket.voucher.size_used = bucket
+                    .voucher
+                    .size_used
+                    .saturating_add(stored_size)
+                    .saturating_sub(registered_objects.objects_total_size);
+
+                Self::deposit_event(RawEvent::VoucherChanged(*bucket_id, bucket.voucher.clone()));
+            });
+        }
+    }
+
+    // Decrements the shared content references of the removed data objects. Removes the shared
+    // content with no references from the index.
+    pub(crate) fn release_shared_content(content_references: &ContentReferences) {
+        for (content_id, number) in content_references.iter() {
+            <SharedDataObjects>::mutate(content_id, |record| {
+                let references = record
+                    .as_ref()
+                    .map_or(0, |record| record.references.saturating_sub(*number));
+
+                if references == 0 {
+                    *record = None;
+                } else if let Some(record) = record {
+                    record.references = references;
+                }
+            });
+        }
+    }
+
+    // Deduplicated data objects among the provided bag data objects. Only the accepted data
+    // objects matching the registered content size could be deduplicated.
+    pub(crate) fn data_objects_content_references(
+        bag_id: &BagId<T>,
+        data_objects: &[DataObject<BalanceOf<T>>],
+    ) -> ContentReferences {
+        let mut content_references = ContentReferences::new();
+
+        for data_object in data_objects.iter() {
+            let registered = data_object.accepted
+                && Self::shared_data_object(&data_object.ipfs_content_id)
+                    .map_or(false, |record| record.size == data_object.size);
+
+            if registered {
+                let references = content_references
+                    .entry(data_object.ipfs_content_id.clone())
+                    .or_default();
+
+                *references = references.saturating_add(1);
+            }
+        }
+
+        content_references
+            .into_iter()
+            .filter_map(|(content_id, number)| {
+                let number = number.min(Self::bag_content_references(bag_id, &content_id));
+
+                if number > 0 {
+                    Some((content_id, number))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    // All deduplicated data objects of the bag.
+    pub(crate) fn bag_content_references_by_bag(bag_id: &BagId<T>) -> ContentReferences {
+        <BagContentReferences<T>>::iter_prefix(bag_id).collect()
+    }
+
+    // Updates the bag deduplicated data objects counters.
+    pub(crate) fn change_bag_content_references(
+        bag_id: &BagId<T>,
+        content_references: &ContentReferences,
+        operation: OperationType,
+    ) {
+        for (content_id, number) in content_references.iter() {
+            let references = Self::changed_references(
+                Self::bag_content_references(bag_id, content_id),
+                *number,
+                operation,
+            );
+
+            if references == 0 {
+                <BagContentReferences<T>>::remove(bag_id, content_id);
+            } else {
+                <BagContentReferences<T>>::insert(bag_id, content_id, references);
+            }
+        }
+    }
+
+    // Updates the storage bucket deduplicated data objects counters.
+    pub(crate) fn change_storage_bucket_content_references(
+        bucket_id: &T::StorageBucketId,
+        content_references: &ContentReferences,
+        operation: OperationType,
+    ) {
+        for (content_id, number) in content_references.iter() {
+            let references = Self::changed_references(
+                Self::storage_bucket_content_references(bucket_id, content_id),
+                *number,
+                operation,
+            );
+
+            if references == 0 {
+                <StorageBucketContentReferences<T>>::remove(bucket_id, content_id);
+            } else {
+                <StorageBucketContentReferences<T>>::insert(bucket_id, content_id, references);
+            }
+        }
+    }
+
+    // Voucher update for the storage bucket: the shared content size is counted only when the
+    // storage bucket starts or stops storing the content.
+    pub(crate) fn storage_bucket_voucher_update(
+        bucket_id: &T::StorageBucketId,
+        voucher_update: &VoucherUpdate,
+        content_references: &ContentReferences,
+        operation: OperationType,
+    ) -> VoucherUpdate {
+        content_references.iter().fold(
+            *voucher_update,
+            |mut bucket_voucher_update, (content_id, number)| {
+                let size = Self::shared_data_object(content_id).map_or(0, |record| record.size);
+
+                let old_references = Self::storage_bucket_content_references(bucket_id, content_id);
+                let new_references = Self::changed_references(old_references, *number, operation);
+
+                bucket_voucher_update.objects_total_size = bucket_voucher_update
+                    .objects_total_size
+                    .saturating_sub(number.saturating_mul(size));
+
+                if (old_references == 0) != (new_references == 0) {
+                    bucket_voucher_update.objects_total_size = bucket_voucher_update
+                        .objects_total_size
+                        .saturating_add(size);
+                }
+
+                bucket_voucher_update
+            },
+        )
+    }
+
+    // Applies the operation to the references counter.
+    fn changed_references(references: u64, number: u64, operation: OperationType) -> u64 {
+        match operation {
+            OperationType::Increase => references.saturating_add(number),
+            OperationType::Decrease => references.saturating_sub(number),
+        }
+    }
+}
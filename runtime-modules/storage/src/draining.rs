@@ -0,0 +1,178 @@
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{IterableStorageMap, StorageDoubleMap, StorageMap, StorageValue};
+use sp_runtime::traits::{One, Zero};
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::iter;
+
+use crate::random_buckets::{RandomBucketIdIterator, SequentialBucketIdIterator};
+use crate::{
+    BagId, DrainingStorageBuckets, DrainingStorageBucketsCursor, Module, RawEvent,
+    StorageBucketBagByIndex, Trait, WeightInfoStorage,
+};
+
+// Storage bucket decommissioning: the draining storage bucket doesn't accept new bags and its bags
+// are progressively reassigned to other storage buckets in `on_initialize`. The replacement bucket
+// should accept new bags and have enough voucher capacity for the bag: at most
+// `MaxStorageBucketPickerCandidates` buckets are checked per bag. The bags are processed in passes
+// from the last position of the storage bucket bags index down to the first one (the draining
+// bucket keeps the pass cursor), so the bags without a suitable replacement bucket are retried in
+// the next pass and don't hold back the other bags. The draining buckets are visited in the
+// storage bucket ID order from the persisted cursor: the cursor moves to the next bucket when
+// the pass is finished, so the bucket without a suitable replacement for its bags doesn't hold
+// back the other draining buckets. The drained bucket is deleted along with its operator
+// relationship.
+
+impl<T: Trait> Module<T> {
+    // Reassigns at most `MaxDrainedBagsPerBlock` bags of the draining storage buckets and deletes
+    // the drained buckets. Each checked storage bucket ID counts against the same budget, so the
+    // draining buckets lookup is bounded as well.
+    pub(crate) fn drain_storage_buckets() -> Weight {
+        let max_bags = T::MaxDrainedBagsPerBlock::get();
+
+        if max_bags == 0 {
+            return 0;
+        }
+
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        if <DrainingStorageBuckets<T>>::iter().next().is_none() {
+            return T::DbWeight::get().reads(reads);
+        }
+
+        let initial_cursor = Self::draining_storage_buckets_cursor();
+        let next_storage_bucket_id = Self::next_storage_bucket_id();
+        reads = reads.saturating_add(2);
+
+        let mut bucket_id = initial_cursor;
+        let mut wrapped = false;
+        let mut steps_budget = max_bags;
+        let mut processed_bags: u64 = 0;
+
+        // Each storage bucket ID is checked at most once per block.
+        while steps_budget > 0 && !(wrapped && bucket_id >= initial_cursor) {
+            if bucket_id >= next_storage_bucket_id {
+                wrapped = true;
+                bucket_id = Zero::zero();
+                continue;
+            }
+
+            reads = reads.saturating_add(1);
+            if !<DrainingStorageBuckets<T>>::contains_key(&bucket_id) {
+                steps_budget -= 1;
+                bucket_id += One::one();
+                continue;
+            }
+
+            let (bags, bucket_removed, pass_finished) =
+                Self::drain_storage_bucket(bucket_id, steps_budget);
+            processed_bags = processed_bags.saturating_add(bags);
+            steps_budget = steps_budget.saturating_sub(bags.max(1));
+
+            // Bucket bags number, pass cursor and bucket reads with the pass cursor update or the
+            // removal of the bucket, its draining record and its storage challenges.
+            reads = reads.saturating_add(3);
+            writes = writes.saturating_add(if bucket_removed { 4 } else { 1 });
+
+            if bucket_removed || pass_finished {
+                bucket_id += One::one();
+            }
+        }
+
+        if bucket_id != initial_cursor {
+            <DrainingStorageBucketsCursor<T>>::put(bucket_id);
+            writes = writes.saturating_add(1);
+        }
+
+        let candidates_reads =
+            processed_bags.saturating_mul(T::MaxStorageBucketPickerCandidates::get());
+
+        WeightInfoStorage::<T>::drain_storage_buckets(processed_bags.saturated_into())
+            .saturating_add(T::DbWeight::get().reads_writes(reads, writes))
+            .saturating_add(T::DbWeight::get().reads(candidates_reads))
+    }
+
+    // Reassigns at most `max_bags` bags of the draining storage bucket in its current pass and
+    // deletes the drained bucket. Returns the number of the processed bags and whether the bucket
+    // was deleted or its pass was finished.
+    fn drain_storage_bucket(bucket_id: T::StorageBucketId, max_bags: u64) -> (u64, bool, bool) {
+        // The finished pass is restarted from the last bag.
+        let mut cursor = Self::draining_storage_bucket(&bucket_id);
+        let bags_number = Self::storage_bucket_bags_number(&bucket_id);
+        if cursor == 0 || cursor > bags_number {
+            cursor = bags_number;
+        }
+
+        let mut processed_bags: u64 = 0;
+        while cursor > 0 && processed_bags < max_bags {
+            cursor -= 1;
+            processed_bags += 1;
+
+            let bag_id = <StorageBucketBagByIndex<T>>::get(&bucket_id, cursor);
+
+            Self::reassign_bag_from_draining_storage_bucket(&bag_id, &bucket_id);
+        }
+
+        if Self::storage_bucket_by_id(&bucket_id).no_bags_assigned() {
+            Self::remove_storage_bucket(bucket_id);
+
+            (processed_bags, true, true)
+        } else {
+            <DrainingStorageBuckets<T>>::insert(bucket_id, cursor);
+
+            (processed_bags, false, cursor == 0)
+        }
+    }
+
+    // Replaces the draining storage bucket of the bag with the first sampled storage bucket
+    // passing the "update storage buckets for bag" validation (accepting new bags, voucher capacity
+    // and "storage buckets per bag" limit). It samples at most `MaxStorageBucketPickerCandidates`
+    // bucket IDs: random IDs first, then sequential IDs starting from zero.
+    fn reassign_bag_from_draining_storage_bucket(
+        bag_id: &BagId<T>,
+        draining_bucket_id: &T::StorageBucketId,
+    ) {
+        let remove_buckets = iter::once(*draining_bucket_id).collect::<BTreeSet<_>>();
+
+        let mut sampled_ids = BTreeSet::new();
+        let next_storage_bucket_id = Self::next_storage_bucket_id();
+        let replacement =
+            RandomBucketIdIterator::<T, T::StorageBucketId>::new(next_storage_bucket_id)
+                .chain(SequentialBucketIdIterator::<T, T::StorageBucketId>::new(
+                    next_storage_bucket_id,
+                ))
+                .filter(|bucket_id| sampled_ids.insert(*bucket_id))
+                .take(T::MaxStorageBucketPickerCandidates::get().saturated_into())
+                .filter(|bucket_id| {
+                    Self::ensure_storage_bucket_exists(bucket_id)
+                        .map_or(false, |bucket| bucket.accepting_new_bags)
+                })
+                .find_map(|bucket_id| {
+                    let add_buckets = iter::once(bucket_id).collect::<BTreeSet<_>>();
+
+                    Self::validate_update_storage_buckets_for_bag_params(
+                        bag_id,
+                        &add_buckets,
+                        &remove_buckets,
+                    )
+                    .ok()
+                    .map(|voucher_update| (add_buckets, voucher_update))
+                });
+
+        if let Some((add_buckets, voucher_update)) = replacement {
+            Self::change_storage_buckets_for_bag(
+                bag_id.clone(),
+                add_buckets,
+                remove_buckets,
+                voucher_update,
+            );
+        } else {
+            Self::deposit_event(RawEvent::DrainingStorageBucketBagNotReassigned(
+                *draining_bucket_id,
+                bag_id.clone(),
+            ));
+        }
+    }
+}
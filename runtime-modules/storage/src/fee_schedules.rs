@@ -0,0 +1,106 @@
+use frame_support::dispatch::DispatchResult;
+use frame_support::ensure;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_map::BTreeMap;
+
+use crate::{
+    BagId, BalanceOf, DataObjectClass, DataObjectCreationParameters, DataSizeFeeCategory,
+    DataSizeFeeSchedule, Error, Module, Trait,
+};
+
+// Data size fee schedules: the data size fee can differ per static bag and per dynamic bag type.
+// The schedule can also price the declared data object classes (media, thumbnails, metadata)
+// separately. Bag categories without the schedule are charged with the global data size fee.
+// The data object sizes are summed up per applicable fee and rounded up to megabytes.
+// The uploader protects itself from the fee changes with the expected data size fee: the total
+// data size fee of the upload (including the reference fee for the already stored content)
+// should match it, so the data objects priced differently can be uploaded together.
+// The data object class is declared by the uploader and can't be verified on-chain (the content
+// is stored off-chain): a misdeclared class only changes the price of the upload, the class fees
+// should be set with that in mind.
+
+impl<T: Trait> Module<T> {
+    /// Returns the data size fee schedule applied to the bag: the schedule of the bag category or
+    /// the global data size fee without the object class fees.
+    pub fn bag_data_size_fee_schedule(bag_id: &BagId<T>) -> DataSizeFeeSchedule<BalanceOf<T>> {
+        Self::data_size_fee_schedule(DataSizeFeeCategory::from(bag_id)).unwrap_or_else(|| {
+            DataSizeFeeSchedule {
+                per_megabyte_fee: Self::data_object_per_mega_byte_fee(),
+                object_class_fees: BTreeMap::new(),
+            }
+        })
+    }
+
+    /// Returns the data size fee of the upload of the data objects to the bag: the data size fee
+    /// for the new content and the reference fee for the already stored content.
+    pub fn upload_data_size_fee(
+        bag_id: &BagId<T>,
+        object_creation_list: &[DataObjectCreationParameters],
+    ) -> BalanceOf<T> {
+        let referenced_objects_number = object_creation_list
+            .iter()
+            .filter(|object_params| Self::is_stored_shared_content(object_params))
+            .count();
+
+        Self::calculate_upload_data_size_fee(bag_id, object_creation_list).saturating_add(
+            Self::calculate_reference_fee(referenced_objects_number.saturated_into()),
+        )
+    }
+
+    // Ensures the data size fee of the upload matches the expected data size fee.
+    pub(crate) fn ensure_expected_data_size_fee(
+        bag_id: &BagId<T>,
+        object_creation_list: &[DataObjectCreationParameters],
+        expected_data_size_fee: BalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(
+            Self::upload_data_size_fee(bag_id, object_creation_list) == expected_data_size_fee,
+            Error::<T>::DataSizeFeeChanged
+        );
+
+        Ok(())
+    }
+
+    // Calculates the data size fee for the new content of the upload. The already stored content
+    // is charged with the reference fee instead.
+    pub(crate) fn calculate_upload_data_size_fee(
+        bag_id: &BagId<T>,
+        object_creation_list: &[DataObjectCreationParameters],
+    ) -> BalanceOf<T> {
+        let schedule = Self::bag_data_size_fee_schedule(bag_id);
+
+        let mut sizes_by_class = BTreeMap::<Option<DataObjectClass>, u64>::new();
+
+        for object_params in object_creation_list.iter() {
+            if Self::is_stored_shared_content(object_params) {
+                continue;
+            }
+
+            let class = Self::priced_object_class(&schedule, object_params);
+
+            let size = sizes_by_class.entry(class).or_default();
+            *size = size.saturating_add(object_params.size);
+        }
+
+        sizes_by_class
+            .iter()
+            .fold(Zero::zero(), |fee: BalanceOf<T>, (class, size)| {
+                fee.saturating_add(Self::calculate_data_storage_fee(
+                    schedule.per_megabyte_fee_for(class),
+                    *size,
+                ))
+            })
+    }
+
+    // Declared class of the data object priced by the schedule. Classes missing in the schedule
+    // are charged with the default fee.
+    fn priced_object_class(
+        schedule: &DataSizeFeeSchedule<BalanceOf<T>>,
+        object_params: &DataObjectCreationParameters,
+    ) -> Option<DataObjectClass> {
+        object_params
+            .class
+            .filter(|class| schedule.object_class_fees.contains_key(class))
+    }
+}
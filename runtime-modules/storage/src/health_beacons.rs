@@ -0,0 +1,249 @@
+use frame_support::dispatch::DispatchError;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{ensure, StorageDoubleMap, StorageValue};
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_std::collections::btree_set::BTreeSet;
+
+use crate::{
+    DistributionBucketByFamilyIdById, DistributionBucketId, DistributionHealthBeaconsRound,
+    DistributionOperatorHealth, DistributionOperatorHealthByBucket, Error, HealthBeaconsRound,
+    Module, RawEvent, Trait, WorkerId,
+};
+
+// Distribution operator health beacons: each health beacon period the distribution bucket operators
+// report the bytes served and their node uptime. The number of the health beacons per block is
+// limited. At the end of each period the missed health beacons round starts: the operators of
+// the distributing buckets without the beacon get the missed beacon registered. The round
+// processes a limited number of buckets per block, the operators reporting the beacon before
+// their bucket is processed aren't registered as missing it. A period ending while the previous
+// round is still in progress is skipped. The bucket stops the distribution ('distributing' flag)
+// when all of its operators reach the missed beacons threshold. The operators of the bucket get
+// the health tracking restarted on the bucket mode change.
+
+impl<T: Trait> Module<T> {
+    // Starts the missed health beacons round at the end of each health beacon period and
+    // processes the round in progress.
+    pub(crate) fn process_distribution_health_beacons(now: T::BlockNumber) -> Weight {
+        let period = T::DistributionHealthBeaconPeriod::get();
+
+        if period.is_zero() {
+            return 0;
+        }
+
+        let period_ended = !now.is_zero() && (now % period).is_zero();
+
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        let mut round = match Self::distribution_health_beacons_round() {
+            Some(round) => round,
+            None if period_ended => HealthBeaconsRound {
+                period_start: now.saturating_sub(period),
+                cursor: Self::create_distribution_bucket_id(Zero::zero(), Zero::zero()),
+            },
+            None => return T::DbWeight::get().reads(reads),
+        };
+
+        let next_family_id = Self::next_distribution_bucket_family_id();
+        reads = reads.saturating_add(1);
+
+        let mut round_finished = false;
+        let mut processed: u64 = 0;
+        while processed < T::MaxHealthBeaconBucketsPerBlock::get() {
+            processed += 1;
+
+            let bucket_id = round.cursor.clone();
+
+            if bucket_id.distribution_bucket_family_id >= next_family_id {
+                round_finished = true;
+
+                break;
+            }
+
+            // Deleted families have no buckets.
+            let family =
+                Self::distribution_bucket_family_by_id(bucket_id.distribution_bucket_family_id);
+            reads = reads.saturating_add(1);
+
+            if bucket_id.distribution_bucket_index >= family.next_distribution_bucket_index {
+                round.cursor = Self::create_distribution_bucket_id(
+                    bucket_id.distribution_bucket_family_id + One::one(),
+                    Zero::zero(),
+                );
+
+                continue;
+            }
+
+            round.cursor.distribution_bucket_index += One::one();
+            reads = reads.saturating_add(2);
+
+            if !<DistributionBucketByFamilyIdById<T>>::contains_key(
+                bucket_id.distribution_bucket_family_id,
+                bucket_id.distribution_bucket_index,
+            ) {
+                continue;
+            }
+
+            let bucket = Self::distribution_bucket_by_family_id_by_index(
+                bucket_id.distribution_bucket_family_id,
+                bucket_id.distribution_bucket_index,
+            );
+
+            if !bucket.distributing || bucket.operators.is_empty() {
+                continue;
+            }
+
+            let (bucket_reads, bucket_writes) = Self::register_missed_health_beacons(
+                bucket_id,
+                bucket.operators,
+                round.period_start,
+            );
+
+            reads = reads.saturating_add(bucket_reads);
+            writes = writes.saturating_add(bucket_writes);
+        }
+
+        writes = writes.saturating_add(1);
+        if round_finished {
+            <DistributionHealthBeaconsRound<T>>::kill();
+        } else {
+            <DistributionHealthBeaconsRound<T>>::put(round);
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    // Registers the missed health beacons of the bucket operators and stops the distribution of
+    // the bucket with all operators reaching the missed beacons threshold. Returns the number of
+    // the storage reads and writes.
+    fn register_missed_health_beacons(
+        bucket_id: DistributionBucketId<T>,
+        operators: BTreeSet<WorkerId<T>>,
+        period_start: T::BlockNumber,
+    ) -> (u64, u64) {
+        let threshold = T::MissedDistributionHealthBeaconsThreshold::get();
+
+        let mut reads: u64 = 0;
+        let mut writes: u64 = 0;
+
+        let operators_number = operators.len();
+        let mut failed_operators_number: usize = 0;
+
+        for worker_id in operators {
+            reads = reads.saturating_add(1);
+
+            let missed_beacons = match Self::distribution_operator_health(&bucket_id, worker_id) {
+                Some(health) => {
+                    if Self::health_beacon_reported_since(&health, period_start) {
+                        continue;
+                    }
+
+                    health.missed_beacons.saturating_add(1)
+                }
+                None => {
+                    writes = writes.saturating_add(1);
+
+                    // Starts the health tracking of the operator.
+                    <DistributionOperatorHealthByBucket<T>>::insert(
+                        &bucket_id,
+                        worker_id,
+                        DistributionOperatorHealth::<T>::default(),
+                    );
+
+                    continue;
+                }
+            };
+
+            writes = writes.saturating_add(1);
+
+            <DistributionOperatorHealthByBucket<T>>::mutate(&bucket_id, worker_id, |health| {
+                if let Some(health) = health {
+                    health.missed_beacons = missed_beacons;
+                }
+            });
+
+            Self::deposit_event(RawEvent::DistributionHealthBeaconMissed(
+                bucket_id.clone(),
+                worker_id,
+                missed_beacons,
+            ));
+
+            if threshold != 0 && missed_beacons >= threshold {
+                failed_operators_number += 1;
+            }
+        }
+
+        if failed_operators_number == operators_number {
+            writes = writes.saturating_add(2);
+
+            Self::stop_bucket_distribution(bucket_id);
+        }
+
+        (reads, writes)
+    }
+
+    // Switches off the 'distributing' flag of the bucket and restarts the health tracking of its
+    // operators.
+    fn stop_bucket_distribution(bucket_id: DistributionBucketId<T>) {
+        <DistributionBucketByFamilyIdById<T>>::mutate(
+            &bucket_id.distribution_bucket_family_id,
+            &bucket_id.distribution_bucket_index,
+            |bucket| {
+                bucket.distributing = false;
+            },
+        );
+
+        <DistributionOperatorHealthByBucket<T>>::remove_prefix(&bucket_id);
+
+        Self::deposit_event(RawEvent::DistributionBucketModeUpdated(bucket_id, false));
+    }
+
+    // Ensures the health beacons are enabled, the operator hasn't reported the beacon in
+    // the current period and the beacons per block limit isn't reached. Returns the updated
+    // beacons number in the block.
+    pub(crate) fn ensure_distribution_health_beacon_can_be_reported(
+        bucket_id: &DistributionBucketId<T>,
+        worker_id: &WorkerId<T>,
+    ) -> Result<(T::BlockNumber, u64), DispatchError> {
+        let period = T::DistributionHealthBeaconPeriod::get();
+
+        ensure!(
+            !period.is_zero(),
+            Error::<T>::DistributionHealthBeaconsDisabled
+        );
+
+        let now = <frame_system::Module<T>>::block_number();
+        let current_period_start = now.saturating_sub(now % period);
+
+        let already_reported = Self::distribution_operator_health(bucket_id, worker_id)
+            .map_or(false, |health| {
+                Self::health_beacon_reported_since(&health, current_period_start)
+            });
+
+        ensure!(
+            !already_reported,
+            Error::<T>::DistributionHealthBeaconAlreadyReported
+        );
+
+        let (block, beacons_number) = Self::distribution_health_beacons_in_block();
+        let beacons_number = if block == now { beacons_number } else { 0 };
+
+        ensure!(
+            beacons_number < T::MaxDistributionHealthBeaconsPerBlock::get(),
+            Error::<T>::MaxDistributionHealthBeaconsPerBlockExceeded
+        );
+
+        Ok((now, beacons_number.saturating_add(1)))
+    }
+
+    // Checks whether the last health beacon was reported at the provided block or later.
+    fn health_beacon_reported_since(
+        health: &DistributionOperatorHealth<T>,
+        block: T::BlockNumber,
+    ) -> bool {
+        health
+            .last_beacon_at
+            .map_or(false, |last_beacon_at| last_beacon_at >= block)
+    }
+}
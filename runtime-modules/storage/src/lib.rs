@@ -10,6 +10,8 @@
 //! - extrinsics for the distribution group leader
 //! - extrinsics for the storage provider
 //! - extrinsics for the distribution provider
+//! - extrinsics for the working group leaders
+//! - extrinsics for the governance (root origin)
 //! - public methods for the pallet integration
 //!
 //! #### Storage working group leader extrinsics
@@ -29,6 +31,18 @@
 //! updates global uploading status.
 //! - [update_data_size_fee](./struct.Module.html#method.update_data_size_fee) - updates size-based
 //! pricing of new objects uploaded.
+//! - [update_data_size_fee_schedule](./struct.Module.html#method.update_data_size_fee_schedule) -
+//! sets the data size fee schedule of the static bag or the dynamic bag type (also by proposal).
+//! - [update_storage_rent](./struct.Module.html#method.update_storage_rent) - updates size-based
+//! per block storage rent for the dynamic bags.
+//! - [update_data_object_reference_fee](./struct.Module.html#method.update_data_object_reference_fee) -
+//! updates the fee for uploading the already stored content (enables content deduplication).
+//! - [update_dynamic_bag_quota](./struct.Module.html#method.update_dynamic_bag_quota) - updates
+//! the upload quota for the dynamic bags of the given type.
+//! - [set_bag_quota_override](./struct.Module.html#method.set_bag_quota_override) - sets
+//! the upload quota override for the bag.
+//! - [update_storage_operator_reward_rates](./struct.Module.html#method.update_storage_operator_reward_rates) -
+//! updates the load-based reward rates of the storage operators.
 //! - [update_storage_buckets_per_bag_limit](./struct.Module.html#method.update_storage_buckets_per_bag_limit) -
 //! updates "Storage buckets per bag" number limit.
 //! - [update_storage_buckets_voucher_max_limits](./struct.Module.html#method.update_storage_buckets_voucher_max_limits) -
@@ -37,10 +51,19 @@
 //! updates number of storage buckets used in given dynamic bag creation policy.
 //! - [update_blacklist](./struct.Module.html#method.update_blacklist) - adds and removes hashes to
 //! the current blacklist.
+//! - [set_blacklist_entry](./struct.Module.html#method.set_blacklist_entry) - adds or updates
+//! the blacklist entry with the rationale and the optional expiry.
+//! - [purge_blacklisted_data_objects](./struct.Module.html#method.purge_blacklisted_data_objects) -
+//! removes the already uploaded data objects matching the blacklist entry.
 //! - [update_storage_bucket_status](./struct.Module.html#method.update_storage_bucket_status) -
 //! updates whether new bags are being accepted for storage.
 //! - [set_storage_bucket_voucher_limits](./struct.Module.html#method.set_storage_bucket_voucher_limits) -
 //! sets storage bucket voucher limits.
+//! - [start_storage_bucket_draining](./struct.Module.html#method.start_storage_bucket_draining) -
+//! starts the storage bucket decommissioning: its bags are reassigned to other buckets and
+//! the drained bucket is deleted.
+//! - [cancel_storage_bucket_draining](./struct.Module.html#method.cancel_storage_bucket_draining) -
+//! stops the storage bucket decommissioning.
 //!
 //!
 //! #### Storage provider extrinsics
@@ -50,6 +73,18 @@
 //! sets storage operator metadata.
 //! - [accept_pending_data_objects](./struct.Module.html#method.accept_pending_data_objects) - a
 //! storage provider signals that the data object was successfully uploaded to its storage.
+//! - [accept_pending_data_object_parts](./struct.Module.html#method.accept_pending_data_object_parts) -
+//! a storage provider signals that the parts of the multi-part data object were uploaded.
+//! - [respond_to_storage_challenge](./struct.Module.html#method.respond_to_storage_challenge) - a
+//! storage provider proves that it stores the challenged data object chunk.
+//!
+//! #### Storage rent extrinsics
+//! - [top_up_bag_rent](./struct.Module.html#method.top_up_bag_rent) - prepays the storage rent
+//! of a dynamic bag for a number of blocks.
+//!
+//! #### Bag owner extrinsics
+//! - [move_bag_data_objects](./struct.Module.html#method.move_bag_data_objects) - moves data
+//! objects between the bags managed by the signer account (eg.: member and channel bags).
 //!
 //! #### Distribution working group leader extrinsics
 //! - [create_distribution_bucket_family](./struct.Module.html#method.create_distribution_bucket_family) -
@@ -70,6 +105,8 @@
 //! updates "distributing" flag for a distribution bucket.
 //! - [update_families_in_dynamic_bag_creation_policy](./struct.Module.html#method.update_families_in_dynamic_bag_creation_policy) -
 //!  updates distribution bucket families used in given dynamic bag creation policy.
+//! - [update_regions_in_dynamic_bag_creation_policy](./struct.Module.html#method.update_regions_in_dynamic_bag_creation_policy) -
+//!  updates regions requiring a distribution bucket in given dynamic bag creation policy.
 //! - [invite_distribution_bucket_operator](./struct.Module.html#method.invite_distribution_bucket_operator) -
 //!  invites a distribution bucket operator.
 //! - [cancel_distribution_bucket_operator_invite](./struct.Module.html#method.cancel_distribution_bucket_operator_invite) -
@@ -78,12 +115,30 @@
 //!  Removes a distribution bucket operator.
 //! - [set_distribution_bucket_family_metadata](./struct.Module.html#method.set_distribution_bucket_family_metadata) -
 //! Sets distribution bucket family metadata.
+//! - [set_distribution_bucket_family_region](./struct.Module.html#method.set_distribution_bucket_family_region) -
+//! Sets distribution bucket family region.
+//! - [set_distribution_bucket_region](./struct.Module.html#method.set_distribution_bucket_region) -
+//! Sets distribution bucket region.
+//! - [update_distribution_operator_reward_rates](./struct.Module.html#method.update_distribution_operator_reward_rates) -
+//! Updates the load-based reward rates of the distribution operators.
 //!
 //! #### Distribution provider extrinsics
 //! - [accept_distribution_bucket_invitation](./struct.Module.html#method.accept_distribution_bucket_invitation) -
 //!  Accepts pending invite for a distribution bucket.
 //! - [set_distribution_operator_metadata](./struct.Module.html#method.set_distribution_operator_metadata) -
 //!  Set distribution operator metadata for the distribution bucket.
+//! - [report_distribution_operator_health](./struct.Module.html#method.report_distribution_operator_health) -
+//!  Reports the periodic health beacon of the distribution operator.
+//!
+//! #### Working group leader extrinsics
+//! - [upload_working_group_data_objects](./struct.Module.html#method.upload_working_group_data_objects) -
+//!  Uploads data objects to the static bag of the working group.
+//! - [delete_working_group_data_objects](./struct.Module.html#method.delete_working_group_data_objects) -
+//!  Deletes data objects from the static bag of the working group.
+//!
+//! #### Governance extrinsics
+//! - [execute_storage_administrative_action](./struct.Module.html#method.execute_storage_administrative_action) -
+//!  Executes the storage administrative action (data objects upload or dynamic bag creation).
 //!
 //! #### Public methods
 //! Public integration methods are exposed via the [DataObjectStorage](./trait.DataObjectStorage.html)
@@ -111,6 +166,26 @@
 //! - MaxDistributionBucketFamilyNumber
 //! - DistributionBucketsPerBagValueConstraint
 //! - MaxNumberOfPendingInvitationsPerDistributionBucket
+//! - StorageChallengePeriod
+//! - MaxStorageChallengesPerBlock
+//! - StorageChallengeResponsePeriod
+//! - ChallengeChunkSize
+//! - MissedStorageChallengesSlashingThreshold
+//! - MissedStorageChallengesSlashingPenalty
+//! - InitialBagRentPeriod
+//! - StorageRentGracePeriod
+//! - MaxGarbageCollectedDataObjectsPerBlock
+//! - MaxBagRentExpirationsPerBlock
+//! - MaxBlacklistExpirationsPerBlock
+//! - MaxRegionTagLength
+//! - OperatorRewardPeriod
+//! - MaxOperatorRewardBucketsPerBlock
+//! - MaxDataObjectPartsNumber
+//! - MaxDrainedBagsPerBlock
+//! - DistributionHealthBeaconPeriod
+//! - MaxDistributionHealthBeaconsPerBlock
+//! - MissedDistributionHealthBeaconsThreshold
+//! - MaxHealthBeaconBucketsPerBlock
 
 // Compiler demand.
 #![recursion_limit = "256"]
@@ -129,20 +204,37 @@ mod tests;
 mod benchmarking;
 
 //pub(crate) mod distribution_bucket_picker;
+
+// Self-contained subsystems (their helpers and `on_initialize` processing) are kept in separate
+// modules implementing `Module<T>`, the declarations (storage, extrinsics, events and errors)
+// stay in this file. The modules import the used items explicitly.
+mod blacklist;
+mod challenges;
+mod deduplication;
+mod draining;
+mod fee_schedules;
+mod health_beacons;
+mod migrations;
+mod multipart;
+mod queries;
 pub(crate) mod random_buckets;
+mod rent;
+mod rewards;
 
 use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::{Currency, ExistenceRequirement, Get, Randomness};
+use frame_support::weights::Weight;
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageDoubleMap, Parameter,
+    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageDoubleMap,
+    IterableStorageMap, Parameter,
 };
 use frame_system::{ensure_root, ensure_signed};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
 use sp_runtime::traits::{AccountIdConversion, MaybeSerialize, Member, Saturating};
-use sp_runtime::{ModuleId, SaturatedConversion};
+use sp_runtime::{ModuleId, Perbill, SaturatedConversion};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::iter;
@@ -150,10 +242,26 @@ use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 
 use common::constraints::BoundedValueConstraint;
+use common::storage::StorageObjectOwner;
 use common::working_group::WorkingGroup;
 
+pub use challenges::{
+    content_chunk_hash, content_chunks_number, content_merkle_proof, content_merkle_root,
+    verify_content_merkle_proof,
+};
 use random_buckets::DistributionBucketPicker;
-use random_buckets::StorageBucketPicker;
+pub use random_buckets::{CapacityAwareStorageBucketPicker, StorageBucketPicker};
+
+/// Storage bucket selection policy for the new dynamic bags.
+pub trait StorageBucketsSelectionPolicy<T: Trait> {
+    /// Selects storage bucket IDs to assign to a new dynamic bag of the provided type.
+    /// Selected buckets exist, accept new bags and have enough voucher room for the
+    /// optional voucher update. Returns an accumulated bucket ID set or an empty set.
+    fn pick_storage_buckets(
+        bag_type: DynamicBagType,
+        voucher_update: Option<VoucherUpdate>,
+    ) -> BTreeSet<T::StorageBucketId>;
+}
 
 /// Public interface for the storage module.
 pub trait DataObjectStorage<T: Trait> {
@@ -162,7 +270,10 @@ pub trait DataObjectStorage<T: Trait> {
     fn can_upload_data_objects(params: &UploadParameters<T>) -> DispatchResult;
 
     /// Upload new data objects.
-    fn upload_data_objects(params: UploadParameters<T>) -> DispatchResult;
+    /// Returns the ids of the uploaded data objects in the object creation list order.
+    fn upload_data_objects(
+        params: UploadParameters<T>,
+    ) -> Result<Vec<T::DataObjectId>, DispatchError>;
 
     /// Validates moving objects parameters.
     /// Validates voucher usage for affected buckets.
@@ -238,6 +349,74 @@ pub trait DataObjectStorage<T: Trait> {
     fn get_data_objects_id(bag_id: &BagId<T>) -> BTreeSet<T::DataObjectId>;
 }
 
+/// Storage pallet WeightInfo.
+/// Note: the runtime implementation should be generated from the pallet benchmarks with the
+/// benchmark CLI (`scripts/generate-weights.sh`), it holds the placeholder weights until then.
+pub trait WeightInfo {
+    fn delete_storage_bucket() -> Weight;
+    fn update_uploading_blocked_status() -> Weight;
+    fn update_data_size_fee() -> Weight;
+    fn update_storage_buckets_per_bag_limit() -> Weight;
+    fn update_storage_buckets_voucher_max_limits() -> Weight;
+    fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy() -> Weight;
+    fn update_blacklist(i: u32, j: u32, k: u32) -> Weight;
+    fn create_storage_bucket() -> Weight;
+    fn update_storage_buckets_for_bag(i: u32, j: u32) -> Weight;
+    fn cancel_storage_bucket_operator_invite() -> Weight;
+    fn invite_storage_bucket_operator() -> Weight;
+    fn remove_storage_bucket_operator() -> Weight;
+    fn update_storage_bucket_status() -> Weight;
+    fn set_storage_bucket_voucher_limits() -> Weight;
+    fn accept_storage_bucket_invitation() -> Weight;
+    fn set_storage_operator_metadata(i: u32) -> Weight;
+    fn accept_pending_data_objects(i: u32) -> Weight;
+    fn accept_pending_data_object_parts(i: u32) -> Weight;
+    fn move_bag_data_objects(i: u32) -> Weight;
+    fn start_storage_bucket_draining() -> Weight;
+    fn cancel_storage_bucket_draining() -> Weight;
+    fn drain_storage_buckets(i: u32) -> Weight;
+    fn create_distribution_bucket_family() -> Weight;
+    fn delete_distribution_bucket_family() -> Weight;
+    fn create_distribution_bucket() -> Weight;
+    fn update_distribution_bucket_status() -> Weight;
+    fn delete_distribution_bucket() -> Weight;
+    fn update_distribution_buckets_for_bag(i: u32, j: u32) -> Weight;
+    fn update_distribution_buckets_per_bag_limit() -> Weight;
+    fn update_distribution_bucket_mode() -> Weight;
+    fn update_families_in_dynamic_bag_creation_policy(i: u32) -> Weight;
+    fn invite_distribution_bucket_operator() -> Weight;
+    fn cancel_distribution_bucket_operator_invite() -> Weight;
+    fn remove_distribution_bucket_operator() -> Weight;
+    fn set_distribution_bucket_family_metadata(i: u32) -> Weight;
+    fn accept_distribution_bucket_invitation() -> Weight;
+    fn set_distribution_operator_metadata(i: u32) -> Weight;
+    fn upload_data_objects(i: u32) -> Weight;
+    fn move_data_objects(i: u32) -> Weight;
+    fn delete_data_objects(i: u32) -> Weight;
+    fn create_dynamic_bag(i: u32, j: u32) -> Weight;
+    fn delete_dynamic_bag(i: u32, j: u32) -> Weight;
+    fn re_replicate_bags(i: u32) -> Weight;
+    fn respond_to_storage_challenge(i: u32, j: u32) -> Weight;
+    fn update_storage_rent() -> Weight;
+    fn top_up_bag_rent() -> Weight;
+    fn update_data_object_reference_fee() -> Weight;
+    fn update_dynamic_bag_quota() -> Weight;
+    fn set_bag_quota_override() -> Weight;
+    fn update_regions_in_dynamic_bag_creation_policy(i: u32) -> Weight;
+    fn set_distribution_bucket_family_region(i: u32) -> Weight;
+    fn set_distribution_bucket_region(i: u32) -> Weight;
+    fn update_storage_operator_reward_rates() -> Weight;
+    fn update_distribution_operator_reward_rates() -> Weight;
+    fn set_blacklist_entry(i: u32) -> Weight;
+    fn purge_blacklisted_data_objects(i: u32) -> Weight;
+    fn update_data_size_fee_schedule() -> Weight;
+    fn report_distribution_operator_health() -> Weight;
+    fn upload_working_group_data_objects(i: u32) -> Weight;
+    fn delete_working_group_data_objects(i: u32) -> Weight;
+}
+
+type WeightInfoStorage<T> = <T as Trait>::WeightInfo;
+
 /// Storage trait.
 pub trait Trait: frame_system::Trait + balances::Trait + common::MembershipTypes {
     /// Storage event type.
@@ -338,9 +517,20 @@ pub trait Trait: frame_system::Trait + balances::Trait + common::MembershipTypes
     /// Defines max random iteration number (eg.: when picking the storage buckets).
     type MaxRandomIterationNumber: Get<u64>;
 
+    /// Defines max number of the storage buckets compared by the capacity-aware storage bucket
+    /// picker (or checked as the replacement bucket for the bag of the draining storage bucket).
+    type MaxStorageBucketPickerCandidates: Get<u64>;
+
+    /// Defines max number of the distribution buckets compared by the distribution bucket picker
+    /// for a dynamic bag creation policy region.
+    type MaxDistributionBucketPickerCandidates: Get<u64>;
+
     /// Something that provides randomness in the runtime.
     type Randomness: Randomness<Self::Hash>;
 
+    /// Storage bucket selection policy for the new dynamic bags.
+    type StorageBucketsSelectionPolicy: StorageBucketsSelectionPolicy<Self>;
+
     /// Defines max allowed distribution bucket family number.
     type MaxDistributionBucketFamilyNumber: Get<u64>;
 
@@ -350,6 +540,90 @@ pub trait Trait: frame_system::Trait + balances::Trait + common::MembershipTypes
     /// Max data object size in bytes.
     type MaxDataObjectSize: Get<u64>;
 
+    /// Max number of under-replicated dynamic bags processed in `on_initialize` (also the max
+    /// number of bags checked by the replication audit).
+    /// Zero disables the automatic re-replication and the replication audit.
+    type MaxReReplicatedBagsPerBlock: Get<u64>;
+
+    /// Storage challenge period in blocks: each period starts a round of challenges for every
+    /// storage bucket with an active operator. Zero disables the storage challenges.
+    type StorageChallengePeriod: Get<Self::BlockNumber>;
+
+    /// Max number of storage buckets challenged in `on_initialize` (the challenge round is spread
+    /// over multiple blocks).
+    type MaxStorageChallengesPerBlock: Get<u64>;
+
+    /// Number of blocks given to the storage operator to respond to the storage challenge
+    /// (limited by the storage challenge period).
+    type StorageChallengeResponsePeriod: Get<Self::BlockNumber>;
+
+    /// Data object content chunk size in bytes (Merkle tree leaf) used in storage challenges.
+    type ChallengeChunkSize: Get<u64>;
+
+    /// Number of missed storage challenges that triggers the storage operator slashing.
+    /// Zero disables the slashing.
+    type MissedStorageChallengesSlashingThreshold: Get<u64>;
+
+    /// Storage operator stake slashed on reaching the missed storage challenges threshold.
+    type MissedStorageChallengesSlashingPenalty: Get<BalanceOf<Self>>;
+
+    /// Prepaid storage rent period granted to the dynamic bags created while the rent is enabled.
+    type InitialBagRentPeriod: Get<Self::BlockNumber>;
+
+    /// Number of blocks after the exhausted bag prepayment before the bag data objects are
+    /// garbage collected.
+    type StorageRentGracePeriod: Get<Self::BlockNumber>;
+
+    /// Max number of data objects of the expired bags removed in `on_initialize`.
+    /// Zero disables the garbage collection.
+    type MaxGarbageCollectedDataObjectsPerBlock: Get<u64>;
+
+    /// Max number of the bag rent expirations processed in `on_initialize` (the scanned blocks
+    /// without expirations are counted too). Remaining expirations are processed in the next
+    /// blocks.
+    type MaxBagRentExpirationsPerBlock: Get<u64>;
+
+    /// Max number of the blacklist entry expirations processed in `on_initialize` (the scanned
+    /// blocks without expirations are counted too). Remaining expirations are processed in the
+    /// next blocks.
+    type MaxBlacklistExpirationsPerBlock: Get<u64>;
+
+    /// Max length of the distribution bucket region tag.
+    type MaxRegionTagLength: Get<u32>;
+
+    /// Period of the load-based operator rewards. Zero disables the rewards.
+    type OperatorRewardPeriod: Get<Self::BlockNumber>;
+
+    /// Max number of storage buckets and max number of distribution buckets processed by the
+    /// operator rewards rounds in `on_initialize` (the rounds are spread over multiple blocks).
+    type MaxOperatorRewardBucketsPerBlock: Get<u64>;
+
+    /// Max number of parts of the multi-part data object.
+    type MaxDataObjectPartsNumber: Get<u64>;
+
+    /// Max number of the draining storage bucket bags reassigned in `on_initialize`.
+    /// Zero disables the automatic storage bucket draining.
+    type MaxDrainedBagsPerBlock: Get<u64>;
+
+    /// Distribution operator health beacon period in blocks: each distribution operator should
+    /// report a health beacon once per period. Zero disables the health beacons.
+    type DistributionHealthBeaconPeriod: Get<Self::BlockNumber>;
+
+    /// Max number of the distribution health beacons per block.
+    type MaxDistributionHealthBeaconsPerBlock: Get<u64>;
+
+    /// Number of consecutive missed health beacons of the distribution operators that stops
+    /// the bucket distribution ('distributing' flag) when reached by all bucket operators.
+    /// Zero disables the automatic switching.
+    type MissedDistributionHealthBeaconsThreshold: Get<u64>;
+
+    /// Max number of distribution buckets processed by the missed health beacons round in
+    /// `on_initialize` (the round is spread over multiple blocks).
+    type MaxHealthBeaconBucketsPerBlock: Get<u64>;
+
+    /// Weight information for extrinsics and public methods in this pallet.
+    type WeightInfo: WeightInfo;
+
     /// Demand the storage working group leader authorization.
     /// TODO: Refactor after merging with the Olympia release.
     fn ensure_storage_working_group_leader_origin(origin: Self::Origin) -> DispatchResult;
@@ -365,6 +639,28 @@ pub trait Trait: frame_system::Trait + balances::Trait + common::MembershipTypes
     /// TODO: Refactor after merging with the Olympia release.
     fn ensure_storage_worker_exists(worker_id: &WorkerId<Self>) -> DispatchResult;
 
+    /// Slash the storage worker stake.
+    /// TODO: Refactor after merging with the Olympia release.
+    fn slash_storage_worker_stake(
+        worker_id: &WorkerId<Self>,
+        penalty: BalanceOf<Self>,
+    ) -> DispatchResult;
+
+    /// Returns the storage working group budget.
+    /// TODO: Refactor after merging with the Olympia release.
+    fn storage_working_group_budget() -> BalanceOf<Self>;
+
+    /// Pay the storage worker reward from the storage working group budget.
+    /// TODO: Refactor after merging with the Olympia release.
+    fn pay_storage_worker_reward(
+        worker_id: &WorkerId<Self>,
+        reward: BalanceOf<Self>,
+    ) -> DispatchResult;
+
+    /// Add the paid storage rent to the storage working group budget.
+    /// TODO: Refactor after merging with the Olympia release.
+    fn fund_storage_working_group_budget(amount: BalanceOf<Self>);
+
     /// Demand the distribution group leader authorization.
     /// TODO: Refactor after merging with the Olympia release.
     fn ensure_distribution_working_group_leader_origin(origin: Self::Origin) -> DispatchResult;
@@ -379,6 +675,42 @@ pub trait Trait: frame_system::Trait + balances::Trait + common::MembershipTypes
     /// Validate distribution worker existence.
     /// TODO: Refactor after merging with the Olympia release.
     fn ensure_distribution_worker_exists(worker_id: &WorkerId<Self>) -> DispatchResult;
+
+    /// Returns the distribution working group budget.
+    /// TODO: Refactor after merging with the Olympia release.
+    fn distribution_working_group_budget() -> BalanceOf<Self>;
+
+    /// Pay the distribution worker reward from the distribution working group budget.
+    /// TODO: Refactor after merging with the Olympia release.
+    fn pay_distribution_worker_reward(
+        worker_id: &WorkerId<Self>,
+        reward: BalanceOf<Self>,
+    ) -> DispatchResult;
+
+    /// Checks whether the account can manage the data objects of the storage owner
+    /// (eg.: member controller account or channel owner account).
+    fn is_storage_owner_account(
+        owner: &StorageObjectOwner<MemberId<Self>, Self::ChannelId>,
+        account_id: &Self::AccountId,
+    ) -> bool;
+
+    /// Demand the working group leader authorization (via the `WorkingGroupAuthenticator` of
+    /// the working group).
+    fn ensure_working_group_leader_origin(
+        origin: Self::Origin,
+        working_group: WorkingGroup,
+    ) -> DispatchResult;
+
+    /// Notifies about the data objects removed without the owner request (eg.: garbage collected
+    /// on the bag storage rent expiration), so the references to them can be cleared.
+    fn on_data_objects_removed(
+        bag_id: &BagId<Self>,
+        data_object_ids: &BTreeSet<Self::DataObjectId>,
+    );
+
+    /// Checks whether the data object is referenced outside of the storage (eg.: by the video
+    /// asset slot), so the bag owner can't move it to another bag.
+    fn is_data_object_in_use(bag_id: &BagId<Self>, data_object_id: &Self::DataObjectId) -> bool;
 }
 
 /// Operations with local pallet account.
@@ -444,6 +776,10 @@ pub struct DynamicBagCreationPolicy<DistributionBucketFamilyId: Ord> {
     /// to distribute bag, and for each the number of buckets in that family
     /// which should be used.
     pub families: BTreeMap<DistributionBucketFamilyId, u32>,
+
+    /// The regions which should get at least one distribution bucket of the bag. Buckets are
+    /// sampled from the policy families.
+    pub regions: BTreeSet<RegionTag>,
 }
 
 impl<DistributionBucketFamilyId: Ord> DynamicBagCreationPolicy<DistributionBucketFamilyId> {
@@ -454,7 +790,7 @@ impl<DistributionBucketFamilyId: Ord> DynamicBagCreationPolicy<DistributionBucke
 
     // Verifies non-zero number of required distribution buckets.
     pub(crate) fn no_distribution_buckets_required(&self) -> bool {
-        self.families.iter().map(|(_, num)| num).sum::<u32>() == 0
+        self.families.iter().map(|(_, num)| num).sum::<u32>() == 0 && self.regions.is_empty()
     }
 }
 
@@ -470,6 +806,12 @@ pub type StorageTreasury<T> = ModuleAccountHandler<T, <T as Trait>::ModuleId>;
 /// IPFS hash type alias (content ID).
 pub type Cid = Vec<u8>;
 
+/// Blake2-256 hash of the content chunks and their Merkle tree nodes.
+pub type ContentHash = [u8; 32];
+
+/// Geographic region tag of the distribution buckets (eg.: "eu-west").
+pub type RegionTag = Vec<u8>;
+
 // Alias for the Substrate balances pallet.
 type Balances<T> = balances::Module<T>;
 
@@ -507,8 +849,39 @@ pub struct DataObject<Balance> {
 
     /// Content identifier presented as IPFS hash.
     pub ipfs_content_id: Vec<u8>,
+
+    /// Merkle root over the content chunk hashes (required for the storage challenges).
+    pub content_root: Option<ContentHash>,
+
+    /// Hashes of the object parts accepted separately (empty for the single-part object).
+    pub parts: Vec<ContentHash>,
 }
 
+/// Blacklisted content ID record.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct BlacklistEntry<BlockNumber> {
+    /// Reason of the blacklisting.
+    pub rationale: Vec<u8>,
+
+    /// Block when the entry is removed from the blacklist (`None` - never expires).
+    pub expires_at: Option<BlockNumber>,
+}
+
+/// Deduplicated content shared by the data objects with the same content ID.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct SharedDataObjectRecord {
+    /// Content size in bytes.
+    pub size: u64,
+
+    /// Number of the data objects referencing the content.
+    pub references: u64,
+}
+
+// Number of the deduplicated data objects by content ID.
+type ContentReferences = BTreeMap<Cid, u64>;
+
 /// Type alias for the BagRecord.
 pub type Bag<T> = BagRecord<<T as Trait>::StorageBucketId, DistributionBucketId<T>, BalanceOf<T>>;
 
@@ -579,6 +952,15 @@ pub struct DataObjectCreationParameters {
 
     /// Content identifier presented as IPFS hash.
     pub ipfs_content_id: Vec<u8>,
+
+    /// Merkle root over the content chunk hashes (required for the storage challenges).
+    pub content_root: Option<ContentHash>,
+
+    /// Hashes of the object parts accepted separately (empty for the single-part object).
+    pub parts: Vec<ContentHash>,
+
+    /// Declared data object class priced separately by the data size fee schedule.
+    pub class: Option<DataObjectClass>,
 }
 
 /// Type alias for the BagIdType.
@@ -701,7 +1083,8 @@ pub struct UploadParametersRecord<MemberId, ChannelId, AccountId, Balance> {
     /// Account for the data object deletion prize.
     pub deletion_prize_source_account_id: AccountId,
 
-    /// Expected data size fee value for this extrinsic call.
+    /// Expected data size fee of the upload: the data size fee for the new content by the bag
+    /// schedule and the reference fee for the already stored content.
     pub expected_data_size_fee: Balance,
 }
 
@@ -720,6 +1103,29 @@ pub struct DynamicBagDeletionPrizeRecord<AccountId, Balance> {
     pub prize: Balance,
 }
 
+/// Alias for the StorageAdministrativeActionRecord
+pub type StorageAdministrativeAction<T> = StorageAdministrativeActionRecord<
+    MemberId<T>,
+    <T as Trait>::ChannelId,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+>;
+
+/// Storage administrative action. Executed by the root origin (eg.: the governance proposal
+/// funded from the proposer account).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum StorageAdministrativeActionRecord<MemberId, ChannelId, AccountId, Balance> {
+    /// Upload new data objects.
+    UploadDataObjects(UploadParametersRecord<MemberId, ChannelId, AccountId, Balance>),
+
+    /// Create a dynamic bag with the optional deletion prize.
+    CreateDynamicBag(
+        DynamicBagIdType<MemberId, ChannelId>,
+        Option<DynamicBagDeletionPrizeRecord<AccountId, Balance>>,
+    ),
+}
+
 /// Defines storage bucket parameters.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
@@ -747,9 +1153,159 @@ enum OperationType {
     Decrease,
 }
 
-// Helper-struct - defines voucher changes.
-#[derive(Clone, PartialEq, Eq, Debug, Copy, Default)]
-struct VoucherUpdate {
+/// Fees of the prospective upload.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UploadFeeQuote<Balance> {
+    /// Data size fee including the reference fee for the already stored content (slashed on
+    /// upload). Expected data size fee of the upload.
+    pub data_size_fee: Balance,
+
+    /// Total deletion prize of the data objects (deposited to the storage treasury).
+    pub deletion_prize: Balance,
+
+    /// Storage rent for the remaining prepaid period of the bag (charged on upload).
+    pub storage_rent: Balance,
+}
+
+/// Upload quota of the bag: data objects number and total size limits.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BagQuota {
+    /// Object number limit.
+    pub objects_limit: u64,
+
+    /// Total size limit.
+    pub size_limit: u64,
+}
+
+/// Data object class declared on upload.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord)]
+pub enum DataObjectClass {
+    /// Media content (e.g. video).
+    Media,
+
+    /// Thumbnail (e.g. channel avatar or video cover).
+    Thumbnail,
+
+    /// Metadata.
+    Metadata,
+}
+
+impl Default for DataObjectClass {
+    fn default() -> Self {
+        Self::Media
+    }
+}
+
+/// Bag category priced by the data size fee schedule: the static bag or the dynamic bag type.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, PartialOrd, Ord)]
+pub enum DataSizeFeeCategory {
+    /// Static bag.
+    Static(StaticBagId),
+
+    /// Dynamic bags of the type.
+    Dynamic(DynamicBagType),
+}
+
+impl Default for DataSizeFeeCategory {
+    fn default() -> Self {
+        Self::Static(Default::default())
+    }
+}
+
+impl<MemberId, ChannelId> From<&BagIdType<MemberId, ChannelId>> for DataSizeFeeCategory
+where
+    MemberId: Default + Clone,
+    ChannelId: Clone,
+{
+    fn from(bag_id: &BagIdType<MemberId, ChannelId>) -> Self {
+        match bag_id {
+            BagIdType::Static(static_bag_id) => Self::Static(static_bag_id.clone()),
+            BagIdType::Dynamic(dynamic_bag_id) => Self::Dynamic(dynamic_bag_id.clone().into()),
+        }
+    }
+}
+
+/// Data size fee schedule of the bag category.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct DataSizeFeeSchedule<Balance> {
+    /// Fee per megabyte of the data objects without the declared class (or with the class
+    /// missing in the schedule).
+    pub per_megabyte_fee: Balance,
+
+    /// Fees per megabyte of the declared data object classes.
+    pub object_class_fees: BTreeMap<DataObjectClass, Balance>,
+}
+
+impl<Balance: Copy> DataSizeFeeSchedule<Balance> {
+    // Fee per megabyte of the data object with the declared class.
+    fn per_megabyte_fee_for(&self, class: &Option<DataObjectClass>) -> Balance {
+        class
+            .as_ref()
+            .and_then(|class| self.object_class_fees.get(class))
+            .copied()
+            .unwrap_or(self.per_megabyte_fee)
+    }
+}
+
+/// Load-based operator reward rates per reward period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OperatorRewardRates<Balance> {
+    /// Reward per stored megabyte.
+    pub per_megabyte: Balance,
+
+    /// Reward per assigned bag.
+    pub per_bag: Balance,
+}
+
+/// Stage of the operator rewards round.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OperatorRewardsStage<Balance> {
+    /// Summing up the bucket rewards (the total rewards of the processed buckets).
+    Summing(Balance),
+
+    /// Paying the bucket rewards reduced to the share of the working group budget.
+    Paying(Perbill),
+}
+
+/// Operator rewards round. The bucket rewards are summed up first to get the share of the
+/// working group budget to pay, then the rewards are paid. Both stages are spread over
+/// multiple blocks.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct OperatorRewardsRound<Balance, BucketId> {
+    /// Reward rates of the round.
+    pub rates: OperatorRewardRates<Balance>,
+
+    /// Current stage of the round.
+    pub stage: OperatorRewardsStage<Balance>,
+
+    /// Next bucket ID to process in the current stage.
+    pub cursor: BucketId,
+}
+
+/// Missed health beacons round: registers the missed health beacons of the health beacon period
+/// for the distribution buckets. The round is spread over multiple blocks.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct HealthBeaconsRound<BlockNumber, BucketId> {
+    /// Start block of the health beacon period checked by the round.
+    pub period_start: BlockNumber,
+
+    /// Next bucket ID to process.
+    pub cursor: BucketId,
+}
+
+/// Helper-struct - defines voucher changes.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Copy, Default)]
+pub struct VoucherUpdate {
     /// Total number.
     pub objects_number: u64,
 
@@ -842,6 +1398,35 @@ impl<WorkerId, AccountId> StorageBucketRecord<WorkerId, AccountId> {
     }
 }
 
+/// Type alias for the StorageChallengeRecord.
+pub type StorageChallenge<T> = StorageChallengeRecord<
+    WorkerId<T>,
+    BagId<T>,
+    <T as Trait>::DataObjectId,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// Storage challenge issued to the storage bucket operator: the operator must provide
+/// the data object content chunk with its Merkle proof before the challenge expires.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct StorageChallengeRecord<WorkerId, BagId, DataObjectId, BlockNumber> {
+    /// Challenged storage operator.
+    pub worker_id: WorkerId,
+
+    /// Bag of the challenged data object.
+    pub bag_id: BagId,
+
+    /// Challenged data object ID.
+    pub data_object_id: DataObjectId,
+
+    /// Challenged content chunk index.
+    pub chunk_index: u64,
+
+    /// Last block to respond to the challenge.
+    pub expires_at: BlockNumber,
+}
+
 // Helper-struct for the data object uploading.
 #[derive(Default, Clone, Debug)]
 struct DataObjectCandidates<T: Trait> {
@@ -860,6 +1445,12 @@ struct BagUpdate<Balance> {
 
     // Total deletion prize for data objects.
     total_deletion_prize: Balance,
+
+    // Data objects with the already stored content (subset of the voucher update).
+    referenced_objects: VoucherUpdate,
+
+    // Data size fee for the new content according to the bag data size fee schedule.
+    data_size_fee: Balance,
 }
 
 impl<Balance: Saturating + Copy> BagUpdate<Balance> {
@@ -871,6 +1462,13 @@ impl<Balance: Saturating + Copy> BagUpdate<Balance> {
 
         *self
     }
+
+    // Marks the added object as referencing the already stored content.
+    fn add_referenced_object(&mut self, size: u64) -> Self {
+        self.referenced_objects.add_object(size);
+
+        *self
+    }
 }
 
 /// Type alias for the DistributionBucketFamilyRecord.
@@ -883,6 +1481,9 @@ pub type DistributionBucketFamily<T> =
 pub struct DistributionBucketFamilyRecord<DistributionBucketIndex> {
     /// Next distribution bucket index.
     pub next_distribution_bucket_index: DistributionBucketIndex,
+
+    /// Default region of the family distribution buckets.
+    pub region: Option<RegionTag>,
 }
 
 impl<DistributionBucketIndex: BaseArithmetic>
@@ -934,6 +1535,9 @@ pub struct DistributionBucketRecord<WorkerId: Ord> {
 
     /// Number of assigned bags.
     pub assigned_bags: u64,
+
+    /// Distribution bucket region. Overrides the family region.
+    pub region: Option<RegionTag>,
 }
 
 impl<WorkerId: Ord> DistributionBucketRecord<WorkerId> {
@@ -953,6 +1557,57 @@ impl<WorkerId: Ord> DistributionBucketRecord<WorkerId> {
     }
 }
 
+/// Type alias for the DistributionOperatorHealthRecord.
+pub type DistributionOperatorHealth<T> =
+    DistributionOperatorHealthRecord<<T as frame_system::Trait>::BlockNumber>;
+
+/// Health of the distribution bucket operator reported by the periodic health beacons.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct DistributionOperatorHealthRecord<BlockNumber> {
+    /// Block of the last health beacon. None if no beacon was reported since the start of
+    /// the health tracking.
+    pub last_beacon_at: Option<BlockNumber>,
+
+    /// Number of bytes served reported by the last health beacon.
+    pub bytes_served: u64,
+
+    /// Operator node uptime in seconds reported by the last health beacon.
+    pub uptime: u64,
+
+    /// Number of consecutive missed health beacon periods.
+    pub missed_beacons: u64,
+}
+
+/// Storage layout versions of the storage pallet.
+/// On runtime upgrade the migrations of every version newer than the stored one are run,
+/// then the stored version is bumped to `StorageLayoutVersion::LATEST`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum StorageLayoutVersion {
+    /// Layout before storage versioning was introduced
+    V0,
+    /// Storage bucket bags reverse index and dynamic bags index
+    V1,
+    /// Data object content Merkle root and parts, bag data objects index
+    V2,
+    /// Distribution bucket family, distribution bucket and dynamic bag creation policy regions
+    V3,
+    /// Distribution bucket bags size and bags index
+    V4,
+}
+
+impl StorageLayoutVersion {
+    /// Current storage layout version.
+    pub const LATEST: Self = Self::V4;
+}
+
+impl Default for StorageLayoutVersion {
+    fn default() -> Self {
+        Self::V0
+    }
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as Storage {
         /// Defines whether all new uploads blocked
@@ -971,8 +1626,19 @@ decl_storage! {
         pub StorageBucketById get (fn storage_bucket_by_id): map hasher(blake2_128_concat)
             T::StorageBucketId => StorageBucket<T>;
 
-        /// Blacklisted data object hashes.
-        pub Blacklist get (fn blacklist): map hasher(blake2_128_concat) Cid => ();
+        /// Blacklisted data object hashes. The entries added before the rationale was introduced
+        /// are read as the default entry (no rationale, never expires).
+        pub Blacklist get (fn blacklist):
+            map hasher(blake2_128_concat) Cid => BlacklistEntry<T::BlockNumber>;
+
+        /// Scheduled blacklist entry expirations by block.
+        pub BlacklistExpirations get (fn blacklist_expiration): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) Cid => ();
+
+        /// Next block of the scheduled blacklist entry expirations to process (zero before the
+        /// first processed block).
+        pub BlacklistExpirationsCursor get (fn blacklist_expirations_cursor): T::BlockNumber;
 
         /// Blacklist collection counter.
         pub CurrentBlacklistSize get (fn current_blacklist_size): u64;
@@ -980,6 +1646,12 @@ decl_storage! {
         /// Size based pricing of new objects uploaded.
         pub DataObjectPerMegabyteFee get (fn data_object_per_mega_byte_fee): BalanceOf<T>;
 
+        /// Data size fee schedules of the bag categories (the global data size fee is used for the
+        /// bag categories without the schedule).
+        pub DataSizeFeeSchedules get (fn data_size_fee_schedule):
+            map hasher(blake2_128_concat) DataSizeFeeCategory
+            => Option<DataSizeFeeSchedule<BalanceOf<T>>>;
+
         /// "Storage buckets per bag" number limit.
         pub StorageBucketsPerBagLimit get (fn storage_buckets_per_bag_limit): u64;
 
@@ -999,6 +1671,27 @@ decl_storage! {
             hasher(blake2_128_concat) BagId<T>,
             hasher(blake2_128_concat) T::DataObjectId => DataObject<BalanceOf<T>>;
 
+        /// Data objects of the bag by their position in the bag data objects index (random access
+        /// for the storage challenges).
+        pub BagDataObjectByIndex get (fn bag_data_object_by_index): double_map
+            hasher(blake2_128_concat) BagId<T>,
+            hasher(blake2_128_concat) u64 => T::DataObjectId;
+
+        /// Position of the data object in the bag data objects index.
+        pub BagDataObjectIndex get (fn bag_data_object_index): double_map
+            hasher(blake2_128_concat) BagId<T>,
+            hasher(blake2_128_concat) T::DataObjectId => u64;
+
+        /// Number of the data objects in the bag data objects index.
+        pub BagDataObjectsNumber get (fn bag_data_objects_number):
+            map hasher(blake2_128_concat) BagId<T> => u64;
+
+        /// Parts of the multi-part data objects held by the storage buckets. Records of the buckets
+        /// removed from the data object bag are outdated and should be ignored.
+        pub DataObjectPartsByBucket get (fn data_object_parts_by_bucket): double_map
+            hasher(blake2_128_concat) T::DataObjectId,
+            hasher(blake2_128_concat) T::StorageBucketId => BTreeSet<u64>;
+
         /// Distribution bucket family id counter. Starts at zero.
         pub NextDistributionBucketFamilyId get(fn next_distribution_bucket_family_id):
             T::DistributionBucketFamilyId;
@@ -1019,6 +1712,175 @@ decl_storage! {
 
         /// "Distribution buckets per bag" number limit.
         pub DistributionBucketsPerBagLimit get (fn distribution_buckets_per_bag_limit): u64;
+
+        /// Storage layout version, used to select the migrations to run on runtime upgrade.
+        pub LayoutVersion get (fn layout_version) build(|_| StorageLayoutVersion::LATEST):
+            StorageLayoutVersion;
+
+        /// Bags assigned to the storage buckets (reverse index for the bag `stored_by` set) with
+        /// their position in the storage bucket bags index.
+        pub StorageBucketBags get (fn storage_bucket_bag_index): double_map
+            hasher(blake2_128_concat) T::StorageBucketId,
+            hasher(blake2_128_concat) BagId<T> => u64;
+
+        /// Bags assigned to the storage bucket by their position in the storage bucket bags index
+        /// (random access for the storage challenges).
+        pub StorageBucketBagByIndex get (fn storage_bucket_bag_by_index): double_map
+            hasher(blake2_128_concat) T::StorageBucketId,
+            hasher(blake2_128_concat) u64 => BagId<T>;
+
+        /// Number of the bags in the storage bucket bags index.
+        pub StorageBucketBagsNumber get (fn storage_bucket_bags_number):
+            map hasher(blake2_128_concat) T::StorageBucketId => u64;
+
+        /// Dynamic bags by their position in the dynamic bags index (bounded iteration over the
+        /// dynamic bags).
+        pub DynamicBagByIndex get (fn dynamic_bag_by_index):
+            map hasher(blake2_128_concat) u64 => DynamicBagId<T>;
+
+        /// Position of the dynamic bag in the dynamic bags index.
+        pub DynamicBagIndex get (fn dynamic_bag_index):
+            map hasher(blake2_128_concat) DynamicBagId<T> => u64;
+
+        /// Number of the dynamic bags.
+        pub DynamicBagsNumber get (fn dynamic_bags_number): u64;
+
+        /// Position in the dynamic bags index of the next bag to check for the replication
+        /// after the dynamic bag creation policy was raised. None when no check is in progress.
+        pub ReplicationAuditCursor get (fn replication_audit_cursor): Option<u64>;
+
+        /// Dynamic bags with less active storage buckets (existing and accepting new bags) than
+        /// required by the dynamic bag creation policy.
+        pub UnderReplicatedBags get (fn under_replicated_bag):
+            map hasher(blake2_128_concat) DynamicBagId<T> => ();
+
+        /// Under-replicated dynamic bags awaiting the automatic re-replication.
+        pub ReReplicationQueue get (fn re_replication_queue):
+            map hasher(blake2_128_concat) u64 => DynamicBagId<T>;
+
+        /// Re-replication queue head (the next bag to process).
+        pub ReReplicationQueueHead get (fn re_replication_queue_head): u64;
+
+        /// Re-replication queue tail (the next free position).
+        pub ReReplicationQueueTail get (fn re_replication_queue_tail): u64;
+
+        /// Storage buckets being decommissioned: their bags are reassigned to other buckets. Keeps
+        /// the number of the bags left to process in the current draining pass.
+        pub DrainingStorageBuckets get (fn draining_storage_bucket):
+            map hasher(blake2_128_concat) T::StorageBucketId => u64;
+
+        /// Next storage bucket ID to check for the draining in `on_initialize`.
+        pub DrainingStorageBucketsCursor get (fn draining_storage_buckets_cursor):
+            T::StorageBucketId;
+
+        /// Outstanding storage challenges by storage bucket.
+        pub StorageChallenges get (fn storage_challenge):
+            map hasher(blake2_128_concat) T::StorageBucketId => Option<StorageChallenge<T>>;
+
+        /// Next storage bucket ID to challenge in the current storage challenge period. None when
+        /// every storage bucket was challenged.
+        pub StorageChallengeCursor get (fn storage_challenge_cursor): Option<T::StorageBucketId>;
+
+        /// Missed storage challenges number by storage bucket (reset on the operator slashing).
+        pub MissedStorageChallenges get (fn missed_storage_challenges):
+            map hasher(blake2_128_concat) T::StorageBucketId => u64;
+
+        /// Size based per block storage rent for the dynamic bags. Zero disables the rent.
+        pub DataObjectPerMegabytePerBlockRent get (fn data_object_per_mega_byte_per_block_rent):
+            BalanceOf<T>;
+
+        /// Last prepaid block by dynamic bag (for the bags subject to the storage rent).
+        pub BagRentPaidThrough get (fn bag_rent_paid_through):
+            map hasher(blake2_128_concat) DynamicBagId<T> => Option<T::BlockNumber>;
+
+        /// Scheduled bag rent expirations (end of the grace period) by block.
+        pub BagRentExpirations get (fn bag_rent_expiration): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) DynamicBagId<T> => ();
+
+        /// Next block of the scheduled bag rent expirations to process (zero before the first
+        /// processed block).
+        pub BagRentExpirationsCursor get (fn bag_rent_expirations_cursor): T::BlockNumber;
+
+        /// Bags with the expired storage rent awaiting the data objects garbage collection.
+        pub ExpiredBags get (fn expired_bag):
+            map hasher(blake2_128_concat) DynamicBagId<T> => ();
+
+        /// Fee for uploading the already stored content (charged instead of the data size fee).
+        /// `None` disables the content deduplication.
+        pub DataObjectReferenceFee get (fn data_object_reference_fee): Option<BalanceOf<T>>;
+
+        /// Accepted deduplicated content by content ID.
+        pub SharedDataObjects get (fn shared_data_object):
+            map hasher(blake2_128_concat) Cid => Option<SharedDataObjectRecord>;
+
+        /// Number of the deduplicated data objects by bag and content ID.
+        pub BagContentReferences get (fn bag_content_references): double_map
+            hasher(blake2_128_concat) BagId<T>,
+            hasher(blake2_128_concat) Cid => u64;
+
+        /// Number of the deduplicated data objects stored by the storage bucket by content ID.
+        pub StorageBucketContentReferences get (fn storage_bucket_content_references): double_map
+            hasher(blake2_128_concat) T::StorageBucketId,
+            hasher(blake2_128_concat) Cid => u64;
+
+        /// Default upload quota for the dynamic bags by the dynamic bag type. Missing quota means
+        /// unlimited uploads.
+        pub DynamicBagQuotas get (fn dynamic_bag_quota):
+            map hasher(blake2_128_concat) DynamicBagType => Option<BagQuota>;
+
+        /// Upload quota overrides for the individual bags.
+        pub BagQuotaOverrides get (fn bag_quota_override):
+            map hasher(blake2_128_concat) BagId<T> => Option<BagQuota>;
+
+        /// Load-based reward rates of the storage operators.
+        pub StorageOperatorRewardRates get (fn storage_operator_reward_rates):
+            OperatorRewardRates<BalanceOf<T>>;
+
+        /// Load-based reward rates of the distribution operators.
+        pub DistributionOperatorRewardRates get (fn distribution_operator_reward_rates):
+            OperatorRewardRates<BalanceOf<T>>;
+
+        /// Storage operator rewards round in progress.
+        pub StorageOperatorRewardsRound get (fn storage_operator_rewards_round):
+            Option<OperatorRewardsRound<BalanceOf<T>, T::StorageBucketId>>;
+
+        /// Distribution operator rewards round in progress.
+        pub DistributionOperatorRewardsRound get (fn distribution_operator_rewards_round):
+            Option<OperatorRewardsRound<BalanceOf<T>, DistributionBucketId<T>>>;
+
+        /// Total size of the bags assigned to the distribution bucket.
+        pub DistributionBucketBagsSize get (fn distribution_bucket_bags_size):
+            map hasher(blake2_128_concat) DistributionBucketId<T> => u64;
+
+        /// Bags assigned to the distribution buckets (reverse index for the bag `distributed_by`
+        /// set) with their position in the distribution bucket bags index.
+        pub DistributionBucketBags get (fn distribution_bucket_bag_index): double_map
+            hasher(blake2_128_concat) DistributionBucketId<T>,
+            hasher(blake2_128_concat) BagId<T> => u64;
+
+        /// Bags assigned to the distribution bucket by their position in the distribution bucket
+        /// bags index (paged queries).
+        pub DistributionBucketBagByIndex get (fn distribution_bucket_bag_by_index): double_map
+            hasher(blake2_128_concat) DistributionBucketId<T>,
+            hasher(blake2_128_concat) u64 => BagId<T>;
+
+        /// Number of the bags in the distribution bucket bags index.
+        pub DistributionBucketBagsNumber get (fn distribution_bucket_bags_number):
+            map hasher(blake2_128_concat) DistributionBucketId<T> => u64;
+
+        /// Health of the distribution bucket operators reported by the health beacons.
+        pub DistributionOperatorHealthByBucket get (fn distribution_operator_health): double_map
+            hasher(blake2_128_concat) DistributionBucketId<T>,
+            hasher(blake2_128_concat) WorkerId<T> => Option<DistributionOperatorHealth<T>>;
+
+        /// Number of the health beacons reported in the block (block number, beacons number).
+        pub DistributionHealthBeaconsInBlock get (fn distribution_health_beacons_in_block):
+            (T::BlockNumber, u64);
+
+        /// Missed health beacons round in progress.
+        pub DistributionHealthBeaconsRound get (fn distribution_health_beacons_round):
+            Option<HealthBeaconsRound<T::BlockNumber, DistributionBucketId<T>>>;
     }
 }
 
@@ -1037,6 +1899,7 @@ decl_event! {
         <T as Trait>::DistributionBucketFamilyId,
         DistributionBucketId = DistributionBucketId<T>,
         <T as Trait>::DistributionBucketIndex,
+        <T as frame_system::Trait>::BlockNumber,
     {
         /// Emits on creating the storage bucket.
         /// Params
@@ -1090,6 +1953,15 @@ decl_event! {
         /// - pending data objects
         PendingDataObjectsAccepted(StorageBucketId, WorkerId, BagId, BTreeSet<DataObjectId>),
 
+        /// Emits on accepting the parts of the multi-part data object.
+        /// Params
+        /// - storage bucket ID
+        /// - worker ID (storage provider ID)
+        /// - bag ID
+        /// - data object ID
+        /// - accepted part indices
+        DataObjectPartsAccepted(StorageBucketId, WorkerId, BagId, DataObjectId, BTreeSet<u64>),
+
         /// Emits on cancelling the storage bucket invitation.
         /// Params
         /// - storage bucket ID
@@ -1116,6 +1988,12 @@ decl_event! {
         /// - new data size fee
         DataObjectPerMegabyteFeeUpdated(Balance),
 
+        /// Emits on changing the data size fee schedule of the bag category.
+        /// Params
+        /// - bag category
+        /// - new schedule (`None` removes the schedule)
+        DataSizeFeeScheduleUpdated(DataSizeFeeCategory, Option<DataSizeFeeSchedule<Balance>>),
+
         /// Emits on changing the "Storage buckets per bag" number limit.
         /// Params
         /// - new limit
@@ -1134,6 +2012,27 @@ decl_event! {
         /// - data object IDs
         DataObjectsMoved(BagId, BagId, BTreeSet<DataObjectId>),
 
+        /// Emits on moving data objects between the bags by the bag owner.
+        /// Params
+        /// - bag owner account ID
+        /// - source bag ID
+        /// - destination bag ID
+        /// - data object IDs
+        /// - voucher delta (removed from the source bag storage buckets and added to
+        /// the destination bag storage buckets)
+        /// - destination bag storage buckets replicating the data objects (not storing
+        /// the source bag)
+        /// - storage rent charged for the remaining destination bag prepayment
+        DataObjectsMovedByOwner(
+            AccountId,
+            BagId,
+            BagId,
+            BTreeSet<DataObjectId>,
+            VoucherUpdate,
+            BTreeSet<StorageBucketId>,
+            Balance,
+        ),
+
         /// Emits on data objects deletion from bags.
         /// Params
         /// - account ID for the deletion prize
@@ -1153,6 +2052,24 @@ decl_event! {
         /// - hashes to add to the blacklist
         UpdateBlacklist(BTreeSet<Cid>, BTreeSet<Cid>),
 
+        /// Emits on adding or updating the blacklist entry.
+        /// Params
+        /// - blacklisted hash
+        /// - blacklist entry (rationale and expiry)
+        BlacklistEntrySet(Cid, BlacklistEntry<BlockNumber>),
+
+        /// Emits on removing the expired blacklist entry.
+        /// Params
+        /// - hash removed from the blacklist
+        BlacklistEntryExpired(Cid),
+
+        /// Emits on purging the uploaded data objects matching the blacklist entry.
+        /// Params
+        /// - blacklisted hash
+        /// - bag ID
+        /// - data object IDs
+        BlacklistedDataObjectsPurged(Cid, BagId, BTreeSet<DataObjectId>),
+
         /// Emits on deleting a dynamic bag.
         /// Params
         /// - account ID for the deletion prize
@@ -1183,6 +2100,23 @@ decl_event! {
         /// - storage bucket ID
         StorageBucketDeleted(StorageBucketId),
 
+        /// Emits on starting the storage bucket draining.
+        /// Params
+        /// - storage bucket ID
+        StorageBucketDrainingStarted(StorageBucketId),
+
+        /// Emits on canceling the storage bucket draining.
+        /// Params
+        /// - storage bucket ID
+        StorageBucketDrainingCanceled(StorageBucketId),
+
+        /// Emits when no replacement storage bucket was found for the bag of the draining
+        /// storage bucket (the bag is retried in the next draining pass).
+        /// Params
+        /// - draining storage bucket ID
+        /// - bag ID
+        DrainingStorageBucketBagNotReassigned(StorageBucketId, BagId),
+
         /// Emits on updating the number of storage buckets in dynamic bag creation policy.
         /// Params
         /// - dynamic bag type
@@ -1311,22 +2245,161 @@ decl_event! {
             DistributionBucketFamilyId,
             Vec<u8>
         ),
-    }
-}
-
-decl_error! {
-    /// Storage module predefined errors
-    pub enum Error for Module<T: Trait>{
-        /// Empty "data object creation" collection.
-        NoObjectsOnUpload,
 
-        /// The requested storage bucket doesn't exist.
-        StorageBucketDoesntExist,
+        /// Emits when the dynamic bag has less active storage buckets than required by
+        /// the dynamic bag creation policy.
+        /// Params
+        /// - dynamic bag ID
+        DynamicBagUnderReplicated(DynamicBagId),
 
-        /// The requested storage bucket is not bound to a bag.
-        StorageBucketIsNotBoundToBag,
+        /// Emits when the under-replicated dynamic bag has enough active storage buckets again.
+        /// Params
+        /// - dynamic bag ID
+        DynamicBagReplicationRestored(DynamicBagId),
 
-        /// The requested storage bucket is already bound to a bag.
+        /// Emits on issuing the storage challenge.
+        /// Params
+        /// - storage bucket ID
+        /// - storage operator ID
+        /// - bag ID
+        /// - data object ID
+        /// - content chunk index
+        /// - last block to respond to the challenge
+        StorageChallengeIssued(StorageBucketId, WorkerId, BagId, DataObjectId, u64, BlockNumber),
+
+        /// Emits on the successful storage challenge response.
+        /// Params
+        /// - storage bucket ID
+        /// - storage operator ID
+        StorageChallengePassed(StorageBucketId, WorkerId),
+
+        /// Emits on the missed storage challenge.
+        /// Params
+        /// - storage bucket ID
+        /// - storage operator ID
+        /// - missed storage challenges number
+        StorageChallengeMissed(StorageBucketId, WorkerId, u64),
+
+        /// Emits on slashing the storage operator for the missed storage challenges.
+        /// Params
+        /// - storage bucket ID
+        /// - storage operator ID
+        /// - slashing penalty
+        StorageOperatorSlashed(StorageBucketId, WorkerId, Balance),
+
+        /// Emits on changing the size-based per block storage rent.
+        /// Params
+        /// - new rent
+        StorageRentUpdated(Balance),
+
+        /// Emits on the bag storage rent top-up.
+        /// Params
+        /// - account ID of the payer
+        /// - dynamic bag ID
+        /// - paid storage rent
+        /// - new paid-through block
+        BagRentToppedUp(AccountId, DynamicBagId, Balance, BlockNumber),
+
+        /// Emits when the bag storage rent grace period ends.
+        /// Params
+        /// - dynamic bag ID
+        BagRentExpired(DynamicBagId),
+
+        /// Emits on removing the data objects of the bag with the expired storage rent.
+        /// Params
+        /// - bag ID
+        /// - data objects IDs
+        DataObjectsGarbageCollected(BagId, BTreeSet<DataObjectId>),
+
+        /// Emits on changing the fee for uploading the already stored content.
+        /// Params
+        /// - new fee (`None` disables the content deduplication)
+        DataObjectReferenceFeeUpdated(Option<Balance>),
+
+        /// Emits on changing the upload quota for the dynamic bags of the given type.
+        /// Params
+        /// - dynamic bag type
+        /// - new quota (`None` removes the quota)
+        DynamicBagQuotaUpdated(DynamicBagType, Option<BagQuota>),
+
+        /// Emits on changing the upload quota override for the bag.
+        /// Params
+        /// - bag ID
+        /// - new quota (`None` removes the override)
+        BagQuotaOverrideUpdated(BagId, Option<BagQuota>),
+
+        /// Emits on dynamic bag creation policy update (distribution regions).
+        /// Params
+        /// - dynamic bag type
+        /// - regions requiring a distribution bucket
+        RegionsInDynamicBagCreationPolicyUpdated(DynamicBagType, BTreeSet<RegionTag>),
+
+        /// Emits on setting the distribution bucket family region.
+        /// Params
+        /// - distribution bucket family ID
+        /// - region (`None` removes the region)
+        DistributionBucketFamilyRegionUpdated(DistributionBucketFamilyId, Option<RegionTag>),
+
+        /// Emits on setting the distribution bucket region.
+        /// Params
+        /// - distribution bucket ID
+        /// - region (`None` falls back to the family region)
+        DistributionBucketRegionUpdated(DistributionBucketId, Option<RegionTag>),
+
+        /// Emits on changing the storage operator reward rates.
+        /// Params
+        /// - new reward rates
+        StorageOperatorRewardRatesUpdated(OperatorRewardRates<Balance>),
+
+        /// Emits on changing the distribution operator reward rates.
+        /// Params
+        /// - new reward rates
+        DistributionOperatorRewardRatesUpdated(OperatorRewardRates<Balance>),
+
+        /// Emits on paying the load-based reward to the storage operator.
+        /// Params
+        /// - storage bucket ID
+        /// - worker ID (storage provider ID)
+        /// - reward
+        StorageOperatorRewardPaid(StorageBucketId, WorkerId, Balance),
+
+        /// Emits on paying the load-based reward to the distribution operator.
+        /// Params
+        /// - distribution bucket ID
+        /// - worker ID (distribution provider ID)
+        /// - reward
+        DistributionOperatorRewardPaid(DistributionBucketId, WorkerId, Balance),
+
+        /// Emits on reporting the distribution operator health beacon.
+        /// Params
+        /// - distribution bucket ID
+        /// - worker ID (distribution provider ID)
+        /// - bytes served
+        /// - uptime in seconds
+        DistributionOperatorHealthReported(DistributionBucketId, WorkerId, u64, u64),
+
+        /// Emits on the missed distribution operator health beacon.
+        /// Params
+        /// - distribution bucket ID
+        /// - worker ID (distribution provider ID)
+        /// - missed health beacons number
+        DistributionHealthBeaconMissed(DistributionBucketId, WorkerId, u64),
+    }
+}
+
+decl_error! {
+    /// Storage module predefined errors
+    pub enum Error for Module<T: Trait>{
+        /// Empty "data object creation" collection.
+        NoObjectsOnUpload,
+
+        /// The requested storage bucket doesn't exist.
+        StorageBucketDoesntExist,
+
+        /// The requested storage bucket is not bound to a bag.
+        StorageBucketIsNotBoundToBag,
+
+        /// The requested storage bucket is already bound to a bag.
         StorageBucketIsBoundToBag,
 
         /// Invalid operation with invites: there is no storage bucket invitation.
@@ -1380,6 +2453,43 @@ decl_error! {
         /// Blacklist size limit exceeded.
         BlacklistSizeLimitExceeded,
 
+        /// Blacklist entry doesn't exist.
+        BlacklistEntryDoesntExist,
+
+        /// Blacklist entry expiry should be in the future.
+        InvalidBlacklistEntryExpiry,
+
+        /// Data object content ID doesn't match the blacklist entry.
+        DataObjectIsNotBlacklisted,
+
+        /// Multi-part data object should have from 2 to `MaxDataObjectPartsNumber` non-empty
+        /// parts.
+        InvalidDataObjectPartsNumber,
+
+        /// Data object isn't a multi-part data object.
+        DataObjectIsNotMultipart,
+
+        /// Multi-part data object should be accepted part by part.
+        DataObjectIsMultipart,
+
+        /// Data object part indices are empty.
+        DataObjectPartIndicesAreEmpty,
+
+        /// Data object part index exceeds the number of the data object parts.
+        InvalidDataObjectPartIndex,
+
+        /// Account is not authorized to manage the bag data objects.
+        NotBagOwnerAccount,
+
+        /// Storage bucket is being drained.
+        StorageBucketIsDraining,
+
+        /// Data object is referenced outside of the storage and can't be moved.
+        DataObjectIsInUse,
+
+        /// Storage bucket is not being drained.
+        StorageBucketIsNotDraining,
+
         /// Max object size limit exceeded for voucher.
         VoucherMaxObjectSizeLimitExceeded,
 
@@ -1490,6 +2600,47 @@ decl_error! {
 
         /// Invalid transactor account ID for this bucket.
         InvalidTransactorAccount,
+
+        /// There is no outstanding storage challenge for the storage bucket.
+        StorageChallengeDoesntExist,
+
+        /// The storage challenge response period has expired.
+        StorageChallengeExpired,
+
+        /// The content chunk or its Merkle proof doesn't match the challenged data object.
+        InvalidStorageChallengeResponse,
+
+        /// The storage rent is disabled.
+        StorageRentDisabled,
+
+        /// The bag storage rent top-up period should be non-zero.
+        ZeroBagRentTopUpPeriod,
+
+        /// The bag storage rent prepayment is exhausted.
+        BagRentExhausted,
+
+        /// The bag isn't subject to the storage rent (created before the storage rent was enabled).
+        BagIsNotSubjectToRent,
+
+        /// Max data objects number or total size per bag exceeded.
+        BagQuotaExceeded,
+
+        /// Region tag is empty or exceeds `MaxRegionTagLength`.
+        InvalidRegionTag,
+
+        /// Number of the regions and the family distribution buckets in the dynamic bag creation
+        /// policy exceeds
+        /// the `DistributionBucketsPerBagLimit`.
+        MaxRegionsNumberPerBagLimitExceeded,
+
+        /// The distribution health beacons are disabled.
+        DistributionHealthBeaconsDisabled,
+
+        /// Max number of the distribution health beacons per block exceeded.
+        MaxDistributionHealthBeaconsPerBlockExceeded,
+
+        /// The health beacon was already reported in the current health beacon period.
+        DistributionHealthBeaconAlreadyReported,
     }
 }
 
@@ -1536,10 +2687,93 @@ decl_module! {
         /// Exports const - max data object size in bytes.
         const MaxDataObjectSize: u64 = T::MaxDataObjectSize::get();
 
+        /// Exports const - max number of under-replicated dynamic bags processed per block.
+        const MaxReReplicatedBagsPerBlock: u64 = T::MaxReReplicatedBagsPerBlock::get();
+
+        /// Exports const - storage challenge period in blocks.
+        const StorageChallengePeriod: T::BlockNumber = T::StorageChallengePeriod::get();
+
+        /// Exports const - max number of storage buckets challenged in a block.
+        const MaxStorageChallengesPerBlock: u64 = T::MaxStorageChallengesPerBlock::get();
+
+        /// Exports const - storage challenge response period in blocks.
+        const StorageChallengeResponsePeriod: T::BlockNumber =
+            T::StorageChallengeResponsePeriod::get();
+
+        /// Exports const - content chunk size in bytes for the storage challenges.
+        const ChallengeChunkSize: u64 = T::ChallengeChunkSize::get();
+
+        /// Exports const - number of missed storage challenges that triggers the slashing.
+        const MissedStorageChallengesSlashingThreshold: u64 =
+            T::MissedStorageChallengesSlashingThreshold::get();
+
+        /// Exports const - storage operator stake slashed for the missed storage challenges.
+        const MissedStorageChallengesSlashingPenalty: BalanceOf<T> =
+            T::MissedStorageChallengesSlashingPenalty::get();
+
+        /// Exports const - prepaid storage rent period for the new dynamic bags.
+        const InitialBagRentPeriod: T::BlockNumber = T::InitialBagRentPeriod::get();
+
+        /// Exports const - storage rent grace period before the garbage collection.
+        const StorageRentGracePeriod: T::BlockNumber = T::StorageRentGracePeriod::get();
+
+        /// Exports const - max number of garbage collected data objects per block.
+        const MaxGarbageCollectedDataObjectsPerBlock: u64 =
+            T::MaxGarbageCollectedDataObjectsPerBlock::get();
+
+        /// Exports const - max number of processed bag rent expirations per block.
+        const MaxBagRentExpirationsPerBlock: u64 = T::MaxBagRentExpirationsPerBlock::get();
+
+        /// Exports const - max number of processed blacklist entry expirations per block.
+        const MaxBlacklistExpirationsPerBlock: u64 = T::MaxBlacklistExpirationsPerBlock::get();
+
+        /// Exports const - max length of the distribution bucket region tag.
+        const MaxRegionTagLength: u32 = T::MaxRegionTagLength::get();
+
+        /// Exports const - period of the load-based operator rewards.
+        const OperatorRewardPeriod: T::BlockNumber = T::OperatorRewardPeriod::get();
+
+        /// Exports const - max number of storage or distribution buckets processed by the operator
+        /// rewards rounds in a block.
+        const MaxOperatorRewardBucketsPerBlock: u64 = T::MaxOperatorRewardBucketsPerBlock::get();
+
+        /// Exports const - max number of parts of the multi-part data object.
+        const MaxDataObjectPartsNumber: u64 = T::MaxDataObjectPartsNumber::get();
+
+        /// Exports const - max number of the draining storage bucket bags reassigned per block.
+        const MaxDrainedBagsPerBlock: u64 = T::MaxDrainedBagsPerBlock::get();
+
+        /// Exports const - distribution operator health beacon period in blocks.
+        const DistributionHealthBeaconPeriod: T::BlockNumber =
+            T::DistributionHealthBeaconPeriod::get();
+
+        /// Exports const - max number of the distribution health beacons per block.
+        const MaxDistributionHealthBeaconsPerBlock: u64 =
+            T::MaxDistributionHealthBeaconsPerBlock::get();
+
+        /// Exports const - number of missed health beacons that stops the bucket distribution.
+        const MissedDistributionHealthBeaconsThreshold: u64 =
+            T::MissedDistributionHealthBeaconsThreshold::get();
+
+        /// Exports const - max number of distribution buckets processed by the missed health
+        /// beacons round in a block.
+        const MaxHealthBeaconBucketsPerBlock: u64 = T::MaxHealthBeaconBucketsPerBlock::get();
+
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            Self::re_replicate_bags()
+                .saturating_add(Self::audit_bags_replication())
+                .saturating_add(Self::process_storage_challenges(n))
+                .saturating_add(Self::process_bag_rent(n))
+                .saturating_add(Self::process_blacklist_expirations(n))
+                .saturating_add(Self::process_operator_rewards(n))
+                .saturating_add(Self::drain_storage_buckets())
+                .saturating_add(Self::process_distribution_health_beacons(n))
+        }
+
         // ===== Storage Lead actions =====
 
         /// Delete storage bucket. Must be empty. Storage operator must be missing.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::delete_storage_bucket()]
         pub fn delete_storage_bucket(
             origin,
             storage_bucket_id: T::StorageBucketId,
@@ -1560,15 +2794,11 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            <StorageBucketById<T>>::remove(storage_bucket_id);
-
-            Self::deposit_event(
-                RawEvent::StorageBucketDeleted(storage_bucket_id)
-            );
+            Self::remove_storage_bucket(storage_bucket_id);
         }
 
         /// Updates global uploading flag.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_uploading_blocked_status()]
         pub fn update_uploading_blocked_status(origin, new_status: bool) {
             T::ensure_storage_working_group_leader_origin(origin)?;
 
@@ -1582,7 +2812,7 @@ decl_module! {
         }
 
         /// Updates size-based pricing of new objects uploaded.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_data_size_fee()]
         pub fn update_data_size_fee(origin, new_data_size_fee: BalanceOf<T>) {
             T::ensure_storage_working_group_leader_origin(origin)?;
 
@@ -1595,8 +2825,114 @@ decl_module! {
             Self::deposit_event(RawEvent::DataObjectPerMegabyteFeeUpdated(new_data_size_fee));
         }
 
+        /// Sets or removes the data size fee schedule of the bag category. Storage working group
+        /// leader or root (proposal) origin.
+        #[weight = WeightInfoStorage::<T>::update_data_size_fee_schedule()]
+        pub fn update_data_size_fee_schedule(
+            origin,
+            category: DataSizeFeeCategory,
+            schedule: Option<DataSizeFeeSchedule<BalanceOf<T>>>,
+        ) {
+            Self::ensure_storage_leader_or_root_origin(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DataSizeFeeSchedules::<T>::mutate(&category, |current| *current = schedule.clone());
+
+            Self::deposit_event(RawEvent::DataSizeFeeScheduleUpdated(category, schedule));
+        }
+
+        /// Updates size-based per block storage rent for the dynamic bags. Zero disables the rent.
+        #[weight = WeightInfoStorage::<T>::update_storage_rent()]
+        pub fn update_storage_rent(origin, new_rent: BalanceOf<T>) {
+            T::ensure_storage_working_group_leader_origin(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DataObjectPerMegabytePerBlockRent::<T>::put(new_rent);
+
+            Self::deposit_event(RawEvent::StorageRentUpdated(new_rent));
+        }
+
+        /// Updates the fee for uploading the already stored content. `None` disables the content
+        /// deduplication for the new uploads.
+        #[weight = WeightInfoStorage::<T>::update_data_object_reference_fee()]
+        pub fn update_data_object_reference_fee(origin, new_fee: Option<BalanceOf<T>>) {
+            T::ensure_storage_working_group_leader_origin(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DataObjectReferenceFee::<T>::put(new_fee);
+
+            Self::deposit_event(RawEvent::DataObjectReferenceFeeUpdated(new_fee));
+        }
+
+        /// Updates the upload quota for the dynamic bags of the given type. `None` removes
+        /// the quota.
+        #[weight = WeightInfoStorage::<T>::update_dynamic_bag_quota()]
+        pub fn update_dynamic_bag_quota(
+            origin,
+            dynamic_bag_type: DynamicBagType,
+            quota: Option<BagQuota>,
+        ) {
+            T::ensure_storage_working_group_leader_origin(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match quota {
+                Some(quota) => DynamicBagQuotas::insert(dynamic_bag_type, quota),
+                None => DynamicBagQuotas::remove(dynamic_bag_type),
+            }
+
+            Self::deposit_event(RawEvent::DynamicBagQuotaUpdated(dynamic_bag_type, quota));
+        }
+
+        /// Sets the upload quota override for the bag. `None` removes the override.
+        #[weight = WeightInfoStorage::<T>::set_bag_quota_override()]
+        pub fn set_bag_quota_override(origin, bag_id: BagId<T>, quota: Option<BagQuota>) {
+            T::ensure_storage_working_group_leader_origin(origin)?;
+
+            Self::ensure_bag_exists(&bag_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match quota {
+                Some(quota) => <BagQuotaOverrides<T>>::insert(&bag_id, quota),
+                None => <BagQuotaOverrides<T>>::remove(&bag_id),
+            }
+
+            Self::deposit_event(RawEvent::BagQuotaOverrideUpdated(bag_id, quota));
+        }
+
+        /// Updates the load-based reward rates of the storage operators.
+        #[weight = WeightInfoStorage::<T>::update_storage_operator_reward_rates()]
+        pub fn update_storage_operator_reward_rates(
+            origin,
+            rates: OperatorRewardRates<BalanceOf<T>>,
+        ) {
+            T::ensure_storage_working_group_leader_origin(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            StorageOperatorRewardRates::<T>::put(rates);
+
+            Self::deposit_event(RawEvent::StorageOperatorRewardRatesUpdated(rates));
+        }
+
         /// Updates "Storage buckets per bag" number limit.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_storage_buckets_per_bag_limit()]
         pub fn update_storage_buckets_per_bag_limit(origin, new_limit: u64) {
             T::ensure_storage_working_group_leader_origin(origin)?;
 
@@ -1616,7 +2952,7 @@ decl_module! {
         }
 
         /// Updates "Storage buckets voucher max limits".
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_storage_buckets_voucher_max_limits()]
         pub fn update_storage_buckets_voucher_max_limits(
             origin,
             new_objects_size: u64,
@@ -1637,7 +2973,7 @@ decl_module! {
         }
 
         /// Update number of storage buckets used in given dynamic bag creation policy.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_number_of_storage_buckets_in_dynamic_bag_creation_policy()]
         pub fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy(
             origin,
             dynamic_bag_type: DynamicBagType,
@@ -1651,6 +2987,13 @@ decl_module! {
 
             let mut creation_policy = Self::get_dynamic_bag_creation_policy(dynamic_bag_type);
 
+            // existing bags are checked for the replication over the next blocks
+            if number_of_storage_buckets > creation_policy.number_of_storage_buckets
+                && T::MaxReReplicatedBagsPerBlock::get() > 0
+            {
+                ReplicationAuditCursor::put(0);
+            }
+
             creation_policy.number_of_storage_buckets = number_of_storage_buckets;
 
             DynamicBagCreationPolicies::<T>::insert(dynamic_bag_type, creation_policy);
@@ -1664,7 +3007,11 @@ decl_module! {
         }
 
         /// Add and remove hashes to the current blacklist.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_blacklist(
+            remove_hashes.len().saturated_into(),
+            add_hashes.len().saturated_into(),
+            Module::<T>::hashes_total_length(remove_hashes.iter().chain(add_hashes.iter())),
+        )]
         pub fn update_blacklist(
             origin,
             remove_hashes: BTreeSet<Cid>,
@@ -1692,11 +3039,13 @@ decl_module! {
             //
 
             for cid in verified_remove_hashes.iter() {
-                Blacklist::remove(cid);
+                Self::clear_blacklist_entry_expiration(cid);
+
+                <Blacklist<T>>::remove(cid);
             }
 
             for cid in verified_add_hashes.iter() {
-                Blacklist::insert(cid, ());
+                <Blacklist<T>>::insert(cid, BlacklistEntry::default());
             }
 
             CurrentBlacklistSize::put(updated_blacklist_size);
@@ -1704,8 +3053,81 @@ decl_module! {
             Self::deposit_event(RawEvent::UpdateBlacklist(remove_hashes, add_hashes));
         }
 
+        /// Add or update the blacklist entry with the rationale and the optional expiry.
+        #[weight = WeightInfoStorage::<T>::set_blacklist_entry(
+            entry.rationale.len().saturated_into(),
+        )]
+        pub fn set_blacklist_entry(origin, cid: Cid, entry: BlacklistEntry<T::BlockNumber>) {
+            T::ensure_storage_working_group_leader_origin(origin)?;
+
+            if let Some(expires_at) = entry.expires_at {
+                ensure!(
+                    expires_at > <frame_system::Module<T>>::block_number(),
+                    Error::<T>::InvalidBlacklistEntryExpiry
+                );
+            }
+
+            let entry_exists = <Blacklist<T>>::contains_key(&cid);
+
+            if !entry_exists {
+                ensure!(
+                    Self::current_blacklist_size() < T::BlacklistSizeLimit::get(),
+                    Error::<T>::BlacklistSizeLimitExceeded
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::set_blacklist_entry_expiration(&cid, entry.expires_at);
+
+            <Blacklist<T>>::insert(&cid, entry.clone());
+
+            if !entry_exists {
+                CurrentBlacklistSize::mutate(|size| *size = size.saturating_add(1));
+            }
+
+            Self::deposit_event(RawEvent::BlacklistEntrySet(cid, entry));
+        }
+
+        /// Remove the uploaded data objects of the bag matching the blacklist entry. Deletion
+        /// prizes are forfeited (kept by the treasury): the uploader of the blacklisted content
+        /// isn't refunded and the prizes can't be paid out to an arbitrary account.
+        #[weight = WeightInfoStorage::<T>::purge_blacklisted_data_objects(
+            data_object_ids.len().saturated_into(),
+        )]
+        pub fn purge_blacklisted_data_objects(
+            origin,
+            cid: Cid,
+            bag_id: BagId<T>,
+            data_object_ids: BTreeSet<T::DataObjectId>,
+        ) {
+            T::ensure_storage_working_group_leader_origin(origin)?;
+
+            let bag = Self::ensure_bag_exists(&bag_id)?;
+
+            let bag_change = Self::validate_purge_blacklisted_data_objects_params(
+                &cid,
+                &bag_id,
+                &data_object_ids,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::purge_data_objects(&bag_id, &bag, &data_object_ids, &bag_change.voucher_update);
+
+            Self::deposit_event(RawEvent::BlacklistedDataObjectsPurged(
+                cid,
+                bag_id,
+                data_object_ids,
+            ));
+        }
+
         /// Create storage bucket.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::create_storage_bucket()]
         pub fn create_storage_bucket(
             origin,
             invite_worker: Option<WorkerId<T>>,
@@ -1756,7 +3178,10 @@ decl_module! {
         }
 
         /// Updates storage buckets for a bag..
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_storage_buckets_for_bag(
+            add_buckets.len().saturated_into(),
+            remove_buckets.len().saturated_into(),
+        )]
         pub fn update_storage_buckets_for_bag(
             origin,
             bag_id: BagId<T>,
@@ -1777,36 +3202,16 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            // Update vouchers.
-            if !add_buckets.is_empty() {
-                Self::change_storage_buckets_vouchers(
-                    &add_buckets,
-                    &voucher_update,
-                    OperationType::Increase
-                );
-            }
-            if !remove_buckets.is_empty() {
-                Self::change_storage_buckets_vouchers(
-                    &remove_buckets,
-                    &voucher_update,
-                    OperationType::Decrease
-                );
-            }
-
-            // Update bag counters.
-            Self::change_bag_assignments_for_storage_buckets(&add_buckets, &remove_buckets);
-
-            Bags::<T>::mutate(&bag_id, |bag| {
-                bag.update_storage_buckets(&mut add_buckets.clone(), &remove_buckets);
-            });
-
-            Self::deposit_event(
-                RawEvent::StorageBucketsUpdatedForBag(bag_id, add_buckets, remove_buckets)
+            Self::change_storage_buckets_for_bag(
+                bag_id,
+                add_buckets,
+                remove_buckets,
+                voucher_update,
             );
         }
 
         /// Cancel pending storage bucket invite. An invitation must be pending.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::cancel_storage_bucket_operator_invite()]
         pub fn cancel_storage_bucket_operator_invite(origin, storage_bucket_id: T::StorageBucketId){
             T::ensure_storage_working_group_leader_origin(origin)?;
 
@@ -1828,7 +3233,7 @@ decl_module! {
         }
 
         /// Invite storage bucket operator. Must be missing.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::invite_storage_bucket_operator()]
         pub fn invite_storage_bucket_operator(
             origin,
             storage_bucket_id: T::StorageBucketId,
@@ -1857,7 +3262,7 @@ decl_module! {
         }
 
         /// Removes storage bucket operator.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::remove_storage_bucket_operator()]
         pub fn remove_storage_bucket_operator(
             origin,
             storage_bucket_id: T::StorageBucketId,
@@ -1877,13 +3282,15 @@ decl_module! {
                     StorageBucketOperatorStatus::Missing;
             });
 
+            Self::clear_storage_challenges(&storage_bucket_id);
+
             Self::deposit_event(
                 RawEvent::StorageBucketOperatorRemoved(storage_bucket_id)
             );
         }
 
         /// Update whether new bags are being accepted for storage.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = Module::<T>::update_storage_bucket_status_weight(storage_bucket_id)]
         pub fn update_storage_bucket_status(
             origin,
             storage_bucket_id: T::StorageBucketId,
@@ -1893,6 +3300,11 @@ decl_module! {
 
             Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
 
+            ensure!(
+                !accepting_new_bags || !<DrainingStorageBuckets<T>>::contains_key(&storage_bucket_id),
+                Error::<T>::StorageBucketIsDraining
+            );
+
             //
             // == MUTATION SAFE ==
             //
@@ -1901,13 +3313,15 @@ decl_module! {
                 bucket.accepting_new_bags = accepting_new_bags;
             });
 
+            Self::update_storage_bucket_bags_replication_status(&storage_bucket_id);
+
             Self::deposit_event(
                 RawEvent::StorageBucketStatusUpdated(storage_bucket_id, accepting_new_bags)
             );
         }
 
         /// Sets storage bucket voucher limits.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::set_storage_bucket_voucher_limits()]
         pub fn set_storage_bucket_voucher_limits(
             origin,
             storage_bucket_id: T::StorageBucketId,
@@ -1949,21 +3363,76 @@ decl_module! {
             );
         }
 
-        // ===== Storage Operator actions =====
-
-        /// Accept the storage bucket invitation. An invitation must match the worker_id parameter.
-        /// It accepts an additional account ID (transactor) for accepting data objects to prevent
-        /// transaction nonce collisions.
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn accept_storage_bucket_invitation(
+        /// Starts the storage bucket decommissioning. The bucket stops accepting new bags and its
+        /// bags are progressively reassigned to other storage buckets in `on_initialize`.
+        /// The drained bucket is deleted.
+        #[weight = Module::<T>::start_storage_bucket_draining_weight(storage_bucket_id)]
+        pub fn start_storage_bucket_draining(
             origin,
-            worker_id: WorkerId<T>,
             storage_bucket_id: T::StorageBucketId,
-            transactor_account_id: T::AccountId,
         ) {
-            T::ensure_storage_worker_origin(origin, worker_id)?;
+            T::ensure_storage_working_group_leader_origin(origin)?;
 
-            let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+            Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            ensure!(
+                !<DrainingStorageBuckets<T>>::contains_key(&storage_bucket_id),
+                Error::<T>::StorageBucketIsDraining
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <StorageBucketById<T>>::mutate(storage_bucket_id, |bucket| {
+                bucket.accepting_new_bags = false;
+            });
+
+            <DrainingStorageBuckets<T>>::insert(storage_bucket_id, 0);
+
+            Self::update_storage_bucket_bags_replication_status(&storage_bucket_id);
+
+            Self::deposit_event(RawEvent::StorageBucketDrainingStarted(storage_bucket_id));
+        }
+
+        /// Stops the storage bucket decommissioning. The already reassigned bags are kept by
+        /// the new storage buckets, the bucket remains not accepting new bags.
+        #[weight = WeightInfoStorage::<T>::cancel_storage_bucket_draining()]
+        pub fn cancel_storage_bucket_draining(
+            origin,
+            storage_bucket_id: T::StorageBucketId,
+        ) {
+            T::ensure_storage_working_group_leader_origin(origin)?;
+
+            ensure!(
+                <DrainingStorageBuckets<T>>::contains_key(&storage_bucket_id),
+                Error::<T>::StorageBucketIsNotDraining
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DrainingStorageBuckets<T>>::remove(storage_bucket_id);
+
+            Self::deposit_event(RawEvent::StorageBucketDrainingCanceled(storage_bucket_id));
+        }
+
+        // ===== Storage Operator actions =====
+
+        /// Accept the storage bucket invitation. An invitation must match the worker_id parameter.
+        /// It accepts an additional account ID (transactor) for accepting data objects to prevent
+        /// transaction nonce collisions.
+        #[weight = WeightInfoStorage::<T>::accept_storage_bucket_invitation()]
+        pub fn accept_storage_bucket_invitation(
+            origin,
+            worker_id: WorkerId<T>,
+            storage_bucket_id: T::StorageBucketId,
+            transactor_account_id: T::AccountId,
+        ) {
+            T::ensure_storage_worker_origin(origin, worker_id)?;
+
+            let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
 
             Self::ensure_bucket_storage_provider_invitation_status(&bucket, worker_id)?;
 
@@ -1989,7 +3458,9 @@ decl_module! {
         }
 
         /// Sets storage operator metadata (eg.: storage node URL).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::set_storage_operator_metadata(
+            metadata.len().saturated_into(),
+        )]
         pub fn set_storage_operator_metadata(
             origin,
             worker_id: WorkerId<T>,
@@ -2012,7 +3483,10 @@ decl_module! {
         }
 
         /// A storage provider signals that the data object was successfully uploaded to its storage.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Multi-part data objects are accepted part by part (`accept_pending_data_object_parts`).
+        #[weight = WeightInfoStorage::<T>::accept_pending_data_objects(
+            data_objects.len().saturated_into(),
+        )]
         pub fn accept_pending_data_objects(
             origin,
             worker_id: WorkerId<T>,
@@ -2039,12 +3513,19 @@ decl_module! {
             //
 
             // Accept data objects for a bag.
+            let mut accepted_data_objects = Vec::new();
             for data_object_id in data_objects.iter() {
                 DataObjectsById::<T>::mutate(&bag_id, data_object_id, |data_object| {
-                    data_object.accepted = true;
+                    if !data_object.accepted {
+                        data_object.accepted = true;
+
+                        accepted_data_objects.push(data_object.clone());
+                    }
                 });
             }
 
+            Self::register_accepted_shared_content(&bag_id, &accepted_data_objects);
+
             Self::deposit_event(
                 RawEvent::PendingDataObjectsAccepted(
                     storage_bucket_id,
@@ -2055,10 +3536,278 @@ decl_module! {
             );
         }
 
+        /// A storage provider signals that the parts of the multi-part data object were
+        /// successfully uploaded to its storage. The data object is accepted when the storage
+        /// bucket holds all of its parts.
+        #[weight = WeightInfoStorage::<T>::accept_pending_data_object_parts(
+            parts.len().saturated_into(),
+        )]
+        pub fn accept_pending_data_object_parts(
+            origin,
+            worker_id: WorkerId<T>,
+            storage_bucket_id: T::StorageBucketId,
+            bag_id: BagId<T>,
+            data_object_id: T::DataObjectId,
+            parts: BTreeSet<u64>,
+        ) {
+            let transactor_account_id = ensure_signed(origin)?;
+
+            let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            Self::ensure_bucket_transactor_access(&bucket, worker_id, transactor_account_id)?;
+
+            let data_object = Self::validate_accept_pending_data_object_parts_params(
+                &bag_id,
+                &data_object_id,
+                &storage_bucket_id,
+                &parts,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let all_parts_held = Self::add_data_object_parts_held(
+                &data_object_id,
+                &storage_bucket_id,
+                &data_object,
+                &parts,
+            );
+
+            Self::deposit_event(
+                RawEvent::DataObjectPartsAccepted(
+                    storage_bucket_id,
+                    worker_id,
+                    bag_id.clone(),
+                    data_object_id,
+                    parts
+                )
+            );
+
+            if all_parts_held && !data_object.accepted {
+                let accepted_data_object = DataObjectsById::<T>::mutate(
+                    &bag_id,
+                    &data_object_id,
+                    |data_object| {
+                        data_object.accepted = true;
+
+                        data_object.clone()
+                    }
+                );
+
+                Self::register_accepted_shared_content(&bag_id, &[accepted_data_object]);
+
+                Self::deposit_event(
+                    RawEvent::PendingDataObjectsAccepted(
+                        storage_bucket_id,
+                        worker_id,
+                        bag_id,
+                        iter::once(data_object_id).collect(),
+                    )
+                );
+            }
+        }
+
+        /// A storage provider responds to the outstanding storage challenge with the challenged
+        /// content chunk and its Merkle proof.
+        #[weight = WeightInfoStorage::<T>::respond_to_storage_challenge(
+            proof.len().saturated_into(),
+            chunk.len().saturated_into(),
+        )]
+        pub fn respond_to_storage_challenge(
+            origin,
+            worker_id: WorkerId<T>,
+            storage_bucket_id: T::StorageBucketId,
+            chunk: Vec<u8>,
+            proof: Vec<ContentHash>,
+        ) {
+            let transactor_account_id = ensure_signed(origin)?;
+
+            let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            Self::ensure_bucket_transactor_access(&bucket, worker_id, transactor_account_id)?;
+
+            let challenge = Self::ensure_storage_challenge_can_be_answered(&storage_bucket_id)?;
+
+            Self::validate_storage_challenge_response(&challenge, &chunk, &proof)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <StorageChallenges<T>>::remove(storage_bucket_id);
+
+            Self::deposit_event(
+                RawEvent::StorageChallengePassed(storage_bucket_id, worker_id)
+            );
+        }
+
+        // ===== Storage rent actions =====
+
+        /// Prepays the storage rent of the dynamic bag for the provided number of blocks.
+        /// The rent is calculated for the current bag size (at least one megabyte, so an empty
+        /// bag can't be kept for free). The bags created before the storage rent was enabled keep
+        /// the free storage and can't be topped up.
+        #[weight = WeightInfoStorage::<T>::top_up_bag_rent()]
+        pub fn top_up_bag_rent(
+            origin,
+            dynamic_bag_id: DynamicBagId<T>,
+            blocks: T::BlockNumber,
+        ) {
+            let account_id = ensure_signed(origin)?;
+
+            ensure!(Self::storage_rent_enabled(), Error::<T>::StorageRentDisabled);
+
+            ensure!(!blocks.is_zero(), Error::<T>::ZeroBagRentTopUpPeriod);
+
+            let bag = Self::ensure_dynamic_bag_exists(&dynamic_bag_id)?;
+
+            // The bags with the free storage are never moved under the storage rent.
+            let paid_through = Self::bag_rent_paid_through(&dynamic_bag_id)
+                .ok_or(Error::<T>::BagIsNotSubjectToRent)?;
+
+            // Empty bags are charged for one megabyte.
+            let rent = Self::calculate_storage_rent(bag.objects_total_size.max(1), blocks);
+
+            ensure!(
+                Balances::<T>::usable_balance(&account_id) >= rent,
+                Error::<T>::InsufficientBalance
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::charge_storage_rent(&account_id, rent);
+
+            // The exhausted prepayment is renewed starting from the current block.
+            let now = <frame_system::Module<T>>::block_number();
+            let paid_through = paid_through.max(now).saturating_add(blocks);
+
+            Self::set_bag_rent_paid_through(&dynamic_bag_id, paid_through);
+
+            Self::deposit_event(
+                RawEvent::BagRentToppedUp(account_id, dynamic_bag_id, rent, paid_through)
+            );
+        }
+
+        // ===== Working group leader actions =====
+
+        /// Upload new data objects to the static bag of the working group. The leader account pays
+        /// the data size fee and the deletion prize.
+        #[weight = WeightInfoStorage::<T>::upload_working_group_data_objects(
+            object_creation_list.len().saturated_into(),
+        )]
+        pub fn upload_working_group_data_objects(
+            origin,
+            working_group: WorkingGroup,
+            object_creation_list: Vec<DataObjectCreationParameters>,
+            expected_data_size_fee: BalanceOf<T>,
+        ) {
+            T::ensure_working_group_leader_origin(origin.clone(), working_group)?;
+
+            let account_id = ensure_signed(origin)?;
+
+            let params = UploadParameters::<T> {
+                bag_id: StaticBagId::WorkingGroup(working_group).into(),
+                object_creation_list,
+                deletion_prize_source_account_id: account_id,
+                expected_data_size_fee,
+            };
+
+            Self::upload_data_objects(params)?;
+        }
+
+        /// Delete data objects from the static bag of the working group. The deletion prize is
+        /// transferred to the leader account.
+        #[weight = WeightInfoStorage::<T>::delete_working_group_data_objects(
+            data_objects.len().saturated_into(),
+        )]
+        pub fn delete_working_group_data_objects(
+            origin,
+            working_group: WorkingGroup,
+            data_objects: BTreeSet<T::DataObjectId>,
+        ) {
+            T::ensure_working_group_leader_origin(origin.clone(), working_group)?;
+
+            let account_id = ensure_signed(origin)?;
+
+            let bag_id: BagId<T> = StaticBagId::WorkingGroup(working_group).into();
+
+            Self::delete_data_objects(account_id, bag_id, data_objects)?;
+        }
+
+        // ===== Bag owner actions =====
+
+        /// Moves the data objects between the bags managed by the signer account (eg.: from the
+        /// member bag to the owned channel bag). The remaining prepaid storage rent of the destination bag is charged
+        /// for the moved data. Data objects referenced outside of the storage (eg.: video assets)
+        /// can't be moved.
+        #[weight = WeightInfoStorage::<T>::move_bag_data_objects(objects.len().saturated_into())]
+        pub fn move_bag_data_objects(
+            origin,
+            src_bag_id: BagId<T>,
+            dest_bag_id: BagId<T>,
+            objects: BTreeSet<T::DataObjectId>,
+        ) {
+            let account_id = ensure_signed(origin)?;
+
+            Self::ensure_bag_owner_account(&src_bag_id, &account_id)?;
+            Self::ensure_bag_owner_account(&dest_bag_id, &account_id)?;
+
+            let src_bag = Self::ensure_bag_exists(&src_bag_id)?;
+            let dest_bag = Self::ensure_bag_exists(&dest_bag_id)?;
+
+            let bag_change =
+                Self::validate_data_objects_on_moving(&src_bag_id, &dest_bag_id, &objects)?;
+            let voucher_delta = bag_change.voucher_update;
+
+            Self::ensure_data_objects_not_in_use(&src_bag_id, &objects)?;
+
+            let rent = Self::calculate_remaining_bag_rent(
+                &dest_bag_id,
+                voucher_delta.objects_total_size
+            );
+            Self::ensure_sufficient_balance_for_upload(Some(account_id.clone()), rent)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::move_data_objects_between_bags(
+                &src_bag_id,
+                &src_bag,
+                &dest_bag_id,
+                &dest_bag,
+                &objects,
+                &voucher_delta,
+            );
+
+            Self::charge_storage_rent(&account_id, rent);
+
+            let replicating_bucket_ids = dest_bag
+                .stored_by
+                .difference(&src_bag.stored_by)
+                .copied()
+                .collect::<BTreeSet<_>>();
+
+            Self::deposit_event(
+                RawEvent::DataObjectsMovedByOwner(
+                    account_id,
+                    src_bag_id,
+                    dest_bag_id,
+                    objects,
+                    voucher_delta,
+                    replicating_bucket_ids,
+                    rent,
+                )
+            );
+        }
+
         // ===== Distribution Lead actions =====
 
         /// Create a distribution bucket family.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::create_distribution_bucket_family()]
         pub fn create_distribution_bucket_family(origin) {
             T::ensure_distribution_working_group_leader_origin(origin)?;
 
@@ -2086,7 +3835,7 @@ decl_module! {
         }
 
         /// Deletes a distribution bucket family.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::delete_distribution_bucket_family()]
         pub fn delete_distribution_bucket_family(origin, family_id: T::DistributionBucketFamilyId) {
             T::ensure_distribution_working_group_leader_origin(origin)?;
 
@@ -2116,7 +3865,7 @@ decl_module! {
         }
 
         /// Create a distribution bucket.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::create_distribution_bucket()]
         pub fn create_distribution_bucket(
             origin,
             family_id: T::DistributionBucketFamilyId,
@@ -2136,6 +3885,7 @@ decl_module! {
                 pending_invitations: BTreeSet::new(),
                 operators: BTreeSet::new(),
                 assigned_bags: 0,
+                region: None,
             };
 
             let bucket_index = family.next_distribution_bucket_index;
@@ -2153,7 +3903,7 @@ decl_module! {
         }
 
         /// Updates a distribution bucket 'accepts new bags' flag.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_distribution_bucket_status()]
         pub fn update_distribution_bucket_status(
             origin,
             bucket_id: DistributionBucketId<T>,
@@ -2181,7 +3931,7 @@ decl_module! {
         }
 
         /// Delete distribution bucket. Must be empty.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::delete_distribution_bucket()]
         pub fn delete_distribution_bucket(
             origin,
             bucket_id: DistributionBucketId<T>,
@@ -2211,7 +3961,10 @@ decl_module! {
         }
 
         /// Updates distribution buckets for a bag.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_distribution_buckets_for_bag(
+            add_buckets_indices.len().saturated_into(),
+            remove_buckets_indices.len().saturated_into(),
+        )]
         pub fn update_distribution_buckets_for_bag(
             origin,
             bag_id: BagId<T>,
@@ -2242,13 +3995,17 @@ decl_module! {
                 .map(|idx| Self::create_distribution_bucket_id(family_id, *idx))
                 .collect::<BTreeSet<_>>();
 
-            Bags::<T>::mutate(&bag_id, |bag| {
+            let bag_size = Bags::<T>::mutate(&bag_id, |bag| {
                 bag.update_distribution_buckets(&mut add_buckets_ids.clone(), &remove_buckets_ids);
+
+                bag.objects_total_size
             });
 
             Self::change_bag_assignments_for_distribution_buckets(
+                &bag_id,
                 &add_buckets_ids,
-                &remove_buckets_ids
+                &remove_buckets_ids,
+                bag_size,
             );
 
             Self::deposit_event(
@@ -2262,7 +4019,7 @@ decl_module! {
         }
 
         /// Updates "Distribution buckets per bag" number limit.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_distribution_buckets_per_bag_limit()]
         pub fn update_distribution_buckets_per_bag_limit(origin, new_limit: u64) {
             T::ensure_distribution_working_group_leader_origin(origin)?;
 
@@ -2282,7 +4039,7 @@ decl_module! {
         }
 
         /// Updates 'distributing' flag for the distributing flag.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_distribution_bucket_mode()]
         pub fn update_distribution_bucket_mode(
             origin,
             bucket_id: DistributionBucketId<T>,
@@ -2304,13 +4061,18 @@ decl_module! {
                 }
             );
 
+            // The health tracking restarts for the operators.
+            <DistributionOperatorHealthByBucket<T>>::remove_prefix(&bucket_id);
+
             Self::deposit_event(
                 RawEvent::DistributionBucketModeUpdated(bucket_id, distributing)
             );
         }
 
         /// Update number of distributed buckets used in given dynamic bag creation policy.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::update_families_in_dynamic_bag_creation_policy(
+            families.len().saturated_into(),
+        )]
         pub fn update_families_in_dynamic_bag_creation_policy(
             origin,
             dynamic_bag_type: DynamicBagType,
@@ -2318,7 +4080,10 @@ decl_module! {
         ) {
             T::ensure_distribution_working_group_leader_origin(origin)?;
 
-            Self::validate_update_families_in_dynamic_bag_creation_policy_params(&families)?;
+            Self::validate_update_families_in_dynamic_bag_creation_policy_params(
+                dynamic_bag_type,
+                &families,
+            )?;
 
             //
             // == MUTATION SAFE ==
@@ -2338,8 +4103,42 @@ decl_module! {
             );
         }
 
+        /// Update regions requiring a distribution bucket in the dynamic bag creation policy.
+        #[weight = WeightInfoStorage::<T>::update_regions_in_dynamic_bag_creation_policy(
+            regions.len().saturated_into(),
+        )]
+        pub fn update_regions_in_dynamic_bag_creation_policy(
+            origin,
+            dynamic_bag_type: DynamicBagType,
+            regions: BTreeSet<RegionTag>
+        ) {
+            T::ensure_distribution_working_group_leader_origin(origin)?;
+
+            Self::validate_update_regions_in_dynamic_bag_creation_policy_params(
+                dynamic_bag_type,
+                &regions,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // We initialize the default storage bucket number here if no policy exists.
+            let mut new_policy = Self::get_dynamic_bag_creation_policy(dynamic_bag_type);
+            new_policy.regions = regions.clone();
+
+            DynamicBagCreationPolicies::<T>::insert(dynamic_bag_type, new_policy);
+
+            Self::deposit_event(
+                RawEvent::RegionsInDynamicBagCreationPolicyUpdated(
+                    dynamic_bag_type,
+                    regions
+                )
+            );
+        }
+
         /// Invite an operator. Must be missing.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::invite_distribution_bucket_operator()]
         pub fn invite_distribution_bucket_operator(
             origin,
             bucket_id: DistributionBucketId<T>,
@@ -2369,7 +4168,7 @@ decl_module! {
         }
 
         /// Cancel pending invite. Must be pending.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::cancel_distribution_bucket_operator_invite()]
         pub fn cancel_distribution_bucket_operator_invite(
             origin,
             bucket_id: DistributionBucketId<T>,
@@ -2405,7 +4204,7 @@ decl_module! {
         }
 
         /// Removes distribution bucket operator.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::remove_distribution_bucket_operator()]
         pub fn remove_distribution_bucket_operator(
             origin,
             bucket_id: DistributionBucketId<T>,
@@ -2433,13 +4232,17 @@ decl_module! {
                 }
             );
 
+            <DistributionOperatorHealthByBucket<T>>::remove(&bucket_id, &operator_worker_id);
+
             Self::deposit_event(
                 RawEvent::DistributionBucketOperatorRemoved(bucket_id, operator_worker_id)
             );
         }
 
         /// Set distribution bucket family metadata.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::set_distribution_bucket_family_metadata(
+            metadata.len().saturated_into(),
+        )]
         pub fn set_distribution_bucket_family_metadata(
             origin,
             family_id: T::DistributionBucketFamilyId,
@@ -2461,11 +4264,90 @@ decl_module! {
             );
         }
 
+        /// Set distribution bucket family region (the default region of the family buckets).
+        #[weight = WeightInfoStorage::<T>::set_distribution_bucket_family_region(
+            region.as_ref().map_or(0, |region| region.len()).saturated_into(),
+        )]
+        pub fn set_distribution_bucket_family_region(
+            origin,
+            family_id: T::DistributionBucketFamilyId,
+            region: Option<RegionTag>,
+        ) {
+            T::ensure_distribution_working_group_leader_origin(origin)?;
+
+            Self::ensure_distribution_bucket_family_exists(&family_id)?;
+
+            if let Some(region) = region.as_ref() {
+                Self::ensure_region_tag_is_valid(region)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DistributionBucketFamilyById<T>>::mutate(family_id, |family| {
+                family.region = region.clone();
+            });
+
+            Self::deposit_event(
+                RawEvent::DistributionBucketFamilyRegionUpdated(family_id, region)
+            );
+        }
+
+        /// Set distribution bucket region. `None` falls back to the family region.
+        #[weight = WeightInfoStorage::<T>::set_distribution_bucket_region(
+            region.as_ref().map_or(0, |region| region.len()).saturated_into(),
+        )]
+        pub fn set_distribution_bucket_region(
+            origin,
+            bucket_id: DistributionBucketId<T>,
+            region: Option<RegionTag>,
+        ) {
+            T::ensure_distribution_working_group_leader_origin(origin)?;
+
+            Self::ensure_distribution_bucket_exists(&bucket_id)?;
+
+            if let Some(region) = region.as_ref() {
+                Self::ensure_region_tag_is_valid(region)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DistributionBucketByFamilyIdById<T>>::mutate(
+                bucket_id.distribution_bucket_family_id,
+                bucket_id.distribution_bucket_index,
+                |bucket| {
+                    bucket.region = region.clone();
+                }
+            );
+
+            Self::deposit_event(RawEvent::DistributionBucketRegionUpdated(bucket_id, region));
+        }
+
+        /// Updates the load-based reward rates of the distribution operators.
+        #[weight = WeightInfoStorage::<T>::update_distribution_operator_reward_rates()]
+        pub fn update_distribution_operator_reward_rates(
+            origin,
+            rates: OperatorRewardRates<BalanceOf<T>>,
+        ) {
+            T::ensure_distribution_working_group_leader_origin(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DistributionOperatorRewardRates::<T>::put(rates);
+
+            Self::deposit_event(RawEvent::DistributionOperatorRewardRatesUpdated(rates));
+        }
+
 
         // ===== Distribution Operator actions =====
 
         /// Accept pending invite.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::accept_distribution_bucket_invitation()]
         pub fn accept_distribution_bucket_invitation(
             origin,
             worker_id: WorkerId<T>,
@@ -2499,7 +4381,9 @@ decl_module! {
         }
 
         /// Set distribution operator metadata for the distribution bucket.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = WeightInfoStorage::<T>::set_distribution_operator_metadata(
+            metadata.len().saturated_into(),
+        )]
         pub fn set_distribution_operator_metadata(
             origin,
             worker_id: WorkerId<T>,
@@ -2524,26 +4408,76 @@ decl_module! {
             );
         }
 
-        // ===== Sudo actions (development mode) =====
-
-        /// Upload new data objects. Development mode.
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn sudo_upload_data_objects(origin, params: UploadParameters<T>) {
-            ensure_root(origin)?;
-
-            Self::upload_data_objects(params)?;
-        }
-
-        /// Create a dynamic bag. Development mode.
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn sudo_create_dynamic_bag(
+        /// Reports the periodic health beacon of the distribution operator for the distribution
+        /// bucket: bytes served and the operator node uptime in seconds.
+        #[weight = WeightInfoStorage::<T>::report_distribution_operator_health()]
+        pub fn report_distribution_operator_health(
             origin,
-            bag_id: DynamicBagId<T>,
-            deletion_prize: Option<DynamicBagDeletionPrize<T>>,
+            worker_id: WorkerId<T>,
+            bucket_id: DistributionBucketId<T>,
+            bytes_served: u64,
+            uptime: u64,
         ) {
-            ensure_root(origin)?;
+            T::ensure_distribution_worker_origin(origin, worker_id)?;
 
-            Self::create_dynamic_bag(bag_id, deletion_prize)?;
+            let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
+
+            ensure!(
+                bucket.operators.contains(&worker_id),
+                Error::<T>::MustBeDistributionProviderOperatorForBucket
+            );
+
+            let beacons_in_block = Self::ensure_distribution_health_beacon_can_be_reported(
+                &bucket_id,
+                &worker_id,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DistributionHealthBeaconsInBlock<T>>::put(beacons_in_block);
+
+            <DistributionOperatorHealthByBucket<T>>::insert(
+                &bucket_id,
+                &worker_id,
+                DistributionOperatorHealthRecord {
+                    last_beacon_at: Some(<frame_system::Module<T>>::block_number()),
+                    bytes_served,
+                    uptime,
+                    missed_beacons: 0,
+                },
+            );
+
+            Self::deposit_event(
+                RawEvent::DistributionOperatorHealthReported(
+                    bucket_id,
+                    worker_id,
+                    bytes_served,
+                    uptime,
+                )
+            );
+        }
+
+        // ===== Governance actions =====
+
+        /// Executes the storage administrative action: data objects upload or dynamic bag
+        /// creation. Requires the root origin (eg.: the approved governance proposal).
+        #[weight = Module::<T>::storage_administrative_action_weight(&action)]
+        pub fn execute_storage_administrative_action(
+            origin,
+            action: StorageAdministrativeAction<T>,
+        ) {
+            ensure_root(origin)?;
+
+            match action {
+                StorageAdministrativeActionRecord::UploadDataObjects(params) => {
+                    Self::upload_data_objects(params)?;
+                }
+                StorageAdministrativeActionRecord::CreateDynamicBag(bag_id, deletion_prize) => {
+                    Self::create_dynamic_bag(bag_id, deletion_prize)?;
+                }
+            }
         }
     }
 }
@@ -2554,11 +4488,12 @@ impl<T: Trait> DataObjectStorage<T> for Module<T> {
         Self::validate_upload_data_objects_parameters(params).map(|_| ())
     }
 
-    fn upload_data_objects(params: UploadParameters<T>) -> DispatchResult {
+    fn upload_data_objects(
+        params: UploadParameters<T>,
+    ) -> Result<Vec<T::DataObjectId>, DispatchError> {
         let bag = Self::ensure_bag_exists(&params.bag_id)?;
         let bag_change = Self::validate_upload_data_objects_parameters(&params)?;
-        Self::upload_data_objects_inner(&params, &bag_change, &bag)?;
-        Ok(())
+        Self::upload_data_objects_inner(&params, &bag_change, &bag)
     }
 
     fn can_move_data_objects(
@@ -2584,24 +4519,13 @@ impl<T: Trait> DataObjectStorage<T> for Module<T> {
         // == MUTATION SAFE ==
         //
 
-        for object_id in objects.iter() {
-            DataObjectsById::<T>::swap(&src_bag_id, &object_id, &dest_bag_id, &object_id);
-        }
-
-        // Change source bag.
-        Self::change_storage_bucket_vouchers_for_bag(
+        Self::move_data_objects_between_bags(
             &src_bag_id,
             &src_bag,
-            &bag_change.voucher_update,
-            OperationType::Decrease,
-        );
-
-        // Change destination bag.
-        Self::change_storage_bucket_vouchers_for_bag(
             &dest_bag_id,
             &dest_bag,
+            &objects,
             &bag_change.voucher_update,
-            OperationType::Increase,
         );
 
         Self::deposit_event(RawEvent::DataObjectsMoved(src_bag_id, dest_bag_id, objects));
@@ -2634,17 +4558,23 @@ impl<T: Trait> DataObjectStorage<T> for Module<T> {
             bag_change.total_deletion_prize,
         )?;
 
-        for data_object_id in objects.iter() {
-            DataObjectsById::<T>::remove(&bag_id, &data_object_id);
-        }
+        let removed_data_objects = objects
+            .iter()
+            .map(|data_object_id| Self::take_data_object(&bag_id, &data_object_id))
+            .collect::<Vec<_>>();
+        let content_references =
+            Self::data_objects_content_references(&bag_id, &removed_data_objects);
 
         Self::change_storage_bucket_vouchers_for_bag(
             &bag_id,
             &bag,
             &bag_change.voucher_update,
+            &content_references,
             OperationType::Decrease,
         );
 
+        Self::release_shared_content(&content_references);
+
         Self::deposit_event(RawEvent::DataObjectsDeleted(
             deletion_prize_account_id,
             bag_id,
@@ -2683,16 +4613,27 @@ impl<T: Trait> DataObjectStorage<T> for Module<T> {
 
         <Bags<T>>::remove(&bag_id);
 
+        Self::remove_from_dynamic_bags_index(&dynamic_bag_id);
+
         Self::change_bag_assignments_for_distribution_buckets(
+            &bag_id,
             &BTreeSet::new(),
             &deleted_dynamic_bag.distributed_by,
+            deleted_dynamic_bag.objects_total_size,
         );
 
         Self::change_bag_assignments_for_storage_buckets(
+            &bag_id,
             &BTreeSet::new(),
             &deleted_dynamic_bag.stored_by,
         );
 
+        <UnderReplicatedBags<T>>::remove(&dynamic_bag_id);
+
+        Self::remove_bag_rent(&dynamic_bag_id);
+
+        <BagQuotaOverrides<T>>::remove(&bag_id);
+
         Self::deposit_event(RawEvent::DynamicBagDeleted(
             deletion_prize_account_id,
             dynamic_bag_id,
@@ -2785,6 +4726,69 @@ impl<T: Trait> DataObjectStorage<T> for Module<T> {
 }
 
 impl<T: Trait> Module<T> {
+    /// Weight of the dynamic bag creation: assumes the maximum number of storage and
+    /// distribution buckets allowed by the value constraints.
+    pub fn create_dynamic_bag_weight() -> Weight {
+        WeightInfoStorage::<T>::create_dynamic_bag(
+            T::StorageBucketsPerBagValueConstraint::get()
+                .max()
+                .saturated_into(),
+            T::DistributionBucketsPerBagValueConstraint::get()
+                .max()
+                .saturated_into(),
+        )
+        .saturating_add(Self::pick_region_distribution_buckets_weight())
+    }
+
+    // Weight of picking the distribution buckets for the dynamic bag creation policy regions:
+    // assumes the maximum number of regions. Each region reads the picked buckets and the
+    // candidate buckets along with their families (for the family region).
+    fn pick_region_distribution_buckets_weight() -> Weight {
+        let max_buckets = T::DistributionBucketsPerBagValueConstraint::get().max();
+        let reads_per_region = max_buckets
+            .saturating_add(T::MaxDistributionBucketPickerCandidates::get())
+            .saturating_mul(2);
+
+        T::DbWeight::get().reads(max_buckets.saturating_mul(reads_per_region))
+    }
+
+    /// Weight of the data objects upload.
+    pub fn upload_data_objects_weight(objects_number: u64) -> Weight {
+        WeightInfoStorage::<T>::upload_data_objects(objects_number.saturated_into())
+    }
+
+    /// Weight of the data objects deletion.
+    pub fn delete_data_objects_weight(objects_number: u64) -> Weight {
+        WeightInfoStorage::<T>::delete_data_objects(objects_number.saturated_into())
+    }
+
+    /// Weight of the dynamic bag deletion: assumes the maximum number of storage and
+    /// distribution buckets allowed by the value constraints.
+    pub fn delete_dynamic_bag_weight() -> Weight {
+        WeightInfoStorage::<T>::delete_dynamic_bag(
+            T::StorageBucketsPerBagValueConstraint::get()
+                .max()
+                .saturated_into(),
+            T::DistributionBucketsPerBagValueConstraint::get()
+                .max()
+                .saturated_into(),
+        )
+    }
+
+    /// Weight of the storage administrative action.
+    pub fn storage_administrative_action_weight(action: &StorageAdministrativeAction<T>) -> Weight {
+        match action {
+            StorageAdministrativeActionRecord::UploadDataObjects(params) => {
+                WeightInfoStorage::<T>::upload_data_objects(
+                    params.object_creation_list.len().saturated_into(),
+                )
+            }
+            StorageAdministrativeActionRecord::CreateDynamicBag(..) => {
+                Self::create_dynamic_bag_weight()
+            }
+        }
+    }
+
     // dynamic bag creation logic
     fn create_dynamic_bag_inner(
         dynamic_bag_id: &DynamicBagId<T>,
@@ -2807,12 +4811,25 @@ impl<T: Trait> Module<T> {
 
         <Bags<T>>::insert(&bag_id, bag);
 
+        Self::add_to_dynamic_bags_index(dynamic_bag_id);
+
+        // The new bag is empty.
         Self::change_bag_assignments_for_distribution_buckets(
+            &bag_id,
             &distribution_buckets,
             &BTreeSet::new(),
+            0,
+        );
+
+        Self::change_bag_assignments_for_storage_buckets(
+            &bag_id,
+            &storage_buckets,
+            &BTreeSet::new(),
         );
 
-        Self::change_bag_assignments_for_storage_buckets(&storage_buckets, &BTreeSet::new());
+        Self::update_bag_replication_status(dynamic_bag_id);
+
+        Self::init_bag_rent(dynamic_bag_id);
 
         Self::deposit_event(RawEvent::DynamicBagCreated(
             dynamic_bag_id.clone(),
@@ -2828,7 +4845,7 @@ impl<T: Trait> Module<T> {
         params: &UploadParameters<T>,
         bag_change: &BagUpdate<BalanceOf<T>>,
         bag: &Bag<T>,
-    ) -> DispatchResult {
+    ) -> Result<Vec<T::DataObjectId>, DispatchError> {
         let data = Self::create_data_objects(params.object_creation_list.clone());
 
         //
@@ -2840,8 +4857,11 @@ impl<T: Trait> Module<T> {
             bag_change.total_deletion_prize,
         )?;
 
-        Self::slash_data_size_fee(
+        Self::slash_data_size_fee(&params.deletion_prize_source_account_id, bag_change);
+
+        Self::charge_remaining_bag_rent(
             &params.deletion_prize_source_account_id,
+            &params.bag_id,
             bag_change.voucher_update.objects_total_size,
         );
 
@@ -2851,22 +4871,28 @@ impl<T: Trait> Module<T> {
         // Insert new objects.
         for (data_object_id, data_object) in data.data_objects_map.iter() {
             DataObjectsById::<T>::insert(&params.bag_id, &data_object_id, data_object);
+            Self::add_to_bag_data_objects_index(&params.bag_id, data_object_id);
         }
 
+        // Uploaded content is registered for the deduplication on acceptance.
         Self::change_storage_bucket_vouchers_for_bag(
             &params.bag_id,
             &bag,
             &bag_change.voucher_update,
+            &ContentReferences::new(),
             OperationType::Increase,
         );
 
+        // ids are assigned in the object creation list order
+        let data_object_ids: Vec<_> = data.data_objects_map.keys().cloned().collect();
+
         Self::deposit_event(RawEvent::DataObjectsUploaded(
-            data.data_objects_map.keys().cloned().collect(),
+            data_object_ids.clone(),
             params.clone(),
             T::DataObjectDeletionPrize::get(),
         ));
 
-        Ok(())
+        Ok(data_object_ids)
     }
 
     // Increment distribution family number in the storage.
@@ -2895,7 +4921,7 @@ impl<T: Trait> Module<T> {
 
         let bag_change = upload_params
             .as_ref()
-            .map(|params| {
+            .map(|params| -> Result<_, DispatchError> {
                 // ensure coherent account ids & bag ids
                 if let Some(deletion_prize) = deletion_prize {
                     ensure!(
@@ -2905,7 +4931,15 @@ impl<T: Trait> Module<T> {
                 }
                 ensure!(bag_id == params.bag_id, Error::<T>::BagsNotCoherent);
 
-                Self::validate_bag_change(params)
+                let bag_change = Self::validate_bag_change(params)?;
+
+                Self::ensure_bag_quota_not_exceeded(
+                    &bag_id,
+                    &Bag::<T>::default(),
+                    &bag_change.voucher_update,
+                )?;
+
+                Ok(bag_change)
             })
             .transpose()?;
 
@@ -3125,6 +5159,8 @@ impl<T: Trait> Module<T> {
             deletion_prize,
             size: obj.size,
             ipfs_content_id: obj.ipfs_content_id,
+            content_root: obj.content_root,
+            parts: obj.parts,
         });
 
         let mut next_data_object_id = Self::next_data_object_id();
@@ -3159,7 +5195,12 @@ impl<T: Trait> Module<T> {
         Self::ensure_storage_bucket_bound(&bag, storage_bucket_id)?;
 
         for data_object_id in data_objects.iter() {
-            Self::ensure_data_object_exists(bag_id, data_object_id)?;
+            let data_object = Self::ensure_data_object_exists(bag_id, data_object_id)?;
+
+            ensure!(
+                data_object.parts.is_empty(),
+                Error::<T>::DataObjectIsMultipart
+            );
         }
 
         Ok(())
@@ -3221,7 +5262,11 @@ impl<T: Trait> Module<T> {
             objects_total_size: bag.objects_total_size,
         };
 
-        Self::check_buckets_for_overflow(&add_buckets, &voucher_update)?;
+        Self::check_buckets_for_overflow(
+            &add_buckets,
+            &voucher_update,
+            &Self::bag_content_references_by_bag(bag_id),
+        )?;
 
         Ok(voucher_update)
     }
@@ -3250,23 +5295,188 @@ impl<T: Trait> Module<T> {
         for object_id in object_ids.iter() {
             let data_object = Self::ensure_data_object_exists(&src_bag_id, object_id)?;
 
+            // Blacklisted data objects can't be spread to other bags.
+            ensure!(
+                !<Blacklist<T>>::contains_key(&data_object.ipfs_content_id),
+                Error::<T>::DataObjectBlacklisted
+            );
+
             bag_change.add_object(data_object.size, data_object.deletion_prize);
         }
 
-        Self::check_bag_for_buckets_overflow(&dest_bag, &bag_change.voucher_update)?;
+        Self::check_bag_for_buckets_overflow(
+            &dest_bag,
+            &bag_change.voucher_update,
+            &ContentReferences::new(),
+        )?;
+
+        Self::ensure_bag_quota_not_exceeded(dest_bag_id, &dest_bag, &bag_change.voucher_update)?;
 
         Ok(bag_change)
     }
 
+    // Moves the validated data objects and updates the bags and their storage bucket vouchers.
+    fn move_data_objects_between_bags(
+        src_bag_id: &BagId<T>,
+        src_bag: &Bag<T>,
+        dest_bag_id: &BagId<T>,
+        dest_bag: &Bag<T>,
+        objects: &BTreeSet<T::DataObjectId>,
+        voucher_update: &VoucherUpdate,
+    ) {
+        let moved_data_objects = objects
+            .iter()
+            .map(|object_id| Self::data_object_by_id(src_bag_id, object_id))
+            .collect::<Vec<_>>();
+        let content_references =
+            Self::data_objects_content_references(src_bag_id, &moved_data_objects);
+
+        for object_id in objects.iter() {
+            DataObjectsById::<T>::swap(src_bag_id, object_id, dest_bag_id, object_id);
+            Self::remove_from_bag_data_objects_index(src_bag_id, object_id);
+            Self::add_to_bag_data_objects_index(dest_bag_id, object_id);
+        }
+
+        // Change source bag.
+        Self::change_storage_bucket_vouchers_for_bag(
+            src_bag_id,
+            src_bag,
+            voucher_update,
+            &content_references,
+            OperationType::Decrease,
+        );
+
+        // Change destination bag.
+        Self::change_storage_bucket_vouchers_for_bag(
+            dest_bag_id,
+            dest_bag,
+            voucher_update,
+            &content_references,
+            OperationType::Increase,
+        );
+    }
+
+    // Updates the bag storage buckets along with their vouchers and bag counters.
+    pub(crate) fn change_storage_buckets_for_bag(
+        bag_id: BagId<T>,
+        add_buckets: BTreeSet<T::StorageBucketId>,
+        remove_buckets: BTreeSet<T::StorageBucketId>,
+        voucher_update: VoucherUpdate,
+    ) {
+        let content_references = Self::bag_content_references_by_bag(&bag_id);
+
+        // Update vouchers.
+        if !add_buckets.is_empty() {
+            Self::change_storage_buckets_vouchers(
+                &add_buckets,
+                &voucher_update,
+                &content_references,
+                OperationType::Increase,
+            );
+        }
+        if !remove_buckets.is_empty() {
+            Self::change_storage_buckets_vouchers(
+                &remove_buckets,
+                &voucher_update,
+                &content_references,
+                OperationType::Decrease,
+            );
+        }
+
+        // Update bag counters.
+        Self::change_bag_assignments_for_storage_buckets(&bag_id, &add_buckets, &remove_buckets);
+
+        Bags::<T>::mutate(&bag_id, |bag| {
+            bag.update_storage_buckets(&mut add_buckets.clone(), &remove_buckets);
+        });
+
+        if let BagId::<T>::Dynamic(dynamic_bag_id) = &bag_id {
+            Self::update_bag_replication_status(dynamic_bag_id);
+        }
+
+        Self::deposit_event(RawEvent::StorageBucketsUpdatedForBag(
+            bag_id,
+            add_buckets,
+            remove_buckets,
+        ));
+    }
+
+    // Deletes the storage bucket without the assigned bags along with its challenges.
+    pub(crate) fn remove_storage_bucket(storage_bucket_id: T::StorageBucketId) {
+        <StorageBucketById<T>>::remove(storage_bucket_id);
+        <DrainingStorageBuckets<T>>::remove(storage_bucket_id);
+
+        Self::clear_storage_challenges(&storage_bucket_id);
+
+        Self::deposit_event(RawEvent::StorageBucketDeleted(storage_bucket_id));
+    }
+
+    // Returns the storage owner of the bag.
+    fn bag_owner(bag_id: &BagId<T>) -> StorageObjectOwner<MemberId<T>, T::ChannelId> {
+        match bag_id {
+            BagId::<T>::Static(StaticBagId::Council) => StorageObjectOwner::Council,
+            BagId::<T>::Static(StaticBagId::WorkingGroup(working_group)) => {
+                StorageObjectOwner::WorkingGroup(*working_group)
+            }
+            BagId::<T>::Dynamic(DynamicBagId::<T>::Member(member_id)) => {
+                StorageObjectOwner::Member(*member_id)
+            }
+            BagId::<T>::Dynamic(DynamicBagId::<T>::Channel(channel_id)) => {
+                StorageObjectOwner::Channel(*channel_id)
+            }
+        }
+    }
+
+    // Ensures the account can manage the bag data objects.
+    fn ensure_bag_owner_account(bag_id: &BagId<T>, account_id: &T::AccountId) -> DispatchResult {
+        ensure!(
+            T::is_storage_owner_account(&Self::bag_owner(bag_id), account_id),
+            Error::<T>::NotBagOwnerAccount
+        );
+
+        Ok(())
+    }
+
+    // Ensures the data objects aren't referenced outside of the storage.
+    fn ensure_data_objects_not_in_use(
+        bag_id: &BagId<T>,
+        data_object_ids: &BTreeSet<T::DataObjectId>,
+    ) -> DispatchResult {
+        ensure!(
+            !data_object_ids
+                .iter()
+                .any(|data_object_id| T::is_data_object_in_use(bag_id, data_object_id)),
+            Error::<T>::DataObjectIsInUse
+        );
+
+        Ok(())
+    }
+
+    // Ensures the origin is the storage working group leader or root (executed proposal).
+    fn ensure_storage_leader_or_root_origin(origin: T::Origin) -> DispatchResult {
+        if ensure_root(origin.clone()).is_ok() {
+            return Ok(());
+        }
+
+        T::ensure_storage_working_group_leader_origin(origin)
+    }
+
     // Returns only existing hashes in the blacklist from the original collection.
     #[allow(clippy::redundant_closure)] // doesn't work with Substrate storage functions.
     fn get_existing_hashes(hashes: &BTreeSet<Cid>) -> BTreeSet<Cid> {
-        Self::get_hashes_by_predicate(hashes, |cid| Blacklist::contains_key(cid))
+        Self::get_hashes_by_predicate(hashes, |cid| <Blacklist<T>>::contains_key(cid))
     }
 
     // Returns only nonexisting hashes in the blacklist from the original collection.
     fn get_nonexisting_hashes(hashes: &BTreeSet<Cid>) -> BTreeSet<Cid> {
-        Self::get_hashes_by_predicate(hashes, |cid| !Blacklist::contains_key(cid))
+        Self::get_hashes_by_predicate(hashes, |cid| !<Blacklist<T>>::contains_key(cid))
+    }
+
+    // Total length of the hashes in bytes (weight parameter).
+    fn hashes_total_length<'a>(hashes: impl Iterator<Item = &'a Cid>) -> u32 {
+        hashes
+            .fold(0usize, |total, cid| total.saturating_add(cid.len()))
+            .saturated_into()
     }
 
     // Returns hashes from the original collection selected by predicate.
@@ -3309,6 +5519,7 @@ impl<T: Trait> Module<T> {
         bag_id: &BagId<T>,
         bag: &Bag<T>,
         voucher_update: &VoucherUpdate,
+        content_references: &ContentReferences,
         voucher_operation: OperationType,
     ) {
         // Change bag object and size counters.
@@ -3339,20 +5550,51 @@ impl<T: Trait> Module<T> {
             ));
         });
 
+        Self::change_bag_content_references(bag_id, content_references, voucher_operation);
+
+        // Change related distribution buckets' bags size.
+        for bucket_id in bag.distributed_by.iter() {
+            Self::change_distribution_bucket_bags_size(
+                bucket_id,
+                voucher_update.objects_total_size,
+                voucher_operation,
+            );
+        }
+
         // Change related buckets' vouchers.
-        Self::change_storage_buckets_vouchers(&bag.stored_by, voucher_update, voucher_operation);
+        Self::change_storage_buckets_vouchers(
+            &bag.stored_by,
+            voucher_update,
+            content_references,
+            voucher_operation,
+        );
     }
 
-    // Update total objects size and number for provided storage buckets.
+    // Update total objects size and number for provided storage buckets. The deduplicated
+    // content is counted once per storage bucket.
     fn change_storage_buckets_vouchers(
         bucket_ids: &BTreeSet<T::StorageBucketId>,
         voucher_update: &VoucherUpdate,
+        content_references: &ContentReferences,
         voucher_operation: OperationType,
     ) {
         for bucket_id in bucket_ids.iter() {
+            let bucket_voucher_update = Self::storage_bucket_voucher_update(
+                bucket_id,
+                voucher_update,
+                content_references,
+                voucher_operation,
+            );
+
+            Self::change_storage_bucket_content_references(
+                bucket_id,
+                content_references,
+                voucher_operation,
+            );
+
             <StorageBucketById<T>>::mutate(bucket_id, |bucket| {
                 bucket.voucher =
-                    voucher_update.get_updated_voucher(&bucket.voucher, voucher_operation);
+                    bucket_voucher_update.get_updated_voucher(&bucket.voucher, voucher_operation);
 
                 Self::deposit_event(RawEvent::VoucherChanged(*bucket_id, bucket.voucher.clone()));
             });
@@ -3365,11 +5607,19 @@ impl<T: Trait> Module<T> {
         params: &UploadParameters<T>,
     ) -> Result<BagUpdate<BalanceOf<T>>, DispatchError> {
         let bag_change = Self::validate_bag_change(params)?;
+        let remaining_bag_rent = Self::calculate_remaining_bag_rent(
+            &params.bag_id,
+            bag_change.voucher_update.objects_total_size,
+        );
         Self::ensure_sufficient_balance_for_upload(
             Some(params.deletion_prize_source_account_id.clone()),
-            Self::compute_upload_fees(&bag_change),
+            Self::compute_upload_fees(&bag_change).saturating_add(remaining_bag_rent),
+        )?;
+        Self::ensure_upload_bag_validity(
+            &params.bag_id,
+            &bag_change.voucher_update,
+            &ContentReferences::new(),
         )?;
-        Self::ensure_upload_bag_validity(&params.bag_id, &bag_change.voucher_update)?;
         Ok(bag_change)
     }
 
@@ -3381,12 +5631,14 @@ impl<T: Trait> Module<T> {
 
         Self::ensure_objects_creation_list_validity(&params.object_creation_list)?;
 
-        let bag_change = Self::construct_bag_change(&params.object_creation_list)?;
+        let bag_change = Self::construct_bag_change(&params.bag_id, &params.object_creation_list)?;
+
+        Self::ensure_expected_data_size_fee(
+            &params.bag_id,
+            &params.object_creation_list,
+            params.expected_data_size_fee,
+        )?;
 
-        ensure!(
-            params.expected_data_size_fee == Self::data_object_per_mega_byte_fee(),
-            Error::<T>::DataSizeFeeChanged
-        );
         Ok(bag_change)
     }
 
@@ -3429,16 +5681,56 @@ impl<T: Trait> Module<T> {
     fn ensure_upload_bag_validity(
         bag_id: &BagId<T>,
         voucher_update: &VoucherUpdate,
+        content_references: &ContentReferences,
     ) -> DispatchResult {
         let bag = Self::ensure_bag_exists(bag_id)?;
         // Check buckets.
-        Self::check_bag_for_buckets_overflow(&bag, voucher_update)?;
+        Self::check_bag_for_buckets_overflow(&bag, voucher_update, content_references)?;
+        // Check bag quota.
+        Self::ensure_bag_quota_not_exceeded(bag_id, &bag, voucher_update)?;
+        // Check storage rent.
+        Self::ensure_bag_rent_is_paid(bag_id)?;
+        Ok(())
+    }
+
+    /// Returns the upload quota of the bag: the bag override or the default quota of the dynamic
+    /// bag type. `None` means unlimited uploads.
+    pub fn bag_quota(bag_id: &BagId<T>) -> Option<BagQuota> {
+        Self::bag_quota_override(bag_id).or_else(|| match bag_id {
+            BagIdType::Static(_) => None,
+            BagIdType::Dynamic(dynamic_bag_id) => {
+                let bag_type: DynamicBagType = dynamic_bag_id.clone().into();
+
+                Self::dynamic_bag_quota(bag_type)
+            }
+        })
+    }
+
+    // Ensures the bag data objects fit the bag upload quota after the change.
+    fn ensure_bag_quota_not_exceeded(
+        bag_id: &BagId<T>,
+        bag: &Bag<T>,
+        voucher_update: &VoucherUpdate,
+    ) -> DispatchResult {
+        if let Some(quota) = Self::bag_quota(bag_id) {
+            let objects_number = bag
+                .objects_number
+                .saturating_add(voucher_update.objects_number);
+            let objects_total_size = bag
+                .objects_total_size
+                .saturating_add(voucher_update.objects_total_size);
+
+            ensure!(
+                objects_number <= quota.objects_limit && objects_total_size <= quota.size_limit,
+                Error::<T>::BagQuotaExceeded
+            );
+        }
+
         Ok(())
     }
 
     fn compute_upload_fees(bag_change: &BagUpdate<BalanceOf<T>>) -> BalanceOf<T> {
-        let size_fee =
-            Self::calculate_data_storage_fee(bag_change.voucher_update.objects_total_size);
+        let size_fee = Self::calculate_upload_storage_fee(bag_change);
 
         bag_change.total_deletion_prize.saturating_add(size_fee)
     }
@@ -3450,9 +5742,10 @@ impl<T: Trait> Module<T> {
     }
 
     fn construct_bag_change(
+        bag_id: &BagId<T>,
         object_creation_list: &[DataObjectCreationParameters],
     ) -> Result<BagUpdate<BalanceOf<T>>, DispatchError> {
-        let bag_change = object_creation_list
+        let mut bag_change = object_creation_list
             .iter()
             .try_fold::<_, _, Result<_, DispatchError>>(
                 BagUpdate::default(),
@@ -3467,18 +5760,26 @@ impl<T: Trait> Module<T> {
 
                     // Should not be blacklisted.
                     ensure!(
-                        !Blacklist::contains_key(&object_params.ipfs_content_id),
+                        !<Blacklist<T>>::contains_key(&object_params.ipfs_content_id),
                         Error::<T>::DataObjectBlacklisted,
                     );
 
-                    let bag_change = acc
+                    let mut bag_change = acc
                         .clone()
                         .add_object(object_params.size, T::DataObjectDeletionPrize::get());
 
+                    // Already stored content is charged with the reference fee.
+                    if Self::is_stored_shared_content(object_params) {
+                        bag_change.add_referenced_object(object_params.size);
+                    }
+
                     Ok(bag_change)
                 },
             )?;
 
+        bag_change.data_size_fee =
+            Self::calculate_upload_data_size_fee(bag_id, object_creation_list);
+
         Ok(bag_change)
     }
 
@@ -3499,6 +5800,13 @@ impl<T: Trait> Module<T> {
                 .all(|obj| obj.size <= T::MaxDataObjectSize::get()),
             Error::<T>::MaxDataObjectSizeExceeded
         );
+
+        ensure!(
+            object_creation_list
+                .iter()
+                .all(Self::data_object_parts_number_valid),
+            Error::<T>::InvalidDataObjectPartsNumber
+        );
         Ok(())
     }
 
@@ -3507,8 +5815,9 @@ impl<T: Trait> Module<T> {
     fn check_bag_for_buckets_overflow(
         bag: &Bag<T>,
         voucher_update: &VoucherUpdate,
+        content_references: &ContentReferences,
     ) -> DispatchResult {
-        Self::check_buckets_for_overflow(&bag.stored_by, voucher_update)
+        Self::check_buckets_for_overflow(&bag.stored_by, voucher_update, content_references)
     }
 
     // Iterates through buckets. Verifies voucher parameters to fit the new limits:
@@ -3516,9 +5825,16 @@ impl<T: Trait> Module<T> {
     fn check_buckets_for_overflow(
         bucket_ids: &BTreeSet<T::StorageBucketId>,
         voucher_update: &VoucherUpdate,
+        content_references: &ContentReferences,
     ) -> DispatchResult {
         for bucket_id in bucket_ids.iter() {
             let bucket = Self::storage_bucket_by_id(bucket_id);
+            let voucher_update = Self::storage_bucket_voucher_update(
+                bucket_id,
+                voucher_update,
+                content_references,
+                OperationType::Increase,
+            );
 
             // Total object number limit is not exceeded.
             ensure!(
@@ -3540,9 +5856,12 @@ impl<T: Trait> Module<T> {
 
     // Calculate data storage fee based on size. Fee-value uses megabytes as measure value.
     // Data size will be rounded to nearest greater MB integer.
-    pub(crate) fn calculate_data_storage_fee(bytes: u64) -> BalanceOf<T> {
-        let mb_fee = Self::data_object_per_mega_byte_fee();
+    pub(crate) fn calculate_data_storage_fee(mb_fee: BalanceOf<T>, bytes: u64) -> BalanceOf<T> {
+        mb_fee.saturating_mul(Self::size_in_megabytes(bytes).saturated_into())
+    }
 
+    // Data size in megabytes rounded to nearest greater MB integer.
+    pub(crate) fn size_in_megabytes(bytes: u64) -> u64 {
         const ONE_MB: u64 = 1_048_576;
 
         let mut megabytes = bytes / ONE_MB;
@@ -3551,12 +5870,13 @@ impl<T: Trait> Module<T> {
             megabytes += 1; // rounding to the nearest greater integer
         }
 
-        mb_fee.saturating_mul(megabytes.saturated_into())
+        megabytes
     }
 
-    // Slash data size fee if fee value is set to non-zero.
-    fn slash_data_size_fee(account_id: &T::AccountId, bytes: u64) {
-        let fee = Self::calculate_data_storage_fee(bytes);
+    // Slash data size fee (and the reference fee for the already stored content)
+    // if fee value is set to non-zero.
+    fn slash_data_size_fee(account_id: &T::AccountId, bag_change: &BagUpdate<BalanceOf<T>>) {
+        let fee = Self::calculate_upload_storage_fee(bag_change);
 
         if fee != Zero::zero() {
             let _ = Balances::<T>::slash(account_id, fee);
@@ -3592,7 +5912,7 @@ impl<T: Trait> Module<T> {
         bag_type: DynamicBagType,
         voucher_update: Option<VoucherUpdate>,
     ) -> BTreeSet<T::StorageBucketId> {
-        StorageBucketPicker::<T>::pick_storage_buckets(bag_type, voucher_update)
+        T::StorageBucketsSelectionPolicy::pick_storage_buckets(bag_type, voucher_update)
     }
 
     // Selects distributed bucket ID sets to assign to the dynamic bag.
@@ -3602,6 +5922,366 @@ impl<T: Trait> Module<T> {
         DistributionBucketPicker::<T>::pick_distribution_buckets(bag_type)
     }
 
+    /// Weight of the storage bucket status update: includes the replication checks of the
+    /// bags assigned to the bucket.
+    pub fn update_storage_bucket_status_weight(storage_bucket_id: &T::StorageBucketId) -> Weight {
+        let assigned_bags = Self::storage_bucket_by_id(storage_bucket_id).assigned_bags;
+
+        WeightInfoStorage::<T>::update_storage_bucket_status()
+            .saturating_add(Self::bag_replication_check_weight().saturating_mul(assigned_bags))
+    }
+
+    /// Weight of the storage bucket draining start: includes the replication checks of the
+    /// bags assigned to the bucket.
+    pub fn start_storage_bucket_draining_weight(storage_bucket_id: &T::StorageBucketId) -> Weight {
+        let assigned_bags = Self::storage_bucket_by_id(storage_bucket_id).assigned_bags;
+
+        WeightInfoStorage::<T>::start_storage_bucket_draining()
+            .saturating_add(Self::bag_replication_check_weight().saturating_mul(assigned_bags))
+    }
+
+    // Estimated weight of a single bag replication check.
+    fn bag_replication_check_weight() -> Weight {
+        let max_buckets_per_bag = T::StorageBucketsPerBagValueConstraint::get().max();
+
+        T::DbWeight::get().reads_writes(max_buckets_per_bag.saturating_add(4), 4)
+    }
+
+    /// Returns the dynamic bags with less active storage buckets than required by the dynamic
+    /// bag creation policy.
+    pub fn under_replicated_bags() -> Vec<DynamicBagId<T>> {
+        <UnderReplicatedBags<T>>::iter()
+            .map(|(dynamic_bag_id, _)| dynamic_bag_id)
+            .collect()
+    }
+
+    // Number of the bag storage buckets that exist and accept new bags.
+    fn active_storage_buckets_number(bag: &Bag<T>) -> u64 {
+        bag.stored_by
+            .iter()
+            .filter(|bucket_id| {
+                Self::ensure_storage_bucket_exists(bucket_id)
+                    .map_or(false, |bucket| bucket.accepting_new_bags)
+            })
+            .count()
+            .saturated_into()
+    }
+
+    // Number of active storage buckets the dynamic bag lacks to satisfy the dynamic bag
+    // creation policy.
+    fn missing_replicas_number(dynamic_bag_id: &DynamicBagId<T>, bag: &Bag<T>) -> u64 {
+        let bag_type: DynamicBagType = dynamic_bag_id.clone().into();
+        let creation_policy = Self::get_dynamic_bag_creation_policy(bag_type);
+
+        creation_policy
+            .number_of_storage_buckets
+            .saturating_sub(Self::active_storage_buckets_number(bag))
+    }
+
+    // Tracks the dynamic bag replication: adds the bag to the under-replicated bags (and to the
+    // re-replication queue) or removes it from them.
+    fn update_bag_replication_status(dynamic_bag_id: &DynamicBagId<T>) {
+        let bag_id: BagId<T> = dynamic_bag_id.clone().into();
+
+        let under_replicated = <Bags<T>>::contains_key(&bag_id)
+            && Self::missing_replicas_number(dynamic_bag_id, &Self::bag(&bag_id)) > 0;
+        let tracked = <UnderReplicatedBags<T>>::contains_key(dynamic_bag_id);
+
+        if under_replicated && !tracked {
+            <UnderReplicatedBags<T>>::insert(dynamic_bag_id, ());
+
+            if T::MaxReReplicatedBagsPerBlock::get() > 0 {
+                Self::enqueue_bag_re_replication(dynamic_bag_id);
+            }
+
+            Self::deposit_event(RawEvent::DynamicBagUnderReplicated(dynamic_bag_id.clone()));
+        } else if !under_replicated && tracked {
+            <UnderReplicatedBags<T>>::remove(dynamic_bag_id);
+
+            Self::deposit_event(RawEvent::DynamicBagReplicationRestored(
+                dynamic_bag_id.clone(),
+            ));
+        }
+    }
+
+    // Updates the replication status of the dynamic bags assigned to the storage bucket.
+    fn update_storage_bucket_bags_replication_status(storage_bucket_id: &T::StorageBucketId) {
+        for (bag_id, _) in StorageBucketBags::<T>::iter_prefix(storage_bucket_id) {
+            if let BagId::<T>::Dynamic(dynamic_bag_id) = bag_id {
+                Self::update_bag_replication_status(&dynamic_bag_id);
+            }
+        }
+    }
+
+    // Adds the dynamic bag to the end of the dynamic bags index.
+    pub(crate) fn add_to_dynamic_bags_index(dynamic_bag_id: &DynamicBagId<T>) {
+        let index = Self::dynamic_bags_number();
+
+        <DynamicBagByIndex<T>>::insert(index, dynamic_bag_id);
+        <DynamicBagIndex<T>>::insert(dynamic_bag_id, index);
+        DynamicBagsNumber::put(index.saturating_add(1));
+    }
+
+    // Removes the dynamic bag from the dynamic bags index: the last bag takes its position.
+    fn remove_from_dynamic_bags_index(dynamic_bag_id: &DynamicBagId<T>) {
+        let index = <DynamicBagIndex<T>>::take(dynamic_bag_id);
+        let last_index = Self::dynamic_bags_number().saturating_sub(1);
+        let last_bag_id = <DynamicBagByIndex<T>>::take(last_index);
+
+        if index != last_index {
+            <DynamicBagByIndex<T>>::insert(index, &last_bag_id);
+            <DynamicBagIndex<T>>::insert(&last_bag_id, index);
+
+            // the moved bag would be skipped by the replication audit in progress
+            if let Some(cursor) = Self::replication_audit_cursor() {
+                if index < cursor && last_index >= cursor {
+                    Self::update_bag_replication_status(&last_bag_id);
+                }
+            }
+        }
+
+        DynamicBagsNumber::put(last_index);
+    }
+
+    // Adds the bag to the end of the storage bucket bags index (if not added yet).
+    pub(crate) fn add_to_storage_bucket_bags_index(
+        storage_bucket_id: &T::StorageBucketId,
+        bag_id: &BagId<T>,
+    ) {
+        if <StorageBucketBags<T>>::contains_key(storage_bucket_id, bag_id) {
+            return;
+        }
+
+        let index = Self::storage_bucket_bags_number(storage_bucket_id);
+
+        <StorageBucketBags<T>>::insert(storage_bucket_id, bag_id, index);
+        <StorageBucketBagByIndex<T>>::insert(storage_bucket_id, index, bag_id);
+        <StorageBucketBagsNumber<T>>::insert(storage_bucket_id, index.saturating_add(1));
+    }
+
+    // Removes the bag from the storage bucket bags index: the last bag takes its position.
+    fn remove_from_storage_bucket_bags_index(
+        storage_bucket_id: &T::StorageBucketId,
+        bag_id: &BagId<T>,
+    ) {
+        if !<StorageBucketBags<T>>::contains_key(storage_bucket_id, bag_id) {
+            return;
+        }
+
+        let index = <StorageBucketBags<T>>::take(storage_bucket_id, bag_id);
+        let last_index = Self::storage_bucket_bags_number(storage_bucket_id).saturating_sub(1);
+        let last_bag_id = <StorageBucketBagByIndex<T>>::take(storage_bucket_id, last_index);
+
+        if index != last_index {
+            <StorageBucketBagByIndex<T>>::insert(storage_bucket_id, index, &last_bag_id);
+            <StorageBucketBags<T>>::insert(storage_bucket_id, &last_bag_id, index);
+        }
+
+        if last_index == 0 {
+            <StorageBucketBagsNumber<T>>::remove(storage_bucket_id);
+        } else {
+            <StorageBucketBagsNumber<T>>::insert(storage_bucket_id, last_index);
+        }
+    }
+
+    // Adds the bag to the end of the distribution bucket bags index (if not added yet).
+    pub(crate) fn add_to_distribution_bucket_bags_index(
+        distribution_bucket_id: &DistributionBucketId<T>,
+        bag_id: &BagId<T>,
+    ) {
+        if <DistributionBucketBags<T>>::contains_key(distribution_bucket_id, bag_id) {
+            return;
+        }
+
+        let index = Self::distribution_bucket_bags_number(distribution_bucket_id);
+
+        <DistributionBucketBags<T>>::insert(distribution_bucket_id, bag_id, index);
+        <DistributionBucketBagByIndex<T>>::insert(distribution_bucket_id, index, bag_id);
+        <DistributionBucketBagsNumber<T>>::insert(distribution_bucket_id, index.saturating_add(1));
+    }
+
+    // Removes the bag from the distribution bucket bags index: the last bag takes its position.
+    fn remove_from_distribution_bucket_bags_index(
+        distribution_bucket_id: &DistributionBucketId<T>,
+        bag_id: &BagId<T>,
+    ) {
+        if !<DistributionBucketBags<T>>::contains_key(distribution_bucket_id, bag_id) {
+            return;
+        }
+
+        let index = <DistributionBucketBags<T>>::take(distribution_bucket_id, bag_id);
+        let last_index =
+            Self::distribution_bucket_bags_number(distribution_bucket_id).saturating_sub(1);
+        let last_bag_id =
+            <DistributionBucketBagByIndex<T>>::take(distribution_bucket_id, last_index);
+
+        if index != last_index {
+            <DistributionBucketBagByIndex<T>>::insert(distribution_bucket_id, index, &last_bag_id);
+            <DistributionBucketBags<T>>::insert(distribution_bucket_id, &last_bag_id, index);
+        }
+
+        if last_index == 0 {
+            <DistributionBucketBagsNumber<T>>::remove(distribution_bucket_id);
+        } else {
+            <DistributionBucketBagsNumber<T>>::insert(distribution_bucket_id, last_index);
+        }
+    }
+
+    // Adds the data object to the end of the bag data objects index.
+    pub(crate) fn add_to_bag_data_objects_index(
+        bag_id: &BagId<T>,
+        data_object_id: &T::DataObjectId,
+    ) {
+        let index = Self::bag_data_objects_number(bag_id);
+
+        <BagDataObjectByIndex<T>>::insert(bag_id, index, data_object_id);
+        <BagDataObjectIndex<T>>::insert(bag_id, data_object_id, index);
+        <BagDataObjectsNumber<T>>::insert(bag_id, index.saturating_add(1));
+    }
+
+    // Removes the data object from the bag data objects index: the last data object takes its
+    // position.
+    pub(crate) fn remove_from_bag_data_objects_index(
+        bag_id: &BagId<T>,
+        data_object_id: &T::DataObjectId,
+    ) {
+        let index = <BagDataObjectIndex<T>>::take(bag_id, data_object_id);
+        let last_index = Self::bag_data_objects_number(bag_id).saturating_sub(1);
+        let last_data_object_id = <BagDataObjectByIndex<T>>::take(bag_id, last_index);
+
+        if index != last_index {
+            <BagDataObjectByIndex<T>>::insert(bag_id, index, last_data_object_id);
+            <BagDataObjectIndex<T>>::insert(bag_id, last_data_object_id, index);
+        }
+
+        if last_index == 0 {
+            <BagDataObjectsNumber<T>>::remove(bag_id);
+        } else {
+            <BagDataObjectsNumber<T>>::insert(bag_id, last_index);
+        }
+    }
+
+    // Checks the replication of the dynamic bags from the replication audit cursor after the
+    // dynamic bag creation policy was raised. Checks at most `MaxReReplicatedBagsPerBlock` bags.
+    fn audit_bags_replication() -> Weight {
+        let cursor = match Self::replication_audit_cursor() {
+            Some(cursor) => cursor,
+            None => return 0,
+        };
+
+        let end = cursor
+            .saturating_add(T::MaxReReplicatedBagsPerBlock::get())
+            .min(Self::dynamic_bags_number());
+
+        for index in cursor..end {
+            Self::update_bag_replication_status(&Self::dynamic_bag_by_index(index));
+        }
+
+        if end < Self::dynamic_bags_number() {
+            ReplicationAuditCursor::put(end);
+        } else {
+            ReplicationAuditCursor::kill();
+        }
+
+        Self::bag_replication_check_weight()
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_mul(end.saturating_sub(cursor).saturating_add(1))
+    }
+
+    // Adds the dynamic bag to the end of the re-replication queue.
+    fn enqueue_bag_re_replication(dynamic_bag_id: &DynamicBagId<T>) {
+        let tail = Self::re_replication_queue_tail();
+
+        <ReReplicationQueue<T>>::insert(tail, dynamic_bag_id);
+        ReReplicationQueueTail::put(tail.saturating_add(1));
+    }
+
+    // Assigns replacement storage buckets to the under-replicated dynamic bags from the head of
+    // the re-replication queue. Bags that remain under-replicated are moved to the queue end.
+    // Processes at most `MaxReReplicatedBagsPerBlock` bags (each queued bag at most once).
+    fn re_replicate_bags() -> Weight {
+        let max_bags = T::MaxReReplicatedBagsPerBlock::get();
+
+        if max_bags == 0 {
+            return 0;
+        }
+
+        let queue_length =
+            Self::re_replication_queue_tail().saturating_sub(Self::re_replication_queue_head());
+        let bags_number = max_bags.min(queue_length);
+
+        for _ in 0..bags_number {
+            let head = Self::re_replication_queue_head();
+            let dynamic_bag_id = <ReReplicationQueue<T>>::take(head);
+            ReReplicationQueueHead::put(head.saturating_add(1));
+
+            // Skip the bags that were restored or deleted meanwhile.
+            if !<UnderReplicatedBags<T>>::contains_key(&dynamic_bag_id) {
+                continue;
+            }
+
+            Self::re_replicate_bag(&dynamic_bag_id);
+
+            if <UnderReplicatedBags<T>>::contains_key(&dynamic_bag_id) {
+                Self::enqueue_bag_re_replication(&dynamic_bag_id);
+            }
+        }
+
+        WeightInfoStorage::<T>::re_replicate_bags(bags_number.saturated_into())
+    }
+
+    // Assigns storage buckets picked by the selection policy to the under-replicated dynamic
+    // bag. Respects the bucket vouchers and the "storage buckets per bag" limit.
+    fn re_replicate_bag(dynamic_bag_id: &DynamicBagId<T>) {
+        let bag_id: BagId<T> = dynamic_bag_id.clone().into();
+        let bag = Self::bag(&bag_id);
+
+        let free_bucket_slots = Self::storage_buckets_per_bag_limit()
+            .saturating_sub(bag.stored_by.len().saturated_into());
+        let required_bucket_num = Self::missing_replicas_number(dynamic_bag_id, &bag)
+            .min(free_bucket_slots)
+            .saturated_into();
+
+        let voucher_update = VoucherUpdate {
+            objects_number: bag.objects_number,
+            objects_total_size: bag.objects_total_size,
+        };
+
+        let add_buckets = Self::pick_storage_buckets_for_dynamic_bag(
+            dynamic_bag_id.clone().into(),
+            Some(voucher_update),
+        )
+        .into_iter()
+        .filter(|bucket_id| !bag.stored_by.contains(bucket_id))
+        .take(required_bucket_num)
+        .collect::<BTreeSet<_>>();
+
+        if add_buckets.is_empty() {
+            return;
+        }
+
+        Self::change_storage_buckets_vouchers(
+            &add_buckets,
+            &voucher_update,
+            &Self::bag_content_references_by_bag(&bag_id),
+            OperationType::Increase,
+        );
+
+        Self::change_bag_assignments_for_storage_buckets(&bag_id, &add_buckets, &BTreeSet::new());
+
+        Bags::<T>::mutate(&bag_id, |bag| {
+            bag.update_storage_buckets(&mut add_buckets.clone(), &BTreeSet::new());
+        });
+
+        Self::deposit_event(RawEvent::StorageBucketsUpdatedForBag(
+            bag_id,
+            add_buckets,
+            BTreeSet::new(),
+        ));
+
+        Self::update_bag_replication_status(dynamic_bag_id);
+    }
+
     // Get default dynamic bag policy by bag type.
     fn get_default_dynamic_bag_creation_policy(
         bag_type: DynamicBagType,
@@ -3790,15 +6470,87 @@ impl<T: Trait> Module<T> {
 
     // Ensures validity of the `update_families_in_dynamic_bag_creation_policy` extrinsic parameters
     fn validate_update_families_in_dynamic_bag_creation_policy_params(
+        dynamic_bag_type: DynamicBagType,
         families: &BTreeMap<T::DistributionBucketFamilyId, u32>,
     ) -> DispatchResult {
         for (family_id, _) in families.iter() {
             Self::ensure_distribution_bucket_family_exists(family_id)?;
         }
 
+        Self::ensure_policy_regions_fit_distribution_buckets_per_bag_limit(
+            families,
+            &Self::get_dynamic_bag_creation_policy(dynamic_bag_type).regions,
+        )
+    }
+
+    // Ensures validity of the `update_regions_in_dynamic_bag_creation_policy` extrinsic
+    // parameters.
+    fn validate_update_regions_in_dynamic_bag_creation_policy_params(
+        dynamic_bag_type: DynamicBagType,
+        regions: &BTreeSet<RegionTag>,
+    ) -> DispatchResult {
+        Self::ensure_policy_regions_fit_distribution_buckets_per_bag_limit(
+            &Self::get_dynamic_bag_creation_policy(dynamic_bag_type).families,
+            regions,
+        )?;
+
+        for region in regions.iter() {
+            Self::ensure_region_tag_is_valid(region)?;
+        }
+
+        Ok(())
+    }
+
+    // Ensures the regions of the dynamic bag creation policy along with the family distribution
+    // buckets don't exceed the distribution buckets per bag limit: each region could add a
+    // distribution bucket. Policies without regions aren't limited.
+    fn ensure_policy_regions_fit_distribution_buckets_per_bag_limit(
+        families: &BTreeMap<T::DistributionBucketFamilyId, u32>,
+        regions: &BTreeSet<RegionTag>,
+    ) -> DispatchResult {
+        if regions.is_empty() {
+            return Ok(());
+        }
+
+        let family_buckets_number = families
+            .values()
+            .fold(0u64, |acc, number| acc.saturating_add((*number).into()));
+
+        ensure!(
+            family_buckets_number.saturating_add(regions.len().saturated_into())
+                <= Self::distribution_buckets_per_bag_limit(),
+            Error::<T>::MaxRegionsNumberPerBagLimitExceeded
+        );
+
         Ok(())
     }
 
+    // Ensures the region tag is non-empty and doesn't exceed the max length.
+    fn ensure_region_tag_is_valid(region: &[u8]) -> DispatchResult {
+        ensure!(
+            !region.is_empty()
+                && region.len().saturated_into::<u32>() <= T::MaxRegionTagLength::get(),
+            Error::<T>::InvalidRegionTag
+        );
+
+        Ok(())
+    }
+
+    /// Returns the distribution bucket region: the bucket region or its family region.
+    pub fn distribution_bucket_region(bucket_id: &DistributionBucketId<T>) -> Option<RegionTag> {
+        Self::ensure_distribution_bucket_exists(bucket_id)
+            .ok()
+            .and_then(|bucket| {
+                bucket.region.or_else(|| {
+                    Self::ensure_distribution_bucket_family_exists(
+                        &bucket_id.distribution_bucket_family_id,
+                    )
+                    .ok()
+                    .and_then(|family| family.region)
+                })
+            })
+    }
+
     // Generate random number from zero to upper_bound (excluding).
     pub(crate) fn random_index(seed: &[u8], upper_bound: u64) -> u64 {
         if upper_bound == 0 {
@@ -3867,10 +6619,13 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    // Add and/or remove distribution buckets assignments to bags.
+    // Add and/or remove distribution buckets assignments to bags along with the distribution
+    // bucket bags index and bags size.
     fn change_bag_assignments_for_distribution_buckets(
+        bag_id: &BagId<T>,
         add_buckets: &BTreeSet<DistributionBucketId<T>>,
         remove_buckets: &BTreeSet<DistributionBucketId<T>>,
+        bag_size: u64,
     ) {
         for bucket_id in add_buckets.iter() {
             if DistributionBucketByFamilyIdById::<T>::contains_key(
@@ -3883,7 +6638,13 @@ impl<T: Trait> Module<T> {
                     |bucket| {
                         bucket.register_bag_assignment();
                     },
-                )
+                );
+                Self::add_to_distribution_bucket_bags_index(bucket_id, bag_id);
+                Self::change_distribution_bucket_bags_size(
+                    bucket_id,
+                    bag_size,
+                    OperationType::Increase,
+                );
             }
         }
 
@@ -3898,13 +6659,20 @@ impl<T: Trait> Module<T> {
                     |bucket| {
                         bucket.unregister_bag_assignment();
                     },
-                )
+                );
+                Self::remove_from_distribution_bucket_bags_index(bucket_id, bag_id);
+                Self::change_distribution_bucket_bags_size(
+                    bucket_id,
+                    bag_size,
+                    OperationType::Decrease,
+                );
             }
         }
     }
 
     // Add and/or remove storage buckets assignments to bags.
     fn change_bag_assignments_for_storage_buckets(
+        bag_id: &BagId<T>,
         add_buckets: &BTreeSet<T::StorageBucketId>,
         remove_buckets: &BTreeSet<T::StorageBucketId>,
     ) {
@@ -3912,7 +6680,8 @@ impl<T: Trait> Module<T> {
             if StorageBucketById::<T>::contains_key(bucket_id) {
                 StorageBucketById::<T>::mutate(bucket_id, |bucket| {
                     bucket.register_bag_assignment();
-                })
+                });
+                Self::add_to_storage_bucket_bags_index(bucket_id, bag_id);
             }
         }
 
@@ -3920,7 +6689,8 @@ impl<T: Trait> Module<T> {
             if StorageBucketById::<T>::contains_key(bucket_id) {
                 StorageBucketById::<T>::mutate(bucket_id, |bucket| {
                     bucket.unregister_bag_assignment();
-                })
+                });
+                Self::remove_from_storage_bucket_bags_index(bucket_id, bag_id);
             }
         }
     }
@@ -3943,3 +6713,186 @@ impl<T: Trait> Module<T> {
         }
     }
 }
+
+impl WeightInfo for () {
+    fn delete_storage_bucket() -> Weight {
+        0
+    }
+    fn update_uploading_blocked_status() -> Weight {
+        0
+    }
+    fn update_data_size_fee() -> Weight {
+        0
+    }
+    fn update_storage_buckets_per_bag_limit() -> Weight {
+        0
+    }
+    fn update_storage_buckets_voucher_max_limits() -> Weight {
+        0
+    }
+    fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy() -> Weight {
+        0
+    }
+    fn update_blacklist(_: u32, _: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_storage_bucket() -> Weight {
+        0
+    }
+    fn update_storage_buckets_for_bag(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn cancel_storage_bucket_operator_invite() -> Weight {
+        0
+    }
+    fn invite_storage_bucket_operator() -> Weight {
+        0
+    }
+    fn remove_storage_bucket_operator() -> Weight {
+        0
+    }
+    fn update_storage_bucket_status() -> Weight {
+        0
+    }
+    fn set_storage_bucket_voucher_limits() -> Weight {
+        0
+    }
+    fn accept_storage_bucket_invitation() -> Weight {
+        0
+    }
+    fn set_storage_operator_metadata(_: u32) -> Weight {
+        0
+    }
+    fn accept_pending_data_objects(_: u32) -> Weight {
+        0
+    }
+    fn create_distribution_bucket_family() -> Weight {
+        0
+    }
+    fn delete_distribution_bucket_family() -> Weight {
+        0
+    }
+    fn create_distribution_bucket() -> Weight {
+        0
+    }
+    fn update_distribution_bucket_status() -> Weight {
+        0
+    }
+    fn delete_distribution_bucket() -> Weight {
+        0
+    }
+    fn update_distribution_buckets_for_bag(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn update_distribution_buckets_per_bag_limit() -> Weight {
+        0
+    }
+    fn update_distribution_bucket_mode() -> Weight {
+        0
+    }
+    fn update_families_in_dynamic_bag_creation_policy(_: u32) -> Weight {
+        0
+    }
+    fn invite_distribution_bucket_operator() -> Weight {
+        0
+    }
+    fn cancel_distribution_bucket_operator_invite() -> Weight {
+        0
+    }
+    fn remove_distribution_bucket_operator() -> Weight {
+        0
+    }
+    fn set_distribution_bucket_family_metadata(_: u32) -> Weight {
+        0
+    }
+    fn accept_distribution_bucket_invitation() -> Weight {
+        0
+    }
+    fn set_distribution_operator_metadata(_: u32) -> Weight {
+        0
+    }
+    fn upload_data_objects(_: u32) -> Weight {
+        0
+    }
+    fn move_data_objects(_: u32) -> Weight {
+        0
+    }
+    fn delete_data_objects(_: u32) -> Weight {
+        0
+    }
+    fn create_dynamic_bag(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn delete_dynamic_bag(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn re_replicate_bags(_: u32) -> Weight {
+        0
+    }
+    fn respond_to_storage_challenge(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn update_storage_rent() -> Weight {
+        0
+    }
+    fn top_up_bag_rent() -> Weight {
+        0
+    }
+    fn update_data_object_reference_fee() -> Weight {
+        0
+    }
+    fn update_dynamic_bag_quota() -> Weight {
+        0
+    }
+    fn set_bag_quota_override() -> Weight {
+        0
+    }
+    fn update_regions_in_dynamic_bag_creation_policy(_: u32) -> Weight {
+        0
+    }
+    fn set_distribution_bucket_family_region(_: u32) -> Weight {
+        0
+    }
+    fn set_distribution_bucket_region(_: u32) -> Weight {
+        0
+    }
+    fn update_storage_operator_reward_rates() -> Weight {
+        0
+    }
+    fn update_distribution_operator_reward_rates() -> Weight {
+        0
+    }
+    fn set_blacklist_entry(_: u32) -> Weight {
+        0
+    }
+    fn purge_blacklisted_data_objects(_: u32) -> Weight {
+        0
+    }
+    fn accept_pending_data_object_parts(_: u32) -> Weight {
+        0
+    }
+    fn move_bag_data_objects(_: u32) -> Weight {
+        0
+    }
+    fn start_storage_bucket_draining() -> Weight {
+        0
+    }
+    fn cancel_storage_bucket_draining() -> Weight {
+        0
+    }
+    fn drain_storage_buckets(_: u32) -> Weight {
+        0
+    }
+    fn update_data_size_fee_schedule() -> Weight {
+        0
+    }
+    fn report_distribution_operator_health() -> Weight {
+        0
+    }
+    fn upload_working_group_data_objects(_: u32) -> Weight {
+        0
+    }
+    fn delete_working_group_data_objects(_: u32) -> Weight {
+        0
+    }
+}
@@ -0,0 +1,207 @@
+use codec::Decode;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{
+    IterableStorageDoubleMap, IterableStorageMap, StorageDoubleMap, StorageMap, StorageValue,
+};
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::vec::Vec;
+
+use crate::{
+    BagId, Bags, BalanceOf, DataObject, DataObjectsById, DistributionBucketByFamilyIdById,
+    DistributionBucketFamilyById, DistributionBucketFamilyRecord, DistributionBucketRecord,
+    DynamicBagCreationPolicies, DynamicBagCreationPolicy, LayoutVersion, Module, OperationType,
+    StorageBucketById, StorageLayoutVersion, Trait, WorkerId,
+};
+
+// Data object layout before the content Merkle root and the data object parts were introduced.
+#[derive(Decode)]
+struct DataObjectV0<Balance> {
+    accepted: bool,
+    deletion_prize: Balance,
+    size: u64,
+    ipfs_content_id: Vec<u8>,
+}
+
+// Distribution bucket family layout before the regions were introduced.
+#[derive(Decode)]
+struct DistributionBucketFamilyV0<DistributionBucketIndex> {
+    next_distribution_bucket_index: DistributionBucketIndex,
+}
+
+// Distribution bucket layout before the regions were introduced.
+#[derive(Decode)]
+struct DistributionBucketV0<WorkerId: Ord> {
+    accepting_new_bags: bool,
+    distributing: bool,
+    pending_invitations: BTreeSet<WorkerId>,
+    operators: BTreeSet<WorkerId>,
+    assigned_bags: u64,
+}
+
+// Dynamic bag creation policy layout before the regions were introduced.
+#[derive(Decode)]
+struct DynamicBagCreationPolicyV0<DistributionBucketFamilyId: Ord> {
+    number_of_storage_buckets: u64,
+    families: BTreeMap<DistributionBucketFamilyId, u32>,
+}
+
+impl<T: Trait> Module<T> {
+    /// Run the migrations required by the stored storage layout version, then bump the version.
+    /// Returns the weight of the performed migrations.
+    pub fn on_runtime_upgrade() -> Weight {
+        let version = Self::layout_version();
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        if version < StorageLayoutVersion::V1 {
+            weight = weight.saturating_add(Self::build_bag_indexes());
+        }
+
+        if version < StorageLayoutVersion::V2 {
+            weight = weight.saturating_add(Self::migrate_data_objects());
+        }
+
+        if version < StorageLayoutVersion::V3 {
+            weight = weight.saturating_add(Self::migrate_distribution_regions());
+        }
+
+        if version < StorageLayoutVersion::V4 {
+            weight = weight.saturating_add(Self::build_distribution_bucket_bags_index());
+        }
+
+        LayoutVersion::put(StorageLayoutVersion::LATEST);
+
+        weight
+    }
+
+    // Backfill the storage bucket bags reverse index and the dynamic bags index, then check the
+    // replication of the dynamic bags.
+    fn build_bag_indexes() -> Weight {
+        let mut weight: Weight = 0;
+
+        for (bag_id, bag) in <Bags<T>>::iter() {
+            let buckets_number: u64 = bag.stored_by.len().saturated_into();
+
+            for bucket_id in bag.stored_by.iter() {
+                if StorageBucketById::<T>::contains_key(bucket_id) {
+                    Self::add_to_storage_bucket_bags_index(bucket_id, &bag_id);
+                }
+            }
+
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(
+                buckets_number.saturating_mul(3).saturating_add(1),
+                buckets_number.saturating_mul(3),
+            ));
+
+            if let BagId::<T>::Dynamic(dynamic_bag_id) = bag_id {
+                Self::add_to_dynamic_bags_index(&dynamic_bag_id);
+                Self::update_bag_replication_status(&dynamic_bag_id);
+
+                weight = weight
+                    .saturating_add(T::DbWeight::get().reads_writes(1, 3))
+                    .saturating_add(Self::bag_replication_check_weight());
+            }
+        }
+
+        weight
+    }
+
+    // Add the (unset) content Merkle root and the (empty) parts to the data objects, then
+    // backfill the bag data objects indexes (used by the storage challenges).
+    fn migrate_data_objects() -> Weight {
+        <DataObjectsById<T>>::translate(|_, _, data_object: DataObjectV0<BalanceOf<T>>| {
+            Some(DataObject {
+                accepted: data_object.accepted,
+                deletion_prize: data_object.deletion_prize,
+                size: data_object.size,
+                ipfs_content_id: data_object.ipfs_content_id,
+                content_root: None,
+                parts: Vec::new(),
+            })
+        });
+
+        let mut data_objects_number: u64 = 0;
+
+        for (bag_id, data_object_id, _) in <DataObjectsById<T>>::iter() {
+            Self::add_to_bag_data_objects_index(&bag_id, &data_object_id);
+
+            data_objects_number = data_objects_number.saturating_add(1);
+        }
+
+        T::DbWeight::get().reads_writes(
+            data_objects_number.saturating_mul(3),
+            data_objects_number.saturating_mul(4),
+        )
+    }
+    // Add the (unset) regions to the distribution bucket families and the distribution buckets,
+    // and the (empty) regions to the dynamic bag creation policies.
+    fn migrate_distribution_regions() -> Weight {
+        let mut records_number: u64 = 0;
+
+        <DistributionBucketFamilyById<T>>::translate(
+            |_, family: DistributionBucketFamilyV0<T::DistributionBucketIndex>| {
+                records_number = records_number.saturating_add(1);
+
+                Some(DistributionBucketFamilyRecord {
+                    next_distribution_bucket_index: family.next_distribution_bucket_index,
+                    region: None,
+                })
+            },
+        );
+
+        <DistributionBucketByFamilyIdById<T>>::translate(
+            |_, _, bucket: DistributionBucketV0<WorkerId<T>>| {
+                records_number = records_number.saturating_add(1);
+
+                Some(DistributionBucketRecord {
+                    accepting_new_bags: bucket.accepting_new_bags,
+                    distributing: bucket.distributing,
+                    pending_invitations: bucket.pending_invitations,
+                    operators: bucket.operators,
+                    assigned_bags: bucket.assigned_bags,
+                    region: None,
+                })
+            },
+        );
+
+        <DynamicBagCreationPolicies<T>>::translate(
+            |_, policy: DynamicBagCreationPolicyV0<T::DistributionBucketFamilyId>| {
+                records_number = records_number.saturating_add(1);
+
+                Some(DynamicBagCreationPolicy {
+                    number_of_storage_buckets: policy.number_of_storage_buckets,
+                    families: policy.families,
+                    regions: BTreeSet::new(),
+                })
+            },
+        );
+
+        T::DbWeight::get().reads_writes(records_number, records_number)
+    }
+    // Backfill the bags index and the total size of the bags assigned to the distribution buckets.
+    fn build_distribution_bucket_bags_index() -> Weight {
+        let mut weight: Weight = 0;
+
+        for (bag_id, bag) in <Bags<T>>::iter() {
+            let buckets_number: u64 = bag.distributed_by.len().saturated_into();
+
+            for bucket_id in bag.distributed_by.iter() {
+                Self::add_to_distribution_bucket_bags_index(bucket_id, &bag_id);
+                Self::change_distribution_bucket_bags_size(
+                    bucket_id,
+                    bag.objects_total_size,
+                    OperationType::Increase,
+                );
+            }
+
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(
+                buckets_number.saturating_mul(3).saturating_add(1),
+                buckets_number.saturating_mul(4),
+            ));
+        }
+
+        weight
+    }
+}
@@ -0,0 +1,103 @@
+use frame_support::dispatch::DispatchError;
+use frame_support::traits::Get;
+use frame_support::{ensure, StorageDoubleMap};
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_set::BTreeSet;
+
+use crate::{
+    BagId, BalanceOf, DataObject, DataObjectCreationParameters, DataObjectPartsByBucket,
+    DataObjectsById, Error, Module, Trait,
+};
+
+// Multi-part data objects: large objects are declared with the part hashes on upload and accepted
+// part by part by the storage providers, so the upload can be resumed and verified partially.
+// The parts are the consecutive chunks of `data_object_part_size` bytes, the last part holds the
+// rest of the data object (all parts should be non-empty). `DataObjectPartsByBucket` records the
+// parts held by each storage bucket. The data object is accepted when a storage bucket holds all
+// of its parts: the whole object acceptance isn't allowed for the multi-part data objects.
+
+impl<T: Trait> Module<T> {
+    /// Returns the size of the multi-part data object part: the data object size divided by the
+    /// parts number rounded up. The last part holds the rest of the data object.
+    pub fn data_object_part_size(object_size: u64, parts_number: u64) -> u64 {
+        if parts_number == 0 {
+            return object_size;
+        }
+
+        object_size.saturating_add(parts_number - 1) / parts_number
+    }
+
+    // Verifies the number of the declared data object parts (no parts for the single-part object):
+    // all parts should be non-empty.
+    pub(crate) fn data_object_parts_number_valid(obj: &DataObjectCreationParameters) -> bool {
+        let parts_number: u64 = obj.parts.len().saturated_into();
+
+        if parts_number == 0 {
+            return true;
+        }
+
+        let part_size = Self::data_object_part_size(obj.size, parts_number);
+
+        parts_number >= 2
+            && parts_number <= T::MaxDataObjectPartsNumber::get()
+            && part_size.saturating_mul(parts_number - 1) < obj.size
+    }
+
+    // Ensures validity of the `accept_pending_data_object_parts` extrinsic parameters.
+    pub(crate) fn validate_accept_pending_data_object_parts_params(
+        bag_id: &BagId<T>,
+        data_object_id: &T::DataObjectId,
+        storage_bucket_id: &T::StorageBucketId,
+        parts: &BTreeSet<u64>,
+    ) -> Result<DataObject<BalanceOf<T>>, DispatchError> {
+        ensure!(!parts.is_empty(), Error::<T>::DataObjectPartIndicesAreEmpty);
+
+        let bag = Self::ensure_bag_exists(bag_id)?;
+        Self::ensure_storage_bucket_bound(&bag, storage_bucket_id)?;
+
+        let data_object = Self::ensure_data_object_exists(bag_id, data_object_id)?;
+
+        ensure!(
+            !data_object.parts.is_empty(),
+            Error::<T>::DataObjectIsNotMultipart
+        );
+
+        let parts_number: u64 = data_object.parts.len().saturated_into();
+        ensure!(
+            parts.iter().all(|part_index| *part_index < parts_number),
+            Error::<T>::InvalidDataObjectPartIndex
+        );
+
+        Ok(data_object)
+    }
+
+    // Adds the parts held by the storage bucket. Returns true if the bucket holds all the parts.
+    pub(crate) fn add_data_object_parts_held(
+        data_object_id: &T::DataObjectId,
+        storage_bucket_id: &T::StorageBucketId,
+        data_object: &DataObject<BalanceOf<T>>,
+        parts: &BTreeSet<u64>,
+    ) -> bool {
+        <DataObjectPartsByBucket<T>>::mutate(data_object_id, storage_bucket_id, |held_parts| {
+            held_parts.extend(parts.iter().copied());
+
+            held_parts.len() == data_object.parts.len()
+        })
+    }
+
+    // Removes the data object along with the records of its parts held by the storage buckets.
+    pub(crate) fn take_data_object(
+        bag_id: &BagId<T>,
+        data_object_id: &T::DataObjectId,
+    ) -> DataObject<BalanceOf<T>> {
+        let data_object = <DataObjectsById<T>>::take(bag_id, data_object_id);
+
+        Self::remove_from_bag_data_objects_index(bag_id, data_object_id);
+
+        if !data_object.parts.is_empty() {
+            <DataObjectPartsByBucket<T>>::remove_prefix(data_object_id);
+        }
+
+        data_object
+    }
+}
@@ -0,0 +1,97 @@
+use frame_support::traits::Get;
+use frame_support::StorageDoubleMap;
+use sp_runtime::traits::Saturating;
+use sp_runtime::SaturatedConversion;
+use sp_std::vec::Vec;
+
+use crate::{
+    BagDataObjectByIndex, BagId, BalanceOf, DataObject, DataObjectCreationParameters,
+    DataObjectsById, DistributionBucketBagByIndex, DistributionBucketId, Module,
+    StorageBucketBagByIndex, Trait, UploadFeeQuote, Voucher,
+};
+
+// State queries for the storage and distribution nodes (exposed by the runtime API). They read
+// the pallet storage indexes by pages and are not meant to be called from the extrinsics. Pages are
+// consistent within the same block.
+
+impl<T: Trait> Module<T> {
+    /// Returns the page of the bags stored by the storage bucket.
+    pub fn storage_bucket_bags(
+        storage_bucket_id: &T::StorageBucketId,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BagId<T>> {
+        let bags_number = Self::storage_bucket_bags_number(storage_bucket_id);
+        let first_index: u64 = offset.into();
+        let last_index = first_index.saturating_add(limit.into()).min(bags_number);
+
+        (first_index..last_index)
+            .map(|index| <StorageBucketBagByIndex<T>>::get(storage_bucket_id, index))
+            .collect()
+    }
+
+    /// Returns the page of the bags distributed by the distribution bucket.
+    pub fn distribution_bucket_bags(
+        distribution_bucket_id: &DistributionBucketId<T>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BagId<T>> {
+        let bags_number = Self::distribution_bucket_bags_number(distribution_bucket_id);
+        let first_index: u64 = offset.into();
+        let last_index = first_index.saturating_add(limit.into()).min(bags_number);
+
+        (first_index..last_index)
+            .map(|index| <DistributionBucketBagByIndex<T>>::get(distribution_bucket_id, index))
+            .collect()
+    }
+
+    /// Returns the page of the bag data objects (including the accepted status).
+    pub fn bag_data_objects(
+        bag_id: &BagId<T>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(T::DataObjectId, DataObject<BalanceOf<T>>)> {
+        let objects_number = Self::bag_data_objects_number(bag_id);
+        let first_index: u64 = offset.into();
+        let last_index = first_index.saturating_add(limit.into()).min(objects_number);
+
+        (first_index..last_index)
+            .map(|index| {
+                let data_object_id = <BagDataObjectByIndex<T>>::get(bag_id, index);
+                let data_object = <DataObjectsById<T>>::get(bag_id, &data_object_id);
+
+                (data_object_id, data_object)
+            })
+            .collect()
+    }
+
+    /// Returns the storage bucket voucher (`None` for the non-existing bucket).
+    pub fn storage_bucket_voucher(storage_bucket_id: &T::StorageBucketId) -> Option<Voucher> {
+        Self::ensure_storage_bucket_exists(storage_bucket_id)
+            .ok()
+            .map(|bucket| bucket.voucher)
+    }
+
+    /// Returns the fees of the upload of the data objects to the bag calculated as on upload: the
+    /// data size fee by the bag schedule and the data object classes (the reference fee for the
+    /// already stored content), the deletion prize and the storage rent for the remaining prepaid
+    /// period of the bag. The quoted data size fee is the expected data size fee of the upload.
+    pub fn upload_fee_quote(
+        bag_id: &BagId<T>,
+        object_creation_list: &[DataObjectCreationParameters],
+    ) -> UploadFeeQuote<BalanceOf<T>> {
+        let total_size = object_creation_list
+            .iter()
+            .fold(0u64, |total, object_params| {
+                total.saturating_add(object_params.size)
+            });
+        let objects_number: u64 = object_creation_list.len().saturated_into();
+
+        UploadFeeQuote {
+            data_size_fee: Self::upload_data_size_fee(bag_id, object_creation_list),
+            deletion_prize: T::DataObjectDeletionPrize::get()
+                .saturating_mul(objects_number.saturated_into()),
+            storage_rent: Self::calculate_remaining_bag_rent(bag_id, total_size),
+        }
+    }
+}
@@ -1,11 +1,14 @@
 #![warn(missing_docs)]
 
+use frame_support::traits::Get;
 use sp_std::cell::RefCell;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 
-use crate::{DistributionBucketId, DynamicBagType, Module, Trait};
+use crate::{
+    DistributionBucketId, DynamicBagCreationPolicy, DynamicBagType, Module, RegionTag, Trait,
+};
 
 pub(crate) use super::{RandomBucketIdIterator, SequentialBucketIdIterator};
 
@@ -16,7 +19,8 @@ pub(crate) struct DistributionBucketPicker<T> {
 
 impl<T: Trait> DistributionBucketPicker<T> {
     // Get random distribution buckets from distribution bucket families using the dynamic bag
-    // creation policy.
+    // creation policy. Each policy region not covered by the picked buckets gets an additional
+    // bucket (if any) from the policy families.
     pub(crate) fn pick_distribution_buckets(
         bag_type: DynamicBagType,
     ) -> BTreeSet<DistributionBucketId<T>> {
@@ -29,7 +33,7 @@ impl<T: Trait> DistributionBucketPicker<T> {
         // Distribution bucket IDs accumulator.
         let bucket_ids_cell = RefCell::new(BTreeSet::<T::DistributionBucketIndex>::new());
 
-        creation_policy
+        let mut bucket_ids = creation_policy
             .families
             .iter()
             .filter_map(|(family_id, bucket_num)| {
@@ -73,6 +77,68 @@ impl<T: Trait> DistributionBucketPicker<T> {
                 // rename buckets
             })
             .flatten()
-            .collect::<BTreeSet<_>>()
+            .collect::<BTreeSet<_>>();
+
+        for region in creation_policy.regions.iter() {
+            let region_covered = bucket_ids.iter().any(|bucket_id| {
+                Module::<T>::distribution_bucket_region(bucket_id).as_ref() == Some(region)
+            });
+
+            if region_covered {
+                continue;
+            }
+
+            if let Some(bucket_id) = Self::pick_region_bucket(&creation_policy, region, &bucket_ids)
+            {
+                bucket_ids.insert(bucket_id);
+            }
+        }
+
+        bucket_ids
+    }
+
+    // Get a random distribution bucket accepting new bags in the region from the policy
+    // families. It samples at most `MaxDistributionBucketPickerCandidates` bucket IDs family by
+    // family: random IDs first, then sequential IDs starting from zero. Skips the already picked
+    // buckets.
+    fn pick_region_bucket(
+        creation_policy: &DynamicBagCreationPolicy<T::DistributionBucketFamilyId>,
+        region: &RegionTag,
+        picked_bucket_ids: &BTreeSet<DistributionBucketId<T>>,
+    ) -> Option<DistributionBucketId<T>> {
+        // Sampled distribution bucket IDs.
+        let mut sampled_ids = BTreeSet::new();
+
+        creation_policy
+            .families
+            .keys()
+            .filter_map(|family_id| {
+                Module::<T>::ensure_distribution_bucket_family_exists(family_id)
+                    .ok()
+                    .map(|family| (*family_id, family))
+            })
+            .flat_map(|(family_id, family)| {
+                RandomBucketIdIterator::<T, T::DistributionBucketIndex>::new(
+                    family.next_distribution_bucket_index,
+                )
+                .chain(
+                    SequentialBucketIdIterator::<T, T::DistributionBucketIndex>::new(
+                        family.next_distribution_bucket_index,
+                    ),
+                )
+                .map(move |bucket_idx| DistributionBucketId::<T> {
+                    distribution_bucket_family_id: family_id,
+                    distribution_bucket_index: bucket_idx,
+                })
+            })
+            .filter(|bucket_id| sampled_ids.insert(bucket_id.clone()))
+            .take(T::MaxDistributionBucketPickerCandidates::get() as usize)
+            .find(|bucket_id| {
+                !picked_bucket_ids.contains(bucket_id)
+                    && Module::<T>::ensure_distribution_bucket_exists(bucket_id)
+                        .map(|bucket| bucket.accepting_new_bags)
+                        .unwrap_or(false)
+                    && Module::<T>::distribution_bucket_region(bucket_id).as_ref() == Some(region)
+            })
     }
 }
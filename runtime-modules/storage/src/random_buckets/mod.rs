@@ -10,7 +10,7 @@ pub(crate) mod distribution_bucket_picker;
 pub(crate) mod storage_bucket_picker;
 
 pub(crate) use distribution_bucket_picker::DistributionBucketPicker;
-pub(crate) use storage_bucket_picker::StorageBucketPicker;
+pub use storage_bucket_picker::{CapacityAwareStorageBucketPicker, StorageBucketPicker};
 
 // A meta trait for defining generic bucket ID.
 pub(crate) trait BucketId:
@@ -1,25 +1,31 @@
 #![warn(missing_docs)]
 
+use frame_support::traits::Get;
 use sp_std::cell::RefCell;
+use sp_std::cmp::Ordering;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::marker::PhantomData;
+use sp_std::vec::Vec;
 
 pub(crate) use super::{RandomBucketIdIterator, SequentialBucketIdIterator};
-use crate::{DynamicBagType, Module, Trait, VoucherUpdate};
+use crate::{
+    DynamicBagType, Module, StorageBucket, StorageBucketOperatorStatus,
+    StorageBucketsSelectionPolicy, Trait, VoucherUpdate,
+};
 
-// Generates storage bucket IDs to assign to a new dynamic bag.
-pub(crate) struct StorageBucketPicker<T> {
+/// Generates random storage bucket IDs to assign to a new dynamic bag.
+pub struct StorageBucketPicker<T> {
     trait_marker: PhantomData<T>,
 }
 
-impl<T: Trait> StorageBucketPicker<T> {
+impl<T: Trait> StorageBucketsSelectionPolicy<T> for StorageBucketPicker<T> {
     // Selects storage bucket ID sets to assign to the dynamic bag.
     // At first, it tries to generate random bucket IDs. If acquired random IDs number is not enough
     // it tries to get additional IDs starting from zero up to the total number of the possible IDs.
     // The function filters deleted buckets and disabled buckets (accepting_new_bags == false)
     // Total number of possible IDs is limited by the dynamic bag settings.
     // Returns an accumulated bucket ID set or an empty set.
-    pub(crate) fn pick_storage_buckets(
+    fn pick_storage_buckets(
         bag_type: DynamicBagType,
         voucher_update: Option<VoucherUpdate>,
     ) -> BTreeSet<T::StorageBucketId> {
@@ -31,7 +37,6 @@ impl<T: Trait> StorageBucketPicker<T> {
 
         let required_bucket_num = creation_policy.number_of_storage_buckets as usize;
 
-        // TODO: the selection algorithm will change: https://github.com/Joystream/joystream/issues/2904
         // Storage bucket IDs accumulator.
         let bucket_ids_cell = RefCell::new(BTreeSet::new());
         let next_storage_bucket_id = Module::<T>::next_storage_bucket_id();
@@ -55,36 +60,145 @@ impl<T: Trait> StorageBucketPicker<T> {
 
         bucket_ids_cell.into_inner()
     }
+}
 
+impl<T: Trait> StorageBucketPicker<T> {
     // Verifies storage bucket ID (non-deleted and accepting new bags).
     pub(crate) fn check_storage_bucket_is_valid_for_bag_assigning(
         bucket_id: &T::StorageBucketId,
         voucher_update: &Option<VoucherUpdate>,
     ) -> bool {
-        // Check bucket for existence (return false if not). Check `accepting_new_bags`.
-        let bucket = Module::<T>::ensure_storage_bucket_exists(bucket_id).ok();
-
-        // check that bucket is accepting new bags
-        let accepting_bags = bucket
-            .as_ref()
-            .map_or(false, |bucket| bucket.accepting_new_bags);
+        // Check bucket for existence (return false if not).
+        Module::<T>::ensure_storage_bucket_exists(bucket_id)
+            .ok()
+            .map_or(false, |bucket| {
+                Self::check_storage_bucket_accepts_bag(&bucket, voucher_update)
+            })
+    }
 
+    // Verifies that the storage bucket is accepting new bags and has enough voucher room.
+    pub(crate) fn check_storage_bucket_accepts_bag(
+        bucket: &StorageBucket<T>,
+        voucher_update: &Option<VoucherUpdate>,
+    ) -> bool {
         // check that buckets has enough room for objects and size
-        let limits_sufficient = bucket.as_ref().map_or(false, |bucket| {
-            voucher_update.map_or(true, |voucher_update| {
-                let num_objects_enough = bucket.voucher.objects_limit
-                    >= bucket
-                        .voucher
-                        .objects_used
-                        .saturating_add(voucher_update.objects_number);
-                let size_enough = bucket.voucher.size_limit
-                    >= bucket
-                        .voucher
-                        .size_used
-                        .saturating_add(voucher_update.objects_total_size);
-                size_enough && num_objects_enough
-            })
+        let limits_sufficient = voucher_update.map_or(true, |voucher_update| {
+            let num_objects_enough = bucket.voucher.objects_limit
+                >= bucket
+                    .voucher
+                    .objects_used
+                    .saturating_add(voucher_update.objects_number);
+            let size_enough = bucket.voucher.size_limit
+                >= bucket
+                    .voucher
+                    .size_used
+                    .saturating_add(voucher_update.objects_total_size);
+            size_enough && num_objects_enough
+        });
+
+        bucket.accepting_new_bags && limits_sufficient
+    }
+}
+
+/// Capacity-aware storage bucket selection: it prefers buckets with the most free voucher size
+/// per assigned bag, so new bags are spread evenly in proportion to the free capacity.
+/// Replicas of a bag are never placed in two buckets run by the same storage operator.
+pub struct CapacityAwareStorageBucketPicker<T> {
+    trait_marker: PhantomData<T>,
+}
+
+impl<T: Trait> StorageBucketsSelectionPolicy<T> for CapacityAwareStorageBucketPicker<T> {
+    // Selects storage bucket ID sets to assign to the dynamic bag.
+    // It samples at most `MaxStorageBucketPickerCandidates` bucket IDs: random IDs first, then
+    // sequential IDs starting from zero. It filters deleted buckets, disabled buckets and buckets
+    // without enough voucher room, and orders the remaining buckets by their free size per
+    // assigned bag (ties: fewer assigned bags, then more free object slots, then lower ID).
+    // Buckets of the already selected operators are skipped.
+    // Returns an accumulated bucket ID set or an empty set.
+    fn pick_storage_buckets(
+        bag_type: DynamicBagType,
+        voucher_update: Option<VoucherUpdate>,
+    ) -> BTreeSet<T::StorageBucketId> {
+        let creation_policy = Module::<T>::get_dynamic_bag_creation_policy(bag_type);
+
+        if creation_policy.no_storage_buckets_required() {
+            return BTreeSet::new();
+        }
+
+        let required_bucket_num = creation_policy.number_of_storage_buckets as usize;
+
+        // Sampled storage bucket IDs.
+        let mut sampled_ids = BTreeSet::new();
+        let next_storage_bucket_id = Module::<T>::next_storage_bucket_id();
+        let mut candidates =
+            RandomBucketIdIterator::<T, T::StorageBucketId>::new(next_storage_bucket_id)
+                .chain(SequentialBucketIdIterator::<T, T::StorageBucketId>::new(
+                    next_storage_bucket_id,
+                ))
+                .filter(|bucket_id| sampled_ids.insert(bucket_id.clone()))
+                .take(T::MaxStorageBucketPickerCandidates::get() as usize)
+                .filter_map(|bucket_id| {
+                    Module::<T>::ensure_storage_bucket_exists(&bucket_id)
+                        .ok()
+                        .map(|bucket| (bucket_id, bucket))
+                })
+                .filter(|(_, bucket)| {
+                    StorageBucketPicker::<T>::check_storage_bucket_accepts_bag(
+                        bucket,
+                        &voucher_update,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+        candidates.sort_by(|(id1, bucket1), (id2, bucket2)| {
+            Self::compare_buckets(bucket1, bucket2).then_with(|| id1.cmp(id2))
         });
-        accepting_bags && limits_sufficient
+
+        // Storage operators of the selected buckets.
+        let mut selected_operators = BTreeSet::new();
+
+        candidates
+            .into_iter()
+            .filter(|(_, bucket)| match bucket.operator_status {
+                StorageBucketOperatorStatus::Missing => true,
+                StorageBucketOperatorStatus::InvitedStorageWorker(worker_id)
+                | StorageBucketOperatorStatus::StorageWorker(worker_id, _) => {
+                    selected_operators.insert(worker_id)
+                }
+            })
+            .map(|(bucket_id, _)| bucket_id)
+            .take(required_bucket_num)
+            .collect()
+    }
+}
+
+impl<T: Trait> CapacityAwareStorageBucketPicker<T> {
+    // Orders buckets from the most preferable to the least preferable.
+    fn compare_buckets(bucket1: &StorageBucket<T>, bucket2: &StorageBucket<T>) -> Ordering {
+        // Compares free size per bag by cross multiplication: avoids rounding on division.
+        let weighted_free_size = |bucket: &StorageBucket<T>, other: &StorageBucket<T>| {
+            u128::from(Self::free_size(bucket)) * u128::from(other.assigned_bags.saturating_add(1))
+        };
+
+        weighted_free_size(bucket2, bucket1)
+            .cmp(&weighted_free_size(bucket1, bucket2))
+            .then_with(|| bucket1.assigned_bags.cmp(&bucket2.assigned_bags))
+            .then_with(|| Self::free_objects(bucket2).cmp(&Self::free_objects(bucket1)))
+    }
+
+    // Remaining voucher size of the bucket.
+    fn free_size(bucket: &StorageBucket<T>) -> u64 {
+        bucket
+            .voucher
+            .size_limit
+            .saturating_sub(bucket.voucher.size_used)
+    }
+
+    // Remaining voucher object number of the bucket.
+    fn free_objects(bucket: &StorageBucket<T>) -> u64 {
+        bucket
+            .voucher
+            .objects_limit
+            .saturating_sub(bucket.voucher.objects_used)
     }
 }
@@ -0,0 +1,296 @@
+use frame_support::dispatch::DispatchResult;
+use frame_support::traits::{Currency, Get};
+use frame_support::weights::Weight;
+use frame_support::{
+    ensure, IterableStorageDoubleMap, IterableStorageMap, StorageDoubleMap, StorageMap,
+    StorageValue,
+};
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::vec::Vec;
+
+use crate::{
+    BagId, BagIdType, BagRentExpirations, BagRentExpirationsCursor, BagRentPaidThrough, BalanceOf,
+    Balances, DataObjectsById, DynamicBagId, Error, ExpiredBags, Module, OperationType, RawEvent,
+    Trait, VoucherUpdate,
+};
+
+// Time-based storage rent for the dynamic bags. It's enabled by the non-zero
+// "data object per megabyte per block" rent. Dynamic bags created while the rent is enabled get
+// the initial prepaid period, bags created before keep the free storage.
+// When the prepayment is exhausted the bag enters the grace period: new uploads are rejected.
+// After the grace period the bag data objects are garbage collected (deletion prizes are kept
+// by the storage treasury) and the bag owner module is notified to clear the references to them.
+// The paid rent is added to the storage working group budget: it pays for keeping the data
+// available, so it funds the storage providers instead of being burned.
+
+impl<T: Trait> Module<T> {
+    // Returns true if the storage rent is enabled.
+    pub(crate) fn storage_rent_enabled() -> bool {
+        !Self::data_object_per_mega_byte_per_block_rent().is_zero()
+    }
+
+    // Calculate the storage rent based on size and blocks. Rent-value uses megabytes as measure
+    // value. Data size will be rounded to nearest greater MB integer.
+    pub(crate) fn calculate_storage_rent(bytes: u64, blocks: T::BlockNumber) -> BalanceOf<T> {
+        let mb_rent = Self::data_object_per_mega_byte_per_block_rent();
+        let megabytes = Self::size_in_megabytes(bytes);
+        let blocks: u64 = blocks.saturated_into();
+
+        mb_rent
+            .saturating_mul(megabytes.saturated_into())
+            .saturating_mul(blocks.saturated_into())
+    }
+
+    // Storage rent for the uploaded data for the remaining prepaid period of the bag.
+    pub(crate) fn calculate_remaining_bag_rent(bag_id: &BagId<T>, bytes: u64) -> BalanceOf<T> {
+        let remaining_blocks = Self::bag_rent_paid_through_for_bag_id(bag_id)
+            .map_or(Zero::zero(), |paid_through| {
+                paid_through.saturating_sub(<frame_system::Module<T>>::block_number())
+            });
+
+        Self::calculate_storage_rent(bytes, remaining_blocks)
+    }
+
+    // Charge the storage rent for the uploaded data if the rent is non-zero.
+    pub(crate) fn charge_remaining_bag_rent(
+        account_id: &T::AccountId,
+        bag_id: &BagId<T>,
+        bytes: u64,
+    ) {
+        let rent = Self::calculate_remaining_bag_rent(bag_id, bytes);
+
+        Self::charge_storage_rent(account_id, rent);
+    }
+
+    // Slash the storage rent from the account and add it to the storage working group budget.
+    pub(crate) fn charge_storage_rent(account_id: &T::AccountId, rent: BalanceOf<T>) {
+        if rent.is_zero() {
+            return;
+        }
+
+        let (_, not_slashed) = Balances::<T>::slash(account_id, rent);
+
+        T::fund_storage_working_group_budget(rent.saturating_sub(not_slashed));
+    }
+
+    // Ensures the bag prepayment isn't exhausted (if the bag is subject to the storage rent).
+    pub(crate) fn ensure_bag_rent_is_paid(bag_id: &BagId<T>) -> DispatchResult {
+        if !Self::storage_rent_enabled() {
+            return Ok(());
+        }
+
+        if let Some(paid_through) = Self::bag_rent_paid_through_for_bag_id(bag_id) {
+            ensure!(
+                paid_through >= <frame_system::Module<T>>::block_number(),
+                Error::<T>::BagRentExhausted
+            );
+        }
+
+        Ok(())
+    }
+
+    // Paid-through block for the bags subject to the storage rent.
+    fn bag_rent_paid_through_for_bag_id(bag_id: &BagId<T>) -> Option<T::BlockNumber> {
+        match bag_id {
+            BagIdType::Static(_) => None,
+            BagIdType::Dynamic(dynamic_bag_id) => Self::bag_rent_paid_through(dynamic_bag_id),
+        }
+    }
+
+    // Grants the initial prepaid period to the new dynamic bag if the storage rent is enabled.
+    pub(crate) fn init_bag_rent(dynamic_bag_id: &DynamicBagId<T>) {
+        if !Self::storage_rent_enabled() {
+            return;
+        }
+
+        let paid_through = <frame_system::Module<T>>::block_number()
+            .saturating_add(T::InitialBagRentPeriod::get());
+
+        Self::set_bag_rent_paid_through(dynamic_bag_id, paid_through);
+    }
+
+    // Sets the new paid-through block for the bag and reschedules its rent expiration.
+    pub(crate) fn set_bag_rent_paid_through(
+        dynamic_bag_id: &DynamicBagId<T>,
+        paid_through: T::BlockNumber,
+    ) {
+        Self::clear_bag_rent_expiration(dynamic_bag_id);
+
+        <BagRentPaidThrough<T>>::insert(dynamic_bag_id, paid_through);
+        <BagRentExpirations<T>>::insert(
+            Self::bag_rent_expiration_block(paid_through),
+            dynamic_bag_id,
+            (),
+        );
+    }
+
+    // Removes the bag from the rent tracking (eg.: on the bag deletion).
+    pub(crate) fn remove_bag_rent(dynamic_bag_id: &DynamicBagId<T>) {
+        Self::clear_bag_rent_expiration(dynamic_bag_id);
+
+        <BagRentPaidThrough<T>>::remove(dynamic_bag_id);
+    }
+
+    // Removes the scheduled rent expiration and stops the garbage collection of the bag.
+    fn clear_bag_rent_expiration(dynamic_bag_id: &DynamicBagId<T>) {
+        if let Some(paid_through) = Self::bag_rent_paid_through(dynamic_bag_id) {
+            <BagRentExpirations<T>>::remove(
+                Self::bag_rent_expiration_block(paid_through),
+                dynamic_bag_id,
+            );
+        }
+
+        <ExpiredBags<T>>::remove(dynamic_bag_id);
+    }
+
+    // The block when the grace period ends and the bag data objects are garbage collected.
+    fn bag_rent_expiration_block(paid_through: T::BlockNumber) -> T::BlockNumber {
+        paid_through
+            .saturating_add(T::StorageRentGracePeriod::get())
+            .saturating_add(One::one())
+    }
+
+    // Marks the bags with the ended grace period as expired and garbage collects their data
+    // objects. Processes at most `MaxBagRentExpirationsPerBlock` expirations per block (from the
+    // persisted cursor, so the skipped expirations are processed in the next blocks) and removes
+    // at most `MaxGarbageCollectedDataObjectsPerBlock` data objects per block.
+    pub(crate) fn process_bag_rent(now: T::BlockNumber) -> Weight {
+        // the cursor
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        let rent_enabled = Self::storage_rent_enabled();
+
+        let cursor = Self::bag_rent_expirations_cursor();
+        let mut block = if cursor.is_zero() { now } else { cursor };
+        let mut steps_budget = T::MaxBagRentExpirationsPerBlock::get();
+
+        while block <= now && steps_budget > 0 {
+            let expired_bag_ids = <BagRentExpirations<T>>::iter_prefix(block)
+                .map(|(dynamic_bag_id, _)| dynamic_bag_id)
+                .take(steps_budget.saturated_into())
+                .collect::<Vec<_>>();
+
+            let expirations_number: u64 = expired_bag_ids.len().saturated_into();
+            reads = reads.saturating_add(expirations_number).saturating_add(1);
+            writes = writes.saturating_add(expirations_number);
+
+            // The block without (remaining) expirations is processed too.
+            let block_processed = expirations_number < steps_budget;
+            steps_budget = steps_budget.saturating_sub(expirations_number.max(1));
+
+            for dynamic_bag_id in expired_bag_ids {
+                <BagRentExpirations<T>>::remove(block, &dynamic_bag_id);
+
+                // Bags expired while the rent was disabled keep their data objects.
+                if rent_enabled {
+                    writes = writes.saturating_add(1);
+
+                    <ExpiredBags<T>>::insert(&dynamic_bag_id, ());
+
+                    Self::deposit_event(RawEvent::BagRentExpired(dynamic_bag_id));
+                }
+            }
+
+            if block_processed {
+                block = block.saturating_add(One::one());
+            }
+        }
+
+        if block != cursor {
+            writes = writes.saturating_add(1);
+
+            <BagRentExpirationsCursor<T>>::put(block);
+        }
+
+        let max_objects = T::MaxGarbageCollectedDataObjectsPerBlock::get();
+
+        if !rent_enabled || max_objects == 0 {
+            return T::DbWeight::get().reads_writes(reads.saturating_add(1), writes);
+        }
+
+        let expired_bags = <ExpiredBags<T>>::iter()
+            .map(|(dynamic_bag_id, _)| dynamic_bag_id)
+            .take(max_objects.saturated_into())
+            .collect::<Vec<_>>();
+
+        let mut objects_budget = max_objects;
+
+        for dynamic_bag_id in expired_bags {
+            if objects_budget == 0 {
+                break;
+            }
+
+            let bag_id: BagId<T> = dynamic_bag_id.clone().into();
+
+            let data_object_ids = <DataObjectsById<T>>::iter_prefix(&bag_id)
+                .map(|(data_object_id, _)| data_object_id)
+                .take(objects_budget.saturated_into())
+                .collect::<BTreeSet<_>>();
+
+            let objects_number: u64 = data_object_ids.len().saturated_into();
+            objects_budget = objects_budget.saturating_sub(objects_number.max(1));
+            // the data objects removal and the owner notification
+            reads = reads
+                .saturating_add(objects_number.saturating_mul(3))
+                .saturating_add(3);
+            writes = writes
+                .saturating_add(objects_number.saturating_mul(3))
+                .saturating_add(1);
+
+            if !data_object_ids.is_empty() {
+                reads = reads.saturating_add(T::StorageBucketsPerBagValueConstraint::get().max());
+                writes = writes.saturating_add(T::StorageBucketsPerBagValueConstraint::get().max());
+
+                Self::garbage_collect_data_objects(&bag_id, data_object_ids);
+            }
+
+            if Self::bag(&bag_id).objects_number == 0 {
+                <ExpiredBags<T>>::remove(&dynamic_bag_id);
+            }
+        }
+
+        T::DbWeight::get().reads_writes(reads.saturating_add(1), writes)
+    }
+
+    // Removes the data objects of the expired bag. Deletion prizes are kept by the treasury.
+    fn garbage_collect_data_objects(bag_id: &BagId<T>, data_object_ids: BTreeSet<T::DataObjectId>) {
+        let bag = Self::bag(bag_id);
+
+        let mut voucher_update = VoucherUpdate::default();
+        let mut removed_data_objects = Vec::new();
+        for data_object_id in data_object_ids.iter() {
+            let data_object = Self::take_data_object(bag_id, data_object_id);
+
+            voucher_update.add_object(data_object.size);
+            removed_data_objects.push(data_object);
+        }
+
+        let content_references =
+            Self::data_objects_content_references(bag_id, &removed_data_objects);
+
+        Self::change_storage_bucket_vouchers_for_bag(
+            bag_id,
+            &bag,
+            &voucher_update,
+            &content_references,
+            OperationType::Decrease,
+        );
+
+        Self::release_shared_content(&content_references);
+
+        T::on_data_objects_removed(bag_id, &data_object_ids);
+
+        Self::deposit_event(RawEvent::DataObjectsGarbageCollected(
+            bag_id.clone(),
+            data_object_ids,
+        ));
+    }
+
+    /// Returns the paid-through blocks of the dynamic bags subject to the storage rent.
+    pub fn bags_rent_paid_through() -> Vec<(DynamicBagId<T>, T::BlockNumber)> {
+        <BagRentPaidThrough<T>>::iter().collect()
+    }
+}
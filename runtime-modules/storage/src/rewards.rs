@@ -0,0 +1,369 @@
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{StorageDoubleMap, StorageMap, StorageValue};
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_runtime::{Perbill, SaturatedConversion};
+
+use crate::{
+    BalanceOf, DistributionBucketBagsSize, DistributionBucketByFamilyIdById, DistributionBucketId,
+    DistributionOperatorRewardsRound, Module, OperationType, OperatorRewardRates,
+    OperatorRewardsRound, OperatorRewardsStage, RawEvent, StorageBucketById,
+    StorageBucketOperatorStatus, StorageOperatorRewardsRound, Trait,
+};
+
+// Load-based rewards of the storage and distribution operators. Each reward period the buckets
+// are paid for the stored megabytes and the assigned bags using the reward rates set by the
+// working group leads. Storage buckets store the `Voucher::size_used` bytes, distribution buckets
+// store the total size of their bags (tracked along with the bag assignments). The rewards are
+// paid from the working group budgets: all rewards of the group are reduced proportionally on the
+// insufficient budget. Distribution bucket rewards are split equally between the bucket operators.
+// The rewards round sums up the bucket rewards first, then pays them: both stages process
+// a limited number of buckets per block. A reward period ending while the previous round is still
+// in progress is skipped.
+
+impl<T: Trait> Module<T> {
+    // Starts the storage and distribution operator rewards rounds at the end of each reward
+    // period and processes the rounds in progress.
+    pub(crate) fn process_operator_rewards(now: T::BlockNumber) -> Weight {
+        let period = T::OperatorRewardPeriod::get();
+
+        if period.is_zero() {
+            return 0;
+        }
+
+        let period_ended = !now.is_zero() && (now % period).is_zero();
+
+        let (storage_reads, storage_writes) = Self::process_storage_operator_rewards(period_ended);
+        let (distribution_reads, distribution_writes) =
+            Self::process_distribution_operator_rewards(period_ended);
+
+        T::DbWeight::get().reads_writes(
+            storage_reads.saturating_add(distribution_reads),
+            storage_writes.saturating_add(distribution_writes),
+        )
+    }
+
+    // Updates the total size of the bags assigned to the distribution bucket.
+    pub(crate) fn change_distribution_bucket_bags_size(
+        bucket_id: &DistributionBucketId<T>,
+        size: u64,
+        operation: OperationType,
+    ) {
+        if size == 0 {
+            return;
+        }
+
+        let bags_size = match operation {
+            OperationType::Increase => {
+                Self::distribution_bucket_bags_size(bucket_id).saturating_add(size)
+            }
+            OperationType::Decrease => {
+                Self::distribution_bucket_bags_size(bucket_id).saturating_sub(size)
+            }
+        };
+
+        if bags_size == 0 {
+            <DistributionBucketBagsSize<T>>::remove(bucket_id);
+        } else {
+            <DistributionBucketBagsSize<T>>::insert(bucket_id, bags_size);
+        }
+    }
+
+    // Processes the storage operator rewards round: pays the storage operators for the stored
+    // bytes and assigned bags of their buckets. Returns the number of the storage reads and writes.
+    fn process_storage_operator_rewards(period_ended: bool) -> (u64, u64) {
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        let mut round = match Self::storage_operator_rewards_round() {
+            Some(round) => round,
+            None if period_ended => {
+                reads = reads.saturating_add(1);
+
+                match Self::new_operator_rewards_round(
+                    Self::storage_operator_reward_rates(),
+                    Zero::zero(),
+                ) {
+                    Some(round) => round,
+                    None => return (reads, writes),
+                }
+            }
+            None => return (reads, writes),
+        };
+
+        let next_storage_bucket_id = Self::next_storage_bucket_id();
+        reads = reads.saturating_add(1);
+
+        let mut round_finished = false;
+        let mut processed: u64 = 0;
+        while processed < T::MaxOperatorRewardBucketsPerBlock::get() {
+            processed += 1;
+
+            if round.cursor >= next_storage_bucket_id {
+                reads = reads.saturating_add(1);
+
+                round_finished = !Self::start_operator_rewards_paying_stage(
+                    &mut round,
+                    T::storage_working_group_budget(),
+                    Zero::zero(),
+                );
+
+                if round_finished {
+                    break;
+                }
+
+                continue;
+            }
+
+            let storage_bucket_id = round.cursor;
+            round.cursor += One::one();
+            reads = reads.saturating_add(1);
+
+            if !<StorageBucketById<T>>::contains_key(storage_bucket_id) {
+                continue;
+            }
+
+            let bucket = Self::storage_bucket_by_id(storage_bucket_id);
+
+            if let StorageBucketOperatorStatus::StorageWorker(worker_id, _) = bucket.operator_status
+            {
+                let reward = Self::calculate_operator_reward(
+                    &round.rates,
+                    bucket.voucher.size_used,
+                    bucket.assigned_bags,
+                );
+
+                if let Some(reward) = Self::add_operator_reward(&mut round, reward) {
+                    reads = reads.saturating_add(2);
+                    writes = writes.saturating_add(2);
+
+                    // The worker could have left the working group meanwhile.
+                    if T::pay_storage_worker_reward(&worker_id, reward).is_ok() {
+                        Self::deposit_event(RawEvent::StorageOperatorRewardPaid(
+                            storage_bucket_id,
+                            worker_id,
+                            reward,
+                        ));
+                    }
+                }
+            }
+        }
+
+        writes = writes.saturating_add(1);
+        if round_finished {
+            <StorageOperatorRewardsRound<T>>::kill();
+        } else {
+            <StorageOperatorRewardsRound<T>>::put(round);
+        }
+
+        (reads, writes)
+    }
+
+    // Processes the distribution operator rewards round: pays the distribution operators for the
+    // stored bytes and assigned bags of their buckets. Returns the number of the storage reads
+    // and writes.
+    fn process_distribution_operator_rewards(period_ended: bool) -> (u64, u64) {
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        let mut round = match Self::distribution_operator_rewards_round() {
+            Some(round) => round,
+            None if period_ended => {
+                reads = reads.saturating_add(1);
+
+                match Self::new_operator_rewards_round(
+                    Self::distribution_operator_reward_rates(),
+                    Self::create_distribution_bucket_id(Zero::zero(), Zero::zero()),
+                ) {
+                    Some(round) => round,
+                    None => return (reads, writes),
+                }
+            }
+            None => return (reads, writes),
+        };
+
+        let next_family_id = Self::next_distribution_bucket_family_id();
+        reads = reads.saturating_add(1);
+
+        let mut round_finished = false;
+        let mut processed: u64 = 0;
+        while processed < T::MaxOperatorRewardBucketsPerBlock::get() {
+            processed += 1;
+
+            let bucket_id = round.cursor.clone();
+
+            if bucket_id.distribution_bucket_family_id >= next_family_id {
+                reads = reads.saturating_add(1);
+
+                round_finished = !Self::start_operator_rewards_paying_stage(
+                    &mut round,
+                    T::distribution_working_group_budget(),
+                    Self::create_distribution_bucket_id(Zero::zero(), Zero::zero()),
+                );
+
+                if round_finished {
+                    break;
+                }
+
+                continue;
+            }
+
+            // Deleted families have no buckets.
+            let family =
+                Self::distribution_bucket_family_by_id(bucket_id.distribution_bucket_family_id);
+            reads = reads.saturating_add(1);
+
+            if bucket_id.distribution_bucket_index >= family.next_distribution_bucket_index {
+                round.cursor = Self::create_distribution_bucket_id(
+                    bucket_id.distribution_bucket_family_id + One::one(),
+                    Zero::zero(),
+                );
+
+                continue;
+            }
+
+            round.cursor.distribution_bucket_index += One::one();
+            reads = reads.saturating_add(2);
+
+            if !<DistributionBucketByFamilyIdById<T>>::contains_key(
+                bucket_id.distribution_bucket_family_id,
+                bucket_id.distribution_bucket_index,
+            ) {
+                continue;
+            }
+
+            let bucket = Self::distribution_bucket_by_family_id_by_index(
+                bucket_id.distribution_bucket_family_id,
+                bucket_id.distribution_bucket_index,
+            );
+
+            if !bucket.distributing || bucket.operators.is_empty() {
+                continue;
+            }
+
+            let reward = Self::calculate_operator_reward(
+                &round.rates,
+                Self::distribution_bucket_bags_size(&bucket_id),
+                bucket.assigned_bags,
+            );
+
+            if let Some(reward) = Self::add_operator_reward(&mut round, reward) {
+                let operators_number: u64 = bucket.operators.len().saturated_into();
+                let operator_reward = reward / operators_number.saturated_into();
+
+                if operator_reward.is_zero() {
+                    continue;
+                }
+
+                for worker_id in bucket.operators {
+                    reads = reads.saturating_add(2);
+                    writes = writes.saturating_add(2);
+
+                    // The worker could have left the working group meanwhile.
+                    if T::pay_distribution_worker_reward(&worker_id, operator_reward).is_ok() {
+                        Self::deposit_event(RawEvent::DistributionOperatorRewardPaid(
+                            bucket_id.clone(),
+                            worker_id,
+                            operator_reward,
+                        ));
+                    }
+                }
+            }
+        }
+
+        writes = writes.saturating_add(1);
+        if round_finished {
+            <DistributionOperatorRewardsRound<T>>::kill();
+        } else {
+            <DistributionOperatorRewardsRound<T>>::put(round);
+        }
+
+        (reads, writes)
+    }
+
+    // Creates the operator rewards round summing up the bucket rewards from the first bucket ID.
+    // Returns None if the operator rewards are disabled by the zero rates.
+    fn new_operator_rewards_round<BucketId>(
+        rates: OperatorRewardRates<BalanceOf<T>>,
+        first_bucket_id: BucketId,
+    ) -> Option<OperatorRewardsRound<BalanceOf<T>, BucketId>> {
+        if rates.per_megabyte.is_zero() && rates.per_bag.is_zero() {
+            return None;
+        }
+
+        Some(OperatorRewardsRound {
+            rates,
+            stage: OperatorRewardsStage::Summing(Zero::zero()),
+            cursor: first_bucket_id,
+        })
+    }
+
+    // Starts paying the summed up rewards from the first bucket ID. Returns false if the
+    // rewards were paid already (the round is finished).
+    fn start_operator_rewards_paying_stage<BucketId>(
+        round: &mut OperatorRewardsRound<BalanceOf<T>, BucketId>,
+        budget: BalanceOf<T>,
+        first_bucket_id: BucketId,
+    ) -> bool {
+        match round.stage {
+            OperatorRewardsStage::Summing(total_rewards) => {
+                round.stage = OperatorRewardsStage::Paying(Self::operator_rewards_budget_share(
+                    total_rewards,
+                    budget,
+                ));
+                round.cursor = first_bucket_id;
+
+                true
+            }
+            OperatorRewardsStage::Paying(_) => false,
+        }
+    }
+
+    // Adds the bucket reward to the total rewards on the summing stage. Returns the reward share
+    // to pay on the paying stage (if any).
+    fn add_operator_reward<BucketId>(
+        round: &mut OperatorRewardsRound<BalanceOf<T>, BucketId>,
+        reward: BalanceOf<T>,
+    ) -> Option<BalanceOf<T>> {
+        match round.stage {
+            OperatorRewardsStage::Summing(total_rewards) => {
+                round.stage = OperatorRewardsStage::Summing(total_rewards.saturating_add(reward));
+
+                None
+            }
+            OperatorRewardsStage::Paying(budget_share) => {
+                let reward = budget_share * reward;
+
+                if reward.is_zero() {
+                    None
+                } else {
+                    Some(reward)
+                }
+            }
+        }
+    }
+
+    // Calculates the bucket reward for the stored bytes and assigned bags.
+    pub(crate) fn calculate_operator_reward(
+        rates: &OperatorRewardRates<BalanceOf<T>>,
+        bytes: u64,
+        bags: u64,
+    ) -> BalanceOf<T> {
+        let megabytes = Self::size_in_megabytes(bytes);
+
+        rates
+            .per_megabyte
+            .saturating_mul(megabytes.saturated_into())
+            .saturating_add(rates.per_bag.saturating_mul(bags.saturated_into()))
+    }
+
+    // Share of the rewards to pay: the whole rewards on the sufficient budget, the proportionally
+    // reduced rewards otherwise.
+    fn operator_rewards_budget_share(total_rewards: BalanceOf<T>, budget: BalanceOf<T>) -> Perbill {
+        if budget >= total_rewards {
+            Perbill::one()
+        } else {
+            Perbill::from_rational_approximation(budget, total_rewards)
+        }
+    }
+}
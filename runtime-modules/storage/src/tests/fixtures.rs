@@ -1,5 +1,6 @@
+use common::working_group::WorkingGroup;
 use frame_support::dispatch::DispatchResult;
-use frame_support::storage::StorageMap;
+use frame_support::storage::{StorageDoubleMap, StorageMap};
 use frame_support::traits::{Currency, OnFinalize, OnInitialize};
 use frame_system::{EventRecord, Phase, RawOrigin};
 use sp_runtime::{traits::Zero, DispatchError};
@@ -13,14 +14,17 @@ use super::mocks::{
 };
 
 use crate::tests::mocks::{
-    DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, DEFAULT_MEMBER_ID,
-    DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT, DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+    DEFAULT_CHANNEL_ID, DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, DEFAULT_DISTRIBUTION_PROVIDER_ID,
+    DEFAULT_MEMBER_ID, DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT, DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
     DISTRIBUTION_WG_LEADER_ACCOUNT_ID,
 };
 use crate::{
-    BagId, Cid, DataObjectCreationParameters, DataObjectStorage, DistributionBucket,
-    DistributionBucketId, DynamicBagDeletionPrize, DynamicBagId, DynamicBagType, RawEvent,
-    StaticBagId, StorageBucketOperatorStatus, UploadParameters,
+    BagId, BagQuota, BlacklistEntry, Cid, ContentHash, DataObjectCreationParameters,
+    DataObjectStorage, DataSizeFeeCategory, DataSizeFeeSchedule, DistributionBucket,
+    DistributionBucketId, DistributionOperatorHealthRecord, DynamicBagDeletionPrize, DynamicBagId,
+    DynamicBagType, OperatorRewardRates, RawEvent, RegionTag, StaticBagId,
+    StorageAdministrativeAction, StorageAdministrativeActionRecord, StorageBucketOperatorStatus,
+    UploadParameters,
 };
 
 // Recommendation from Parity on testing on_finalize
@@ -56,6 +60,7 @@ impl EventFixture {
             u64,
             DistributionBucketId<Test>,
             u64,
+            u64,
         >,
     ) {
         let converted_event = TestEvent::storage(expected_raw_event);
@@ -76,6 +81,7 @@ impl EventFixture {
             u64,
             DistributionBucketId<Test>,
             u64,
+            u64,
         >,
     ) {
         let converted_event = TestEvent::storage(expected_raw_event);
@@ -102,6 +108,39 @@ impl EventFixture {
 
         assert!(System::events().iter().any(|ev| *ev == expected_event));
     }
+
+    pub fn events_number() -> usize {
+        System::events().len()
+    }
+
+    // Crate events emitted after the provided number of events.
+    pub fn crate_events_since(
+        events_number: usize,
+    ) -> Vec<
+        RawEvent<
+            u64,
+            u64,
+            u64,
+            UploadParameters<Test>,
+            BagId<Test>,
+            DynamicBagId<Test>,
+            u64,
+            u64,
+            u64,
+            DistributionBucketId<Test>,
+            u64,
+            u64,
+        >,
+    > {
+        System::events()
+            .into_iter()
+            .skip(events_number)
+            .filter_map(|record| match record.event {
+                TestEvent::storage(event) => Some(event),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 const DEFAULT_ACCOUNT_ID: u64 = 1;
@@ -324,7 +363,7 @@ impl UploadFixture {
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let old_next_data_object_id = Storage::next_data_object_id();
-        let actual_result = Storage::upload_data_objects(self.params.clone());
+        let actual_result = Storage::upload_data_objects(self.params.clone()).map(|_| ());
 
         assert_eq!(actual_result, expected_result);
 
@@ -351,6 +390,9 @@ pub fn create_data_object_candidates(
         .map(|idx| DataObjectCreationParameters {
             size: DEFAULT_DATA_OBJECTS_SIZE,
             ipfs_content_id: vec![idx],
+            content_root: None,
+            parts: Vec::new(),
+            class: None,
         })
         .collect()
 }
@@ -465,6 +507,155 @@ impl AcceptPendingDataObjectsFixture {
     }
 }
 
+#[derive(Clone)]
+pub struct AcceptPendingDataObjectPartsFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    storage_bucket_id: u64,
+    bag_id: BagId<Test>,
+    data_object_id: u64,
+    parts: BTreeSet<u64>,
+}
+
+impl AcceptPendingDataObjectPartsFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID),
+            worker_id: DEFAULT_WORKER_ID,
+            storage_bucket_id: Default::default(),
+            bag_id: Default::default(),
+            data_object_id: Default::default(),
+            parts: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_worker_id(self, worker_id: u64) -> Self {
+        Self { worker_id, ..self }
+    }
+
+    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
+        Self {
+            storage_bucket_id,
+            ..self
+        }
+    }
+
+    pub fn with_bag_id(self, bag_id: BagId<Test>) -> Self {
+        Self { bag_id, ..self }
+    }
+
+    pub fn with_data_object_id(self, data_object_id: u64) -> Self {
+        Self {
+            data_object_id,
+            ..self
+        }
+    }
+
+    pub fn with_parts(self, parts: BTreeSet<u64>) -> Self {
+        Self { parts, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_parts =
+            Storage::data_object_parts_by_bucket(self.data_object_id, self.storage_bucket_id);
+
+        let actual_result = Storage::accept_pending_data_object_parts(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.storage_bucket_id,
+            self.bag_id.clone(),
+            self.data_object_id,
+            self.parts.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_parts =
+            Storage::data_object_parts_by_bucket(self.data_object_id, self.storage_bucket_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_parts,
+                old_parts
+                    .union(&self.parts)
+                    .copied()
+                    .collect::<BTreeSet<_>>()
+            );
+        } else {
+            assert_eq!(new_parts, old_parts);
+        }
+    }
+}
+
+pub struct RespondToStorageChallengeFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    storage_bucket_id: u64,
+    chunk: Vec<u8>,
+    proof: Vec<ContentHash>,
+}
+
+impl RespondToStorageChallengeFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID),
+            worker_id: DEFAULT_WORKER_ID,
+            storage_bucket_id: Default::default(),
+            chunk: Vec::new(),
+            proof: Vec::new(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_worker_id(self, worker_id: u64) -> Self {
+        Self { worker_id, ..self }
+    }
+
+    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
+        Self {
+            storage_bucket_id,
+            ..self
+        }
+    }
+
+    pub fn with_chunk(self, chunk: Vec<u8>) -> Self {
+        Self { chunk, ..self }
+    }
+
+    pub fn with_proof(self, proof: Vec<ContentHash>) -> Self {
+        Self { proof, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_challenge = Storage::storage_challenge(&self.storage_bucket_id);
+
+        let actual_result = Storage::respond_to_storage_challenge(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.storage_bucket_id,
+            self.chunk.clone(),
+            self.proof.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(Storage::storage_challenge(&self.storage_bucket_id).is_none());
+        } else {
+            assert_eq!(
+                Storage::storage_challenge(&self.storage_bucket_id),
+                old_challenge
+            );
+        }
+    }
+}
+
 pub struct CancelStorageBucketInvitationFixture {
     origin: RawOrigin<u64>,
     storage_bucket_id: u64,
@@ -648,6 +839,70 @@ impl MoveDataObjectsFixture {
     }
 }
 
+pub struct MoveBagDataObjectsFixture {
+    origin: RawOrigin<u64>,
+    src_bag_id: BagId<Test>,
+    dest_bag_id: BagId<Test>,
+    data_object_ids: BTreeSet<u64>,
+}
+
+impl MoveBagDataObjectsFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            src_bag_id: DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID).into(),
+            dest_bag_id: DynamicBagId::<Test>::Channel(DEFAULT_CHANNEL_ID).into(),
+            data_object_ids: BTreeSet::new(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_src_bag_id(self, src_bag_id: BagId<Test>) -> Self {
+        Self { src_bag_id, ..self }
+    }
+
+    pub fn with_dest_bag_id(self, dest_bag_id: BagId<Test>) -> Self {
+        Self {
+            dest_bag_id,
+            ..self
+        }
+    }
+
+    pub fn with_data_object_ids(self, data_object_ids: BTreeSet<u64>) -> Self {
+        Self {
+            data_object_ids,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Storage::move_bag_data_objects(
+            self.origin.clone().into(),
+            self.src_bag_id.clone(),
+            self.dest_bag_id.clone(),
+            self.data_object_ids.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            for data_object_id in self.data_object_ids.iter() {
+                assert!(!<crate::DataObjectsById<Test>>::contains_key(
+                    &self.src_bag_id,
+                    data_object_id
+                ));
+                assert!(<crate::DataObjectsById<Test>>::contains_key(
+                    &self.dest_bag_id,
+                    data_object_id
+                ));
+            }
+        }
+    }
+}
+
 pub struct DeleteDataObjectsFixture {
     deletion_prize_account_id: u64,
     bag_id: BagId<Test>,
@@ -692,18 +947,20 @@ impl DeleteDataObjectsFixture {
     }
 }
 
-pub struct UpdateStorageBucketStatusFixture {
+pub struct UploadWorkingGroupDataObjectsFixture {
     origin: RawOrigin<u64>,
-    storage_bucket_id: u64,
-    new_status: bool,
+    working_group: WorkingGroup,
+    object_creation_list: Vec<DataObjectCreationParameters>,
+    expected_data_size_fee: u64,
 }
 
-impl UpdateStorageBucketStatusFixture {
+impl UploadWorkingGroupDataObjectsFixture {
     pub fn default() -> Self {
         Self {
-            origin: RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID),
-            storage_bucket_id: Default::default(),
-            new_status: false,
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            working_group: WorkingGroup::Storage,
+            object_creation_list: Default::default(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
         }
     }
 
@@ -711,46 +968,61 @@ impl UpdateStorageBucketStatusFixture {
         Self { origin, ..self }
     }
 
-    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
+    pub fn with_working_group(self, working_group: WorkingGroup) -> Self {
         Self {
-            storage_bucket_id,
+            working_group,
             ..self
         }
     }
 
-    pub fn with_new_status(self, new_status: bool) -> Self {
-        Self { new_status, ..self }
+    pub fn with_object_creation_list(
+        self,
+        object_creation_list: Vec<DataObjectCreationParameters>,
+    ) -> Self {
+        Self {
+            object_creation_list,
+            ..self
+        }
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
-        let actual_result = Storage::update_storage_bucket_status(
+        let bag_id: BagId<Test> = StaticBagId::WorkingGroup(self.working_group).into();
+        let old_objects_number = Storage::bag(&bag_id).objects_number;
+
+        let actual_result = Storage::upload_working_group_data_objects(
             self.origin.clone().into(),
-            self.storage_bucket_id,
-            self.new_status,
+            self.working_group,
+            self.object_creation_list.clone(),
+            self.expected_data_size_fee,
         );
 
         assert_eq!(actual_result, expected_result);
 
-        if actual_result.is_ok() {
-            let bucket = Storage::storage_bucket_by_id(self.storage_bucket_id);
+        let new_objects_number = Storage::bag(&bag_id).objects_number;
 
-            assert_eq!(bucket.accepting_new_bags, self.new_status);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_objects_number,
+                old_objects_number + self.object_creation_list.len() as u64
+            );
+        } else {
+            assert_eq!(new_objects_number, old_objects_number);
         }
     }
 }
 
-pub struct UpdateBlacklistFixture {
+pub struct DeleteWorkingGroupDataObjectsFixture {
     origin: RawOrigin<u64>,
-    remove_hashes: BTreeSet<Cid>,
-    add_hashes: BTreeSet<Cid>,
+    working_group: WorkingGroup,
+    data_object_ids: BTreeSet<u64>,
 }
 
-impl UpdateBlacklistFixture {
+impl DeleteWorkingGroupDataObjectsFixture {
     pub fn default() -> Self {
         Self {
             origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
-            remove_hashes: BTreeSet::new(),
-            add_hashes: BTreeSet::new(),
+            working_group: WorkingGroup::Storage,
+            data_object_ids: Default::default(),
         }
     }
 
@@ -758,69 +1030,309 @@ impl UpdateBlacklistFixture {
         Self { origin, ..self }
     }
 
-    pub fn with_add_hashes(self, add_hashes: BTreeSet<Cid>) -> Self {
-        Self { add_hashes, ..self }
+    pub fn with_working_group(self, working_group: WorkingGroup) -> Self {
+        Self {
+            working_group,
+            ..self
+        }
     }
 
-    pub fn with_remove_hashes(self, remove_hashes: BTreeSet<Cid>) -> Self {
+    pub fn with_data_object_ids(self, data_object_ids: BTreeSet<u64>) -> Self {
         Self {
-            remove_hashes,
+            data_object_ids,
             ..self
         }
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
-        let actual_result = Storage::update_blacklist(
+        let actual_result = Storage::delete_working_group_data_objects(
             self.origin.clone().into(),
-            self.remove_hashes.clone(),
-            self.add_hashes.clone(),
+            self.working_group,
+            self.data_object_ids.clone(),
         );
 
         assert_eq!(actual_result, expected_result);
     }
 }
 
-pub struct DeleteDynamicBagFixture {
-    bag_id: DynamicBagId<Test>,
-    deletion_account_id: u64,
+pub struct ExecuteStorageAdministrativeActionFixture {
+    origin: RawOrigin<u64>,
+    action: StorageAdministrativeAction<Test>,
 }
 
-impl DeleteDynamicBagFixture {
+impl ExecuteStorageAdministrativeActionFixture {
     pub fn default() -> Self {
         Self {
-            bag_id: Default::default(),
-            deletion_account_id: DEFAULT_ACCOUNT_ID,
+            origin: RawOrigin::Root,
+            action: StorageAdministrativeActionRecord::CreateDynamicBag(
+                DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID),
+                None,
+            ),
         }
     }
 
-    pub fn with_deletion_account_id(self, deletion_account_id: u64) -> Self {
-        Self {
-            deletion_account_id,
-            ..self
-        }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
     }
 
-    pub fn with_bag_id(self, bag_id: DynamicBagId<Test>) -> Self {
-        Self { bag_id, ..self }
+    pub fn with_action(self, action: StorageAdministrativeAction<Test>) -> Self {
+        Self { action, ..self }
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
-        let actual_result =
-            Storage::delete_dynamic_bag(self.deletion_account_id, self.bag_id.clone());
+        let actual_result = Storage::execute_storage_administrative_action(
+            self.origin.clone().into(),
+            self.action.clone(),
+        );
 
         assert_eq!(actual_result, expected_result);
     }
 }
 
-pub struct CanDeleteDynamicBagWithObjectsFixture {
-    bag_id: DynamicBagId<Test>,
+pub struct UpdateStorageBucketStatusFixture {
+    origin: RawOrigin<u64>,
+    storage_bucket_id: u64,
+    new_status: bool,
 }
 
-impl CanDeleteDynamicBagWithObjectsFixture {
+impl UpdateStorageBucketStatusFixture {
     pub fn default() -> Self {
         Self {
-            bag_id: Default::default(),
-        }
+            origin: RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            storage_bucket_id: Default::default(),
+            new_status: false,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
+        Self {
+            storage_bucket_id,
+            ..self
+        }
+    }
+
+    pub fn with_new_status(self, new_status: bool) -> Self {
+        Self { new_status, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Storage::update_storage_bucket_status(
+            self.origin.clone().into(),
+            self.storage_bucket_id,
+            self.new_status,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let bucket = Storage::storage_bucket_by_id(self.storage_bucket_id);
+
+            assert_eq!(bucket.accepting_new_bags, self.new_status);
+        }
+    }
+}
+
+pub struct UpdateBlacklistFixture {
+    origin: RawOrigin<u64>,
+    remove_hashes: BTreeSet<Cid>,
+    add_hashes: BTreeSet<Cid>,
+}
+
+impl UpdateBlacklistFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            remove_hashes: BTreeSet::new(),
+            add_hashes: BTreeSet::new(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_add_hashes(self, add_hashes: BTreeSet<Cid>) -> Self {
+        Self { add_hashes, ..self }
+    }
+
+    pub fn with_remove_hashes(self, remove_hashes: BTreeSet<Cid>) -> Self {
+        Self {
+            remove_hashes,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Storage::update_blacklist(
+            self.origin.clone().into(),
+            self.remove_hashes.clone(),
+            self.add_hashes.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+    }
+}
+
+pub struct SetBlacklistEntryFixture {
+    origin: RawOrigin<u64>,
+    cid: Cid,
+    entry: BlacklistEntry<u64>,
+}
+
+impl SetBlacklistEntryFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            cid: Default::default(),
+            entry: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_cid(self, cid: Cid) -> Self {
+        Self { cid, ..self }
+    }
+
+    pub fn with_entry(self, entry: BlacklistEntry<u64>) -> Self {
+        Self { entry, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_entry = Storage::blacklist(&self.cid);
+        let old_blacklist_size = Storage::current_blacklist_size();
+
+        let actual_result = Storage::set_blacklist_entry(
+            self.origin.clone().into(),
+            self.cid.clone(),
+            self.entry.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(Storage::blacklist(&self.cid), self.entry);
+        } else {
+            assert_eq!(Storage::blacklist(&self.cid), old_entry);
+            assert_eq!(Storage::current_blacklist_size(), old_blacklist_size);
+        }
+    }
+}
+
+pub struct PurgeBlacklistedDataObjectsFixture {
+    origin: RawOrigin<u64>,
+    cid: Cid,
+    bag_id: BagId<Test>,
+    data_object_ids: BTreeSet<u64>,
+}
+
+impl PurgeBlacklistedDataObjectsFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            cid: Default::default(),
+            bag_id: Default::default(),
+            data_object_ids: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_cid(self, cid: Cid) -> Self {
+        Self { cid, ..self }
+    }
+
+    pub fn with_bag_id(self, bag_id: BagId<Test>) -> Self {
+        Self { bag_id, ..self }
+    }
+
+    pub fn with_data_object_ids(self, data_object_ids: BTreeSet<u64>) -> Self {
+        Self {
+            data_object_ids,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bag = Storage::bag(&self.bag_id);
+
+        let actual_result = Storage::purge_blacklisted_data_objects(
+            self.origin.clone().into(),
+            self.cid.clone(),
+            self.bag_id.clone(),
+            self.data_object_ids.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bag = Storage::bag(&self.bag_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_bag.objects_number,
+                old_bag.objects_number - self.data_object_ids.len() as u64
+            );
+
+            for data_object_id in self.data_object_ids.iter() {
+                assert!(!<crate::DataObjectsById<Test>>::contains_key(
+                    &self.bag_id,
+                    data_object_id
+                ));
+            }
+        } else {
+            assert_eq!(new_bag, old_bag);
+        }
+    }
+}
+
+pub struct DeleteDynamicBagFixture {
+    bag_id: DynamicBagId<Test>,
+    deletion_account_id: u64,
+}
+
+impl DeleteDynamicBagFixture {
+    pub fn default() -> Self {
+        Self {
+            bag_id: Default::default(),
+            deletion_account_id: DEFAULT_ACCOUNT_ID,
+        }
+    }
+
+    pub fn with_deletion_account_id(self, deletion_account_id: u64) -> Self {
+        Self {
+            deletion_account_id,
+            ..self
+        }
+    }
+
+    pub fn with_bag_id(self, bag_id: DynamicBagId<Test>) -> Self {
+        Self { bag_id, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            Storage::delete_dynamic_bag(self.deletion_account_id, self.bag_id.clone());
+
+        assert_eq!(actual_result, expected_result);
+    }
+}
+
+pub struct CanDeleteDynamicBagWithObjectsFixture {
+    bag_id: DynamicBagId<Test>,
+}
+
+impl CanDeleteDynamicBagWithObjectsFixture {
+    pub fn default() -> Self {
+        Self {
+            bag_id: Default::default(),
+        }
     }
 
     pub fn with_bag_id(self, bag_id: DynamicBagId<Test>) -> Self {
@@ -834,16 +1346,404 @@ impl CanDeleteDynamicBagWithObjectsFixture {
     }
 }
 
-pub struct DeleteStorageBucketFixture {
+pub struct DeleteStorageBucketFixture {
+    origin: RawOrigin<u64>,
+    storage_bucket_id: u64,
+}
+
+impl DeleteStorageBucketFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DEFAULT_ACCOUNT_ID),
+            storage_bucket_id: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
+        Self {
+            storage_bucket_id,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            Storage::delete_storage_bucket(self.origin.clone().into(), self.storage_bucket_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(!<crate::StorageBucketById<Test>>::contains_key(
+                self.storage_bucket_id
+            ));
+        }
+    }
+}
+
+pub struct StartStorageBucketDrainingFixture {
+    origin: RawOrigin<u64>,
+    storage_bucket_id: u64,
+}
+
+impl StartStorageBucketDrainingFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            storage_bucket_id: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
+        Self {
+            storage_bucket_id,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Storage::start_storage_bucket_draining(
+            self.origin.clone().into(),
+            self.storage_bucket_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let bucket = Storage::storage_bucket_by_id(self.storage_bucket_id);
+
+            assert!(!bucket.accepting_new_bags);
+            assert!(<crate::DrainingStorageBuckets<Test>>::contains_key(
+                self.storage_bucket_id
+            ));
+        }
+    }
+}
+
+pub struct CancelStorageBucketDrainingFixture {
+    origin: RawOrigin<u64>,
+    storage_bucket_id: u64,
+}
+
+impl CancelStorageBucketDrainingFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            storage_bucket_id: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
+        Self {
+            storage_bucket_id,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Storage::cancel_storage_bucket_draining(
+            self.origin.clone().into(),
+            self.storage_bucket_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(!<crate::DrainingStorageBuckets<Test>>::contains_key(
+                self.storage_bucket_id
+            ));
+        }
+    }
+}
+
+pub struct RemoveStorageBucketOperatorFixture {
+    origin: RawOrigin<u64>,
+    storage_bucket_id: u64,
+}
+
+impl RemoveStorageBucketOperatorFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            storage_bucket_id: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
+        Self {
+            storage_bucket_id,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bucket = Storage::storage_bucket_by_id(self.storage_bucket_id);
+
+        let actual_result = Storage::remove_storage_bucket_operator(
+            self.origin.clone().into(),
+            self.storage_bucket_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bucket = Storage::storage_bucket_by_id(self.storage_bucket_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_bucket.operator_status,
+                StorageBucketOperatorStatus::Missing
+            );
+        } else {
+            assert_eq!(old_bucket, new_bucket);
+        }
+    }
+}
+
+pub struct UpdateDataObjectPerMegabyteFeeFixture {
+    origin: RawOrigin<u64>,
+    new_fee: u64,
+}
+
+impl UpdateDataObjectPerMegabyteFeeFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            new_fee: 0,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_new_fee(self, new_fee: u64) -> Self {
+        Self { new_fee, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_fee = Storage::data_object_per_mega_byte_fee();
+
+        let actual_result = Storage::update_data_size_fee(self.origin.clone().into(), self.new_fee);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(Storage::data_object_per_mega_byte_fee(), self.new_fee);
+        } else {
+            assert_eq!(old_fee, Storage::data_object_per_mega_byte_fee());
+        }
+    }
+}
+
+pub struct UpdateDataSizeFeeScheduleFixture {
+    origin: RawOrigin<u64>,
+    category: DataSizeFeeCategory,
+    schedule: Option<DataSizeFeeSchedule<u64>>,
+}
+
+impl UpdateDataSizeFeeScheduleFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            category: Default::default(),
+            schedule: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_category(self, category: DataSizeFeeCategory) -> Self {
+        Self { category, ..self }
+    }
+
+    pub fn with_schedule(self, schedule: Option<DataSizeFeeSchedule<u64>>) -> Self {
+        Self { schedule, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_schedule = Storage::data_size_fee_schedule(&self.category);
+
+        let actual_result = Storage::update_data_size_fee_schedule(
+            self.origin.clone().into(),
+            self.category.clone(),
+            self.schedule.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                Storage::data_size_fee_schedule(&self.category),
+                self.schedule
+            );
+        } else {
+            assert_eq!(
+                Storage::data_size_fee_schedule(&self.category),
+                old_schedule
+            );
+        }
+    }
+}
+
+pub struct UpdateStorageRentFixture {
+    origin: RawOrigin<u64>,
+    new_rent: u64,
+}
+
+impl UpdateStorageRentFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            new_rent: 0,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_new_rent(self, new_rent: u64) -> Self {
+        Self { new_rent, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_rent = Storage::data_object_per_mega_byte_per_block_rent();
+
+        let actual_result = Storage::update_storage_rent(self.origin.clone().into(), self.new_rent);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                Storage::data_object_per_mega_byte_per_block_rent(),
+                self.new_rent
+            );
+        } else {
+            assert_eq!(
+                old_rent,
+                Storage::data_object_per_mega_byte_per_block_rent()
+            );
+        }
+    }
+}
+
+pub struct UpdateDataObjectReferenceFeeFixture {
+    origin: RawOrigin<u64>,
+    new_fee: Option<u64>,
+}
+
+impl UpdateDataObjectReferenceFeeFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            new_fee: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_new_fee(self, new_fee: Option<u64>) -> Self {
+        Self { new_fee, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_fee = Storage::data_object_reference_fee();
+
+        let actual_result =
+            Storage::update_data_object_reference_fee(self.origin.clone().into(), self.new_fee);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(Storage::data_object_reference_fee(), self.new_fee);
+        } else {
+            assert_eq!(old_fee, Storage::data_object_reference_fee());
+        }
+    }
+}
+
+pub struct UpdateDynamicBagQuotaFixture {
+    origin: RawOrigin<u64>,
+    dynamic_bag_type: DynamicBagType,
+    quota: Option<BagQuota>,
+}
+
+impl UpdateDynamicBagQuotaFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            dynamic_bag_type: DynamicBagType::Member,
+            quota: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_dynamic_bag_type(self, dynamic_bag_type: DynamicBagType) -> Self {
+        Self {
+            dynamic_bag_type,
+            ..self
+        }
+    }
+
+    pub fn with_quota(self, quota: Option<BagQuota>) -> Self {
+        Self { quota, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_quota = Storage::dynamic_bag_quota(self.dynamic_bag_type);
+
+        let actual_result = Storage::update_dynamic_bag_quota(
+            self.origin.clone().into(),
+            self.dynamic_bag_type,
+            self.quota,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                Storage::dynamic_bag_quota(self.dynamic_bag_type),
+                self.quota
+            );
+        } else {
+            assert_eq!(Storage::dynamic_bag_quota(self.dynamic_bag_type), old_quota);
+        }
+    }
+}
+
+pub struct SetBagQuotaOverrideFixture {
     origin: RawOrigin<u64>,
-    storage_bucket_id: u64,
+    bag_id: BagId<Test>,
+    quota: Option<BagQuota>,
 }
 
-impl DeleteStorageBucketFixture {
+impl SetBagQuotaOverrideFixture {
     pub fn default() -> Self {
         Self {
-            origin: RawOrigin::Signed(DEFAULT_ACCOUNT_ID),
-            storage_bucket_id: Default::default(),
+            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
+            bag_id: Default::default(),
+            quota: None,
         }
     }
 
@@ -851,37 +1751,43 @@ impl DeleteStorageBucketFixture {
         Self { origin, ..self }
     }
 
-    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
-        Self {
-            storage_bucket_id,
-            ..self
-        }
+    pub fn with_bag_id(self, bag_id: BagId<Test>) -> Self {
+        Self { bag_id, ..self }
+    }
+
+    pub fn with_quota(self, quota: Option<BagQuota>) -> Self {
+        Self { quota, ..self }
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
-        let actual_result =
-            Storage::delete_storage_bucket(self.origin.clone().into(), self.storage_bucket_id);
+        let old_quota = Storage::bag_quota_override(&self.bag_id);
+
+        let actual_result = Storage::set_bag_quota_override(
+            self.origin.clone().into(),
+            self.bag_id.clone(),
+            self.quota,
+        );
 
         assert_eq!(actual_result, expected_result);
 
         if actual_result.is_ok() {
-            assert!(!<crate::StorageBucketById<Test>>::contains_key(
-                self.storage_bucket_id
-            ));
+            assert_eq!(Storage::bag_quota_override(&self.bag_id), self.quota);
+        } else {
+            assert_eq!(Storage::bag_quota_override(&self.bag_id), old_quota);
         }
     }
 }
 
-pub struct RemoveStorageBucketOperatorFixture {
+pub struct UpdateStorageOperatorRewardRatesFixture {
     origin: RawOrigin<u64>,
-    storage_bucket_id: u64,
+    rates: OperatorRewardRates<u64>,
 }
 
-impl RemoveStorageBucketOperatorFixture {
+impl UpdateStorageOperatorRewardRatesFixture {
     pub fn default() -> Self {
         Self {
             origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
-            storage_bucket_id: Default::default(),
+            rates: Default::default(),
         }
     }
 
@@ -889,45 +1795,38 @@ impl RemoveStorageBucketOperatorFixture {
         Self { origin, ..self }
     }
 
-    pub fn with_storage_bucket_id(self, storage_bucket_id: u64) -> Self {
-        Self {
-            storage_bucket_id,
-            ..self
-        }
+    pub fn with_rates(self, rates: OperatorRewardRates<u64>) -> Self {
+        Self { rates, ..self }
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
-        let old_bucket = Storage::storage_bucket_by_id(self.storage_bucket_id);
+        let old_rates = Storage::storage_operator_reward_rates();
 
-        let actual_result = Storage::remove_storage_bucket_operator(
-            self.origin.clone().into(),
-            self.storage_bucket_id,
-        );
+        let actual_result =
+            Storage::update_storage_operator_reward_rates(self.origin.clone().into(), self.rates);
 
         assert_eq!(actual_result, expected_result);
 
-        let new_bucket = Storage::storage_bucket_by_id(self.storage_bucket_id);
         if actual_result.is_ok() {
-            assert_eq!(
-                new_bucket.operator_status,
-                StorageBucketOperatorStatus::Missing
-            );
+            assert_eq!(Storage::storage_operator_reward_rates(), self.rates);
         } else {
-            assert_eq!(old_bucket, new_bucket);
+            assert_eq!(Storage::storage_operator_reward_rates(), old_rates);
         }
     }
 }
 
-pub struct UpdateDataObjectPerMegabyteFeeFixture {
+pub struct TopUpBagRentFixture {
     origin: RawOrigin<u64>,
-    new_fee: u64,
+    dynamic_bag_id: DynamicBagId<Test>,
+    blocks: u64,
 }
 
-impl UpdateDataObjectPerMegabyteFeeFixture {
+impl TopUpBagRentFixture {
     pub fn default() -> Self {
         Self {
-            origin: RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID),
-            new_fee: 0,
+            origin: RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            dynamic_bag_id: DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID),
+            blocks: 0,
         }
     }
 
@@ -935,21 +1834,43 @@ impl UpdateDataObjectPerMegabyteFeeFixture {
         Self { origin, ..self }
     }
 
-    pub fn with_new_fee(self, new_fee: u64) -> Self {
-        Self { new_fee, ..self }
+    pub fn with_dynamic_bag_id(self, dynamic_bag_id: DynamicBagId<Test>) -> Self {
+        Self {
+            dynamic_bag_id,
+            ..self
+        }
+    }
+
+    pub fn with_blocks(self, blocks: u64) -> Self {
+        Self { blocks, ..self }
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
-        let old_fee = Storage::data_object_per_mega_byte_fee();
+        let old_paid_through = Storage::bag_rent_paid_through(&self.dynamic_bag_id);
 
-        let actual_result = Storage::update_data_size_fee(self.origin.clone().into(), self.new_fee);
+        let actual_result = Storage::top_up_bag_rent(
+            self.origin.clone().into(),
+            self.dynamic_bag_id.clone(),
+            self.blocks,
+        );
 
         assert_eq!(actual_result, expected_result);
 
         if actual_result.is_ok() {
-            assert_eq!(Storage::data_object_per_mega_byte_fee(), self.new_fee);
+            let expected_paid_through = old_paid_through
+                .unwrap_or_default()
+                .max(System::block_number())
+                + self.blocks;
+
+            assert_eq!(
+                Storage::bag_rent_paid_through(&self.dynamic_bag_id),
+                Some(expected_paid_through)
+            );
         } else {
-            assert_eq!(old_fee, Storage::data_object_per_mega_byte_fee());
+            assert_eq!(
+                Storage::bag_rent_paid_through(&self.dynamic_bag_id),
+                old_paid_through
+            );
         }
     }
 }
@@ -1807,6 +2728,199 @@ impl UpdateFamiliesInDynamicBagCreationPolicyFixture {
     }
 }
 
+pub struct UpdateRegionsInDynamicBagCreationPolicyFixture {
+    origin: RawOrigin<u64>,
+    dynamic_bag_type: DynamicBagType,
+    regions: BTreeSet<RegionTag>,
+}
+
+impl UpdateRegionsInDynamicBagCreationPolicyFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID),
+            dynamic_bag_type: Default::default(),
+            regions: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_regions(self, regions: BTreeSet<RegionTag>) -> Self {
+        Self { regions, ..self }
+    }
+
+    pub fn with_dynamic_bag_type(self, dynamic_bag_type: DynamicBagType) -> Self {
+        Self {
+            dynamic_bag_type,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_policy = Storage::get_dynamic_bag_creation_policy(self.dynamic_bag_type);
+
+        let actual_result = Storage::update_regions_in_dynamic_bag_creation_policy(
+            self.origin.clone().into(),
+            self.dynamic_bag_type,
+            self.regions.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_policy = Storage::get_dynamic_bag_creation_policy(self.dynamic_bag_type);
+        assert_eq!(old_policy.families, new_policy.families);
+
+        if actual_result.is_ok() {
+            assert_eq!(new_policy.regions, self.regions);
+        } else {
+            assert_eq!(old_policy, new_policy);
+        }
+    }
+}
+
+pub struct SetDistributionBucketFamilyRegionFixture {
+    origin: RawOrigin<u64>,
+    family_id: u64,
+    region: Option<RegionTag>,
+}
+
+impl SetDistributionBucketFamilyRegionFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID),
+            family_id: Default::default(),
+            region: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_family_id(self, family_id: u64) -> Self {
+        Self { family_id, ..self }
+    }
+
+    pub fn with_region(self, region: Option<RegionTag>) -> Self {
+        Self { region, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_family = Storage::distribution_bucket_family_by_id(self.family_id);
+
+        let actual_result = Storage::set_distribution_bucket_family_region(
+            self.origin.clone().into(),
+            self.family_id,
+            self.region.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_family = Storage::distribution_bucket_family_by_id(self.family_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(new_family.region, self.region);
+        } else {
+            assert_eq!(old_family, new_family);
+        }
+    }
+}
+
+pub struct SetDistributionBucketRegionFixture {
+    origin: RawOrigin<u64>,
+    bucket_id: DistributionBucketId<Test>,
+    region: Option<RegionTag>,
+}
+
+impl SetDistributionBucketRegionFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID),
+            bucket_id: Default::default(),
+            region: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_bucket_id(self, bucket_id: DistributionBucketId<Test>) -> Self {
+        Self { bucket_id, ..self }
+    }
+
+    pub fn with_region(self, region: Option<RegionTag>) -> Self {
+        Self { region, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bucket = Storage::distribution_bucket_by_family_id_by_index(
+            self.bucket_id.distribution_bucket_family_id,
+            self.bucket_id.distribution_bucket_index,
+        );
+
+        let actual_result = Storage::set_distribution_bucket_region(
+            self.origin.clone().into(),
+            self.bucket_id.clone(),
+            self.region.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bucket = Storage::distribution_bucket_by_family_id_by_index(
+            self.bucket_id.distribution_bucket_family_id,
+            self.bucket_id.distribution_bucket_index,
+        );
+
+        if actual_result.is_ok() {
+            assert_eq!(new_bucket.region, self.region);
+        } else {
+            assert_eq!(old_bucket, new_bucket);
+        }
+    }
+}
+
+pub struct UpdateDistributionOperatorRewardRatesFixture {
+    origin: RawOrigin<u64>,
+    rates: OperatorRewardRates<u64>,
+}
+
+impl UpdateDistributionOperatorRewardRatesFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID),
+            rates: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_rates(self, rates: OperatorRewardRates<u64>) -> Self {
+        Self { rates, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_rates = Storage::distribution_operator_reward_rates();
+
+        let actual_result = Storage::update_distribution_operator_reward_rates(
+            self.origin.clone().into(),
+            self.rates,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(Storage::distribution_operator_reward_rates(), self.rates);
+        } else {
+            assert_eq!(Storage::distribution_operator_reward_rates(), old_rates);
+        }
+    }
+}
+
 pub struct InviteDistributionBucketOperatorFixture {
     origin: RawOrigin<u64>,
     operator_worker_id: u64,
@@ -2044,6 +3158,79 @@ impl SetDistributionBucketMetadataFixture {
     }
 }
 
+pub struct ReportDistributionOperatorHealthFixture {
+    origin: RawOrigin<u64>,
+    bucket_id: DistributionBucketId<Test>,
+    worker_id: u64,
+    bytes_served: u64,
+    uptime: u64,
+}
+
+impl ReportDistributionOperatorHealthFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID),
+            bucket_id: Default::default(),
+            worker_id: DEFAULT_DISTRIBUTION_PROVIDER_ID,
+            bytes_served: Default::default(),
+            uptime: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_bucket_id(self, bucket_id: DistributionBucketId<Test>) -> Self {
+        Self { bucket_id, ..self }
+    }
+
+    pub fn with_worker_id(self, worker_id: u64) -> Self {
+        Self { worker_id, ..self }
+    }
+
+    pub fn with_bytes_served(self, bytes_served: u64) -> Self {
+        Self {
+            bytes_served,
+            ..self
+        }
+    }
+
+    pub fn with_uptime(self, uptime: u64) -> Self {
+        Self { uptime, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_health = Storage::distribution_operator_health(&self.bucket_id, &self.worker_id);
+
+        let actual_result = Storage::report_distribution_operator_health(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.bucket_id.clone(),
+            self.bytes_served,
+            self.uptime,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_health = Storage::distribution_operator_health(&self.bucket_id, &self.worker_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_health,
+                Some(DistributionOperatorHealthRecord {
+                    last_beacon_at: Some(System::block_number()),
+                    bytes_served: self.bytes_served,
+                    uptime: self.uptime,
+                    missed_beacons: 0,
+                })
+            );
+        } else {
+            assert_eq!(old_health, new_health);
+        }
+    }
+}
+
 pub struct RemoveDistributionBucketOperatorFixture {
     origin: RawOrigin<u64>,
     bucket_index: u64,
@@ -1,5 +1,7 @@
 #![cfg(test)]
 
+use common::storage::StorageObjectOwner;
+use common::working_group::WorkingGroup;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 pub use frame_support::traits::LockIdentifier;
 use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
@@ -10,6 +12,9 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     ModuleId, Perbill,
 };
+use staking_handler::LockComparator;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
 
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -33,6 +38,8 @@ impl_outer_event! {
         storage<T>,
         frame_system<T>,
         membership_mod<T>,
+        working_group Instance2 <T>,
+        working_group Instance9 <T>,
     }
 }
 
@@ -60,11 +67,34 @@ parameter_types! {
         crate::StorageBucketsPerBagValueConstraint {min: 3, max_min_diff: 7};
     pub const InitialStorageBucketsNumberForDynamicBag: u64 = 3;
     pub const MaxRandomIterationNumber: u64 = 3;
+    pub const MaxStorageBucketPickerCandidates: u64 = 10;
+    pub const MaxDistributionBucketPickerCandidates: u64 = 10;
     pub const DefaultMemberDynamicBagNumberOfStorageBuckets: u64 = 3;
     pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u64 = 4;
     pub const DistributionBucketsPerBagValueConstraint: crate::DistributionBucketsPerBagValueConstraint =
         crate::StorageBucketsPerBagValueConstraint {min: 3, max_min_diff: 7};
     pub const MaxDataObjectSize: u64 = 400;
+    pub const MaxReReplicatedBagsPerBlock: u64 = 2;
+    pub const StorageChallengePeriod: u64 = 10;
+    pub const MaxStorageChallengesPerBlock: u64 = 2;
+    pub const StorageChallengeResponsePeriod: u64 = 5;
+    pub const ChallengeChunkSize: u64 = 4;
+    pub const MissedStorageChallengesSlashingThreshold: u64 = 2;
+    pub const MissedStorageChallengesSlashingPenalty: u64 = 100;
+    pub const InitialBagRentPeriod: u64 = 20;
+    pub const StorageRentGracePeriod: u64 = 10;
+    pub const MaxGarbageCollectedDataObjectsPerBlock: u64 = 2;
+    pub const MaxBagRentExpirationsPerBlock: u64 = 2;
+    pub const MaxBlacklistExpirationsPerBlock: u64 = 2;
+    pub const MaxRegionTagLength: u32 = 8;
+    pub const OperatorRewardPeriod: u64 = 10;
+    pub const MaxOperatorRewardBucketsPerBlock: u64 = 20;
+    pub const MaxDataObjectPartsNumber: u64 = 10;
+    pub const MaxDrainedBagsPerBlock: u64 = 2;
+    pub const DistributionHealthBeaconPeriod: u64 = 10;
+    pub const MaxDistributionHealthBeaconsPerBlock: u64 = 2;
+    pub const MissedDistributionHealthBeaconsThreshold: u64 = 2;
+    pub const MaxHealthBeaconBucketsPerBlock: u64 = 20;
 }
 
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: u64 = 100001;
@@ -77,6 +107,33 @@ pub const DEFAULT_DISTRIBUTION_PROVIDER_ID: u64 = 12;
 pub const ANOTHER_DISTRIBUTION_PROVIDER_ID: u64 = 13;
 pub const INITIAL_BALANCE: u64 = 10_000;
 pub const BAG_DELETION_PRIZE_VALUE: u64 = 100;
+pub const DEFAULT_WORKING_GROUP_BUDGET: u64 = 10_000;
+
+thread_local! {
+    pub static STORAGE_WG_BUDGET: RefCell<u64> = RefCell::new(DEFAULT_WORKING_GROUP_BUDGET);
+    pub static DISTRIBUTION_WG_BUDGET: RefCell<u64> =
+        RefCell::new(DEFAULT_WORKING_GROUP_BUDGET);
+    pub static REMOVED_DATA_OBJECTS: RefCell<BTreeSet<u64>> = RefCell::new(BTreeSet::new());
+    pub static IN_USE_DATA_OBJECTS: RefCell<BTreeSet<u64>> = RefCell::new(BTreeSet::new());
+}
+
+// Pays the worker reward from the mocked working group budget.
+fn pay_from_mocked_budget(
+    budget: &'static std::thread::LocalKey<RefCell<u64>>,
+    reward: u64,
+) -> DispatchResult {
+    budget.with(|val| {
+        let mut budget = val.borrow_mut();
+
+        if *budget < reward {
+            Err(DispatchError::Other("Insufficient budget"))
+        } else {
+            *budget -= reward;
+
+            Ok(())
+        }
+    })
+}
 pub const VOUCHER_SIZE_LIMIT: u64 = 100;
 pub const VOUCHER_OBJECTS_LIMIT: u64 = 20;
 pub const DEFAULT_STORAGE_BUCKET_SIZE_LIMIT: u64 = 100;
@@ -100,13 +157,38 @@ impl crate::Trait for Test {
     type DefaultChannelDynamicBagNumberOfStorageBuckets =
         DefaultChannelDynamicBagNumberOfStorageBuckets;
     type Randomness = CollectiveFlip;
+    type StorageBucketsSelectionPolicy = crate::StorageBucketPicker<Test>;
     type MaxRandomIterationNumber = MaxRandomIterationNumber;
+    type MaxStorageBucketPickerCandidates = MaxStorageBucketPickerCandidates;
+    type MaxDistributionBucketPickerCandidates = MaxDistributionBucketPickerCandidates;
     type MaxDistributionBucketFamilyNumber = MaxDistributionBucketFamilyNumber;
     type DistributionBucketsPerBagValueConstraint = DistributionBucketsPerBagValueConstraint;
     type MaxNumberOfPendingInvitationsPerDistributionBucket =
         MaxNumberOfPendingInvitationsPerDistributionBucket;
     type MaxDataObjectSize = MaxDataObjectSize;
+    type MaxReReplicatedBagsPerBlock = MaxReReplicatedBagsPerBlock;
+    type StorageChallengePeriod = StorageChallengePeriod;
+    type MaxStorageChallengesPerBlock = MaxStorageChallengesPerBlock;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type ChallengeChunkSize = ChallengeChunkSize;
+    type MissedStorageChallengesSlashingThreshold = MissedStorageChallengesSlashingThreshold;
+    type MissedStorageChallengesSlashingPenalty = MissedStorageChallengesSlashingPenalty;
+    type InitialBagRentPeriod = InitialBagRentPeriod;
+    type StorageRentGracePeriod = StorageRentGracePeriod;
+    type MaxGarbageCollectedDataObjectsPerBlock = MaxGarbageCollectedDataObjectsPerBlock;
+    type MaxBagRentExpirationsPerBlock = MaxBagRentExpirationsPerBlock;
+    type MaxBlacklistExpirationsPerBlock = MaxBlacklistExpirationsPerBlock;
+    type MaxRegionTagLength = MaxRegionTagLength;
+    type OperatorRewardPeriod = OperatorRewardPeriod;
+    type MaxOperatorRewardBucketsPerBlock = MaxOperatorRewardBucketsPerBlock;
+    type MaxDataObjectPartsNumber = MaxDataObjectPartsNumber;
+    type MaxDrainedBagsPerBlock = MaxDrainedBagsPerBlock;
+    type DistributionHealthBeaconPeriod = DistributionHealthBeaconPeriod;
+    type MaxDistributionHealthBeaconsPerBlock = MaxDistributionHealthBeaconsPerBlock;
+    type MissedDistributionHealthBeaconsThreshold = MissedDistributionHealthBeaconsThreshold;
+    type MaxHealthBeaconBucketsPerBlock = MaxHealthBeaconBucketsPerBlock;
     type ContentId = u64;
+    type WeightInfo = ();
 
     fn ensure_storage_working_group_leader_origin(origin: Self::Origin) -> DispatchResult {
         let account_id = ensure_signed(origin)?;
@@ -139,6 +221,24 @@ impl crate::Trait for Test {
         }
     }
 
+    fn slash_storage_worker_stake(worker_id: &u64, _penalty: u64) -> DispatchResult {
+        Self::ensure_storage_worker_exists(worker_id)
+    }
+
+    fn storage_working_group_budget() -> u64 {
+        STORAGE_WG_BUDGET.with(|val| *val.borrow())
+    }
+
+    fn pay_storage_worker_reward(worker_id: &u64, reward: u64) -> DispatchResult {
+        Self::ensure_storage_worker_exists(worker_id)?;
+
+        pay_from_mocked_budget(&STORAGE_WG_BUDGET, reward)
+    }
+
+    fn fund_storage_working_group_budget(amount: u64) {
+        STORAGE_WG_BUDGET.with(|val| *val.borrow_mut() += amount);
+    }
+
     fn ensure_distribution_working_group_leader_origin(origin: Self::Origin) -> DispatchResult {
         let account_id = ensure_signed(origin)?;
 
@@ -171,10 +271,56 @@ impl crate::Trait for Test {
             Ok(())
         }
     }
+
+    fn distribution_working_group_budget() -> u64 {
+        DISTRIBUTION_WG_BUDGET.with(|val| *val.borrow())
+    }
+
+    fn pay_distribution_worker_reward(worker_id: &u64, reward: u64) -> DispatchResult {
+        Self::ensure_distribution_worker_exists(worker_id)?;
+
+        pay_from_mocked_budget(&DISTRIBUTION_WG_BUDGET, reward)
+    }
+
+    fn is_storage_owner_account(owner: &StorageObjectOwner<u64, u64>, account_id: &u64) -> bool {
+        match owner {
+            StorageObjectOwner::Member(member_id) => *account_id == member_id + 1,
+            StorageObjectOwner::Channel(channel_id) => *account_id == channel_id + 1,
+            StorageObjectOwner::Council | StorageObjectOwner::WorkingGroup(_) => false,
+        }
+    }
+
+    fn ensure_working_group_leader_origin(
+        origin: Self::Origin,
+        working_group: WorkingGroup,
+    ) -> DispatchResult {
+        let account_id = ensure_signed(origin)?;
+
+        let leader_account_id = match working_group {
+            WorkingGroup::Storage => STORAGE_WG_LEADER_ACCOUNT_ID,
+            WorkingGroup::Distribution => DISTRIBUTION_WG_LEADER_ACCOUNT_ID,
+            _ => return Err(DispatchError::BadOrigin),
+        };
+
+        if account_id != leader_account_id {
+            Err(DispatchError::BadOrigin)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn on_data_objects_removed(_: &crate::BagId<Self>, data_object_ids: &BTreeSet<u64>) {
+        REMOVED_DATA_OBJECTS.with(|val| val.borrow_mut().extend(data_object_ids.iter().cloned()));
+    }
+
+    fn is_data_object_in_use(_: &crate::BagId<Self>, data_object_id: &u64) -> bool {
+        IN_USE_DATA_OBJECTS.with(|val| val.borrow().contains(data_object_id))
+    }
 }
 
 pub const DEFAULT_MEMBER_ID: u64 = 100;
 pub const DEFAULT_MEMBER_ACCOUNT_ID: u64 = 101;
+pub const DEFAULT_CHANNEL_ID: u64 = 100;
 
 parameter_types! {
     pub const ScreenedMemberMaxInitialBalance: u64 = 5000;
@@ -185,6 +331,169 @@ impl common::MembershipTypes for Test {
     type ActorId = u64;
 }
 
+parameter_types! {
+    pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const MaxDeputyLeads: u32 = 2;
+    pub const LockId: [u8; 8] = [9; 8];
+    pub const MinimumApplicationStake: u32 = 50;
+    pub const LeaderOpeningStake: u32 = 20;
+}
+
+// The storage working group instance alias.
+pub type StorageWorkingGroupInstance = working_group::Instance2;
+
+// The distribution working group instance alias.
+pub type DistributionWorkingGroupInstance = working_group::Instance9;
+
+// Working groups are used only by the benchmarks: the pallet authorization in tests is mocked.
+impl working_group::Trait<StorageWorkingGroupInstance> for Test {
+    type Event = TestEvent;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
+    type StakingAccountValidator = ();
+    type StakingHandler = staking_handler::StakingManager<Self, LockId>;
+    type MemberOriginValidator = ();
+    type MinUnstakingPeriodLimit = ();
+    type RewardPeriod = ();
+    type WeightInfo = Weights;
+    type MinimumApplicationStake = MinimumApplicationStake;
+    type LeaderOpeningStake = LeaderOpeningStake;
+}
+
+impl working_group::Trait<DistributionWorkingGroupInstance> for Test {
+    type Event = TestEvent;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
+    type StakingAccountValidator = ();
+    type StakingHandler = staking_handler::StakingManager<Self, LockId>;
+    type MemberOriginValidator = ();
+    type MinUnstakingPeriodLimit = ();
+    type RewardPeriod = ();
+    type WeightInfo = Weights;
+    type MinimumApplicationStake = MinimumApplicationStake;
+    type LeaderOpeningStake = LeaderOpeningStake;
+}
+
+impl LockComparator<<Test as balances::Trait>::Balance> for Test {
+    fn are_locks_conflicting(
+        _new_lock: &LockIdentifier,
+        _existing_locks: &[LockIdentifier],
+    ) -> bool {
+        false
+    }
+}
+
+impl common::StakingAccountValidator<Test> for () {
+    fn is_member_staking_account(_: &u64, _: &u64) -> bool {
+        unimplemented!()
+    }
+}
+
+impl common::membership::MemberOriginValidator<Origin, u64, u64> for () {
+    fn ensure_member_controller_account_origin(_: Origin, _: u64) -> Result<u64, DispatchError> {
+        unimplemented!()
+    }
+
+    fn is_member_controller_account(_: &u64, _: &u64) -> bool {
+        unimplemented!()
+    }
+}
+
+// Weights info stub
+pub struct Weights;
+impl working_group::WeightInfo for Weights {
+    fn on_initialize_leaving(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn on_initialize_rewarding_with_missing_reward(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn on_initialize_rewarding_with_missing_reward_cant_pay(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn on_initialize_rewarding_without_missing_reward(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn apply_on_opening(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn fill_opening_lead() -> u64 {
+        unimplemented!()
+    }
+
+    fn fill_opening_worker(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn update_role_account() -> u64 {
+        unimplemented!()
+    }
+
+    fn cancel_opening() -> u64 {
+        unimplemented!()
+    }
+
+    fn withdraw_application() -> u64 {
+        unimplemented!()
+    }
+
+    fn slash_stake(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn terminate_role_worker(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn terminate_role_lead(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn increase_stake() -> u64 {
+        unimplemented!()
+    }
+
+    fn decrease_stake() -> u64 {
+        unimplemented!()
+    }
+
+    fn spend_from_budget() -> u64 {
+        unimplemented!()
+    }
+
+    fn update_reward_amount() -> u64 {
+        unimplemented!()
+    }
+
+    fn set_status_text(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn update_reward_account() -> u64 {
+        unimplemented!()
+    }
+
+    fn set_budget() -> u64 {
+        unimplemented!()
+    }
+
+    fn add_opening(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn leave_role(_: u32) -> u64 {
+        unimplemented!()
+    }
+    fn set_deputy_lead() -> u64 {
+        unimplemented!()
+    }
+}
+
 impl pallet_timestamp::Trait for Test {
     type Moment = u64;
     type OnTimestampSet = ();
@@ -5,7 +5,7 @@ pub(crate) mod mocks;
 
 use frame_support::dispatch::DispatchError;
 use frame_support::traits::Currency;
-use frame_support::{StorageDoubleMap, StorageMap, StorageValue};
+use frame_support::{IterableStorageDoubleMap, StorageDoubleMap, StorageMap, StorageValue};
 use frame_system::RawOrigin;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
@@ -15,23 +15,39 @@ use sp_std::iter::{repeat, FromIterator};
 use common::working_group::WorkingGroup;
 
 use crate::{
-    BagId, DataObject, DataObjectCreationParameters, DataObjectStorage, DistributionBucketFamily,
-    DistributionBucketId, DynamicBagCreationPolicy, DynamicBagDeletionPrize, DynamicBagId,
-    DynamicBagType, Error, ModuleAccount, RawEvent, StaticBagId, StorageBucketOperatorStatus,
-    StorageTreasury, UploadParameters, Voucher,
+    content_chunk_hash, content_merkle_proof, content_merkle_root, verify_content_merkle_proof,
+    BagId, BagQuota, BagRentExpirations, BlacklistEntry, BlacklistExpirations,
+    CapacityAwareStorageBucketPicker, Cid, ContentHash, DataObject, DataObjectClass,
+    DataObjectCreationParameters, DataObjectStorage, DataObjectsById, DataSizeFeeCategory,
+    DataSizeFeeSchedule, DistributionBucketFamily, DistributionBucketFamilyRecord,
+    DistributionBucketId, DistributionBucketRecord, DynamicBagCreationPolicy,
+    DynamicBagDeletionPrize, DynamicBagId, DynamicBagType, Error, ExpiredBags, ModuleAccount,
+    OperatorRewardRates, RawEvent, SharedDataObjectRecord, StaticBagId,
+    StorageBucketOperatorStatus, StorageBucketsSelectionPolicy, StorageChallengeRecord,
+    StorageLayoutVersion, StorageTreasury, UploadFeeQuote, UploadParameters, Voucher,
+    VoucherUpdate,
 };
 
 use mocks::{
-    build_test_externalities, Balances, BlacklistSizeLimit, DataObjectDeletionPrize,
-    DefaultChannelDynamicBagNumberOfStorageBuckets, DefaultMemberDynamicBagNumberOfStorageBuckets,
-    InitialStorageBucketsNumberForDynamicBag, MaxDataObjectSize, MaxDistributionBucketFamilyNumber,
-    MaxRandomIterationNumber, Storage, Test, ANOTHER_DISTRIBUTION_PROVIDER_ID,
-    ANOTHER_STORAGE_PROVIDER_ID, BAG_DELETION_PRIZE_VALUE,
-    DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, DEFAULT_DISTRIBUTION_PROVIDER_ID,
+    build_test_externalities, Balances, BlacklistSizeLimit, ChallengeChunkSize,
+    DataObjectDeletionPrize, DefaultChannelDynamicBagNumberOfStorageBuckets,
+    DefaultMemberDynamicBagNumberOfStorageBuckets, DistributionHealthBeaconPeriod,
+    InitialBagRentPeriod, InitialStorageBucketsNumberForDynamicBag, MaxBagRentExpirationsPerBlock,
+    MaxBlacklistExpirationsPerBlock, MaxDataObjectPartsNumber, MaxDataObjectSize,
+    MaxDistributionBucketFamilyNumber, MaxDistributionHealthBeaconsPerBlock,
+    MaxDrainedBagsPerBlock, MaxGarbageCollectedDataObjectsPerBlock, MaxHealthBeaconBucketsPerBlock,
+    MaxOperatorRewardBucketsPerBlock, MaxRandomIterationNumber, MaxReReplicatedBagsPerBlock,
+    MaxRegionTagLength, MaxStorageBucketPickerCandidates, MaxStorageChallengesPerBlock,
+    MissedDistributionHealthBeaconsThreshold, MissedStorageChallengesSlashingPenalty,
+    MissedStorageChallengesSlashingThreshold, OperatorRewardPeriod, Storage,
+    StorageChallengePeriod, StorageChallengeResponsePeriod, StorageRentGracePeriod, Test,
+    ANOTHER_DISTRIBUTION_PROVIDER_ID, ANOTHER_STORAGE_PROVIDER_ID, BAG_DELETION_PRIZE_VALUE,
+    DEFAULT_CHANNEL_ID, DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, DEFAULT_DISTRIBUTION_PROVIDER_ID,
     DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID, DEFAULT_STORAGE_BUCKETS_NUMBER,
     DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT, DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
-    DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID, DEFAULT_STORAGE_PROVIDER_ID,
-    DISTRIBUTION_WG_LEADER_ACCOUNT_ID, INITIAL_BALANCE, STORAGE_WG_LEADER_ACCOUNT_ID,
+    DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID, DEFAULT_STORAGE_PROVIDER_ID, DEFAULT_WORKING_GROUP_BUDGET,
+    DISTRIBUTION_WG_BUDGET, DISTRIBUTION_WG_LEADER_ACCOUNT_ID, INITIAL_BALANCE,
+    IN_USE_DATA_OBJECTS, REMOVED_DATA_OBJECTS, STORAGE_WG_BUDGET, STORAGE_WG_LEADER_ACCOUNT_ID,
     VOUCHER_OBJECTS_LIMIT, VOUCHER_SIZE_LIMIT,
 };
 
@@ -773,6 +789,8 @@ fn upload_succeeded() {
                     .clone(),
                 deletion_prize: DataObjectDeletionPrize::get(),
                 accepted: false,
+                content_root: None,
+                parts: Vec::new(),
             }
         );
 
@@ -987,6 +1005,8 @@ fn upload_succeeded_with_dynamic_bag() {
                     .clone(),
                 deletion_prize: DataObjectDeletionPrize::get(),
                 accepted: false,
+                content_root: None,
+                parts: Vec::new(),
             }
         );
     });
@@ -1061,6 +1081,9 @@ fn upload_fails_with_zero_object_size() {
             deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
             object_creation_list: vec![DataObjectCreationParameters {
                 ipfs_content_id: vec![1],
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
                 size: 0,
             }],
             expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
@@ -1080,6 +1103,9 @@ fn upload_fails_with_empty_object_cid() {
             deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
             object_creation_list: vec![DataObjectCreationParameters {
                 ipfs_content_id: Vec::new(),
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
                 size: 220,
             }],
             expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
@@ -1663,6 +1689,272 @@ fn accept_pending_data_objects_fails_with_non_existing_bucket_id() {
     });
 }
 
+fn create_multipart_data_object(parts_number: usize) -> DataObjectCreationParameters {
+    DataObjectCreationParameters {
+        size: 10,
+        ipfs_content_id: vec![1],
+        content_root: None,
+        parts: vec![[1u8; 32]; parts_number],
+        class: None,
+    }
+}
+
+fn setup_multipart_data_object_test(parts_number: usize) -> (BagId<Test>, u64, u64) {
+    let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+
+    let objects_limit = 2;
+    let size_limit = 100;
+    let bucket_id = create_storage_bucket_and_assign_to_bag(
+        bag_id.clone(),
+        Some(DEFAULT_STORAGE_PROVIDER_ID),
+        objects_limit,
+        size_limit,
+    );
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    let data_object_id = Storage::next_data_object_id();
+    upload_data_objects_to_bag(
+        bag_id.clone(),
+        vec![create_multipart_data_object(parts_number)],
+        Ok(()),
+    );
+
+    (bag_id, bucket_id, data_object_id)
+}
+
+#[test]
+fn accept_pending_data_object_parts_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (bag_id, bucket_id, data_object_id) = setup_multipart_data_object_test(3);
+        let parts = BTreeSet::from_iter(vec![0, 2]);
+
+        AcceptPendingDataObjectPartsFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id.clone())
+            .with_data_object_id(data_object_id)
+            .with_parts(parts.clone())
+            .call_and_assert(Ok(()));
+
+        // Not all parts are held yet.
+        let data_object = Storage::ensure_data_object_exists(&bag_id, &data_object_id).unwrap();
+        assert!(!data_object.accepted);
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectPartsAccepted(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            bag_id,
+            data_object_id,
+            parts,
+        ));
+    });
+}
+
+#[test]
+fn accept_pending_data_object_parts_accepts_data_object_with_all_parts() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (bag_id, bucket_id, data_object_id) = setup_multipart_data_object_test(3);
+
+        let accept_parts_fixture = AcceptPendingDataObjectPartsFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id.clone())
+            .with_data_object_id(data_object_id);
+
+        // The interrupted upload is resumed with the remaining parts.
+        accept_parts_fixture
+            .clone()
+            .with_parts(BTreeSet::from_iter(vec![0, 1]))
+            .call_and_assert(Ok(()));
+        accept_parts_fixture
+            .with_parts(BTreeSet::from_iter(vec![1, 2]))
+            .call_and_assert(Ok(()));
+
+        let data_object = Storage::ensure_data_object_exists(&bag_id, &data_object_id).unwrap();
+        assert!(data_object.accepted);
+
+        EventFixture::contains_crate_event(RawEvent::DataObjectPartsAccepted(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            bag_id.clone(),
+            data_object_id,
+            BTreeSet::from_iter(vec![1, 2]),
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::PendingDataObjectsAccepted(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            bag_id,
+            BTreeSet::from_iter(vec![data_object_id]),
+        ));
+    });
+}
+
+#[test]
+fn accept_pending_data_object_parts_fails_with_non_multipart_data_object() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id, _) = setup_multipart_data_object_test(3);
+
+        let data_object_id = Storage::next_data_object_id();
+        upload_data_objects_to_bag(bag_id.clone(), create_single_data_object(), Ok(()));
+
+        AcceptPendingDataObjectPartsFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id)
+            .with_data_object_id(data_object_id)
+            .with_parts(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::DataObjectIsNotMultipart.into()));
+    });
+}
+
+#[test]
+fn accept_pending_data_object_parts_fails_with_invalid_part_index() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id, data_object_id) = setup_multipart_data_object_test(3);
+
+        AcceptPendingDataObjectPartsFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id)
+            .with_data_object_id(data_object_id)
+            .with_parts(BTreeSet::from_iter(vec![0, 3]))
+            .call_and_assert(Err(Error::<Test>::InvalidDataObjectPartIndex.into()));
+    });
+}
+
+#[test]
+fn accept_pending_data_object_parts_fails_with_empty_parts() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id, data_object_id) = setup_multipart_data_object_test(3);
+
+        AcceptPendingDataObjectPartsFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id)
+            .with_data_object_id(data_object_id)
+            .call_and_assert(Err(Error::<Test>::DataObjectPartIndicesAreEmpty.into()));
+    });
+}
+
+#[test]
+fn accept_pending_data_object_parts_fails_with_non_existing_data_object() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id, data_object_id) = setup_multipart_data_object_test(3);
+
+        AcceptPendingDataObjectPartsFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id)
+            .with_data_object_id(data_object_id + 1)
+            .with_parts(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::DataObjectDoesntExist.into()));
+    });
+}
+
+#[test]
+fn accept_pending_data_object_parts_fails_with_invalid_storage_provider() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id, data_object_id) = setup_multipart_data_object_test(3);
+
+        AcceptPendingDataObjectPartsFixture::default()
+            .with_worker_id(ANOTHER_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id)
+            .with_data_object_id(data_object_id)
+            .with_parts(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::InvalidStorageProvider.into()));
+    });
+}
+
+#[test]
+fn accept_pending_data_objects_fails_with_multipart_data_object() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id, data_object_id) = setup_multipart_data_object_test(3);
+
+        AcceptPendingDataObjectsFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(BTreeSet::from_iter(vec![data_object_id]))
+            .call_and_assert(Err(Error::<Test>::DataObjectIsMultipart.into()));
+
+        let data_object = Storage::ensure_data_object_exists(&bag_id, &data_object_id).unwrap();
+        assert!(!data_object.accepted);
+    });
+}
+
+#[test]
+fn data_object_part_size_rounds_up_object_size() {
+    assert_eq!(Storage::data_object_part_size(10, 3), 4);
+    assert_eq!(Storage::data_object_part_size(10, 5), 2);
+    assert_eq!(Storage::data_object_part_size(10, 0), 10);
+}
+
+#[test]
+fn delete_data_objects_removes_multipart_data_object_parts() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id, data_object_id) = setup_multipart_data_object_test(3);
+
+        AcceptPendingDataObjectPartsFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id.clone())
+            .with_data_object_id(data_object_id)
+            .with_parts(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Ok(()));
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(bag_id)
+            .with_data_object_ids(BTreeSet::from_iter(vec![data_object_id]))
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Ok(()));
+
+        assert!(!crate::DataObjectPartsByBucket::<Test>::contains_key(
+            data_object_id,
+            bucket_id
+        ));
+    });
+}
+
+#[test]
+fn upload_fails_with_invalid_data_object_parts_number() {
+    build_test_externalities().execute_with(|| {
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        create_storage_bucket_and_assign_to_bag(bag_id.clone(), None, 1, 100);
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        let single_part = create_multipart_data_object(1);
+        let too_many_parts =
+            create_multipart_data_object(MaxDataObjectPartsNumber::get() as usize + 1);
+        let parts_exceeding_size = DataObjectCreationParameters {
+            size: 2,
+            ..create_multipart_data_object(3)
+        };
+        // The part size is 2 bytes: 5 parts hold the whole data object, the last part is empty.
+        let empty_last_part = create_multipart_data_object(6);
+
+        for object_params in vec![
+            single_part,
+            too_many_parts,
+            parts_exceeding_size,
+            empty_last_part,
+        ] {
+            upload_data_objects_to_bag(
+                bag_id.clone(),
+                vec![object_params],
+                Err(Error::<Test>::InvalidDataObjectPartsNumber.into()),
+            );
+        }
+    });
+}
+
 #[test]
 fn cancel_storage_bucket_operator_invite_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -2173,1425 +2465,5438 @@ fn move_data_objects_fails_with_same_bag() {
     });
 }
 
+fn setup_move_bag_data_objects_test(objects_number: u8) -> (BagId<Test>, BagId<Test>, u64, u64) {
+    let src_dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+    let dest_dynamic_bag_id = DynamicBagId::<Test>::Channel(DEFAULT_CHANNEL_ID);
+    create_dynamic_bag(&src_dynamic_bag_id);
+    create_dynamic_bag(&dest_dynamic_bag_id);
+
+    let src_bag_id: BagId<Test> = src_dynamic_bag_id.into();
+    let dest_bag_id: BagId<Test> = dest_dynamic_bag_id.into();
+
+    let src_bucket_id = create_default_storage_bucket_and_assign_to_bag(src_bag_id.clone());
+    let dest_bucket_id = create_default_storage_bucket_and_assign_to_bag(dest_bag_id.clone());
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    let upload_params = UploadParameters::<Test> {
+        bag_id: src_bag_id.clone(),
+        deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        object_creation_list: create_data_object_candidates(1, objects_number),
+        expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+    };
+
+    UploadFixture::default()
+        .with_params(upload_params)
+        .call_and_assert(Ok(()));
+
+    (src_bag_id, dest_bag_id, src_bucket_id, dest_bucket_id)
+}
+
 #[test]
-fn delete_data_objects_succeeded() {
+fn move_bag_data_objects_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let initial_balance = 1000;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
-
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        let bag_id = BagId::<Test>::Dynamic(dynamic_bag_id.clone());
+        let objects_number = 3;
+        let (src_bag_id, dest_bag_id, src_bucket_id, dest_bucket_id) =
+            setup_move_bag_data_objects_test(objects_number);
 
-        create_dynamic_bag(&dynamic_bag_id);
+        let ids = (0..objects_number as u64).collect::<BTreeSet<_>>();
 
-        let upload_params = UploadParameters::<Test> {
-            bag_id: bag_id.clone(),
-            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-            object_creation_list: create_single_data_object(),
-            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
-        };
+        let events_number = EventFixture::events_number();
 
-        UploadFixture::default()
-            .with_params(upload_params.clone())
+        MoveBagDataObjectsFixture::default()
+            .with_data_object_ids(ids.clone())
             .call_and_assert(Ok(()));
 
-        let data_object_id = 0; // just uploaded data object
-
-        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+        let voucher_delta = VoucherUpdate {
+            objects_number: objects_number as u64,
+            objects_total_size: objects_number as u64 * DEFAULT_DATA_OBJECTS_SIZE,
+        };
 
-        // pre-checks
-        assert!(<crate::DataObjectsById<Test>>::contains_key(
-            &bag_id,
-            &data_object_id
-        ));
+        let src_bucket = Storage::storage_bucket_by_id(src_bucket_id);
+        assert_eq!(src_bucket.voucher.objects_used, 0);
+        assert_eq!(src_bucket.voucher.size_used, 0);
 
+        let dest_bucket = Storage::storage_bucket_by_id(dest_bucket_id);
         assert_eq!(
-            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
-            initial_balance - DataObjectDeletionPrize::get()
+            dest_bucket.voucher.objects_used,
+            voucher_delta.objects_number
         );
         assert_eq!(
-            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
-            DataObjectDeletionPrize::get()
+            dest_bucket.voucher.size_used,
+            voucher_delta.objects_total_size
         );
 
-        DeleteDataObjectsFixture::default()
-            .with_bag_id(bag_id.clone())
-            .with_data_object_ids(data_object_ids.clone())
-            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
-            .call_and_assert(Ok(()));
-
-        // post-checks
-        assert!(!<crate::DataObjectsById<Test>>::contains_key(
-            &bag_id,
-            &data_object_id
-        ));
-
-        assert_eq!(
-            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
-            initial_balance
-        );
+        // The owner move is reported by the single event.
         assert_eq!(
-            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
-            0
+            EventFixture::crate_events_since(events_number),
+            vec![RawEvent::DataObjectsMovedByOwner(
+                DEFAULT_MEMBER_ACCOUNT_ID,
+                src_bag_id,
+                dest_bag_id,
+                ids,
+                voucher_delta,
+                BTreeSet::from_iter(vec![dest_bucket_id]),
+                0,
+            )]
         );
-
-        EventFixture::assert_last_crate_event(RawEvent::DataObjectsDeleted(
-            DEFAULT_MEMBER_ACCOUNT_ID,
-            bag_id,
-            data_object_ids,
-        ));
     });
 }
 
 #[test]
-fn delete_data_objects_fails_with_non_existent_dynamic_bag() {
+fn move_bag_data_objects_charges_remaining_destination_bag_rent() {
     build_test_externalities().execute_with(|| {
-        let initial_balance = 1000;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        let bag_id = BagId::<Test>::Dynamic(dynamic_bag_id.clone());
+        let rent = 2;
+        UpdateStorageRentFixture::default()
+            .with_new_rent(rent)
+            .call_and_assert(Ok(()));
 
-        let data_object_id = 0;
+        let objects_number = 3;
+        setup_move_bag_data_objects_test(objects_number);
 
-        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+        let old_balance = Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID);
 
-        DeleteDataObjectsFixture::default()
-            .with_bag_id(bag_id.clone())
-            .with_data_object_ids(data_object_ids.clone())
-            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
-            .call_and_assert(Err(Error::<Test>::DynamicBagDoesntExist.into()));
+        MoveBagDataObjectsFixture::default()
+            .with_data_object_ids((0..objects_number as u64).collect())
+            .call_and_assert(Ok(()));
+
+        // The moved data (less than one megabyte) is charged for the destination bag prepayment.
+        let storage_rent = rent * InitialBagRentPeriod::get();
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            old_balance - storage_rent
+        );
     });
 }
 
 #[test]
-fn delete_data_objects_fails_with_invalid_treasury_balance() {
+fn move_bag_data_objects_fails_with_insufficient_balance_for_rent() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
-        let invite_worker = Some(storage_provider_id);
-
-        CreateStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_invite_worker(invite_worker)
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        let initial_balance = 1000;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
-
-        let council_bag_id = BagId::<Test>::Static(StaticBagId::Council);
-        let upload_params = UploadParameters::<Test> {
-            bag_id: council_bag_id.clone(),
-            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-            object_creation_list: create_single_data_object(),
-            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
-        };
-
-        UploadFixture::default()
-            .with_params(upload_params.clone())
+        UpdateStorageRentFixture::default()
+            .with_new_rent(1)
             .call_and_assert(Ok(()));
 
-        let data_object_id = 0; // just uploaded data object
-
-        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+        let objects_number = 1;
+        setup_move_bag_data_objects_test(objects_number);
 
-        // Corrupt module balance.
         let _ = Balances::slash(
-            &<StorageTreasury<Test>>::module_account_id(),
-            <StorageTreasury<Test>>::usable_balance(),
+            &DEFAULT_MEMBER_ACCOUNT_ID,
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
         );
 
-        DeleteDataObjectsFixture::default()
-            .with_bag_id(council_bag_id.clone())
-            .with_data_object_ids(data_object_ids.clone())
-            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
-            .call_and_assert(Err(Error::<Test>::InsufficientTreasuryBalance.into()));
+        MoveBagDataObjectsFixture::default()
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::InsufficientBalance.into()));
     });
 }
 
 #[test]
-fn delete_data_objects_succeeded_with_voucher_usage() {
+fn move_bag_data_objects_fails_with_non_owner_account() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let objects_number = 1;
+        let (src_bag_id, _, _, _) = setup_move_bag_data_objects_test(objects_number);
 
-        let bucket_id = create_default_storage_bucket_and_assign_to_bag(bag_id.clone());
-
-        let initial_balance = 1000;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
-
-        let object_creation_list = create_single_data_object();
-
-        let upload_params = UploadParameters::<Test> {
-            bag_id: bag_id.clone(),
-            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-            object_creation_list: object_creation_list.clone(),
-            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
-        };
-
-        UploadFixture::default()
-            .with_params(upload_params.clone())
-            .call_and_assert(Ok(()));
-
-        EventFixture::contains_crate_event(RawEvent::BagObjectsChanged(
-            bag_id.clone(),
-            object_creation_list[0].size,
-            1,
-        ));
-
-        let data_object_id = 0; // just uploaded data object
-
-        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
-
-        //// Pre-check voucher
-        let bucket = Storage::storage_bucket_by_id(bucket_id);
+        let non_owner_account_id = 1;
+        MoveBagDataObjectsFixture::default()
+            .with_origin(RawOrigin::Signed(non_owner_account_id))
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::NotBagOwnerAccount.into()));
 
-        assert_eq!(bucket.voucher.objects_used, 1);
-        assert_eq!(bucket.voucher.size_used, object_creation_list[0].size);
+        let another_member_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID + 1);
+        create_dynamic_bag(&another_member_bag_id);
 
-        DeleteDataObjectsFixture::default()
-            .with_bag_id(bag_id.clone())
-            .with_data_object_ids(data_object_ids.clone())
-            .call_and_assert(Ok(()));
+        MoveBagDataObjectsFixture::default()
+            .with_src_bag_id(src_bag_id)
+            .with_dest_bag_id(another_member_bag_id.into())
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::NotBagOwnerAccount.into()));
+    });
+}
 
-        assert!(!<crate::DataObjectsById<Test>>::contains_key(
-            &bag_id,
-            &data_object_id
-        ));
+#[test]
+fn move_bag_data_objects_fails_with_data_object_in_use() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        //// Post-check voucher
-        let bucket = Storage::storage_bucket_by_id(bucket_id);
+        let objects_number = 2;
+        setup_move_bag_data_objects_test(objects_number);
 
-        assert_eq!(bucket.voucher.objects_used, 0);
-        assert_eq!(bucket.voucher.size_used, 0);
+        // The data object is referenced outside of the storage (eg.: by the video asset slot).
+        IN_USE_DATA_OBJECTS.with(|val| val.borrow_mut().insert(1));
 
-        EventFixture::contains_crate_event(RawEvent::BagObjectsChanged(bag_id.clone(), 0, 0));
+        MoveBagDataObjectsFixture::default()
+            .with_data_object_ids(BTreeSet::from_iter(vec![0, 1]))
+            .call_and_assert(Err(Error::<Test>::DataObjectIsInUse.into()));
     });
 }
 
 #[test]
-fn delete_data_objects_fails_with_empty_params() {
+fn move_bag_data_objects_fails_with_static_bags() {
     build_test_externalities().execute_with(|| {
-        DeleteDataObjectsFixture::default()
-            .call_and_assert(Err(Error::<Test>::DataObjectIdParamsAreEmpty.into()));
+        MoveBagDataObjectsFixture::default()
+            .with_src_bag_id(BagId::<Test>::Static(StaticBagId::Council))
+            .call_and_assert(Err(Error::<Test>::NotBagOwnerAccount.into()));
+
+        MoveBagDataObjectsFixture::default()
+            .with_dest_bag_id(BagId::<Test>::Static(StaticBagId::WorkingGroup(
+                WorkingGroup::Storage,
+            )))
+            .call_and_assert(Err(Error::<Test>::NotBagOwnerAccount.into()));
     });
 }
 
 #[test]
-fn delete_data_objects_fails_with_non_existing_data_object() {
+fn move_bag_data_objects_fails_with_same_bag() {
     build_test_externalities().execute_with(|| {
-        let council_bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        create_dynamic_bag(&dynamic_bag_id);
 
-        let data_object_id = 0;
-        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+        MoveBagDataObjectsFixture::default()
+            .with_dest_bag_id(dynamic_bag_id.into())
+            .call_and_assert(Err(Error::<Test>::SourceAndDestinationBagsAreEqual.into()));
+    });
+}
 
-        DeleteDataObjectsFixture::default()
-            .with_bag_id(council_bag_id.clone())
-            .with_data_object_ids(data_object_ids.clone())
-            .call_and_assert(Err(Error::<Test>::DataObjectDoesntExist.into()));
+#[test]
+fn move_bag_data_objects_fails_with_unsigned_origin() {
+    build_test_externalities().execute_with(|| {
+        MoveBagDataObjectsFixture::default()
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn update_storage_bucket_status_succeeded() {
+fn upload_working_group_data_objects_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let bucket_id = CreateStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let initial_balance = 1000;
+        increase_account_balance(&STORAGE_WG_LEADER_ACCOUNT_ID, initial_balance);
 
-        let new_status = true;
-        UpdateStorageBucketStatusFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .with_new_status(new_status)
+        let object_creation_list = create_data_object_candidates(1, 2);
+
+        UploadWorkingGroupDataObjectsFixture::default()
+            .with_object_creation_list(object_creation_list.clone())
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::StorageBucketStatusUpdated(
-            bucket_id, new_status,
+        let deletion_prize = DataObjectDeletionPrize::get() * 2;
+        assert_eq!(
+            Balances::usable_balance(&STORAGE_WG_LEADER_ACCOUNT_ID),
+            initial_balance - deletion_prize
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsUploaded(
+            vec![0, 1],
+            UploadParameters::<Test> {
+                bag_id: StaticBagId::WorkingGroup(WorkingGroup::Storage).into(),
+                object_creation_list,
+                deletion_prize_source_account_id: STORAGE_WG_LEADER_ACCOUNT_ID,
+                expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+            },
+            deletion_prize,
         ));
     });
 }
 
 #[test]
-fn update_storage_bucket_status_fails_with_invalid_origin() {
+fn upload_working_group_data_objects_succeeded_with_matching_group_leader() {
     build_test_externalities().execute_with(|| {
-        UpdateStorageBucketStatusFixture::default()
-            .with_origin(RawOrigin::Root)
+        increase_account_balance(&DISTRIBUTION_WG_LEADER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        UploadWorkingGroupDataObjectsFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_working_group(WorkingGroup::Distribution)
+            .with_object_creation_list(create_single_data_object())
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn upload_working_group_data_objects_fails_with_other_group_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        increase_account_balance(&STORAGE_WG_LEADER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        UploadWorkingGroupDataObjectsFixture::default()
+            .with_working_group(WorkingGroup::Distribution)
+            .with_object_creation_list(create_single_data_object())
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn update_storage_bucket_status_fails_with_invalid_storage_bucket() {
+fn upload_working_group_data_objects_fails_with_invalid_expected_data_size_fee() {
     build_test_externalities().execute_with(|| {
-        UpdateStorageBucketStatusFixture::default()
+        increase_account_balance(&STORAGE_WG_LEADER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        UpdateDataObjectPerMegabyteFeeFixture::default()
             .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+            .with_new_fee(10)
+            .call_and_assert(Ok(()));
+
+        UploadWorkingGroupDataObjectsFixture::default()
+            .with_object_creation_list(create_single_data_object())
+            .call_and_assert(Err(Error::<Test>::DataSizeFeeChanged.into()));
     });
 }
 
 #[test]
-fn update_blacklist_succeeded() {
+fn delete_working_group_data_objects_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let cid1 = vec![1];
-        let cid2 = vec![2];
+        let initial_balance = 1000;
+        increase_account_balance(&STORAGE_WG_LEADER_ACCOUNT_ID, initial_balance);
 
-        let add_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_add_hashes(add_hashes.clone())
+        UploadWorkingGroupDataObjectsFixture::default()
+            .with_object_creation_list(create_data_object_candidates(1, 2))
             .call_and_assert(Ok(()));
 
-        assert!(crate::Blacklist::contains_key(&cid1));
-        assert_eq!(Storage::current_blacklist_size(), 1);
-
-        let remove_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
-        let add_hashes = BTreeSet::from_iter(vec![cid2.clone()]);
+        let data_object_ids = BTreeSet::from_iter(vec![0, 1]);
 
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_add_hashes(add_hashes.clone())
-            .with_remove_hashes(remove_hashes.clone())
+        DeleteWorkingGroupDataObjectsFixture::default()
+            .with_data_object_ids(data_object_ids.clone())
             .call_and_assert(Ok(()));
 
-        assert!(!crate::Blacklist::contains_key(&cid1));
-        assert!(crate::Blacklist::contains_key(&cid2));
-        assert_eq!(Storage::current_blacklist_size(), 1);
+        let bag_id: BagId<Test> = StaticBagId::WorkingGroup(WorkingGroup::Storage).into();
+        assert_eq!(Storage::bag(&bag_id).objects_number, 0);
+        assert_eq!(
+            Balances::usable_balance(&STORAGE_WG_LEADER_ACCOUNT_ID),
+            initial_balance
+        );
 
-        EventFixture::assert_last_crate_event(RawEvent::UpdateBlacklist(remove_hashes, add_hashes));
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsDeleted(
+            STORAGE_WG_LEADER_ACCOUNT_ID,
+            bag_id,
+            data_object_ids,
+        ));
     });
 }
 
 #[test]
-fn update_blacklist_failed_with_exceeding_size_limit() {
+fn delete_working_group_data_objects_fails_with_other_group_leader_origin() {
     build_test_externalities().execute_with(|| {
-        let cid1 = vec![1];
-        let cid2 = vec![2];
-        let cid3 = vec![3];
-
-        let add_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+        increase_account_balance(&STORAGE_WG_LEADER_ACCOUNT_ID, INITIAL_BALANCE);
 
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_add_hashes(add_hashes.clone())
+        UploadWorkingGroupDataObjectsFixture::default()
+            .with_object_creation_list(create_single_data_object())
             .call_and_assert(Ok(()));
 
-        let remove_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
-        let add_hashes = BTreeSet::from_iter(vec![cid2.clone(), cid3.clone()]);
-
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_add_hashes(add_hashes.clone())
-            .with_remove_hashes(remove_hashes.clone())
-            .call_and_assert(Err(Error::<Test>::BlacklistSizeLimitExceeded.into()));
+        DeleteWorkingGroupDataObjectsFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
 
-        assert!(crate::Blacklist::contains_key(&cid1));
-        assert!(!crate::Blacklist::contains_key(&cid2));
-        assert!(!crate::Blacklist::contains_key(&cid3));
+#[test]
+fn delete_working_group_data_objects_fails_with_non_existing_data_object() {
+    build_test_externalities().execute_with(|| {
+        DeleteWorkingGroupDataObjectsFixture::default()
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::DataObjectDoesntExist.into()));
     });
 }
 
 #[test]
-fn update_blacklist_failed_with_exceeding_size_limit_with_non_existent_remove_hashes() {
+fn execute_storage_administrative_action_succeeded_with_data_objects_upload() {
     build_test_externalities().execute_with(|| {
-        let cid1 = vec![1];
-        let cid2 = vec![2];
-        let cid3 = vec![3];
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let add_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+        let initial_balance = 1000;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
 
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_add_hashes(add_hashes.clone())
-            .call_and_assert(Ok(()));
+        let upload_params = UploadParameters::<Test> {
+            bag_id: BagId::<Test>::from(StaticBagId::Council),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: create_data_object_candidates(1, 2),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
 
-        let remove_hashes = BTreeSet::from_iter(vec![cid3.clone()]);
-        let add_hashes = BTreeSet::from_iter(vec![cid2.clone()]);
+        ExecuteStorageAdministrativeActionFixture::default()
+            .with_action(StorageAdministrativeActionRecord::UploadDataObjects(
+                upload_params.clone(),
+            ))
+            .call_and_assert(Ok(()));
 
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_add_hashes(add_hashes.clone())
-            .with_remove_hashes(remove_hashes.clone())
-            .call_and_assert(Err(Error::<Test>::BlacklistSizeLimitExceeded.into()));
+        let bag = Storage::bag(&upload_params.bag_id);
+        assert_eq!(bag.objects_number, 2);
 
-        assert!(crate::Blacklist::contains_key(&cid1));
-        assert!(!crate::Blacklist::contains_key(&cid2));
-        assert!(!crate::Blacklist::contains_key(&cid3));
+        let deletion_prize = DataObjectDeletionPrize::get() * 2;
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsUploaded(
+            vec![0, 1],
+            upload_params,
+            deletion_prize,
+        ));
     });
 }
 
 #[test]
-fn update_blacklist_succeeds_with_existent_remove_hashes() {
+fn execute_storage_administrative_action_succeeded_with_dynamic_bag_creation() {
     build_test_externalities().execute_with(|| {
-        let cid1 = vec![1];
-
-        let add_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_add_hashes(add_hashes.clone())
-            .call_and_assert(Ok(()));
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
 
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_add_hashes(add_hashes.clone())
+        ExecuteStorageAdministrativeActionFixture::default()
+            .with_action(StorageAdministrativeActionRecord::CreateDynamicBag(
+                dynamic_bag_id.clone(),
+                None,
+            ))
             .call_and_assert(Ok(()));
 
-        assert!(crate::Blacklist::contains_key(&cid1));
-        assert_eq!(Storage::current_blacklist_size(), 1);
+        assert!(Storage::ensure_bag_exists(&dynamic_bag_id.into()).is_ok());
     });
 }
 
 #[test]
-fn update_blacklist_fails_with_non_leader_origin() {
+fn execute_storage_administrative_action_fails_with_non_root_origin() {
     build_test_externalities().execute_with(|| {
-        let non_leader_id = 1;
-
-        UpdateBlacklistFixture::default()
-            .with_origin(RawOrigin::Signed(non_leader_id))
+        ExecuteStorageAdministrativeActionFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
-fn create_default_storage_bucket_and_assign_to_bag(bag_id: BagId<Test>) -> u64 {
-    let objects_limit = 1;
-    let size_limit = 100;
+#[test]
+fn execute_storage_administrative_action_fails_with_existing_dynamic_bag() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
 
-    create_storage_bucket_and_assign_to_bag(bag_id, None, objects_limit, size_limit)
+        create_dynamic_bag(&dynamic_bag_id);
+
+        ExecuteStorageAdministrativeActionFixture::default()
+            .with_action(StorageAdministrativeActionRecord::CreateDynamicBag(
+                dynamic_bag_id,
+                None,
+            ))
+            .call_and_assert(Err(Error::<Test>::DynamicBagExists.into()));
+    });
 }
 
-fn create_storage_bucket_and_assign_to_bag(
-    bag_id: BagId<Test>,
-    storage_provider_id: Option<u64>,
-    objects_limit: u64,
-    size_limit: u64,
-) -> u64 {
-    set_max_voucher_limits();
-    set_default_update_storage_buckets_per_bag_limit();
+#[test]
+fn delete_data_objects_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-    let bucket_id = CreateStorageBucketFixture::default()
-        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-        .with_invite_worker(storage_provider_id)
-        .with_objects_limit(objects_limit)
-        .with_size_limit(size_limit)
-        .call_and_assert(Ok(()))
-        .unwrap();
+        let initial_balance = 1000;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
 
-    let buckets = BTreeSet::from_iter(vec![bucket_id]);
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let bag_id = BagId::<Test>::Dynamic(dynamic_bag_id.clone());
 
-    UpdateStorageBucketForBagsFixture::default()
-        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-        .with_bag_id(bag_id.clone())
-        .with_add_bucket_ids(buckets.clone())
-        .call_and_assert(Ok(()));
+        create_dynamic_bag(&dynamic_bag_id);
 
-    if let Some(storage_provider_id) = storage_provider_id {
-        AcceptStorageBucketInvitationFixture::default()
+        let upload_params = UploadParameters::<Test> {
+            bag_id: bag_id.clone(),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: create_single_data_object(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params.clone())
+            .call_and_assert(Ok(()));
+
+        let data_object_id = 0; // just uploaded data object
+
+        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+
+        // pre-checks
+        assert!(<crate::DataObjectsById<Test>>::contains_key(
+            &bag_id,
+            &data_object_id
+        ));
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            initial_balance - DataObjectDeletionPrize::get()
+        );
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            DataObjectDeletionPrize::get()
+        );
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(data_object_ids.clone())
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Ok(()));
+
+        // post-checks
+        assert!(!<crate::DataObjectsById<Test>>::contains_key(
+            &bag_id,
+            &data_object_id
+        ));
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            initial_balance
+        );
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            0
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsDeleted(
+            DEFAULT_MEMBER_ACCOUNT_ID,
+            bag_id,
+            data_object_ids,
+        ));
+    });
+}
+
+#[test]
+fn delete_data_objects_fails_with_non_existent_dynamic_bag() {
+    build_test_externalities().execute_with(|| {
+        let initial_balance = 1000;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let bag_id = BagId::<Test>::Dynamic(dynamic_bag_id.clone());
+
+        let data_object_id = 0;
+
+        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(data_object_ids.clone())
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Err(Error::<Test>::DynamicBagDoesntExist.into()));
+    });
+}
+
+#[test]
+fn delete_data_objects_fails_with_invalid_treasury_balance() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
+        let invite_worker = Some(storage_provider_id);
+
+        CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_invite_worker(invite_worker)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let initial_balance = 1000;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let council_bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let upload_params = UploadParameters::<Test> {
+            bag_id: council_bag_id.clone(),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: create_single_data_object(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params.clone())
+            .call_and_assert(Ok(()));
+
+        let data_object_id = 0; // just uploaded data object
+
+        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+
+        // Corrupt module balance.
+        let _ = Balances::slash(
+            &<StorageTreasury<Test>>::module_account_id(),
+            <StorageTreasury<Test>>::usable_balance(),
+        );
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(council_bag_id.clone())
+            .with_data_object_ids(data_object_ids.clone())
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Err(Error::<Test>::InsufficientTreasuryBalance.into()));
+    });
+}
+
+#[test]
+fn delete_data_objects_succeeded_with_voucher_usage() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+
+        let bucket_id = create_default_storage_bucket_and_assign_to_bag(bag_id.clone());
+
+        let initial_balance = 1000;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let object_creation_list = create_single_data_object();
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id: bag_id.clone(),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: object_creation_list.clone(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::BagObjectsChanged(
+            bag_id.clone(),
+            object_creation_list[0].size,
+            1,
+        ));
+
+        let data_object_id = 0; // just uploaded data object
+
+        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+
+        //// Pre-check voucher
+        let bucket = Storage::storage_bucket_by_id(bucket_id);
+
+        assert_eq!(bucket.voucher.objects_used, 1);
+        assert_eq!(bucket.voucher.size_used, object_creation_list[0].size);
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(data_object_ids.clone())
+            .call_and_assert(Ok(()));
+
+        assert!(!<crate::DataObjectsById<Test>>::contains_key(
+            &bag_id,
+            &data_object_id
+        ));
+
+        //// Post-check voucher
+        let bucket = Storage::storage_bucket_by_id(bucket_id);
+
+        assert_eq!(bucket.voucher.objects_used, 0);
+        assert_eq!(bucket.voucher.size_used, 0);
+
+        EventFixture::contains_crate_event(RawEvent::BagObjectsChanged(bag_id.clone(), 0, 0));
+    });
+}
+
+#[test]
+fn delete_data_objects_fails_with_empty_params() {
+    build_test_externalities().execute_with(|| {
+        DeleteDataObjectsFixture::default()
+            .call_and_assert(Err(Error::<Test>::DataObjectIdParamsAreEmpty.into()));
+    });
+}
+
+#[test]
+fn delete_data_objects_fails_with_non_existing_data_object() {
+    build_test_externalities().execute_with(|| {
+        let council_bag_id = BagId::<Test>::Static(StaticBagId::Council);
+
+        let data_object_id = 0;
+        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(council_bag_id.clone())
+            .with_data_object_ids(data_object_ids.clone())
+            .call_and_assert(Err(Error::<Test>::DataObjectDoesntExist.into()));
+    });
+}
+
+#[test]
+fn update_storage_bucket_status_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let new_status = true;
+        UpdateStorageBucketStatusFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_status(new_status)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketStatusUpdated(
+            bucket_id, new_status,
+        ));
+    });
+}
+
+#[test]
+fn update_storage_bucket_status_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        UpdateStorageBucketStatusFixture::default()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_storage_bucket_status_fails_with_invalid_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        UpdateStorageBucketStatusFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn update_blacklist_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let cid1 = vec![1];
+        let cid2 = vec![2];
+
+        let add_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_add_hashes(add_hashes.clone())
+            .call_and_assert(Ok(()));
+
+        assert!(crate::Blacklist::<Test>::contains_key(&cid1));
+        assert_eq!(Storage::current_blacklist_size(), 1);
+
+        let remove_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+        let add_hashes = BTreeSet::from_iter(vec![cid2.clone()]);
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_add_hashes(add_hashes.clone())
+            .with_remove_hashes(remove_hashes.clone())
+            .call_and_assert(Ok(()));
+
+        assert!(!crate::Blacklist::<Test>::contains_key(&cid1));
+        assert!(crate::Blacklist::<Test>::contains_key(&cid2));
+        assert_eq!(Storage::current_blacklist_size(), 1);
+
+        EventFixture::assert_last_crate_event(RawEvent::UpdateBlacklist(remove_hashes, add_hashes));
+    });
+}
+
+#[test]
+fn update_blacklist_failed_with_exceeding_size_limit() {
+    build_test_externalities().execute_with(|| {
+        let cid1 = vec![1];
+        let cid2 = vec![2];
+        let cid3 = vec![3];
+
+        let add_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_add_hashes(add_hashes.clone())
+            .call_and_assert(Ok(()));
+
+        let remove_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+        let add_hashes = BTreeSet::from_iter(vec![cid2.clone(), cid3.clone()]);
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_add_hashes(add_hashes.clone())
+            .with_remove_hashes(remove_hashes.clone())
+            .call_and_assert(Err(Error::<Test>::BlacklistSizeLimitExceeded.into()));
+
+        assert!(crate::Blacklist::<Test>::contains_key(&cid1));
+        assert!(!crate::Blacklist::<Test>::contains_key(&cid2));
+        assert!(!crate::Blacklist::<Test>::contains_key(&cid3));
+    });
+}
+
+#[test]
+fn update_blacklist_failed_with_exceeding_size_limit_with_non_existent_remove_hashes() {
+    build_test_externalities().execute_with(|| {
+        let cid1 = vec![1];
+        let cid2 = vec![2];
+        let cid3 = vec![3];
+
+        let add_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_add_hashes(add_hashes.clone())
+            .call_and_assert(Ok(()));
+
+        let remove_hashes = BTreeSet::from_iter(vec![cid3.clone()]);
+        let add_hashes = BTreeSet::from_iter(vec![cid2.clone()]);
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_add_hashes(add_hashes.clone())
+            .with_remove_hashes(remove_hashes.clone())
+            .call_and_assert(Err(Error::<Test>::BlacklistSizeLimitExceeded.into()));
+
+        assert!(crate::Blacklist::<Test>::contains_key(&cid1));
+        assert!(!crate::Blacklist::<Test>::contains_key(&cid2));
+        assert!(!crate::Blacklist::<Test>::contains_key(&cid3));
+    });
+}
+
+#[test]
+fn update_blacklist_succeeds_with_existent_remove_hashes() {
+    build_test_externalities().execute_with(|| {
+        let cid1 = vec![1];
+
+        let add_hashes = BTreeSet::from_iter(vec![cid1.clone()]);
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_add_hashes(add_hashes.clone())
+            .call_and_assert(Ok(()));
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_add_hashes(add_hashes.clone())
+            .call_and_assert(Ok(()));
+
+        assert!(crate::Blacklist::<Test>::contains_key(&cid1));
+        assert_eq!(Storage::current_blacklist_size(), 1);
+    });
+}
+
+#[test]
+fn update_blacklist_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_blacklist_entry_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let cid = vec![1];
+        let expires_at = 10;
+        let entry = BlacklistEntry {
+            rationale: b"rationale".to_vec(),
+            expires_at: Some(expires_at),
+        };
+
+        SetBlacklistEntryFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_cid(cid.clone())
+            .with_entry(entry.clone())
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Storage::current_blacklist_size(), 1);
+        assert!(BlacklistExpirations::<Test>::contains_key(expires_at, &cid));
+
+        EventFixture::assert_last_crate_event(RawEvent::BlacklistEntrySet(cid, entry));
+    });
+}
+
+#[test]
+fn set_blacklist_entry_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        SetBlacklistEntryFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_blacklist_entry_fails_with_past_expiry() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 5;
+        run_to_block(starting_block);
+
+        SetBlacklistEntryFixture::default()
+            .with_cid(vec![1])
+            .with_entry(BlacklistEntry {
+                rationale: Vec::new(),
+                expires_at: Some(starting_block),
+            })
+            .call_and_assert(Err(Error::<Test>::InvalidBlacklistEntryExpiry.into()));
+    });
+}
+
+#[test]
+fn set_blacklist_entry_fails_with_exceeding_size_limit() {
+    build_test_externalities().execute_with(|| {
+        let cids = (0..=BlacklistSizeLimit::get() as u8).map(|idx| vec![idx]);
+
+        for (idx, cid) in cids.enumerate() {
+            let expected_result = if (idx as u64) < BlacklistSizeLimit::get() {
+                Ok(())
+            } else {
+                Err(Error::<Test>::BlacklistSizeLimitExceeded.into())
+            };
+
+            SetBlacklistEntryFixture::default()
+                .with_cid(cid)
+                .call_and_assert(expected_result);
+        }
+    });
+}
+
+#[test]
+fn set_blacklist_entry_succeeded_with_existing_entry_at_size_limit() {
+    build_test_externalities().execute_with(|| {
+        let cid = vec![1];
+
+        UpdateBlacklistFixture::default()
+            .with_add_hashes(BTreeSet::from_iter(vec![cid.clone()]))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Storage::current_blacklist_size(), BlacklistSizeLimit::get());
+
+        SetBlacklistEntryFixture::default()
+            .with_cid(cid)
+            .with_entry(BlacklistEntry {
+                rationale: b"rationale".to_vec(),
+                expires_at: None,
+            })
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Storage::current_blacklist_size(), BlacklistSizeLimit::get());
+    });
+}
+
+#[test]
+fn blacklist_entry_expires_at_expiry_block() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let cid = vec![1];
+        let expires_at = 10;
+
+        SetBlacklistEntryFixture::default()
+            .with_cid(cid.clone())
+            .with_entry(BlacklistEntry {
+                rationale: Vec::new(),
+                expires_at: Some(expires_at),
+            })
+            .call_and_assert(Ok(()));
+
+        run_to_block(expires_at - 1);
+        assert!(crate::Blacklist::<Test>::contains_key(&cid));
+
+        run_to_block(expires_at);
+
+        assert!(!crate::Blacklist::<Test>::contains_key(&cid));
+        assert_eq!(Storage::current_blacklist_size(), 0);
+        EventFixture::assert_last_crate_event(RawEvent::BlacklistEntryExpired(cid));
+    });
+}
+
+#[test]
+fn blacklist_expirations_are_processed_in_bounded_batches() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let max_expirations = MaxBlacklistExpirationsPerBlock::get();
+        let expires_at = 10;
+
+        // The blacklist size limit is bypassed to schedule the expirations to the same block.
+        let cids = (0..=max_expirations)
+            .map(|index| vec![index as u8])
+            .collect::<Vec<_>>();
+        for cid in cids.iter() {
+            crate::Blacklist::<Test>::insert(
+                cid,
+                BlacklistEntry {
+                    rationale: Vec::new(),
+                    expires_at: Some(expires_at),
+                },
+            );
+            BlacklistExpirations::<Test>::insert(expires_at, cid, ());
+        }
+        crate::CurrentBlacklistSize::put(cids.len() as u64);
+
+        run_to_block(expires_at);
+
+        assert_eq!(Storage::current_blacklist_size(), 1);
+        assert_eq!(Storage::blacklist_expirations_cursor(), expires_at);
+
+        // The remaining expiration is processed in the next block.
+        run_to_block(expires_at + 1);
+
+        assert_eq!(Storage::current_blacklist_size(), 0);
+        for cid in cids {
+            assert!(!crate::Blacklist::<Test>::contains_key(&cid));
+            EventFixture::contains_crate_event(RawEvent::BlacklistEntryExpired(cid));
+        }
+        assert_eq!(Storage::blacklist_expirations_cursor(), expires_at + 2);
+    });
+}
+
+#[test]
+fn blacklist_entry_update_reschedules_expiration() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let cid = vec![1];
+        let expires_at = 10;
+
+        SetBlacklistEntryFixture::default()
+            .with_cid(cid.clone())
+            .with_entry(BlacklistEntry {
+                rationale: Vec::new(),
+                expires_at: Some(expires_at),
+            })
+            .call_and_assert(Ok(()));
+
+        SetBlacklistEntryFixture::default()
+            .with_cid(cid.clone())
+            .with_entry(BlacklistEntry {
+                rationale: Vec::new(),
+                expires_at: None,
+            })
+            .call_and_assert(Ok(()));
+
+        run_to_block(expires_at);
+
+        assert!(crate::Blacklist::<Test>::contains_key(&cid));
+        assert_eq!(Storage::current_blacklist_size(), 1);
+    });
+}
+
+#[test]
+fn update_blacklist_clears_blacklist_entry_expiration() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let cid = vec![1];
+        let expires_at = 10;
+
+        SetBlacklistEntryFixture::default()
+            .with_cid(cid.clone())
+            .with_entry(BlacklistEntry {
+                rationale: Vec::new(),
+                expires_at: Some(expires_at),
+            })
+            .call_and_assert(Ok(()));
+
+        UpdateBlacklistFixture::default()
+            .with_remove_hashes(BTreeSet::from_iter(vec![cid.clone()]))
+            .call_and_assert(Ok(()));
+
+        assert!(!BlacklistExpirations::<Test>::contains_key(
+            expires_at, &cid
+        ));
+    });
+}
+
+// Uploads the data objects with the same content ID to the member dynamic bag and blacklists
+// the content ID.
+fn setup_blacklisted_data_objects_test(
+    cid: Cid,
+    objects_number: u64,
+) -> (BagId<Test>, BTreeSet<u64>) {
+    let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+    create_dynamic_bag(&dynamic_bag_id);
+
+    let bag_id: BagId<Test> = dynamic_bag_id.into();
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    let first_data_object_id = Storage::next_data_object_id();
+    let object_creation_list = (0..objects_number)
+        .map(|_| DataObjectCreationParameters {
+            size: DEFAULT_DATA_OBJECTS_SIZE,
+            ipfs_content_id: cid.clone(),
+            content_root: None,
+            parts: Vec::new(),
+            class: None,
+        })
+        .collect();
+    upload_data_objects_to_bag(bag_id.clone(), object_creation_list, Ok(()));
+
+    UpdateBlacklistFixture::default()
+        .with_add_hashes(BTreeSet::from_iter(vec![cid]))
+        .call_and_assert(Ok(()));
+
+    let data_object_ids = (first_data_object_id..first_data_object_id + objects_number).collect();
+
+    (bag_id, data_object_ids)
+}
+
+#[test]
+fn purge_blacklisted_data_objects_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let cid = vec![1];
+        let objects_number = 2;
+        let (bag_id, data_object_ids) =
+            setup_blacklisted_data_objects_test(cid.clone(), objects_number);
+
+        let balance = Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID);
+        let treasury_balance =
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id());
+
+        PurgeBlacklistedDataObjectsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_cid(cid.clone())
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(data_object_ids.clone())
+            .call_and_assert(Ok(()));
+
+        // Deletion prizes are forfeited.
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            balance
+        );
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            treasury_balance
+        );
+        assert_eq!(Storage::bag(&bag_id).objects_total_size, 0);
+        assert_eq!(
+            REMOVED_DATA_OBJECTS.with(|val| val.borrow().clone()),
+            data_object_ids
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::BlacklistedDataObjectsPurged(
+            cid,
+            bag_id,
+            data_object_ids,
+        ));
+    });
+}
+
+#[test]
+fn purge_blacklisted_data_objects_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        PurgeBlacklistedDataObjectsFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn purge_blacklisted_data_objects_fails_with_non_blacklisted_cid() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, data_object_ids) = setup_blacklisted_data_objects_test(vec![1], 1);
+
+        PurgeBlacklistedDataObjectsFixture::default()
+            .with_cid(vec![2])
+            .with_bag_id(bag_id)
+            .with_data_object_ids(data_object_ids)
+            .call_and_assert(Err(Error::<Test>::BlacklistEntryDoesntExist.into()));
+    });
+}
+
+#[test]
+fn purge_blacklisted_data_objects_fails_with_mismatching_data_object() {
+    build_test_externalities().execute_with(|| {
+        let cid = vec![1];
+        let (bag_id, mut data_object_ids) = setup_blacklisted_data_objects_test(cid.clone(), 1);
+
+        let non_blacklisted_data_object_id = Storage::next_data_object_id();
+        upload_data_objects_to_bag(bag_id.clone(), create_data_object_candidates(2, 1), Ok(()));
+        data_object_ids.insert(non_blacklisted_data_object_id);
+
+        PurgeBlacklistedDataObjectsFixture::default()
+            .with_cid(cid)
+            .with_bag_id(bag_id)
+            .with_data_object_ids(data_object_ids)
+            .call_and_assert(Err(Error::<Test>::DataObjectIsNotBlacklisted.into()));
+    });
+}
+
+#[test]
+fn purge_blacklisted_data_objects_fails_with_non_existent_data_object() {
+    build_test_externalities().execute_with(|| {
+        let cid = vec![1];
+        let (bag_id, _) = setup_blacklisted_data_objects_test(cid.clone(), 1);
+
+        PurgeBlacklistedDataObjectsFixture::default()
+            .with_cid(cid)
+            .with_bag_id(bag_id)
+            .with_data_object_ids(BTreeSet::from_iter(vec![Storage::next_data_object_id()]))
+            .call_and_assert(Err(Error::<Test>::DataObjectDoesntExist.into()));
+    });
+}
+
+#[test]
+fn blacklisted_data_objects_returns_objects_matching_blacklist_entry() {
+    build_test_externalities().execute_with(|| {
+        let cid = vec![1];
+        let (bag_id, data_object_ids) = setup_blacklisted_data_objects_test(cid.clone(), 2);
+
+        upload_data_objects_to_bag(bag_id.clone(), create_data_object_candidates(2, 1), Ok(()));
+
+        let objects =
+            BTreeSet::from_iter(Storage::blacklisted_data_objects(&cid, &bag_id).into_iter());
+
+        assert_eq!(objects, data_object_ids);
+        assert!(Storage::blacklisted_data_objects(
+            &cid,
+            &BagId::<Test>::Static(StaticBagId::Council)
+        )
+        .is_empty());
+    });
+}
+
+#[test]
+fn blacklisted_data_objects_page_returns_objects_of_all_bags() {
+    build_test_externalities().execute_with(|| {
+        let cid = vec![1];
+
+        // The content is uploaded before the blacklisting.
+        let another_bag_id: BagId<Test> = StaticBagId::Council.into();
+        let another_data_object_id = Storage::next_data_object_id();
+        let object_creation_list = vec![DataObjectCreationParameters {
+            size: DEFAULT_DATA_OBJECTS_SIZE,
+            ipfs_content_id: cid.clone(),
+            content_root: None,
+            parts: Vec::new(),
+            class: None,
+        }];
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        upload_data_objects_to_bag(another_bag_id.clone(), object_creation_list, Ok(()));
+
+        let (bag_id, data_object_ids) = setup_blacklisted_data_objects_test(cid.clone(), 2);
+
+        let mut expected_objects = data_object_ids
+            .into_iter()
+            .map(|data_object_id| (bag_id.clone(), data_object_id))
+            .collect::<BTreeSet<_>>();
+        expected_objects.insert((another_bag_id, another_data_object_id));
+
+        let first_page = Storage::blacklisted_data_objects_page(&cid, 0, 2);
+        let second_page = Storage::blacklisted_data_objects_page(&cid, 2, 2);
+
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(
+            BTreeSet::from_iter(first_page.into_iter().chain(second_page.into_iter())),
+            expected_objects
+        );
+        assert!(Storage::blacklisted_data_objects_page(&vec![2], 0, 2).is_empty());
+    });
+}
+
+#[test]
+fn move_data_objects_fails_with_blacklisted_data_object() {
+    build_test_externalities().execute_with(|| {
+        let (src_bag_id, data_object_ids) = setup_blacklisted_data_objects_test(vec![1], 1);
+
+        let dest_dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID + 1);
+        create_dynamic_bag(&dest_dynamic_bag_id);
+
+        MoveDataObjectsFixture::default()
+            .with_src_bag_id(src_bag_id)
+            .with_dest_bag_id(dest_dynamic_bag_id.into())
+            .with_data_object_ids(data_object_ids)
+            .call_and_assert(Err(Error::<Test>::DataObjectBlacklisted.into()));
+    });
+}
+
+fn create_default_storage_bucket_and_assign_to_bag(bag_id: BagId<Test>) -> u64 {
+    let objects_limit = 1;
+    let size_limit = 100;
+
+    create_storage_bucket_and_assign_to_bag(bag_id, None, objects_limit, size_limit)
+}
+
+fn create_storage_bucket_and_assign_to_bag(
+    bag_id: BagId<Test>,
+    storage_provider_id: Option<u64>,
+    objects_limit: u64,
+    size_limit: u64,
+) -> u64 {
+    set_max_voucher_limits();
+    set_default_update_storage_buckets_per_bag_limit();
+
+    let bucket_id = CreateStorageBucketFixture::default()
+        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+        .with_invite_worker(storage_provider_id)
+        .with_objects_limit(objects_limit)
+        .with_size_limit(size_limit)
+        .call_and_assert(Ok(()))
+        .unwrap();
+
+    let buckets = BTreeSet::from_iter(vec![bucket_id]);
+
+    UpdateStorageBucketForBagsFixture::default()
+        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+        .with_bag_id(bag_id.clone())
+        .with_add_bucket_ids(buckets.clone())
+        .call_and_assert(Ok(()));
+
+    if let Some(storage_provider_id) = storage_provider_id {
+        AcceptStorageBucketInvitationFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_transactor_account_id(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_worker_id(storage_provider_id)
+            .call_and_assert(Ok(()));
+    }
+
+    bucket_id
+}
+
+#[test]
+fn delete_dynamic_bags_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 1000;
+        let deletion_prize_value = 77;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_prize(DynamicBagDeletionPrize::<Test> {
+                account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                prize: deletion_prize_value,
+            })
+            .call_and_assert(Ok(()));
+
+        // pre-check balances
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            initial_balance - deletion_prize_value
+        );
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            deletion_prize_value
+        );
+
+        DeleteDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Ok(()));
+
+        // post-check balances
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            0
+        );
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            initial_balance
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::DynamicBagDeleted(
+            DEFAULT_MEMBER_ACCOUNT_ID,
+            dynamic_bag_id,
+        ));
+    });
+}
+
+#[test]
+fn delete_dynamic_bags_succeeded_with_assigned_distribution_buckets() {
+    build_test_externalities().execute_with(|| {
+        let initial_balance = 1000;
+        let deletion_prize_value = 77;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let distribution_buckets_number = 10;
+        let family_policy_number1 = 2;
+        let family_policy_number2 = 3;
+
+        create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER);
+        let (family1, _) =
+            create_distribution_bucket_family_with_buckets(distribution_buckets_number);
+        let (family2, _) =
+            create_distribution_bucket_family_with_buckets(distribution_buckets_number);
+
+        let family_policy = BTreeMap::from_iter(vec![
+            (family1, family_policy_number1),
+            (family2, family_policy_number2),
+        ]);
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(family_policy)
+            .call_and_assert(Ok(()));
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_prize(DynamicBagDeletionPrize::<Test> {
+                account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                prize: deletion_prize_value,
+            })
+            .call_and_assert(Ok(()));
+
+        let bag = Storage::dynamic_bag(&dynamic_bag_id);
+
+        let total_distributed_buckets_number = family_policy_number1 + family_policy_number2;
+        assert_eq!(
+            bag.distributed_by.len(),
+            total_distributed_buckets_number as usize
+        );
+
+        let distributed_by_bag = bag.distributed_by.clone();
+        for distribution_bucket_id in &distributed_by_bag {
+            let bucket = Storage::distribution_bucket_by_family_id_by_index(
+                distribution_bucket_id.distribution_bucket_family_id,
+                distribution_bucket_id.distribution_bucket_index,
+            );
+
+            assert_eq!(bucket.assigned_bags, 1);
+        }
+
+        DeleteDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Ok(()));
+
+        for distribution_bucket_id in &distributed_by_bag {
+            let bucket = Storage::distribution_bucket_by_family_id_by_index(
+                distribution_bucket_id.distribution_bucket_family_id,
+                distribution_bucket_id.distribution_bucket_index,
+            );
+
+            assert_eq!(bucket.assigned_bags, 0);
+        }
+    });
+}
+
+#[test]
+fn delete_dynamic_bags_succeeded_with_assigned_storage_buckets() {
+    build_test_externalities().execute_with(|| {
+        let initial_balance = 1000;
+        let deletion_prize_value = 77;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let storage_buckets_number = DefaultMemberDynamicBagNumberOfStorageBuckets::get();
+        create_storage_buckets(storage_buckets_number);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_prize(DynamicBagDeletionPrize::<Test> {
+                account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                prize: deletion_prize_value,
+            })
+            .call_and_assert(Ok(()));
+
+        let bag = Storage::dynamic_bag(&dynamic_bag_id);
+
+        assert_eq!(bag.stored_by.len(), storage_buckets_number as usize);
+
+        let stored_by_bag = bag.stored_by.clone();
+        for bucket_id in &stored_by_bag {
+            let bucket = Storage::storage_bucket_by_id(bucket_id);
+
+            assert_eq!(bucket.assigned_bags, 1);
+        }
+
+        DeleteDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Ok(()));
+
+        for bucket_id in &stored_by_bag {
+            let bucket = Storage::storage_bucket_by_id(bucket_id);
+
+            assert_eq!(bucket.assigned_bags, 0);
+        }
+    });
+}
+
+#[test]
+fn delete_dynamic_bags_fails_with_non_existent_dynamic_bag() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+
+        DeleteDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Err(Error::<Test>::DynamicBagDoesntExist.into()));
+    });
+}
+
+#[test]
+fn delete_dynamic_bags_fails_with_insufficient_balance_for_deletion_prize() {
+    build_test_externalities().execute_with(|| {
+        let initial_balance = 1000;
+        let deletion_prize_value = 77;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_prize(DynamicBagDeletionPrize::<Test> {
+                account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                prize: deletion_prize_value,
+            })
+            .call_and_assert(Ok(()));
+
+        // Corrupt module balance.
+        let _ = Balances::slash(
+            &<StorageTreasury<Test>>::module_account_id(),
+            <StorageTreasury<Test>>::usable_balance(),
+        );
+
+        DeleteDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Err(Error::<Test>::InsufficientTreasuryBalance.into()));
+    });
+}
+
+#[test]
+fn delete_storage_bucket_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        DeleteStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketDeleted(bucket_id));
+    });
+}
+
+#[test]
+fn delete_storage_bucket_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        DeleteStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn delete_storage_bucket_fails_with_non_existing_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        DeleteStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn delete_storage_bucket_fails_with_non_empty_bucket() {
+    build_test_externalities().execute_with(|| {
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+
+        let bucket_id = create_default_storage_bucket_and_assign_to_bag(bag_id.clone());
+
+        let object_creation_list = create_single_data_object();
+
+        let initial_balance = 1000;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id: bag_id.clone(),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: object_creation_list.clone(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params.clone())
+            .call_and_assert(Ok(()));
+
+        DeleteStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::CannotDeleteNonEmptyStorageBucket.into()));
+    });
+}
+
+#[test]
+fn delete_storage_bucket_fails_with_assigned_bag() {
+    build_test_externalities().execute_with(|| {
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+
+        let bucket_id = create_default_storage_bucket_and_assign_to_bag(bag_id.clone());
+
+        DeleteStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageBucketIsBoundToBag.into()));
+    });
+}
+
+#[test]
+fn remove_storage_bucket_operator_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
+        let invite_worker = Some(storage_provider_id);
+
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_invite_worker(invite_worker)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        AcceptStorageBucketInvitationFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_worker_id(storage_provider_id)
+            .call_and_assert(Ok(()));
+
+        RemoveStorageBucketOperatorFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketOperatorRemoved(bucket_id));
+    });
+}
+
+#[test]
+fn remove_storage_bucket_operator_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_storage_provider_id = 1;
+
+        RemoveStorageBucketOperatorFixture::default()
+            .with_origin(RawOrigin::Signed(non_storage_provider_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn remove_storage_bucket_operator_fails_with_non_existing_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        RemoveStorageBucketOperatorFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn remove_storage_bucket_operator_fails_with_non_accepted_storage_provider() {
+    build_test_externalities().execute_with(|| {
+        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
+        let invite_worker = Some(storage_provider_id);
+
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_invite_worker(invite_worker)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        RemoveStorageBucketOperatorFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageProviderMustBeSet.into()));
+    });
+}
+
+#[test]
+fn remove_storage_bucket_operator_fails_with_missing_storage_provider() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_invite_worker(None)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        RemoveStorageBucketOperatorFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageProviderMustBeSet.into()));
+    });
+}
+
+#[test]
+fn update_data_size_fee_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_fee = 1000;
+
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_fee(new_fee)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectPerMegabyteFeeUpdated(new_fee));
+    });
+}
+
+#[test]
+fn update_data_size_fee_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn data_size_fee_calculation_works_properly() {
+    build_test_externalities().execute_with(|| {
+        const ONE_MB: u64 = 1_048_576;
+
+        // Fee set to zero.
+        assert_eq!(
+            Storage::calculate_data_storage_fee(Storage::data_object_per_mega_byte_fee(), ONE_MB),
+            0
+        );
+
+        let data_size_fee = 1000;
+
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_fee(data_size_fee)
+            .call_and_assert(Ok(()));
+
+        let mb_fee = Storage::data_object_per_mega_byte_fee();
+
+        // Fee set.
+        assert_eq!(
+            Storage::calculate_data_storage_fee(mb_fee, ONE_MB),
+            data_size_fee
+        );
+        assert_eq!(
+            Storage::calculate_data_storage_fee(mb_fee, 2 * ONE_MB),
+            2 * data_size_fee
+        );
+
+        // Rounding works correctly.
+        assert_eq!(
+            Storage::calculate_data_storage_fee(mb_fee, ONE_MB + 1),
+            2 * data_size_fee
+        );
+    });
+}
+
+#[test]
+fn update_data_size_fee_schedule_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let category = DataSizeFeeCategory::Dynamic(DynamicBagType::Channel);
+        let schedule = DataSizeFeeSchedule {
+            per_megabyte_fee: 100,
+            object_class_fees: vec![(DataObjectClass::Thumbnail, 10)].into_iter().collect(),
+        };
+
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_category(category.clone())
+            .with_schedule(Some(schedule.clone()))
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DataSizeFeeScheduleUpdated(
+            category.clone(),
+            Some(schedule),
+        ));
+
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_category(category.clone())
+            .with_schedule(None)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DataSizeFeeScheduleUpdated(category, None));
+    });
+}
+
+#[test]
+fn update_data_size_fee_schedule_succeeded_with_root_origin() {
+    build_test_externalities().execute_with(|| {
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_origin(RawOrigin::Root)
+            .with_schedule(Some(DataSizeFeeSchedule {
+                per_megabyte_fee: 100,
+                object_class_fees: BTreeMap::new(),
+            }))
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn update_data_size_fee_schedule_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn upload_charges_data_size_fee_by_bag_schedule_and_object_class() {
+    build_test_externalities().execute_with(|| {
+        let initial_balance = 1000;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_new_fee(1)
+            .call_and_assert(Ok(()));
+
+        let per_megabyte_fee = 100;
+        let thumbnail_fee = 10;
+
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_category(DataSizeFeeCategory::Static(StaticBagId::Council))
+            .with_schedule(Some(DataSizeFeeSchedule {
+                per_megabyte_fee,
+                object_class_fees: vec![(DataObjectClass::Thumbnail, thumbnail_fee)]
+                    .into_iter()
+                    .collect(),
+            }))
+            .call_and_assert(Ok(()));
+
+        // Objects without the class and with the class missing in the schedule are charged
+        // together with the default schedule fee, objects with the priced class are charged with
+        // the class fee in the same upload.
+        let classes = vec![
+            None,
+            Some(DataObjectClass::Metadata),
+            Some(DataObjectClass::Thumbnail),
+        ];
+        let object_creation_list = create_data_object_candidates(1, 3)
+            .into_iter()
+            .zip(classes)
+            .map(|(object_params, class)| DataObjectCreationParameters {
+                class,
+                ..object_params
+            })
+            .collect::<Vec<_>>();
+
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+
+        assert_eq!(
+            Storage::upload_data_size_fee(&bag_id, &object_creation_list),
+            per_megabyte_fee + thumbnail_fee
+        );
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id,
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list,
+            expected_data_size_fee: per_megabyte_fee + thumbnail_fee,
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            initial_balance - 3 * DataObjectDeletionPrize::get() - per_megabyte_fee - thumbnail_fee
+        );
+    });
+}
+
+#[test]
+fn upload_fails_with_data_size_fee_changed_by_object_class_fee() {
+    build_test_externalities().execute_with(|| {
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, 1000);
+
+        let per_megabyte_fee = 100;
+        let thumbnail_fee = 10;
+
+        let schedule = DataSizeFeeSchedule {
+            per_megabyte_fee,
+            object_class_fees: vec![(DataObjectClass::Thumbnail, thumbnail_fee)]
+                .into_iter()
+                .collect(),
+        };
+
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_category(DataSizeFeeCategory::Static(StaticBagId::Council))
+            .with_schedule(Some(schedule.clone()))
+            .call_and_assert(Ok(()));
+
+        let classes = vec![None, Some(DataObjectClass::Thumbnail)];
+        let object_creation_list = create_data_object_candidates(1, 2)
+            .into_iter()
+            .zip(classes)
+            .map(|(object_params, class)| DataObjectCreationParameters {
+                class,
+                ..object_params
+            })
+            .collect::<Vec<_>>();
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id: BagId::<Test>::Static(StaticBagId::Council),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list,
+            expected_data_size_fee: per_megabyte_fee + thumbnail_fee,
+        };
+
+        // The class fee changes after the upload fee was quoted.
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_category(DataSizeFeeCategory::Static(StaticBagId::Council))
+            .with_schedule(Some(DataSizeFeeSchedule {
+                object_class_fees: vec![(DataObjectClass::Thumbnail, 2 * thumbnail_fee)]
+                    .into_iter()
+                    .collect(),
+                ..schedule
+            }))
+            .call_and_assert(Ok(()));
+
+        UploadFixture::default()
+            .with_params(upload_params)
+            .call_and_assert(Err(Error::<Test>::DataSizeFeeChanged.into()));
+    });
+}
+
+#[test]
+fn upload_fails_with_data_size_fee_changed_by_bag_schedule() {
+    build_test_externalities().execute_with(|| {
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, 1000);
+
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_category(DataSizeFeeCategory::Static(StaticBagId::Council))
+            .with_schedule(Some(DataSizeFeeSchedule {
+                per_megabyte_fee: 100,
+                object_class_fees: BTreeMap::new(),
+            }))
+            .call_and_assert(Ok(()));
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id: BagId::<Test>::Static(StaticBagId::Council),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: create_single_data_object(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params)
+            .call_and_assert(Err(Error::<Test>::DataSizeFeeChanged.into()));
+    });
+}
+
+#[test]
+fn storage_bucket_voucher_changed_event_fired() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let bag_id = BagId::<Test>::Dynamic(dynamic_bag_id.clone());
+        create_dynamic_bag(&dynamic_bag_id);
+
+        let objects_limit = 1;
+        let size_limit = 100;
+
+        let bucket_id = create_storage_bucket_and_assign_to_bag(
+            bag_id.clone(),
+            None,
+            objects_limit,
+            size_limit,
+        );
+
+        let object_creation_list = create_single_data_object();
+
+        let initial_balance = 1000;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id: bag_id.clone(),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: object_creation_list.clone(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::VoucherChanged(
+            bucket_id,
+            Voucher {
+                objects_limit,
+                size_limit,
+                objects_used: 1,
+                size_used: object_creation_list[0].size,
+            },
+        ));
+    });
+}
+
+#[test]
+fn update_storage_buckets_per_bag_limit_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_limit = 4;
+
+        UpdateStorageBucketsPerBagLimitFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_limit(new_limit)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketsPerBagLimitUpdated(
+            new_limit,
+        ));
+    });
+}
+
+#[test]
+fn update_storage_buckets_per_bag_limit_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateStorageBucketsPerBagLimitFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_storage_buckets_per_bag_limit_fails_with_incorrect_value() {
+    build_test_externalities().execute_with(|| {
+        let new_limit = 0;
+
+        UpdateStorageBucketsPerBagLimitFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_limit(new_limit)
+            .call_and_assert(Err(Error::<Test>::StorageBucketsPerBagLimitTooLow.into()));
+
+        let new_limit = 100;
+
+        UpdateStorageBucketsPerBagLimitFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_limit(new_limit)
+            .call_and_assert(Err(Error::<Test>::StorageBucketsPerBagLimitTooHigh.into()));
+    });
+}
+
+fn set_update_storage_buckets_per_bag_limit(new_limit: u64) {
+    UpdateStorageBucketsPerBagLimitFixture::default()
+        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+        .with_new_limit(new_limit)
+        .call_and_assert(Ok(()))
+}
+
+fn set_default_update_storage_buckets_per_bag_limit() {
+    let new_limit = 7;
+
+    set_update_storage_buckets_per_bag_limit(new_limit);
+}
+
+#[test]
+fn set_storage_bucket_voucher_limits_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_max_voucher_limits();
+
+        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
+        let invite_worker = Some(storage_provider_id);
+
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_invite_worker(invite_worker)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        AcceptStorageBucketInvitationFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_worker_id(storage_provider_id)
+            .call_and_assert(Ok(()));
+
+        let new_objects_size_limit = 1;
+        let new_objects_number_limit = 1;
+
+        SetStorageBucketVoucherLimitsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_objects_number_limit(new_objects_number_limit)
+            .with_new_objects_size_limit(new_objects_size_limit)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketVoucherLimitsSet(
+            bucket_id,
+            new_objects_size_limit,
+            new_objects_number_limit,
+        ));
+    });
+}
+
+#[test]
+fn set_storage_bucket_voucher_limits_fails_with_invalid_values() {
+    build_test_externalities().execute_with(|| {
+        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
+        let invite_worker = Some(storage_provider_id);
+
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_invite_worker(invite_worker)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        AcceptStorageBucketInvitationFixture::default()
             .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
-            .with_transactor_account_id(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID)
             .with_storage_bucket_id(bucket_id)
-            .with_worker_id(storage_provider_id)
+            .with_worker_id(storage_provider_id)
+            .call_and_assert(Ok(()));
+
+        let invalid_objects_size_limit = 1000;
+        let invalid_objects_number_limit = 1000;
+
+        SetStorageBucketVoucherLimitsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_objects_size_limit(invalid_objects_size_limit)
+            .call_and_assert(Err(Error::<Test>::VoucherMaxObjectSizeLimitExceeded.into()));
+
+        SetStorageBucketVoucherLimitsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_objects_number_limit(invalid_objects_number_limit)
+            .call_and_assert(Err(
+                Error::<Test>::VoucherMaxObjectNumberLimitExceeded.into()
+            ));
+    });
+}
+
+#[test]
+fn set_storage_bucket_voucher_limits_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        SetStorageBucketVoucherLimitsFixture::default()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_storage_bucket_voucher_limits_fails_with_invalid_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        SetStorageBucketVoucherLimitsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+    });
+}
+
+fn set_max_voucher_limits() {
+    set_max_voucher_limits_with_params(VOUCHER_SIZE_LIMIT, VOUCHER_OBJECTS_LIMIT);
+}
+
+fn set_max_voucher_limits_with_params(size_limit: u64, objects_limit: u64) {
+    UpdateStorageBucketsVoucherMaxLimitsFixture::default()
+        .with_new_objects_size_limit(size_limit)
+        .with_new_objects_number_limit(objects_limit)
+        .call_and_assert(Ok(()));
+}
+
+#[test]
+fn update_storage_buckets_voucher_max_limits_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_size_limit = 14;
+        let new_number_limit = 4;
+
+        UpdateStorageBucketsVoucherMaxLimitsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_objects_number_limit(new_number_limit)
+            .with_new_objects_size_limit(new_size_limit)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketsVoucherMaxLimitsUpdated(
+            new_size_limit,
+            new_number_limit,
+        ));
+    });
+}
+
+#[test]
+fn update_storage_buckets_voucher_max_limits_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateStorageBucketsVoucherMaxLimitsFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn create_dynamic_bag_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let distribution_buckets_number = 10;
+        let family_policy_number1 = 2;
+        let family_policy_number2 = 3;
+
+        create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER);
+        let (family1, _) =
+            create_distribution_bucket_family_with_buckets(distribution_buckets_number);
+        let (family2, _) =
+            create_distribution_bucket_family_with_buckets(distribution_buckets_number);
+
+        let family_policy = BTreeMap::from_iter(vec![
+            (family1, family_policy_number1),
+            (family2, family_policy_number2),
+        ]);
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(family_policy)
+            .call_and_assert(Ok(()));
+
+        let deletion_prize_value = 100;
+        let deletion_prize_account_id = DEFAULT_MEMBER_ACCOUNT_ID;
+        let initial_balance = 10000;
+        increase_account_balance(&deletion_prize_account_id, initial_balance);
+
+        let deletion_prize = DynamicBagDeletionPrize::<Test> {
+            prize: deletion_prize_value,
+            account_id: deletion_prize_account_id,
+        };
+
+        // pre-check balances
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            initial_balance
+        );
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            0
+        );
+
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_prize(deletion_prize.clone())
+            .call_and_assert(Ok(()));
+
+        let bag = Storage::dynamic_bag(&dynamic_bag_id);
+
+        // Check that IDs are within possible range.
+        assert!(bag
+            .stored_by
+            .iter()
+            .all(|id| { *id < Storage::next_storage_bucket_id() }));
+
+        let creation_policy =
+            Storage::get_dynamic_bag_creation_policy(dynamic_bag_id.clone().into());
+        assert_eq!(
+            bag.stored_by.len(),
+            creation_policy.number_of_storage_buckets as usize
+        );
+
+        let total_distributed_buckets_number = family_policy_number1 + family_policy_number2;
+        assert_eq!(
+            bag.distributed_by.len(),
+            total_distributed_buckets_number as usize
+        );
+
+        for distribution_bucket_id in &bag.distributed_by {
+            let bucket = Storage::distribution_bucket_by_family_id_by_index(
+                distribution_bucket_id.distribution_bucket_family_id,
+                distribution_bucket_id.distribution_bucket_index,
+            );
+
+            assert_eq!(bucket.assigned_bags, 1);
+        }
+
+        assert_eq!(bag.deletion_prize.unwrap(), deletion_prize_value);
+
+        // post-check balances
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            initial_balance - deletion_prize_value
+        );
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            deletion_prize_value
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::DynamicBagCreated(
+            dynamic_bag_id,
+            Some(deletion_prize),
+            BTreeSet::from_iter(bag.stored_by),
+            BTreeSet::from_iter(bag.distributed_by),
+        ));
+    });
+}
+
+#[test]
+fn create_dynamic_bag_fails_with_insufficient_balance() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let deletion_prize_value = 100;
+
+        let deletion_prize = DynamicBagDeletionPrize::<Test> {
+            prize: deletion_prize_value,
+            account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        };
+
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .with_deletion_prize(deletion_prize)
+            .call_and_assert(Err(Error::<Test>::InsufficientBalance.into()));
+    });
+}
+
+#[test]
+fn create_dynamic_bag_failed_with_existing_bag() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .call_and_assert(Ok(()));
+
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .call_and_assert(Err(Error::<Test>::DynamicBagExists.into()));
+    });
+}
+
+fn create_dynamic_bag(dynamic_bag_id: &DynamicBagId<Test>) {
+    CreateDynamicBagFixture::default()
+        .with_bag_id(dynamic_bag_id.clone())
+        .call_and_assert(Ok(()));
+}
+
+#[test]
+fn test_storage_bucket_picking_for_bag_non_random() {
+    build_test_externalities().execute_with(|| {
+        // Randomness disabled at the initial block.
+
+        let initial_buckets_number = InitialStorageBucketsNumberForDynamicBag::get();
+        // No buckets
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+        assert_eq!(bucket_ids, BTreeSet::new());
+
+        // Less then initial buckets number
+        let buckets_number = initial_buckets_number - 1;
+        let created_buckets = create_storage_buckets(buckets_number);
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+
+        assert_eq!(bucket_ids, created_buckets);
+
+        // More then initial buckets number
+        let buckets_number = 5;
+        create_storage_buckets(buckets_number);
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+
+        assert_eq!(
+            bucket_ids,
+            BTreeSet::from_iter((0u64..initial_buckets_number).into_iter())
+        );
+
+        // Check removed buckets
+        let removed_bucket_id = 1;
+        <crate::StorageBucketById<Test>>::remove(removed_bucket_id);
+
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+
+        let mut expected_ids =
+            BTreeSet::from_iter((0u64..(initial_buckets_number + 1)).into_iter());
+        expected_ids.remove(&removed_bucket_id);
+
+        assert_eq!(bucket_ids, expected_ids);
+
+        // Check disabled buckets
+        let disabled_bucket_id = 2;
+        <crate::StorageBucketById<Test>>::mutate(disabled_bucket_id, |bucket| {
+            bucket.accepting_new_bags = false;
+        });
+
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+
+        let mut expected_ids =
+            BTreeSet::from_iter((0u64..(initial_buckets_number + 2)).into_iter());
+        expected_ids.remove(&removed_bucket_id);
+        expected_ids.remove(&disabled_bucket_id);
+
+        assert_eq!(bucket_ids, expected_ids);
+
+        // No storage buckets required
+        crate::DynamicBagCreationPolicies::<Test>::insert(
+            DynamicBagType::Member,
+            DynamicBagCreationPolicy::default(),
+        );
+
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+        assert_eq!(bucket_ids, BTreeSet::new());
+    });
+}
+
+#[test]
+fn test_storage_bucket_picking_for_bag_with_randomness() {
+    build_test_externalities().execute_with(|| {
+        // Enable randomness (disabled at the initial block).
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_buckets_number = InitialStorageBucketsNumberForDynamicBag::get();
+        // No buckets
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+        assert_eq!(bucket_ids, BTreeSet::new());
+
+        // Less then initial buckets number
+        let buckets_number = initial_buckets_number - 1;
+        let created_buckets = create_storage_buckets(buckets_number);
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+
+        assert_eq!(bucket_ids, created_buckets);
+
+        // More then initial buckets number
+        let buckets_number = 5;
+        create_storage_buckets(buckets_number);
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+
+        let sequential_random_ids = BTreeSet::from_iter((0u64..initial_buckets_number).into_iter());
+
+        // Check number of generated IDs
+        assert_eq!(initial_buckets_number, bucket_ids.len() as u64);
+        // Verify that generated IDs differ from sequential ID enumeration.
+        assert_ne!(sequential_random_ids, bucket_ids);
+        // Check that IDs are within possible range.
+        assert!(bucket_ids
+            .iter()
+            .all(|id| { *id < Storage::next_storage_bucket_id() }));
+
+        // Check removed buckets
+        let removed_bucket_id = bucket_ids.iter().next().unwrap();
+        <crate::StorageBucketById<Test>>::remove(removed_bucket_id);
+
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+        // Check number of generated IDs
+        assert_eq!(initial_buckets_number, bucket_ids.len() as u64);
+        // Check that IDs are within possible range.
+        assert!(bucket_ids
+            .iter()
+            .all(|id| { *id < Storage::next_storage_bucket_id() }));
+        // Check removed bucket
+        assert!(!bucket_ids.contains(removed_bucket_id));
+
+        // Check disabled buckets
+        let disabled_bucket_id = 2;
+        <crate::StorageBucketById<Test>>::mutate(disabled_bucket_id, |bucket| {
+            bucket.accepting_new_bags = false;
+        });
+
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+
+        let mut expected_ids =
+            BTreeSet::from_iter((0u64..(initial_buckets_number + 2)).into_iter());
+        expected_ids.remove(&removed_bucket_id);
+        expected_ids.remove(&disabled_bucket_id);
+
+        // Check number of generated IDs
+        assert_eq!(initial_buckets_number, bucket_ids.len() as u64);
+        // Check that IDs are within possible range.
+        assert!(bucket_ids
+            .iter()
+            .all(|id| { *id < Storage::next_storage_bucket_id() }));
+        // Check removed bucket
+        assert!(!bucket_ids.contains(removed_bucket_id));
+
+        // No storage buckets required
+        crate::DynamicBagCreationPolicies::<Test>::insert(
+            DynamicBagType::Member,
+            DynamicBagCreationPolicy::default(),
+        );
+
+        let bucket_ids =
+            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+        assert_eq!(bucket_ids, BTreeSet::new());
+    });
+}
+
+fn pick_storage_buckets_by_capacity(voucher_update: Option<VoucherUpdate>) -> BTreeSet<u64> {
+    CapacityAwareStorageBucketPicker::<Test>::pick_storage_buckets(
+        DynamicBagType::Member,
+        voucher_update,
+    )
+}
+
+#[test]
+fn capacity_aware_storage_bucket_picking_prefers_free_capacity() {
+    build_test_externalities().execute_with(|| {
+        // No buckets
+        assert_eq!(pick_storage_buckets_by_capacity(None), BTreeSet::new());
+
+        create_storage_buckets(5);
+
+        for bucket_id in 0..2 {
+            <crate::StorageBucketById<Test>>::mutate(bucket_id, |bucket| {
+                bucket.voucher.size_used = 90;
+            });
+        }
+
+        assert_eq!(
+            pick_storage_buckets_by_capacity(None),
+            BTreeSet::from_iter(vec![2, 3, 4])
+        );
+    });
+}
+
+#[test]
+fn capacity_aware_storage_bucket_picking_spreads_bags() {
+    build_test_externalities().execute_with(|| {
+        create_storage_buckets(5);
+
+        <crate::StorageBucketById<Test>>::mutate(2, |bucket| {
+            bucket.assigned_bags = 1;
+        });
+
+        assert_eq!(
+            pick_storage_buckets_by_capacity(None),
+            BTreeSet::from_iter(vec![0, 1, 3])
+        );
+
+        // Twice the capacity outweighs a single assigned bag.
+        <crate::StorageBucketById<Test>>::mutate(4, |bucket| {
+            bucket.assigned_bags = 1;
+            bucket.voucher.size_limit = 3 * DEFAULT_STORAGE_BUCKET_SIZE_LIMIT;
+        });
+
+        assert_eq!(
+            pick_storage_buckets_by_capacity(None),
+            BTreeSet::from_iter(vec![0, 1, 4])
+        );
+    });
+}
+
+#[test]
+fn capacity_aware_storage_bucket_picking_skips_same_operator() {
+    build_test_externalities().execute_with(|| {
+        create_storage_buckets(5);
+
+        for bucket_id in 0..2 {
+            <crate::StorageBucketById<Test>>::mutate(bucket_id, |bucket| {
+                bucket.operator_status = StorageBucketOperatorStatus::StorageWorker(
+                    DEFAULT_STORAGE_PROVIDER_ID,
+                    DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID,
+                );
+            });
+        }
+        <crate::StorageBucketById<Test>>::mutate(2, |bucket| {
+            bucket.operator_status =
+                StorageBucketOperatorStatus::InvitedStorageWorker(DEFAULT_STORAGE_PROVIDER_ID);
+        });
+
+        assert_eq!(
+            pick_storage_buckets_by_capacity(None),
+            BTreeSet::from_iter(vec![0, 3, 4])
+        );
+    });
+}
+
+#[test]
+fn capacity_aware_storage_bucket_picking_filters_buckets() {
+    build_test_externalities().execute_with(|| {
+        create_storage_buckets(5);
+
+        <crate::StorageBucketById<Test>>::mutate(0, |bucket| {
+            bucket.voucher.size_used = 60;
+        });
+        <crate::StorageBucketById<Test>>::mutate(1, |bucket| {
+            bucket.accepting_new_bags = false;
+        });
+        <crate::StorageBucketById<Test>>::remove(2);
+
+        let voucher_update = VoucherUpdate {
+            objects_number: 1,
+            objects_total_size: 50,
+        };
+
+        assert_eq!(
+            pick_storage_buckets_by_capacity(Some(voucher_update)),
+            BTreeSet::from_iter(vec![3, 4])
+        );
+
+        // No storage buckets required
+        crate::DynamicBagCreationPolicies::<Test>::insert(
+            DynamicBagType::Member,
+            DynamicBagCreationPolicy::default(),
+        );
+
+        assert_eq!(pick_storage_buckets_by_capacity(None), BTreeSet::new());
+    });
+}
+
+#[test]
+fn capacity_aware_storage_bucket_picking_bounds_candidates() {
+    build_test_externalities().execute_with(|| {
+        let candidates_number = MaxStorageBucketPickerCandidates::get();
+        create_storage_buckets(candidates_number + 3);
+
+        // Buckets with the most free capacity are never sampled without randomness.
+        for bucket_id in candidates_number..candidates_number + 3 {
+            <crate::StorageBucketById<Test>>::mutate(bucket_id, |bucket| {
+                bucket.voucher.size_limit = 3 * DEFAULT_STORAGE_BUCKET_SIZE_LIMIT;
+            });
+        }
+
+        assert_eq!(
+            pick_storage_buckets_by_capacity(None),
+            BTreeSet::from_iter(vec![0, 1, 2])
+        );
+    });
+}
+
+#[test]
+fn dynamic_bag_replication_tracked_on_storage_bucket_status_update() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let buckets = create_storage_buckets(DefaultMemberDynamicBagNumberOfStorageBuckets::get());
+
+        create_dynamic_bag(&dynamic_bag_id);
+
+        assert_eq!(Storage::dynamic_bag(&dynamic_bag_id).stored_by, buckets);
+        assert!(Storage::under_replicated_bags().is_empty());
+
+        let bucket_id = *buckets.iter().next().unwrap();
+
+        UpdateStorageBucketStatusFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_status(false)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Storage::under_replicated_bags(),
+            vec![dynamic_bag_id.clone()]
+        );
+        EventFixture::contains_crate_event(RawEvent::DynamicBagUnderReplicated(
+            dynamic_bag_id.clone(),
+        ));
+
+        UpdateStorageBucketStatusFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_status(true)
+            .call_and_assert(Ok(()));
+
+        assert!(Storage::under_replicated_bags().is_empty());
+        EventFixture::contains_crate_event(RawEvent::DynamicBagReplicationRestored(dynamic_bag_id));
+    });
+}
+
+#[test]
+fn dynamic_bags_checked_for_replication_after_creation_policy_raise() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_default_update_storage_buckets_per_bag_limit();
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        create_storage_buckets(DefaultMemberDynamicBagNumberOfStorageBuckets::get());
+
+        create_dynamic_bag(&dynamic_bag_id);
+
+        assert_eq!(Storage::dynamic_bags_number(), 1);
+        assert!(Storage::under_replicated_bags().is_empty());
+
+        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_dynamic_bag_type(DynamicBagType::Member)
+            .with_new_storage_buckets_number(
+                DefaultMemberDynamicBagNumberOfStorageBuckets::get() + 1,
+            )
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Storage::replication_audit_cursor(), Some(0));
+
+        run_to_block(starting_block + 1);
+
+        assert_eq!(Storage::replication_audit_cursor(), None);
+        assert_eq!(Storage::under_replicated_bags(), vec![dynamic_bag_id]);
+    });
+}
+
+#[test]
+fn storage_layout_migration_builds_bag_indexes() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let bag_id: BagId<Test> = dynamic_bag_id.clone().into();
+        let buckets = create_storage_buckets(DefaultMemberDynamicBagNumberOfStorageBuckets::get());
+
+        create_dynamic_bag(&dynamic_bag_id);
+
+        // Layout before the bag indexes were introduced.
+        crate::LayoutVersion::put(StorageLayoutVersion::V0);
+        for bucket_id in buckets.iter() {
+            crate::StorageBucketBags::<Test>::remove(bucket_id, &bag_id);
+            crate::StorageBucketBagByIndex::<Test>::remove(bucket_id, 0);
+            crate::StorageBucketBagsNumber::<Test>::remove(bucket_id);
+        }
+        crate::DynamicBagByIndex::<Test>::remove(0);
+        crate::DynamicBagIndex::<Test>::remove(&dynamic_bag_id);
+        crate::DynamicBagsNumber::kill();
+
+        Storage::on_runtime_upgrade();
+
+        assert_eq!(Storage::layout_version(), StorageLayoutVersion::LATEST);
+        for bucket_id in buckets.iter() {
+            assert!(crate::StorageBucketBags::<Test>::contains_key(
+                bucket_id, &bag_id
+            ));
+            assert_eq!(Storage::storage_bucket_bag_by_index(bucket_id, 0), bag_id);
+            assert_eq!(Storage::storage_bucket_bags_number(bucket_id), 1);
+        }
+        assert_eq!(Storage::dynamic_bags_number(), 1);
+        assert_eq!(Storage::dynamic_bag_by_index(0), dynamic_bag_id);
+        assert_eq!(Storage::dynamic_bag_index(&dynamic_bag_id), 0);
+    });
+}
+
+#[test]
+fn storage_layout_migration_adds_data_object_fields() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let data_object_id = 7u64;
+        let (accepted, deletion_prize, size, ipfs_content_id) = (true, 5u64, 10u64, vec![1u8]);
+
+        // Data object before the content Merkle root and the parts were introduced.
+        crate::LayoutVersion::put(StorageLayoutVersion::V1);
+        frame_support::storage::unhashed::put(
+            &DataObjectsById::<Test>::hashed_key_for(&bag_id, data_object_id),
+            &(accepted, deletion_prize, size, ipfs_content_id.clone()),
+        );
+
+        Storage::on_runtime_upgrade();
+
+        assert_eq!(Storage::layout_version(), StorageLayoutVersion::LATEST);
+        assert_eq!(
+            Storage::data_object_by_id(&bag_id, data_object_id),
+            DataObject {
+                accepted,
+                deletion_prize,
+                size,
+                ipfs_content_id,
+                content_root: None,
+                parts: Vec::new(),
+            }
+        );
+        assert_eq!(Storage::bag_data_objects_number(&bag_id), 1);
+        assert_eq!(
+            Storage::bag_data_object_by_index(&bag_id, 0),
+            data_object_id
+        );
+    });
+}
+
+#[test]
+fn storage_layout_migration_builds_distribution_bucket_bags_index() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let bucket_id = setup_distribution_bucket_bags_size_test();
+
+        update_distribution_bucket_for_council_bag(&bucket_id, true);
+
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        upload_data_objects_to_bag(bag_id.clone(), create_data_object_candidates(1, 2), Ok(()));
+
+        // The bags index and sizes were not tracked before.
+        crate::LayoutVersion::put(StorageLayoutVersion::V3);
+        crate::DistributionBucketBagsSize::<Test>::remove(&bucket_id);
+        crate::DistributionBucketBags::<Test>::remove(&bucket_id, &bag_id);
+        crate::DistributionBucketBagByIndex::<Test>::remove(&bucket_id, 0);
+        crate::DistributionBucketBagsNumber::<Test>::remove(&bucket_id);
+
+        Storage::on_runtime_upgrade();
+
+        assert_eq!(Storage::layout_version(), StorageLayoutVersion::LATEST);
+        assert_eq!(
+            Storage::distribution_bucket_bags(&bucket_id, 0, 10),
+            vec![bag_id.clone()]
+        );
+        assert_eq!(
+            Storage::distribution_bucket_bags_size(&bucket_id),
+            Storage::bag(&bag_id).objects_total_size
+        );
+    });
+}
+
+#[test]
+fn storage_layout_migration_adds_distribution_regions() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let family_id = 3u64;
+        let bucket_index = 2u64;
+        let next_bucket_index = 5u64;
+        let operators = BTreeSet::from_iter(vec![DEFAULT_DISTRIBUTION_PROVIDER_ID]);
+        let families = BTreeMap::from_iter(vec![(family_id, 2u32)]);
+        let dynamic_bag_type = DynamicBagType::Channel;
+
+        // Distribution records before the regions were introduced.
+        crate::LayoutVersion::put(StorageLayoutVersion::V2);
+        frame_support::storage::unhashed::put(
+            &crate::DistributionBucketFamilyById::<Test>::hashed_key_for(family_id),
+            &next_bucket_index,
+        );
+        frame_support::storage::unhashed::put(
+            &crate::DistributionBucketByFamilyIdById::<Test>::hashed_key_for(
+                family_id,
+                bucket_index,
+            ),
+            &(true, false, BTreeSet::<u64>::new(), operators.clone(), 4u64),
+        );
+        frame_support::storage::unhashed::put(
+            &crate::DynamicBagCreationPolicies::<Test>::hashed_key_for(dynamic_bag_type),
+            &(7u64, families.clone()),
+        );
+
+        Storage::on_runtime_upgrade();
+
+        assert_eq!(Storage::layout_version(), StorageLayoutVersion::LATEST);
+        assert_eq!(
+            Storage::distribution_bucket_family_by_id(family_id),
+            DistributionBucketFamilyRecord {
+                next_distribution_bucket_index: next_bucket_index,
+                region: None,
+            }
+        );
+        assert_eq!(
+            Storage::distribution_bucket_by_family_id_by_index(family_id, bucket_index),
+            DistributionBucketRecord {
+                accepting_new_bags: true,
+                distributing: false,
+                pending_invitations: BTreeSet::new(),
+                operators,
+                assigned_bags: 4,
+                region: None,
+            }
+        );
+        assert_eq!(
+            Storage::dynamic_bag_creation_policy(dynamic_bag_type),
+            DynamicBagCreationPolicy {
+                number_of_storage_buckets: 7,
+                families,
+                regions: BTreeSet::new(),
+            }
+        );
+    });
+}
+
+#[test]
+fn dynamic_bag_replication_tracked_on_storage_buckets_update() {
+    build_test_externalities().execute_with(|| {
+        set_default_update_storage_buckets_per_bag_limit();
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let bag_id: BagId<Test> = dynamic_bag_id.clone().into();
+        let buckets = create_storage_buckets(DefaultMemberDynamicBagNumberOfStorageBuckets::get());
+
+        create_dynamic_bag(&dynamic_bag_id);
+
+        let bucket_id = *buckets.iter().next().unwrap();
+        assert!(crate::StorageBucketBags::<Test>::contains_key(
+            bucket_id, &bag_id
+        ));
+
+        UpdateStorageBucketForBagsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_remove_bucket_ids(BTreeSet::from_iter(vec![bucket_id]))
+            .call_and_assert(Ok(()));
+
+        assert!(!crate::StorageBucketBags::<Test>::contains_key(
+            bucket_id, &bag_id
+        ));
+        assert_eq!(Storage::under_replicated_bags(), vec![dynamic_bag_id]);
+    });
+}
+
+#[test]
+fn under_replicated_bags_re_replicated_on_initialize() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_default_update_storage_buckets_per_bag_limit();
+
+        // Bags created without storage buckets.
+        let dynamic_bag_ids = (0..3)
+            .map(|member_id| DynamicBagId::<Test>::Member(member_id))
+            .collect::<Vec<_>>();
+        for dynamic_bag_id in dynamic_bag_ids.iter() {
+            create_dynamic_bag(dynamic_bag_id);
+        }
+
+        assert_eq!(Storage::under_replicated_bags().len(), 3);
+
+        let buckets = create_storage_buckets(DefaultMemberDynamicBagNumberOfStorageBuckets::get());
+
+        run_to_block(starting_block + 1);
+
+        // Max re-replicated bags per block is limited.
+        assert_eq!(
+            Storage::under_replicated_bags().len() as u64,
+            3 - MaxReReplicatedBagsPerBlock::get()
+        );
+
+        run_to_block(starting_block + 2);
+
+        assert!(Storage::under_replicated_bags().is_empty());
+        for dynamic_bag_id in dynamic_bag_ids {
+            let bag_id: BagId<Test> = dynamic_bag_id.clone().into();
+
+            assert_eq!(Storage::bag(&bag_id).stored_by, buckets);
+            EventFixture::contains_crate_event(RawEvent::StorageBucketsUpdatedForBag(
+                bag_id,
+                buckets.clone(),
+                BTreeSet::new(),
+            ));
+            EventFixture::contains_crate_event(RawEvent::DynamicBagReplicationRestored(
+                dynamic_bag_id,
+            ));
+        }
+
+        for bucket_id in buckets {
+            assert_eq!(Storage::storage_bucket_by_id(bucket_id).assigned_bags, 3);
+        }
+    });
+}
+
+#[test]
+fn under_replicated_bags_stay_queued_without_available_buckets() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+
+        create_dynamic_bag(&dynamic_bag_id);
+
+        run_to_block(starting_block + 2);
+
+        assert_eq!(
+            Storage::under_replicated_bags(),
+            vec![dynamic_bag_id.clone()]
+        );
+        assert_eq!(
+            Storage::re_replication_queue_tail() - Storage::re_replication_queue_head(),
+            1
+        );
+
+        // Deleted bags are dropped from the tracking.
+        DeleteDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id)
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + 3);
+
+        assert!(Storage::under_replicated_bags().is_empty());
+        assert_eq!(
+            Storage::re_replication_queue_tail(),
+            Storage::re_replication_queue_head()
+        );
+    });
+}
+
+#[test]
+fn start_storage_bucket_draining_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        StartStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketDrainingStarted(bucket_id));
+    });
+}
+
+#[test]
+fn start_storage_bucket_draining_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        StartStorageBucketDrainingFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn start_storage_bucket_draining_fails_with_non_existing_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        StartStorageBucketDrainingFixture::default()
+            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn start_storage_bucket_draining_fails_with_draining_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        StartStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        StartStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageBucketIsDraining.into()));
+    });
+}
+
+#[test]
+fn cancel_storage_bucket_draining_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        StartStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        CancelStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketDrainingCanceled(bucket_id));
+
+        // The canceled bucket can accept new bags again.
+        UpdateStorageBucketStatusFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_status(true)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn cancel_storage_bucket_draining_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        CancelStorageBucketDrainingFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn cancel_storage_bucket_draining_fails_with_non_draining_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        CancelStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageBucketIsNotDraining.into()));
+    });
+}
+
+#[test]
+fn update_storage_bucket_status_fails_with_draining_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        StartStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        UpdateStorageBucketStatusFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_status(true)
+            .call_and_assert(Err(Error::<Test>::StorageBucketIsDraining.into()));
+    });
+}
+
+// Assigns a new storage bucket to the static bags and uploads a data object to the council bag.
+fn setup_storage_bucket_draining_test() -> (u64, Vec<BagId<Test>>) {
+    let bag_ids = vec![
+        BagId::<Test>::Static(StaticBagId::Council),
+        BagId::<Test>::Static(StaticBagId::WorkingGroup(WorkingGroup::Storage)),
+        BagId::<Test>::Static(StaticBagId::WorkingGroup(WorkingGroup::Content)),
+    ];
+
+    let bucket_id = create_default_storage_bucket_and_assign_to_bag(bag_ids[0].clone());
+    for bag_id in bag_ids.iter().skip(1) {
+        UpdateStorageBucketForBagsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_add_bucket_ids(BTreeSet::from_iter(vec![bucket_id]))
+            .call_and_assert(Ok(()));
+    }
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    let upload_params = UploadParameters::<Test> {
+        bag_id: bag_ids[0].clone(),
+        deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        object_creation_list: create_single_data_object(),
+        expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+    };
+
+    UploadFixture::default()
+        .with_params(upload_params)
+        .call_and_assert(Ok(()));
+
+    (bucket_id, bag_ids)
+}
+
+#[test]
+fn draining_storage_bucket_bags_reassigned_on_initialize() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (draining_bucket_id, bag_ids) = setup_storage_bucket_draining_test();
+        let draining_bucket = Storage::storage_bucket_by_id(draining_bucket_id);
+
+        let replacement_bucket_id = create_storage_buckets(1).into_iter().next().unwrap();
+
+        StartStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(draining_bucket_id)
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + 1);
+
+        // Max drained bags per block is limited.
+        assert_eq!(
+            Storage::storage_bucket_by_id(draining_bucket_id).assigned_bags,
+            bag_ids.len() as u64 - MaxDrainedBagsPerBlock::get()
+        );
+
+        run_to_block(starting_block + 2);
+
+        assert!(!<crate::StorageBucketById<Test>>::contains_key(
+            draining_bucket_id
+        ));
+        assert!(!<crate::DrainingStorageBuckets<Test>>::contains_key(
+            draining_bucket_id
+        ));
+        EventFixture::contains_crate_event(RawEvent::StorageBucketDeleted(draining_bucket_id));
+
+        for bag_id in bag_ids {
+            assert_eq!(
+                Storage::bag(&bag_id).stored_by,
+                BTreeSet::from_iter(vec![replacement_bucket_id])
+            );
+        }
+
+        let replacement_bucket = Storage::storage_bucket_by_id(replacement_bucket_id);
+        assert_eq!(replacement_bucket.assigned_bags, 3);
+        assert_eq!(
+            replacement_bucket.voucher.objects_used,
+            draining_bucket.voucher.objects_used
+        );
+        assert_eq!(
+            replacement_bucket.voucher.size_used,
+            draining_bucket.voucher.size_used
+        );
+    });
+}
+
+#[test]
+fn draining_storage_bucket_bags_wait_for_suitable_replacement_bucket() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (draining_bucket_id, bag_ids) = setup_storage_bucket_draining_test();
+
+        // The bucket voucher can't fit the council bag.
+        let zero_objects_limit = 0;
+        let full_bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_objects_limit(zero_objects_limit)
+            .with_size_limit(DEFAULT_STORAGE_BUCKET_SIZE_LIMIT)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        StartStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(draining_bucket_id)
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + 3);
+
+        // Empty bags are reassigned, the council bag is kept by the draining bucket.
+        assert!(Storage::bag(&bag_ids[0])
+            .stored_by
+            .contains(&draining_bucket_id));
+        assert!(!Storage::bag(&bag_ids[1])
+            .stored_by
+            .contains(&draining_bucket_id));
+        assert!(Storage::bag(&bag_ids[1])
+            .stored_by
+            .contains(&full_bucket_id));
+        assert_eq!(
+            Storage::storage_bucket_by_id(draining_bucket_id).assigned_bags,
+            1
+        );
+        EventFixture::contains_crate_event(RawEvent::DrainingStorageBucketBagNotReassigned(
+            draining_bucket_id,
+            bag_ids[0].clone(),
+        ));
+
+        let replacement_bucket_id = create_storage_buckets(1).into_iter().next().unwrap();
+
+        run_to_block(starting_block + 4);
+
+        assert_eq!(
+            Storage::bag(&bag_ids[0]).stored_by,
+            BTreeSet::from_iter(vec![replacement_bucket_id])
+        );
+        assert!(!<crate::StorageBucketById<Test>>::contains_key(
+            draining_bucket_id
+        ));
+    });
+}
+
+#[test]
+fn draining_storage_bucket_bags_without_replacement_do_not_hold_back_other_bags() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (draining_bucket_id, bag_ids) = setup_storage_bucket_draining_test();
+
+        // The last assigned bags (processed first) can't be fitted by the replacement bucket.
+        for bag_id in bag_ids.iter().skip(1) {
+            upload_data_objects_to_bag(bag_id.clone(), create_data_object_candidates(1, 2), Ok(()));
+        }
+
+        let objects_limit = 1;
+        let replacement_bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_objects_limit(objects_limit)
+            .with_size_limit(DEFAULT_STORAGE_BUCKET_SIZE_LIMIT)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        StartStorageBucketDrainingFixture::default()
+            .with_storage_bucket_id(draining_bucket_id)
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + 1);
+
+        assert_eq!(
+            Storage::bag(&bag_ids[0]).stored_by,
+            BTreeSet::from_iter(vec![draining_bucket_id])
+        );
+
+        // The council bag is processed after the bags without the replacement bucket.
+        run_to_block(starting_block + 2);
+
+        assert_eq!(
+            Storage::bag(&bag_ids[0]).stored_by,
+            BTreeSet::from_iter(vec![replacement_bucket_id])
+        );
+        for bag_id in bag_ids.iter().skip(1) {
+            assert_eq!(
+                Storage::bag(bag_id).stored_by,
+                BTreeSet::from_iter(vec![draining_bucket_id])
+            );
+        }
+        assert_eq!(
+            Storage::storage_bucket_by_id(draining_bucket_id).assigned_bags,
+            2
+        );
+    });
+}
+
+#[test]
+fn draining_storage_bucket_without_replacement_does_not_hold_back_other_buckets() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (stuck_bucket_id, bag_ids) = setup_storage_bucket_draining_test();
+
+        // The bucket voucher can't fit the council bag.
+        let zero_objects_limit = 0;
+        let full_bucket_id = CreateStorageBucketFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_objects_limit(zero_objects_limit)
+            .with_size_limit(DEFAULT_STORAGE_BUCKET_SIZE_LIMIT)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let distribution_bag_id =
+            BagId::<Test>::Static(StaticBagId::WorkingGroup(WorkingGroup::Distribution));
+        let draining_bucket_id =
+            create_default_storage_bucket_and_assign_to_bag(distribution_bag_id.clone());
+
+        for bucket_id in vec![stuck_bucket_id, draining_bucket_id] {
+            StartStorageBucketDrainingFixture::default()
+                .with_storage_bucket_id(bucket_id)
+                .call_and_assert(Ok(()));
+        }
+
+        run_to_block(starting_block + 3);
+
+        // The council bag is kept by the first draining bucket while the second one is drained.
+        assert!(Storage::bag(&bag_ids[0])
+            .stored_by
+            .contains(&stuck_bucket_id));
+        assert_eq!(
+            Storage::bag(&distribution_bag_id).stored_by,
+            BTreeSet::from_iter(vec![full_bucket_id])
+        );
+        assert!(<crate::StorageBucketById<Test>>::contains_key(
+            stuck_bucket_id
+        ));
+        assert!(!<crate::StorageBucketById<Test>>::contains_key(
+            draining_bucket_id
+        ));
+        assert!(!<crate::DrainingStorageBuckets<Test>>::contains_key(
+            draining_bucket_id
+        ));
+    });
+}
+
+#[test]
+fn content_merkle_proofs_verified_for_all_chunks() {
+    for chunks_number in 1..10u64 {
+        let chunks = (0..chunks_number)
+            .map(|idx| vec![idx as u8; 4])
+            .collect::<Vec<_>>();
+        let leaves = chunks
+            .iter()
+            .map(|chunk| content_chunk_hash(chunk))
+            .collect::<Vec<_>>();
+        let root = content_merkle_root(&leaves);
+
+        for (chunk_index, chunk) in chunks.iter().enumerate() {
+            let chunk_index = chunk_index as u64;
+            let proof = content_merkle_proof(&leaves, chunk_index);
+
+            assert!(verify_content_merkle_proof(
+                chunk,
+                chunk_index,
+                chunks_number,
+                &proof,
+                &root
+            ));
+
+            // Different chunk.
+            assert!(!verify_content_merkle_proof(
+                &[u8::MAX; 4],
+                chunk_index,
+                chunks_number,
+                &proof,
+                &root
+            ));
+
+            // Index out of range.
+            assert!(!verify_content_merkle_proof(
+                chunk,
+                chunks_number,
+                chunks_number,
+                &proof,
+                &root
+            ));
+
+            // Proof with an extra hash.
+            let mut extended_proof = proof.clone();
+            extended_proof.push(root);
+            assert!(!verify_content_merkle_proof(
+                chunk,
+                chunk_index,
+                chunks_number,
+                &extended_proof,
+                &root
+            ));
+
+            if !proof.is_empty() {
+                assert!(!verify_content_merkle_proof(
+                    chunk,
+                    chunk_index,
+                    chunks_number,
+                    &proof[1..],
+                    &root
+                ));
+            }
+        }
+    }
+}
+
+// Content of the challengeable data object: three chunks (the last chunk is shorter).
+fn storage_challenge_test_content() -> Vec<u8> {
+    (0..(2 * ChallengeChunkSize::get() + 2) as u8).collect()
+}
+
+fn storage_challenge_test_content_leaves() -> Vec<ContentHash> {
+    storage_challenge_test_content()
+        .chunks(ChallengeChunkSize::get() as usize)
+        .map(content_chunk_hash)
+        .collect()
+}
+
+// Returns the content chunk and its Merkle proof for the outstanding storage challenge.
+fn storage_challenge_test_response(bucket_id: u64) -> (Vec<u8>, Vec<ContentHash>) {
+    let challenge = Storage::storage_challenge(bucket_id).unwrap();
+    let chunk = storage_challenge_test_content()
+        .chunks(ChallengeChunkSize::get() as usize)
+        .nth(challenge.chunk_index as usize)
+        .unwrap()
+        .to_vec();
+    let proof = content_merkle_proof(
+        &storage_challenge_test_content_leaves(),
+        challenge.chunk_index,
+    );
+
+    (chunk, proof)
+}
+
+// Creates the storage bucket with the operator for the council bag and uploads an accepted
+// data object with the content Merkle root. Returns the storage bucket ID.
+fn setup_storage_challenge_test() -> u64 {
+    let bag_id: BagId<Test> = StaticBagId::Council.into();
+
+    let bucket_id = create_storage_bucket_and_assign_to_bag(
+        bag_id.clone(),
+        Some(DEFAULT_STORAGE_PROVIDER_ID),
+        DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+        DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+    );
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    let upload_params = UploadParameters::<Test> {
+        bag_id: bag_id.clone(),
+        deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        object_creation_list: vec![DataObjectCreationParameters {
+            size: storage_challenge_test_content().len() as u64,
+            ipfs_content_id: vec![1],
+            content_root: Some(content_merkle_root(&storage_challenge_test_content_leaves())),
+            parts: Vec::new(),
+            class: None,
+        }],
+        expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+    };
+
+    UploadFixture::default()
+        .with_params(upload_params)
+        .call_and_assert(Ok(()));
+
+    AcceptPendingDataObjectsFixture::default()
+        .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+        .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+        .with_storage_bucket_id(bucket_id)
+        .with_bag_id(bag_id)
+        .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+        .call_and_assert(Ok(()));
+
+    bucket_id
+}
+
+#[test]
+fn storage_challenge_issued_and_passed() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_storage_challenge_test();
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+
+        let challenge_block = StorageChallengePeriod::get();
+        run_to_block(challenge_block);
+
+        let challenge = Storage::storage_challenge(bucket_id).unwrap();
+        let expires_at = challenge_block + StorageChallengeResponsePeriod::get();
+        assert_eq!(
+            challenge,
+            StorageChallengeRecord {
+                worker_id: DEFAULT_STORAGE_PROVIDER_ID,
+                bag_id: bag_id.clone(),
+                data_object_id: 0,
+                chunk_index: challenge.chunk_index,
+                expires_at,
+            }
+        );
+        assert!(challenge.chunk_index < 3);
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageChallengeIssued(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            bag_id,
+            0,
+            challenge.chunk_index,
+            expires_at,
+        ));
+
+        let (chunk, proof) = storage_challenge_test_response(bucket_id);
+
+        RespondToStorageChallengeFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_chunk(chunk)
+            .with_proof(proof)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageChallengePassed(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+        ));
+
+        // Answered challenges are not counted as missed.
+        run_to_block(2 * challenge_block);
+
+        assert_eq!(Storage::missed_storage_challenges(bucket_id), 0);
+        assert!(Storage::storage_challenge(bucket_id).is_some());
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_non_existing_challenge() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_storage_challenge_test();
+
+        RespondToStorageChallengeFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageChallengeDoesntExist.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_invalid_transactor() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_storage_challenge_test();
+
+        run_to_block(StorageChallengePeriod::get());
+
+        let (chunk, proof) = storage_challenge_test_response(bucket_id);
+
+        RespondToStorageChallengeFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID))
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_chunk(chunk)
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::InvalidTransactorAccount.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_invalid_response() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_storage_challenge_test();
+
+        run_to_block(StorageChallengePeriod::get());
+
+        let (chunk, proof) = storage_challenge_test_response(bucket_id);
+
+        let mut invalid_chunk = chunk.clone();
+        invalid_chunk[0] = invalid_chunk[0].wrapping_add(1);
+
+        RespondToStorageChallengeFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_chunk(invalid_chunk)
+            .with_proof(proof.clone())
+            .call_and_assert(Err(Error::<Test>::InvalidStorageChallengeResponse.into()));
+
+        let mut extended_chunk = chunk.clone();
+        extended_chunk.push(0);
+
+        RespondToStorageChallengeFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_chunk(extended_chunk)
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::InvalidStorageChallengeResponse.into()));
+
+        RespondToStorageChallengeFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_chunk(chunk)
+            .with_proof(Vec::new())
+            .call_and_assert(Err(Error::<Test>::InvalidStorageChallengeResponse.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_expired_challenge() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_storage_challenge_test();
+
+        let challenge_block = StorageChallengePeriod::get();
+        run_to_block(challenge_block + StorageChallengeResponsePeriod::get() + 1);
+
+        let (chunk, proof) = storage_challenge_test_response(bucket_id);
+
+        RespondToStorageChallengeFixture::default()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_chunk(chunk)
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::StorageChallengeExpired.into()));
+    });
+}
+
+#[test]
+fn missed_storage_challenges_trigger_operator_slashing() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_storage_challenge_test();
+
+        let period = StorageChallengePeriod::get();
+        let threshold = MissedStorageChallengesSlashingThreshold::get();
+
+        for missed_challenges in 1..threshold {
+            run_to_block(period * (missed_challenges + 1));
+
+            assert_eq!(
+                Storage::missed_storage_challenges(bucket_id),
+                missed_challenges
+            );
+            EventFixture::contains_crate_event(RawEvent::StorageChallengeMissed(
+                bucket_id,
+                DEFAULT_STORAGE_PROVIDER_ID,
+                missed_challenges,
+            ));
+        }
+
+        run_to_block(period * (threshold + 1));
+
+        EventFixture::contains_crate_event(RawEvent::StorageChallengeMissed(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            threshold,
+        ));
+        EventFixture::contains_crate_event(RawEvent::StorageOperatorSlashed(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            MissedStorageChallengesSlashingPenalty::get(),
+        ));
+
+        // The missed challenges counter is reset after slashing.
+        assert_eq!(Storage::missed_storage_challenges(bucket_id), 0);
+        assert!(Storage::storage_challenge(bucket_id).is_some());
+    });
+}
+
+#[test]
+fn storage_challenges_cleared_on_storage_bucket_operator_removal() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_storage_challenge_test();
+
+        let period = StorageChallengePeriod::get();
+        run_to_block(2 * period);
+
+        assert_eq!(Storage::missed_storage_challenges(bucket_id), 1);
+        assert!(Storage::storage_challenge(bucket_id).is_some());
+
+        RemoveStorageBucketOperatorFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Storage::missed_storage_challenges(bucket_id), 0);
+        assert!(Storage::storage_challenge(bucket_id).is_none());
+
+        // No challenges for the storage buckets without operators.
+        run_to_block(3 * period);
+
+        assert!(Storage::storage_challenge(bucket_id).is_none());
+    });
+}
+
+#[test]
+fn storage_challenges_spread_over_blocks() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let bucket_ids = (0..MaxStorageChallengesPerBlock::get())
+            .map(|_| {
+                create_storage_bucket_and_assign_to_bag(
+                    bag_id.clone(),
+                    Some(DEFAULT_STORAGE_PROVIDER_ID),
+                    DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+                    DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+                )
+            })
+            .collect::<Vec<_>>();
+        let last_bucket_id = setup_storage_challenge_test();
+
+        let challenge_block = StorageChallengePeriod::get();
+        run_to_block(challenge_block);
+
+        for bucket_id in bucket_ids.iter() {
+            assert!(Storage::storage_challenge(bucket_id).is_some());
+        }
+        assert!(Storage::storage_challenge(last_bucket_id).is_none());
+        assert_eq!(Storage::storage_challenge_cursor(), Some(last_bucket_id));
+
+        run_to_block(challenge_block + 1);
+
+        assert!(Storage::storage_challenge(last_bucket_id).is_some());
+        assert_eq!(Storage::storage_challenge_cursor(), None);
+    });
+}
+
+#[test]
+fn storage_challenges_use_bag_data_objects_index() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_storage_challenge_test();
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+
+        assert_eq!(Storage::storage_bucket_bags_number(bucket_id), 1);
+        assert_eq!(Storage::storage_bucket_bag_by_index(bucket_id, 0), bag_id);
+        assert_eq!(Storage::bag_data_objects_number(&bag_id), 1);
+        assert_eq!(Storage::bag_data_object_by_index(&bag_id, 0), 0);
+        assert_eq!(Storage::bag_data_object_index(&bag_id, 0), 0);
+
+        let data_object_ids = BTreeSet::from_iter(vec![0]);
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(data_object_ids)
+            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Storage::bag_data_objects_number(&bag_id), 0);
+
+        // No challenges for the storage buckets without data objects.
+        run_to_block(StorageChallengePeriod::get());
+
+        assert!(Storage::storage_challenge(bucket_id).is_none());
+    });
+}
+
+#[test]
+fn update_storage_rent_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_rent = 10;
+
+        UpdateStorageRentFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_rent(new_rent)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageRentUpdated(new_rent));
+    });
+}
+
+#[test]
+fn update_storage_rent_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateStorageRentFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+// Enables the storage rent, creates the member dynamic bag with the storage bucket and uploads
+// the data objects into the bag.
+fn setup_storage_rent_test(rent: u64, objects_number: u8) -> DynamicBagId<Test> {
+    UpdateStorageRentFixture::default()
+        .with_new_rent(rent)
+        .call_and_assert(Ok(()));
+
+    let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+    create_dynamic_bag(&dynamic_bag_id);
+
+    create_storage_bucket_and_assign_to_bag(
+        dynamic_bag_id.clone().into(),
+        None,
+        DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+        DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+    );
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    let upload_params = UploadParameters::<Test> {
+        bag_id: dynamic_bag_id.clone().into(),
+        deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        object_creation_list: create_data_object_candidates(1, objects_number),
+        expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+    };
+
+    UploadFixture::default()
+        .with_params(upload_params)
+        .call_and_assert(Ok(()));
+
+    dynamic_bag_id
+}
+
+#[test]
+fn dynamic_bag_creation_grants_initial_bag_rent_period() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        // No rent tracking while the storage rent is disabled.
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        create_dynamic_bag(&dynamic_bag_id);
+
+        assert_eq!(Storage::bag_rent_paid_through(&dynamic_bag_id), None);
+
+        UpdateStorageRentFixture::default()
+            .with_new_rent(1)
+            .call_and_assert(Ok(()));
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Channel(1);
+        create_dynamic_bag(&dynamic_bag_id);
+
+        assert_eq!(
+            Storage::bag_rent_paid_through(&dynamic_bag_id),
+            Some(starting_block + InitialBagRentPeriod::get())
+        );
+        assert_eq!(
+            Storage::bags_rent_paid_through(),
+            vec![(dynamic_bag_id, starting_block + InitialBagRentPeriod::get())]
+        );
+    });
+}
+
+#[test]
+fn upload_pays_storage_rent_for_remaining_bag_rent_period() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let rent = 2;
+        let objects_number = 3;
+        setup_storage_rent_test(rent, objects_number);
+
+        // Every data object is rounded to one megabyte.
+        let storage_rent = rent * objects_number as u64 * InitialBagRentPeriod::get();
+        let deletion_prizes = DataObjectDeletionPrize::get() * objects_number as u64;
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - deletion_prizes - storage_rent
+        );
+    });
+}
+
+#[test]
+fn top_up_bag_rent_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let rent = 2;
+        let objects_number = 3;
+        let dynamic_bag_id = setup_storage_rent_test(rent, objects_number);
+
+        let account_id = 1000;
+        let initial_balance = 1000;
+        increase_account_balance(&account_id, initial_balance);
+
+        let blocks = 10;
+        let paid_through = starting_block + InitialBagRentPeriod::get() + blocks;
+
+        TopUpBagRentFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_dynamic_bag_id(dynamic_bag_id.clone())
+            .with_blocks(blocks)
+            .call_and_assert(Ok(()));
+
+        let storage_rent = rent * objects_number as u64 * blocks;
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            initial_balance - storage_rent
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::BagRentToppedUp(
+            account_id,
+            dynamic_bag_id.clone(),
+            storage_rent,
+            paid_through,
+        ));
+
+        // The expiration is rescheduled.
+        let expiration_block = paid_through + StorageRentGracePeriod::get() + 1;
+        assert!(BagRentExpirations::<Test>::contains_key(
+            expiration_block,
+            &dynamic_bag_id
+        ));
+        assert!(!BagRentExpirations::<Test>::contains_key(
+            expiration_block - blocks,
+            &dynamic_bag_id
+        ));
+    });
+}
+
+#[test]
+fn top_up_bag_rent_renews_exhausted_prepayment_from_current_block() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_id = setup_storage_rent_test(1, 1);
+
+        let grace_block = starting_block + InitialBagRentPeriod::get() + 2;
+        run_to_block(grace_block);
+
+        let blocks = 10;
+
+        TopUpBagRentFixture::default()
+            .with_dynamic_bag_id(dynamic_bag_id.clone())
+            .with_blocks(blocks)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Storage::bag_rent_paid_through(&dynamic_bag_id),
+            Some(grace_block + blocks)
+        );
+    });
+}
+
+#[test]
+fn top_up_bag_rent_fails_with_disabled_storage_rent() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        create_dynamic_bag(&dynamic_bag_id);
+
+        TopUpBagRentFixture::default()
+            .with_dynamic_bag_id(dynamic_bag_id)
+            .with_blocks(10)
+            .call_and_assert(Err(Error::<Test>::StorageRentDisabled.into()));
+    });
+}
+
+#[test]
+fn top_up_bag_rent_fails_with_zero_blocks() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_id = setup_storage_rent_test(1, 1);
+
+        TopUpBagRentFixture::default()
+            .with_dynamic_bag_id(dynamic_bag_id)
+            .with_blocks(0)
+            .call_and_assert(Err(Error::<Test>::ZeroBagRentTopUpPeriod.into()));
+    });
+}
+
+#[test]
+fn top_up_bag_rent_fails_with_non_existent_dynamic_bag() {
+    build_test_externalities().execute_with(|| {
+        UpdateStorageRentFixture::default()
+            .with_new_rent(1)
+            .call_and_assert(Ok(()));
+
+        TopUpBagRentFixture::default()
+            .with_dynamic_bag_id(DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID))
+            .with_blocks(10)
+            .call_and_assert(Err(Error::<Test>::DynamicBagDoesntExist.into()));
+    });
+}
+
+#[test]
+fn top_up_bag_rent_fails_with_free_storage_bag() {
+    build_test_externalities().execute_with(|| {
+        // The bag created before the storage rent was enabled.
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        create_dynamic_bag(&dynamic_bag_id);
+
+        UpdateStorageRentFixture::default()
+            .with_new_rent(1)
+            .call_and_assert(Ok(()));
+
+        TopUpBagRentFixture::default()
+            .with_dynamic_bag_id(dynamic_bag_id.clone())
+            .with_blocks(10)
+            .call_and_assert(Err(Error::<Test>::BagIsNotSubjectToRent.into()));
+
+        assert_eq!(Storage::bag_rent_paid_through(&dynamic_bag_id), None);
+    });
+}
+
+#[test]
+fn top_up_bag_rent_fails_with_insufficient_balance() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_id = setup_storage_rent_test(1, 1);
+
+        let account_without_balance = 1000;
+
+        TopUpBagRentFixture::default()
+            .with_origin(RawOrigin::Signed(account_without_balance))
+            .with_dynamic_bag_id(dynamic_bag_id)
+            .with_blocks(10)
+            .call_and_assert(Err(Error::<Test>::InsufficientBalance.into()));
+    });
+}
+
+#[test]
+fn upload_fails_with_exhausted_bag_rent() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_id = setup_storage_rent_test(1, 1);
+
+        run_to_block(starting_block + InitialBagRentPeriod::get() + 1);
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id: dynamic_bag_id.into(),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: create_single_data_object(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params)
+            .call_and_assert(Err(Error::<Test>::BagRentExhausted.into()));
+    });
+}
+
+#[test]
+fn expired_bag_data_objects_garbage_collected_after_grace_period() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let objects_number = 3;
+        let dynamic_bag_id = setup_storage_rent_test(1, objects_number);
+        let bag_id: BagId<Test> = dynamic_bag_id.clone().into();
+        let bucket_id = Storage::dynamic_bag(&dynamic_bag_id)
+            .stored_by
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let paid_through = starting_block + InitialBagRentPeriod::get();
+        let expiration_block = paid_through + StorageRentGracePeriod::get() + 1;
+
+        // Data objects are kept during the grace period.
+        run_to_block(expiration_block - 1);
+
+        assert_eq!(
+            Storage::dynamic_bag(&dynamic_bag_id).objects_number,
+            objects_number as u64
+        );
+        assert!(!ExpiredBags::<Test>::contains_key(&dynamic_bag_id));
+
+        run_to_block(expiration_block);
+
+        let max_objects = MaxGarbageCollectedDataObjectsPerBlock::get();
+
+        let remaining_object_ids = DataObjectsById::<Test>::iter_prefix(&bag_id)
+            .map(|(data_object_id, _)| data_object_id)
+            .collect::<BTreeSet<_>>();
+        let collected_object_ids = (0..objects_number as u64)
+            .filter(|data_object_id| !remaining_object_ids.contains(data_object_id))
+            .collect::<BTreeSet<_>>();
+
+        EventFixture::contains_crate_event(RawEvent::BagRentExpired(dynamic_bag_id.clone()));
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsGarbageCollected(
+            bag_id.clone(),
+            collected_object_ids.clone(),
+        ));
+        assert_eq!(collected_object_ids.len() as u64, max_objects);
+        assert_eq!(
+            REMOVED_DATA_OBJECTS.with(|val| val.borrow().clone()),
+            collected_object_ids
+        );
+        assert!(ExpiredBags::<Test>::contains_key(&dynamic_bag_id));
+        assert_eq!(
+            Storage::dynamic_bag(&dynamic_bag_id).objects_number,
+            objects_number as u64 - max_objects
+        );
+
+        run_to_block(expiration_block + 1);
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsGarbageCollected(
+            bag_id.clone(),
+            remaining_object_ids,
+        ));
+        assert!(!ExpiredBags::<Test>::contains_key(&dynamic_bag_id));
+
+        let bag = Storage::dynamic_bag(&dynamic_bag_id);
+        assert_eq!(bag.objects_number, 0);
+        assert_eq!(bag.objects_total_size, 0);
+        assert_eq!(
+            Storage::storage_bucket_by_id(bucket_id)
+                .voucher
+                .objects_used,
+            0
+        );
+        assert_eq!(
+            Storage::storage_bucket_by_id(bucket_id).voucher.size_used,
+            0
+        );
+    });
+}
+
+#[test]
+fn top_up_bag_rent_stops_garbage_collection() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let objects_number = 3;
+        let dynamic_bag_id = setup_storage_rent_test(1, objects_number);
+
+        let paid_through = starting_block + InitialBagRentPeriod::get();
+        let expiration_block = paid_through + StorageRentGracePeriod::get() + 1;
+        run_to_block(expiration_block);
+
+        let remaining_objects_number =
+            objects_number as u64 - MaxGarbageCollectedDataObjectsPerBlock::get();
+
+        TopUpBagRentFixture::default()
+            .with_dynamic_bag_id(dynamic_bag_id.clone())
+            .with_blocks(10)
             .call_and_assert(Ok(()));
-    }
 
-    bucket_id
+        assert!(!ExpiredBags::<Test>::contains_key(&dynamic_bag_id));
+
+        run_to_block(expiration_block + 1);
+
+        assert_eq!(
+            Storage::dynamic_bag(&dynamic_bag_id).objects_number,
+            remaining_objects_number
+        );
+    });
 }
 
 #[test]
-fn delete_dynamic_bags_succeeded() {
+fn top_up_bag_rent_charges_one_megabyte_for_empty_bag() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let initial_balance = 1000;
-        let deletion_prize_value = 77;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+        let rent = 2;
+        UpdateStorageRentFixture::default()
+            .with_new_rent(rent)
+            .call_and_assert(Ok(()));
 
         let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        CreateDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_prize(DynamicBagDeletionPrize::<Test> {
-                account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-                prize: deletion_prize_value,
-            })
+        create_dynamic_bag(&dynamic_bag_id);
+
+        let account_id = 1000;
+        let initial_balance = 1000;
+        increase_account_balance(&account_id, initial_balance);
+
+        let blocks = 10;
+
+        TopUpBagRentFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_dynamic_bag_id(dynamic_bag_id)
+            .with_blocks(blocks)
             .call_and_assert(Ok(()));
 
-        // pre-check balances
         assert_eq!(
-            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
-            initial_balance - deletion_prize_value
+            Balances::usable_balance(&account_id),
+            initial_balance - rent * blocks
         );
+    });
+}
+
+#[test]
+fn storage_rent_is_added_to_storage_working_group_budget() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let rent = 2;
+        let initial_budget = STORAGE_WG_BUDGET.with(|val| *val.borrow());
+        let dynamic_bag_id = setup_storage_rent_test(rent, 1);
+
+        // The upload pays the rent for the remaining bag rent period.
+        let upload_rent = rent * InitialBagRentPeriod::get();
         assert_eq!(
-            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
-            deletion_prize_value
+            STORAGE_WG_BUDGET.with(|val| *val.borrow()),
+            initial_budget + upload_rent
         );
 
-        DeleteDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+        let blocks = 10;
+        TopUpBagRentFixture::default()
+            .with_dynamic_bag_id(dynamic_bag_id)
+            .with_blocks(blocks)
             .call_and_assert(Ok(()));
 
-        // post-check balances
         assert_eq!(
-            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            STORAGE_WG_BUDGET.with(|val| *val.borrow()),
+            initial_budget + upload_rent + rent * blocks
+        );
+    });
+}
+
+#[test]
+fn bag_rent_expirations_are_processed_in_bounded_batches() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        UpdateStorageRentFixture::default()
+            .with_new_rent(1)
+            .call_and_assert(Ok(()));
+
+        let max_expirations = MaxBagRentExpirationsPerBlock::get();
+        let dynamic_bag_ids = (0..=max_expirations)
+            .map(|member_id| DynamicBagId::<Test>::Member(member_id))
+            .collect::<Vec<_>>();
+        for dynamic_bag_id in dynamic_bag_ids.iter() {
+            create_dynamic_bag(dynamic_bag_id);
+        }
+
+        let expiration_block =
+            starting_block + InitialBagRentPeriod::get() + StorageRentGracePeriod::get() + 1;
+
+        run_to_block(expiration_block);
+
+        let expired_bags_number = dynamic_bag_ids
+            .iter()
+            .filter(|dynamic_bag_id| {
+                !BagRentExpirations::<Test>::contains_key(expiration_block, dynamic_bag_id)
+            })
+            .count() as u64;
+        assert_eq!(expired_bags_number, max_expirations);
+        assert_eq!(Storage::bag_rent_expirations_cursor(), expiration_block);
+
+        // The remaining expiration is processed in the next block.
+        run_to_block(expiration_block + 1);
+
+        assert_eq!(
+            BagRentExpirations::<Test>::iter_prefix(expiration_block).count(),
             0
         );
+        for dynamic_bag_id in dynamic_bag_ids {
+            EventFixture::contains_crate_event(RawEvent::BagRentExpired(dynamic_bag_id));
+        }
+        assert_eq!(Storage::bag_rent_expirations_cursor(), expiration_block + 2);
+    });
+}
+
+#[test]
+fn static_bags_are_not_subject_to_storage_rent() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        UpdateStorageRentFixture::default()
+            .with_new_rent(1)
+            .call_and_assert(Ok(()));
+
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id: BagId::<Test>::Static(StaticBagId::Council),
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: create_single_data_object(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params)
+            .call_and_assert(Ok(()));
+
         assert_eq!(
             Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
-            initial_balance
+            INITIAL_BALANCE - DataObjectDeletionPrize::get()
         );
+    });
+}
 
-        EventFixture::assert_last_crate_event(RawEvent::DynamicBagDeleted(
-            DEFAULT_MEMBER_ACCOUNT_ID,
-            dynamic_bag_id,
-        ));
+#[test]
+fn update_data_object_reference_fee_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_fee = Some(10);
+
+        UpdateDataObjectReferenceFeeFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_fee(new_fee)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectReferenceFeeUpdated(new_fee));
     });
 }
 
 #[test]
-fn delete_dynamic_bags_succeeded_with_assigned_distribution_buckets() {
+fn update_data_object_reference_fee_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
-        let initial_balance = 1000;
-        let deletion_prize_value = 77;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+        let non_leader_id = 1;
 
-        let distribution_buckets_number = 10;
-        let family_policy_number1 = 2;
-        let family_policy_number2 = 3;
+        UpdateDataObjectReferenceFeeFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .with_new_fee(Some(10))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
 
-        create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER);
-        let (family1, _) =
-            create_distribution_bucket_family_with_buckets(distribution_buckets_number);
-        let (family2, _) =
-            create_distribution_bucket_family_with_buckets(distribution_buckets_number);
+// Creates the member and channel dynamic bags stored by the same storage bucket.
+// Returns the bag IDs and the storage bucket ID.
+fn setup_content_deduplication_test() -> (BagId<Test>, BagId<Test>, u64) {
+    let member_dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+    let channel_dynamic_bag_id = DynamicBagId::<Test>::Channel(1);
+    create_dynamic_bag(&member_dynamic_bag_id);
+    create_dynamic_bag(&channel_dynamic_bag_id);
 
-        let family_policy = BTreeMap::from_iter(vec![
-            (family1, family_policy_number1),
-            (family2, family_policy_number2),
-        ]);
+    let member_bag_id: BagId<Test> = member_dynamic_bag_id.into();
+    let channel_bag_id: BagId<Test> = channel_dynamic_bag_id.into();
 
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::default()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(family_policy)
+    let bucket_id = create_storage_bucket_and_assign_to_bag(
+        member_bag_id.clone(),
+        Some(DEFAULT_STORAGE_PROVIDER_ID),
+        DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+        DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+    );
+
+    UpdateStorageBucketForBagsFixture::default()
+        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+        .with_bag_id(channel_bag_id.clone())
+        .with_add_bucket_ids(BTreeSet::from_iter(vec![bucket_id]))
+        .call_and_assert(Ok(()));
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    (member_bag_id, channel_bag_id, bucket_id)
+}
+
+fn upload_data_object_with_content_id(
+    bag_id: BagId<Test>,
+    ipfs_content_id: Cid,
+    size: u64,
+    expected_result: DispatchResult,
+) {
+    let object_creation_list = vec![DataObjectCreationParameters {
+        size,
+        ipfs_content_id,
+        content_root: None,
+        parts: Vec::new(),
+        class: None,
+    }];
+
+    let upload_params = UploadParameters::<Test> {
+        expected_data_size_fee: Storage::upload_data_size_fee(&bag_id, &object_creation_list),
+        bag_id,
+        deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        object_creation_list,
+    };
+
+    UploadFixture::default()
+        .with_params(upload_params)
+        .call_and_assert(expected_result);
+}
+
+fn accept_deduplication_test_data_object(bag_id: BagId<Test>, bucket_id: u64, data_object_id: u64) {
+    AcceptPendingDataObjectsFixture::default()
+        .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+        .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+        .with_storage_bucket_id(bucket_id)
+        .with_bag_id(bag_id)
+        .with_data_object_ids(BTreeSet::from_iter(vec![data_object_id]))
+        .call_and_assert(Ok(()));
+}
+
+#[test]
+fn deduplicated_upload_charges_reference_fee_and_counts_shared_content_once() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let data_size_fee = 100;
+        let reference_fee = 5;
+
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_new_fee(data_size_fee)
             .call_and_assert(Ok(()));
 
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        CreateDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_prize(DynamicBagDeletionPrize::<Test> {
-                account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-                prize: deletion_prize_value,
+        UpdateDataObjectReferenceFeeFixture::default()
+            .with_new_fee(Some(reference_fee))
+            .call_and_assert(Ok(()));
+
+        let (member_bag_id, channel_bag_id, bucket_id) = setup_content_deduplication_test();
+
+        let content_id = vec![1];
+        let size = DEFAULT_DATA_OBJECTS_SIZE;
+        let deletion_prize = DataObjectDeletionPrize::get();
+
+        upload_data_object_with_content_id(member_bag_id.clone(), content_id.clone(), size, Ok(()));
+        accept_deduplication_test_data_object(member_bag_id, bucket_id, 0);
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - deletion_prize - data_size_fee
+        );
+
+        upload_data_object_with_content_id(
+            channel_bag_id.clone(),
+            content_id.clone(),
+            size,
+            Ok(()),
+        );
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - 2 * deletion_prize - data_size_fee - reference_fee
+        );
+
+        // The pending data object is counted in full.
+        assert_eq!(
+            Storage::storage_bucket_by_id(bucket_id).voucher.size_used,
+            2 * size
+        );
+
+        accept_deduplication_test_data_object(channel_bag_id.clone(), bucket_id, 1);
+
+        assert_eq!(
+            Storage::shared_data_object(&content_id),
+            Some(SharedDataObjectRecord {
+                size,
+                references: 2
             })
+        );
+        assert_eq!(Storage::bag(&channel_bag_id).objects_total_size, size);
+
+        let bucket = Storage::storage_bucket_by_id(bucket_id);
+        assert_eq!(bucket.voucher.objects_used, 2);
+        assert_eq!(bucket.voucher.size_used, size);
+        assert_eq!(
+            Storage::storage_bucket_content_references(bucket_id, &content_id),
+            2
+        );
+    });
+}
+
+#[test]
+fn deduplicated_content_released_on_data_objects_deletion() {
+    build_test_externalities().execute_with(|| {
+        UpdateDataObjectReferenceFeeFixture::default()
+            .with_new_fee(Some(0))
             .call_and_assert(Ok(()));
 
-        let bag = Storage::dynamic_bag(&dynamic_bag_id);
+        let (member_bag_id, channel_bag_id, bucket_id) = setup_content_deduplication_test();
 
-        let total_distributed_buckets_number = family_policy_number1 + family_policy_number2;
+        let content_id = vec![1];
+        let size = DEFAULT_DATA_OBJECTS_SIZE;
+
+        upload_data_object_with_content_id(member_bag_id.clone(), content_id.clone(), size, Ok(()));
+        upload_data_object_with_content_id(
+            channel_bag_id.clone(),
+            content_id.clone(),
+            size,
+            Ok(()),
+        );
+        accept_deduplication_test_data_object(member_bag_id.clone(), bucket_id, 0);
+        accept_deduplication_test_data_object(channel_bag_id.clone(), bucket_id, 1);
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(member_bag_id.clone())
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Ok(()));
+
+        // The content is still stored for the channel bag.
         assert_eq!(
-            bag.distributed_by.len(),
-            total_distributed_buckets_number as usize
+            Storage::shared_data_object(&content_id).map(|record| record.references),
+            Some(1)
+        );
+        assert_eq!(
+            Storage::bag_content_references(&member_bag_id, &content_id),
+            0
         );
 
-        let distributed_by_bag = bag.distributed_by.clone();
-        for distribution_bucket_id in &distributed_by_bag {
-            let bucket = Storage::distribution_bucket_by_family_id_by_index(
-                distribution_bucket_id.distribution_bucket_family_id,
-                distribution_bucket_id.distribution_bucket_index,
-            );
+        let bucket = Storage::storage_bucket_by_id(bucket_id);
+        assert_eq!(bucket.voucher.objects_used, 1);
+        assert_eq!(bucket.voucher.size_used, size);
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(channel_bag_id)
+            .with_data_object_ids(BTreeSet::from_iter(vec![1]))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Storage::shared_data_object(&content_id), None);
+        assert_eq!(
+            Storage::storage_bucket_content_references(bucket_id, &content_id),
+            0
+        );
+
+        let bucket = Storage::storage_bucket_by_id(bucket_id);
+        assert_eq!(bucket.voucher.objects_used, 0);
+        assert_eq!(bucket.voucher.size_used, 0);
+    });
+}
+
+#[test]
+fn deduplicated_content_counted_by_storage_buckets_not_storing_it() {
+    build_test_externalities().execute_with(|| {
+        UpdateDataObjectReferenceFeeFixture::default()
+            .with_new_fee(Some(0))
+            .call_and_assert(Ok(()));
+
+        let (member_bag_id, channel_bag_id, shared_bucket_id) = setup_content_deduplication_test();
+
+        let channel_bucket_id = create_storage_bucket_and_assign_to_bag(
+            channel_bag_id.clone(),
+            None,
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+
+        let content_id = vec![1];
+        let size = DEFAULT_DATA_OBJECTS_SIZE;
+
+        upload_data_object_with_content_id(member_bag_id.clone(), content_id.clone(), size, Ok(()));
+        upload_data_object_with_content_id(
+            channel_bag_id.clone(),
+            content_id.clone(),
+            size,
+            Ok(()),
+        );
+        accept_deduplication_test_data_object(member_bag_id, shared_bucket_id, 0);
+        accept_deduplication_test_data_object(channel_bag_id.clone(), shared_bucket_id, 1);
 
-            assert_eq!(bucket.assigned_bags, 1);
-        }
+        assert_eq!(
+            Storage::storage_bucket_by_id(shared_bucket_id)
+                .voucher
+                .size_used,
+            size
+        );
+        assert_eq!(
+            Storage::storage_bucket_by_id(channel_bucket_id)
+                .voucher
+                .size_used,
+            size
+        );
 
-        DeleteDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
+        // The shared storage bucket still stores the content for the member bag.
+        UpdateStorageBucketForBagsFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(channel_bag_id)
+            .with_remove_bucket_ids(BTreeSet::from_iter(vec![
+                shared_bucket_id,
+                channel_bucket_id,
+            ]))
             .call_and_assert(Ok(()));
 
-        for distribution_bucket_id in &distributed_by_bag {
-            let bucket = Storage::distribution_bucket_by_family_id_by_index(
-                distribution_bucket_id.distribution_bucket_family_id,
-                distribution_bucket_id.distribution_bucket_index,
-            );
+        let shared_bucket = Storage::storage_bucket_by_id(shared_bucket_id);
+        assert_eq!(shared_bucket.voucher.objects_used, 1);
+        assert_eq!(shared_bucket.voucher.size_used, size);
 
-            assert_eq!(bucket.assigned_bags, 0);
-        }
+        let channel_bucket = Storage::storage_bucket_by_id(channel_bucket_id);
+        assert_eq!(channel_bucket.voucher.objects_used, 0);
+        assert_eq!(channel_bucket.voucher.size_used, 0);
+        assert_eq!(
+            Storage::storage_bucket_content_references(channel_bucket_id, &content_id),
+            0
+        );
     });
 }
 
 #[test]
-fn delete_dynamic_bags_succeeded_with_assigned_storage_buckets() {
+fn deduplicated_upload_with_content_size_mismatch_stored_as_new_content() {
     build_test_externalities().execute_with(|| {
-        let initial_balance = 1000;
-        let deletion_prize_value = 77;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+        let data_size_fee = 100;
+        let reference_fee = 5;
 
-        let storage_buckets_number = DefaultMemberDynamicBagNumberOfStorageBuckets::get();
-        create_storage_buckets(storage_buckets_number);
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_new_fee(data_size_fee)
+            .call_and_assert(Ok(()));
 
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        CreateDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_prize(DynamicBagDeletionPrize::<Test> {
-                account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-                prize: deletion_prize_value,
-            })
+        UpdateDataObjectReferenceFeeFixture::default()
+            .with_new_fee(Some(reference_fee))
             .call_and_assert(Ok(()));
 
-        let bag = Storage::dynamic_bag(&dynamic_bag_id);
+        let (member_bag_id, channel_bag_id, bucket_id) = setup_content_deduplication_test();
 
-        assert_eq!(bag.stored_by.len(), storage_buckets_number as usize);
+        let content_id = vec![1];
+        let size = DEFAULT_DATA_OBJECTS_SIZE;
+        let deletion_prize = DataObjectDeletionPrize::get();
 
-        let stored_by_bag = bag.stored_by.clone();
-        for bucket_id in &stored_by_bag {
-            let bucket = Storage::storage_bucket_by_id(bucket_id);
+        upload_data_object_with_content_id(member_bag_id.clone(), content_id.clone(), size, Ok(()));
+        accept_deduplication_test_data_object(member_bag_id, bucket_id, 0);
 
-            assert_eq!(bucket.assigned_bags, 1);
-        }
+        upload_data_object_with_content_id(
+            channel_bag_id.clone(),
+            content_id.clone(),
+            size + 1,
+            Ok(()),
+        );
+        accept_deduplication_test_data_object(channel_bag_id.clone(), bucket_id, 1);
 
-        DeleteDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
-            .call_and_assert(Ok(()));
+        // Charged with the data size fee and counted in full.
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - 2 * deletion_prize - 2 * data_size_fee
+        );
+        assert_eq!(
+            Storage::shared_data_object(&content_id).map(|record| record.references),
+            Some(1)
+        );
+        assert_eq!(
+            Storage::bag_content_references(&channel_bag_id, &content_id),
+            0
+        );
+        assert_eq!(
+            Storage::storage_bucket_by_id(bucket_id).voucher.size_used,
+            2 * size + 1
+        );
 
-        for bucket_id in &stored_by_bag {
-            let bucket = Storage::storage_bucket_by_id(bucket_id);
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(channel_bag_id)
+            .with_data_object_ids(BTreeSet::from_iter(vec![1]))
+            .call_and_assert(Ok(()));
 
-            assert_eq!(bucket.assigned_bags, 0);
-        }
+        assert_eq!(
+            Storage::shared_data_object(&content_id).map(|record| record.references),
+            Some(1)
+        );
+        assert_eq!(
+            Storage::storage_bucket_by_id(bucket_id).voucher.size_used,
+            size
+        );
     });
 }
 
 #[test]
-fn delete_dynamic_bags_fails_with_non_existent_dynamic_bag() {
+fn deduplicated_upload_of_pending_content_charges_data_size_fee() {
     build_test_externalities().execute_with(|| {
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-
-        DeleteDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
-            .call_and_assert(Err(Error::<Test>::DynamicBagDoesntExist.into()));
-    });
-}
+        let data_size_fee = 100;
+        let reference_fee = 5;
 
-#[test]
-fn delete_dynamic_bags_fails_with_insufficient_balance_for_deletion_prize() {
-    build_test_externalities().execute_with(|| {
-        let initial_balance = 1000;
-        let deletion_prize_value = 77;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_new_fee(data_size_fee)
+            .call_and_assert(Ok(()));
 
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        CreateDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_prize(DynamicBagDeletionPrize::<Test> {
-                account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-                prize: deletion_prize_value,
-            })
+        UpdateDataObjectReferenceFeeFixture::default()
+            .with_new_fee(Some(reference_fee))
             .call_and_assert(Ok(()));
 
-        // Corrupt module balance.
-        let _ = Balances::slash(
-            &<StorageTreasury<Test>>::module_account_id(),
-            <StorageTreasury<Test>>::usable_balance(),
-        );
+        let (member_bag_id, channel_bag_id, bucket_id) = setup_content_deduplication_test();
 
-        DeleteDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
-            .call_and_assert(Err(Error::<Test>::InsufficientTreasuryBalance.into()));
-    });
-}
+        let content_id = vec![1];
+        let size = DEFAULT_DATA_OBJECTS_SIZE;
+        let deletion_prize = DataObjectDeletionPrize::get();
 
-#[test]
-fn delete_storage_bucket_succeeded() {
-    build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
+        upload_data_object_with_content_id(member_bag_id.clone(), content_id.clone(), size, Ok(()));
+        upload_data_object_with_content_id(
+            channel_bag_id.clone(),
+            content_id.clone(),
+            size,
+            Ok(()),
+        );
 
-        let bucket_id = CreateStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        // The only existing copy is pending.
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - 2 * deletion_prize - 2 * data_size_fee
+        );
+        assert_eq!(Storage::shared_data_object(&content_id), None);
 
-        DeleteStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
+        // The pending data object deletion doesn't release the registered content.
+        accept_deduplication_test_data_object(channel_bag_id, bucket_id, 1);
+
+        DeleteDataObjectsFixture::default()
+            .with_bag_id(member_bag_id)
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::StorageBucketDeleted(bucket_id));
+        assert_eq!(
+            Storage::shared_data_object(&content_id).map(|record| record.references),
+            Some(1)
+        );
+
+        let bucket = Storage::storage_bucket_by_id(bucket_id);
+        assert_eq!(bucket.voucher.objects_used, 1);
+        assert_eq!(bucket.voucher.size_used, size);
+        assert_eq!(
+            Storage::storage_bucket_content_references(bucket_id, &content_id),
+            1
+        );
     });
 }
 
 #[test]
-fn delete_storage_bucket_fails_with_non_leader_origin() {
+fn content_deduplication_disabled_by_default() {
     build_test_externalities().execute_with(|| {
-        let non_leader_id = 1;
+        let (member_bag_id, channel_bag_id, bucket_id) = setup_content_deduplication_test();
 
-        DeleteStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(non_leader_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
-    });
-}
+        let content_id = vec![1];
+        let size = DEFAULT_DATA_OBJECTS_SIZE;
 
-#[test]
-fn delete_storage_bucket_fails_with_non_existing_storage_bucket() {
-    build_test_externalities().execute_with(|| {
-        DeleteStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+        upload_data_object_with_content_id(member_bag_id, content_id.clone(), size, Ok(()));
+        upload_data_object_with_content_id(channel_bag_id, content_id.clone(), size, Ok(()));
+
+        assert_eq!(Storage::shared_data_object(&content_id), None);
+        assert_eq!(
+            Storage::storage_bucket_by_id(bucket_id).voucher.size_used,
+            2 * size
+        );
     });
 }
 
 #[test]
-fn delete_storage_bucket_fails_with_non_empty_bucket() {
+fn update_dynamic_bag_quota_succeeded() {
     build_test_externalities().execute_with(|| {
-        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
-
-        let bucket_id = create_default_storage_bucket_and_assign_to_bag(bag_id.clone());
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let object_creation_list = create_single_data_object();
+        let quota = Some(BagQuota {
+            objects_limit: 10,
+            size_limit: 1000,
+        });
 
-        let initial_balance = 1000;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
+        UpdateDynamicBagQuotaFixture::default()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_dynamic_bag_type(DynamicBagType::Channel)
+            .with_quota(quota)
+            .call_and_assert(Ok(()));
 
-        let upload_params = UploadParameters::<Test> {
-            bag_id: bag_id.clone(),
-            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-            object_creation_list: object_creation_list.clone(),
-            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
-        };
+        EventFixture::assert_last_crate_event(RawEvent::DynamicBagQuotaUpdated(
+            DynamicBagType::Channel,
+            quota,
+        ));
 
-        UploadFixture::default()
-            .with_params(upload_params.clone())
+        UpdateDynamicBagQuotaFixture::default()
+            .with_dynamic_bag_type(DynamicBagType::Channel)
+            .with_quota(None)
             .call_and_assert(Ok(()));
 
-        DeleteStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .call_and_assert(Err(Error::<Test>::CannotDeleteNonEmptyStorageBucket.into()));
+        EventFixture::assert_last_crate_event(RawEvent::DynamicBagQuotaUpdated(
+            DynamicBagType::Channel,
+            None,
+        ));
     });
 }
 
 #[test]
-fn delete_storage_bucket_fails_with_assigned_bag() {
+fn update_dynamic_bag_quota_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
-        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
-
-        let bucket_id = create_default_storage_bucket_and_assign_to_bag(bag_id.clone());
+        let non_leader_id = 1;
 
-        DeleteStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .call_and_assert(Err(Error::<Test>::StorageBucketIsBoundToBag.into()));
+        UpdateDynamicBagQuotaFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .with_quota(Some(BagQuota::default()))
+            .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn remove_storage_bucket_operator_succeeded() {
+fn set_bag_quota_override_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
-        let invite_worker = Some(storage_provider_id);
-
-        let bucket_id = CreateStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_invite_worker(invite_worker)
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        AcceptStorageBucketInvitationFixture::default()
-            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .with_worker_id(storage_provider_id)
-            .call_and_assert(Ok(()));
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let quota = Some(BagQuota {
+            objects_limit: 10,
+            size_limit: 1000,
+        });
 
-        RemoveStorageBucketOperatorFixture::default()
+        SetBagQuotaOverrideFixture::default()
             .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id.clone())
+            .with_quota(quota)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::StorageBucketOperatorRemoved(bucket_id));
+        EventFixture::assert_last_crate_event(RawEvent::BagQuotaOverrideUpdated(bag_id, quota));
     });
 }
 
 #[test]
-fn remove_storage_bucket_operator_fails_with_non_leader_origin() {
+fn set_bag_quota_override_fails_with_non_existent_dynamic_bag() {
     build_test_externalities().execute_with(|| {
-        let non_storage_provider_id = 1;
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
 
-        RemoveStorageBucketOperatorFixture::default()
-            .with_origin(RawOrigin::Signed(non_storage_provider_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        SetBagQuotaOverrideFixture::default()
+            .with_bag_id(dynamic_bag_id.into())
+            .with_quota(Some(BagQuota::default()))
+            .call_and_assert(Err(Error::<Test>::DynamicBagDoesntExist.into()));
     });
 }
 
 #[test]
-fn remove_storage_bucket_operator_fails_with_non_existing_storage_bucket() {
+fn set_bag_quota_override_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
-        RemoveStorageBucketOperatorFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+        let non_leader_id = 1;
+
+        SetBagQuotaOverrideFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .with_quota(Some(BagQuota::default()))
+            .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
-#[test]
-fn remove_storage_bucket_operator_fails_with_non_accepted_storage_provider() {
-    build_test_externalities().execute_with(|| {
-        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
-        let invite_worker = Some(storage_provider_id);
+// Creates the member dynamic bag with the storage bucket and funds the member account.
+fn setup_bag_quota_test() -> BagId<Test> {
+    let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+    create_dynamic_bag(&dynamic_bag_id);
 
-        let bucket_id = CreateStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_invite_worker(invite_worker)
-            .call_and_assert(Ok(()))
-            .unwrap();
+    let bag_id: BagId<Test> = dynamic_bag_id.into();
 
-        RemoveStorageBucketOperatorFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .call_and_assert(Err(Error::<Test>::StorageProviderMustBeSet.into()));
-    });
+    create_storage_bucket_and_assign_to_bag(
+        bag_id.clone(),
+        None,
+        DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+        DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+    );
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    bag_id
+}
+
+fn upload_data_objects_to_bag(
+    bag_id: BagId<Test>,
+    object_creation_list: Vec<DataObjectCreationParameters>,
+    expected_result: DispatchResult,
+) {
+    let upload_params = UploadParameters::<Test> {
+        bag_id,
+        deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        object_creation_list,
+        expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+    };
+
+    UploadFixture::default()
+        .with_params(upload_params)
+        .call_and_assert(expected_result);
 }
 
 #[test]
-fn remove_storage_bucket_operator_fails_with_missing_storage_provider() {
+fn upload_fails_with_exceeded_dynamic_bag_objects_quota() {
     build_test_externalities().execute_with(|| {
-        let bucket_id = CreateStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_invite_worker(None)
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let bag_id = setup_bag_quota_test();
+
+        UpdateDynamicBagQuotaFixture::default()
+            .with_dynamic_bag_type(DynamicBagType::Member)
+            .with_quota(Some(BagQuota {
+                objects_limit: 2,
+                size_limit: DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+            }))
+            .call_and_assert(Ok(()));
 
-        RemoveStorageBucketOperatorFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .call_and_assert(Err(Error::<Test>::StorageProviderMustBeSet.into()));
+        upload_data_objects_to_bag(bag_id.clone(), create_data_object_candidates(1, 2), Ok(()));
+
+        upload_data_objects_to_bag(
+            bag_id,
+            create_data_object_candidates(3, 1),
+            Err(Error::<Test>::BagQuotaExceeded.into()),
+        );
     });
 }
 
 #[test]
-fn update_data_size_fee_succeeded() {
+fn upload_fails_with_exceeded_dynamic_bag_size_quota() {
     build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
-
-        let new_fee = 1000;
+        let bag_id = setup_bag_quota_test();
 
-        UpdateDataObjectPerMegabyteFeeFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_fee(new_fee)
+        UpdateDynamicBagQuotaFixture::default()
+            .with_dynamic_bag_type(DynamicBagType::Member)
+            .with_quota(Some(BagQuota {
+                objects_limit: DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+                size_limit: DEFAULT_DATA_OBJECTS_SIZE * 2 - 1,
+            }))
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DataObjectPerMegabyteFeeUpdated(new_fee));
+        upload_data_objects_to_bag(
+            bag_id,
+            create_data_object_candidates(1, 2),
+            Err(Error::<Test>::BagQuotaExceeded.into()),
+        );
     });
 }
 
 #[test]
-fn update_data_size_fee_fails_with_non_leader_origin() {
+fn dynamic_bag_quota_is_not_applied_to_other_bag_types() {
     build_test_externalities().execute_with(|| {
-        let non_leader_id = 1;
+        let bag_id = setup_bag_quota_test();
 
-        UpdateDataObjectPerMegabyteFeeFixture::default()
-            .with_origin(RawOrigin::Signed(non_leader_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        UpdateDynamicBagQuotaFixture::default()
+            .with_dynamic_bag_type(DynamicBagType::Channel)
+            .with_quota(Some(BagQuota::default()))
+            .call_and_assert(Ok(()));
+
+        upload_data_objects_to_bag(bag_id, create_data_object_candidates(1, 2), Ok(()));
     });
 }
 
 #[test]
-fn data_size_fee_calculation_works_properly() {
+fn bag_quota_override_takes_precedence_over_dynamic_bag_quota() {
     build_test_externalities().execute_with(|| {
-        const ONE_MB: u64 = 1_048_576;
+        let bag_id = setup_bag_quota_test();
 
-        // Fee set to zero.
-        assert_eq!(Storage::calculate_data_storage_fee(ONE_MB), 0);
+        UpdateDynamicBagQuotaFixture::default()
+            .with_dynamic_bag_type(DynamicBagType::Member)
+            .with_quota(Some(BagQuota::default()))
+            .call_and_assert(Ok(()));
 
-        let data_size_fee = 1000;
+        let quota = Some(BagQuota {
+            objects_limit: 2,
+            size_limit: DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        });
 
-        UpdateDataObjectPerMegabyteFeeFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_fee(data_size_fee)
+        SetBagQuotaOverrideFixture::default()
+            .with_bag_id(bag_id.clone())
+            .with_quota(quota)
             .call_and_assert(Ok(()));
 
-        // Fee set.
-        assert_eq!(Storage::calculate_data_storage_fee(ONE_MB), data_size_fee);
-        assert_eq!(
-            Storage::calculate_data_storage_fee(2 * ONE_MB),
-            2 * data_size_fee
-        );
+        assert_eq!(Storage::bag_quota(&bag_id), quota);
 
-        // Rounding works correctly.
-        assert_eq!(
-            Storage::calculate_data_storage_fee(ONE_MB + 1),
-            2 * data_size_fee
+        upload_data_objects_to_bag(bag_id.clone(), create_data_object_candidates(1, 2), Ok(()));
+
+        upload_data_objects_to_bag(
+            bag_id,
+            create_data_object_candidates(3, 1),
+            Err(Error::<Test>::BagQuotaExceeded.into()),
         );
     });
 }
 
 #[test]
-fn storage_bucket_voucher_changed_event_fired() {
+fn upload_fails_with_exceeded_static_bag_quota_override() {
     build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
-
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        let bag_id = BagId::<Test>::Dynamic(dynamic_bag_id.clone());
-        create_dynamic_bag(&dynamic_bag_id);
-
-        let objects_limit = 1;
-        let size_limit = 100;
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
 
-        let bucket_id = create_storage_bucket_and_assign_to_bag(
+        create_storage_bucket_and_assign_to_bag(
             bag_id.clone(),
             None,
-            objects_limit,
-            size_limit,
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
         );
 
-        let object_creation_list = create_single_data_object();
-
-        let initial_balance = 1000;
-        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, initial_balance);
-
-        let upload_params = UploadParameters::<Test> {
-            bag_id: bag_id.clone(),
-            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-            object_creation_list: object_creation_list.clone(),
-            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
-        };
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
 
-        UploadFixture::default()
-            .with_params(upload_params.clone())
+        SetBagQuotaOverrideFixture::default()
+            .with_bag_id(bag_id.clone())
+            .with_quota(Some(BagQuota {
+                objects_limit: 1,
+                size_limit: DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+            }))
             .call_and_assert(Ok(()));
 
-        EventFixture::contains_crate_event(RawEvent::VoucherChanged(
-            bucket_id,
-            Voucher {
-                objects_limit,
-                size_limit,
-                objects_used: 1,
-                size_used: object_creation_list[0].size,
-            },
-        ));
+        upload_data_objects_to_bag(
+            bag_id,
+            create_data_object_candidates(1, 2),
+            Err(Error::<Test>::BagQuotaExceeded.into()),
+        );
     });
 }
 
 #[test]
-fn update_storage_buckets_per_bag_limit_succeeded() {
+fn move_data_objects_fails_with_exceeded_destination_bag_quota() {
     build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
+        let src_bag_id = BagId::<Test>::Static(StaticBagId::Council);
 
-        let new_limit = 4;
+        create_storage_bucket_and_assign_to_bag(
+            src_bag_id.clone(),
+            None,
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
 
-        UpdateStorageBucketsPerBagLimitFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_limit(new_limit)
-            .call_and_assert(Ok(()));
+        let dest_bag_id = setup_bag_quota_test();
 
-        EventFixture::assert_last_crate_event(RawEvent::StorageBucketsPerBagLimitUpdated(
-            new_limit,
-        ));
-    });
-}
+        upload_data_objects_to_bag(
+            src_bag_id.clone(),
+            create_data_object_candidates(1, 2),
+            Ok(()),
+        );
 
-#[test]
-fn update_storage_buckets_per_bag_limit_origin() {
-    build_test_externalities().execute_with(|| {
-        let non_leader_id = 1;
+        SetBagQuotaOverrideFixture::default()
+            .with_bag_id(dest_bag_id.clone())
+            .with_quota(Some(BagQuota {
+                objects_limit: 1,
+                size_limit: DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+            }))
+            .call_and_assert(Ok(()));
 
-        UpdateStorageBucketsPerBagLimitFixture::default()
-            .with_origin(RawOrigin::Signed(non_leader_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        let ids = BTreeSet::from_iter(
+            <DataObjectsById<Test>>::iter_prefix(&src_bag_id)
+                .map(|(data_object_id, _)| data_object_id),
+        );
+
+        MoveDataObjectsFixture::default()
+            .with_src_bag_id(src_bag_id)
+            .with_dest_bag_id(dest_bag_id)
+            .with_data_object_ids(ids)
+            .call_and_assert(Err(Error::<Test>::BagQuotaExceeded.into()));
     });
 }
 
 #[test]
-fn update_storage_buckets_per_bag_limit_fails_with_incorrect_value() {
+fn create_dynamic_bag_with_objects_fails_with_exceeded_dynamic_bag_quota() {
     build_test_externalities().execute_with(|| {
-        let new_limit = 0;
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        UpdateStorageBucketsPerBagLimitFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_limit(new_limit)
-            .call_and_assert(Err(Error::<Test>::StorageBucketsPerBagLimitTooLow.into()));
+        create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER);
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
 
-        let new_limit = 100;
+        UpdateDynamicBagQuotaFixture::default()
+            .with_dynamic_bag_type(DynamicBagType::Member)
+            .with_quota(Some(BagQuota {
+                objects_limit: 1,
+                size_limit: DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+            }))
+            .call_and_assert(Ok(()));
 
-        UpdateStorageBucketsPerBagLimitFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_limit(new_limit)
-            .call_and_assert(Err(Error::<Test>::StorageBucketsPerBagLimitTooHigh.into()));
+        CreateDynamicBagWithObjectsFixture::default()
+            .with_objects(create_data_object_candidates(1, 2))
+            .call_and_assert(Err(Error::<Test>::BagQuotaExceeded.into()));
     });
 }
 
-fn set_update_storage_buckets_per_bag_limit(new_limit: u64) {
-    UpdateStorageBucketsPerBagLimitFixture::default()
-        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-        .with_new_limit(new_limit)
-        .call_and_assert(Ok(()))
-}
-
-fn set_default_update_storage_buckets_per_bag_limit() {
-    let new_limit = 7;
-
-    set_update_storage_buckets_per_bag_limit(new_limit);
-}
-
 #[test]
-fn set_storage_bucket_voucher_limits_succeeded() {
+fn dynamic_bag_deletion_removes_bag_quota_override() {
     build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
-
-        set_max_voucher_limits();
-
-        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
-        let invite_worker = Some(storage_provider_id);
-
-        let bucket_id = CreateStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_invite_worker(invite_worker)
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let bag_id = setup_bag_quota_test();
 
-        AcceptStorageBucketInvitationFixture::default()
-            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .with_worker_id(storage_provider_id)
+        SetBagQuotaOverrideFixture::default()
+            .with_bag_id(bag_id.clone())
+            .with_quota(Some(BagQuota::default()))
             .call_and_assert(Ok(()));
 
-        let new_objects_size_limit = 1;
-        let new_objects_number_limit = 1;
-
-        SetStorageBucketVoucherLimitsFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .with_new_objects_number_limit(new_objects_number_limit)
-            .with_new_objects_size_limit(new_objects_size_limit)
+        DeleteDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::StorageBucketVoucherLimitsSet(
-            bucket_id,
-            new_objects_size_limit,
-            new_objects_number_limit,
-        ));
+        assert_eq!(Storage::bag_quota_override(&bag_id), None);
     });
 }
 
 #[test]
-fn set_storage_bucket_voucher_limits_fails_with_invalid_values() {
+fn update_storage_operator_reward_rates_succeeded() {
     build_test_externalities().execute_with(|| {
-        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
-        let invite_worker = Some(storage_provider_id);
-
-        let bucket_id = CreateStorageBucketFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_invite_worker(invite_worker)
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        AcceptStorageBucketInvitationFixture::default()
-            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .with_worker_id(storage_provider_id)
-            .call_and_assert(Ok(()));
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let invalid_objects_size_limit = 1000;
-        let invalid_objects_number_limit = 1000;
+        let rates = OperatorRewardRates {
+            per_megabyte: 10,
+            per_bag: 5,
+        };
 
-        SetStorageBucketVoucherLimitsFixture::default()
+        UpdateStorageOperatorRewardRatesFixture::default()
             .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .with_new_objects_size_limit(invalid_objects_size_limit)
-            .call_and_assert(Err(Error::<Test>::VoucherMaxObjectSizeLimitExceeded.into()));
+            .with_rates(rates)
+            .call_and_assert(Ok(()));
 
-        SetStorageBucketVoucherLimitsFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_storage_bucket_id(bucket_id)
-            .with_new_objects_number_limit(invalid_objects_number_limit)
-            .call_and_assert(Err(
-                Error::<Test>::VoucherMaxObjectNumberLimitExceeded.into()
-            ));
+        EventFixture::assert_last_crate_event(RawEvent::StorageOperatorRewardRatesUpdated(rates));
     });
 }
 
 #[test]
-fn set_storage_bucket_voucher_limits_fails_with_invalid_origin() {
+fn update_storage_operator_reward_rates_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
-        SetStorageBucketVoucherLimitsFixture::default()
-            .with_origin(RawOrigin::Root)
-            .call_and_assert(Err(DispatchError::BadOrigin));
-    });
-}
+        let non_leader_id = 1;
 
-#[test]
-fn set_storage_bucket_voucher_limits_fails_with_invalid_storage_bucket() {
-    build_test_externalities().execute_with(|| {
-        SetStorageBucketVoucherLimitsFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+        UpdateStorageOperatorRewardRatesFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
-fn set_max_voucher_limits() {
-    set_max_voucher_limits_with_params(VOUCHER_SIZE_LIMIT, VOUCHER_OBJECTS_LIMIT);
-}
-
-fn set_max_voucher_limits_with_params(size_limit: u64, objects_limit: u64) {
-    UpdateStorageBucketsVoucherMaxLimitsFixture::default()
-        .with_new_objects_size_limit(size_limit)
-        .with_new_objects_number_limit(objects_limit)
-        .call_and_assert(Ok(()));
-}
-
 #[test]
-fn update_storage_buckets_voucher_max_limits_succeeded() {
+fn update_distribution_operator_reward_rates_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let new_size_limit = 14;
-        let new_number_limit = 4;
-
-        UpdateStorageBucketsVoucherMaxLimitsFixture::default()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_objects_number_limit(new_number_limit)
-            .with_new_objects_size_limit(new_size_limit)
-            .call_and_assert(Ok(()));
+        let rates = OperatorRewardRates {
+            per_megabyte: 10,
+            per_bag: 5,
+        };
 
-        EventFixture::assert_last_crate_event(RawEvent::StorageBucketsVoucherMaxLimitsUpdated(
-            new_size_limit,
-            new_number_limit,
+        UpdateDistributionOperatorRewardRatesFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_rates(rates)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionOperatorRewardRatesUpdated(
+            rates,
         ));
     });
 }
 
 #[test]
-fn update_storage_buckets_voucher_max_limits_fails_with_bad_origin() {
+fn update_distribution_operator_reward_rates_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
         let non_leader_id = 1;
 
-        UpdateStorageBucketsVoucherMaxLimitsFixture::default()
+        UpdateDistributionOperatorRewardRatesFixture::default()
             .with_origin(RawOrigin::Signed(non_leader_id))
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
+// Creates the member dynamic bag with the storage bucket operated by the default storage provider.
+fn setup_storage_operator_rewards_test() -> (BagId<Test>, u64) {
+    let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+    create_dynamic_bag(&dynamic_bag_id);
+
+    let bag_id: BagId<Test> = dynamic_bag_id.into();
+
+    let bucket_id = create_storage_bucket_and_assign_to_bag(
+        bag_id.clone(),
+        Some(DEFAULT_STORAGE_PROVIDER_ID),
+        DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+        DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+    );
+
+    (bag_id, bucket_id)
+}
+
 #[test]
-fn create_dynamic_bag_succeeded() {
+fn storage_operator_rewards_are_paid_at_the_end_of_the_reward_period() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        let distribution_buckets_number = 10;
-        let family_policy_number1 = 2;
-        let family_policy_number2 = 3;
+        let (bag_id, bucket_id) = setup_storage_operator_rewards_test();
 
-        create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER);
-        let (family1, _) =
-            create_distribution_bucket_family_with_buckets(distribution_buckets_number);
-        let (family2, _) =
-            create_distribution_bucket_family_with_buckets(distribution_buckets_number);
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        upload_data_objects_to_bag(bag_id, create_data_object_candidates(1, 2), Ok(()));
 
-        let family_policy = BTreeMap::from_iter(vec![
-            (family1, family_policy_number1),
-            (family2, family_policy_number2),
-        ]);
+        let rates = OperatorRewardRates {
+            per_megabyte: 10,
+            per_bag: 5,
+        };
 
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::default()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(family_policy)
+        UpdateStorageOperatorRewardRatesFixture::default()
+            .with_rates(rates)
             .call_and_assert(Ok(()));
 
-        let deletion_prize_value = 100;
-        let deletion_prize_account_id = DEFAULT_MEMBER_ACCOUNT_ID;
-        let initial_balance = 10000;
-        increase_account_balance(&deletion_prize_account_id, initial_balance);
-
-        let deletion_prize = DynamicBagDeletionPrize::<Test> {
-            prize: deletion_prize_value,
-            account_id: deletion_prize_account_id,
-        };
+        let size_used = Storage::storage_bucket_by_id(bucket_id).voucher.size_used;
+        let expected_reward =
+            rates.per_megabyte * Storage::size_in_megabytes(size_used) + rates.per_bag;
 
-        // pre-check balances
-        assert_eq!(
-            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
-            initial_balance
-        );
+        // No rewards before the end of the reward period.
+        run_to_block(OperatorRewardPeriod::get() - 1);
         assert_eq!(
-            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
-            0
+            STORAGE_WG_BUDGET.with(|val| *val.borrow()),
+            DEFAULT_WORKING_GROUP_BUDGET
         );
 
-        CreateDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_prize(deletion_prize.clone())
-            .call_and_assert(Ok(()));
-
-        let bag = Storage::dynamic_bag(&dynamic_bag_id);
-
-        // Check that IDs are within possible range.
-        assert!(bag
-            .stored_by
-            .iter()
-            .all(|id| { *id < Storage::next_storage_bucket_id() }));
+        run_to_block(OperatorRewardPeriod::get());
 
-        let creation_policy =
-            Storage::get_dynamic_bag_creation_policy(dynamic_bag_id.clone().into());
+        EventFixture::assert_last_crate_event(RawEvent::StorageOperatorRewardPaid(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            expected_reward,
+        ));
         assert_eq!(
-            bag.stored_by.len(),
-            creation_policy.number_of_storage_buckets as usize
+            STORAGE_WG_BUDGET.with(|val| *val.borrow()),
+            DEFAULT_WORKING_GROUP_BUDGET - expected_reward
         );
+    });
+}
 
-        let total_distributed_buckets_number = family_policy_number1 + family_policy_number2;
-        assert_eq!(
-            bag.distributed_by.len(),
-            total_distributed_buckets_number as usize
-        );
+#[test]
+fn storage_operator_rewards_are_reduced_on_insufficient_budget() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        for distribution_bucket_id in &bag.distributed_by {
-            let bucket = Storage::distribution_bucket_by_family_id_by_index(
-                distribution_bucket_id.distribution_bucket_family_id,
-                distribution_bucket_id.distribution_bucket_index,
-            );
+        let (_, bucket_id) = setup_storage_operator_rewards_test();
 
-            assert_eq!(bucket.assigned_bags, 1);
-        }
+        let budget = 40;
+        STORAGE_WG_BUDGET.with(|val| *val.borrow_mut() = budget);
 
-        assert_eq!(bag.deletion_prize.unwrap(), deletion_prize_value);
+        UpdateStorageOperatorRewardRatesFixture::default()
+            .with_rates(OperatorRewardRates {
+                per_megabyte: 0,
+                per_bag: 100,
+            })
+            .call_and_assert(Ok(()));
 
-        // post-check balances
-        assert_eq!(
-            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
-            initial_balance - deletion_prize_value
-        );
-        assert_eq!(
-            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
-            deletion_prize_value
-        );
+        run_to_block(OperatorRewardPeriod::get());
 
-        EventFixture::assert_last_crate_event(RawEvent::DynamicBagCreated(
-            dynamic_bag_id,
-            Some(deletion_prize),
-            BTreeSet::from_iter(bag.stored_by),
-            BTreeSet::from_iter(bag.distributed_by),
+        EventFixture::assert_last_crate_event(RawEvent::StorageOperatorRewardPaid(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            budget,
         ));
+        assert_eq!(STORAGE_WG_BUDGET.with(|val| *val.borrow()), 0);
     });
 }
 
 #[test]
-fn create_dynamic_bag_fails_with_insufficient_balance() {
+fn distribution_operator_rewards_are_split_between_bucket_operators() {
     build_test_externalities().execute_with(|| {
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        let deletion_prize_value = 100;
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let deletion_prize = DynamicBagDeletionPrize::<Test> {
-            prize: deletion_prize_value,
-            account_id: DEFAULT_MEMBER_ACCOUNT_ID,
-        };
+        set_default_distribution_buckets_per_bag_limit();
 
-        CreateDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .with_deletion_prize(deletion_prize)
-            .call_and_assert(Err(Error::<Test>::InsufficientBalance.into()));
+        let (family_id, bucket_ids) = create_distribution_bucket_family_with_buckets(1);
+        let bucket_id = bucket_ids[0].clone();
+        let operators = vec![
+            DEFAULT_DISTRIBUTION_PROVIDER_ID,
+            ANOTHER_DISTRIBUTION_PROVIDER_ID,
+        ];
+
+        for operator_id in operators.iter() {
+            InviteDistributionBucketOperatorFixture::default()
+                .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+                .with_bucket_index(bucket_id.distribution_bucket_index)
+                .with_family_id(family_id)
+                .with_operator_worker_id(*operator_id)
+                .call_and_assert(Ok(()));
+
+            AcceptDistributionBucketInvitationFixture::default()
+                .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+                .with_family_id(family_id)
+                .with_bucket_index(bucket_id.distribution_bucket_index)
+                .with_worker_id(*operator_id)
+                .call_and_assert(Ok(()));
+        }
+
+        UpdateDistributionBucketForBagsFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(BagId::<Test>::Static(StaticBagId::Council))
+            .with_family_id(family_id)
+            .with_add_bucket_indices(BTreeSet::from_iter(vec![
+                bucket_id.distribution_bucket_index,
+            ]))
+            .call_and_assert(Ok(()));
+
+        let bucket_reward = 100;
+        UpdateDistributionOperatorRewardRatesFixture::default()
+            .with_rates(OperatorRewardRates {
+                per_megabyte: 0,
+                per_bag: bucket_reward,
+            })
+            .call_and_assert(Ok(()));
+
+        run_to_block(OperatorRewardPeriod::get());
+
+        let operator_reward = bucket_reward / operators.len() as u64;
+        for operator_id in operators {
+            EventFixture::contains_crate_event(RawEvent::DistributionOperatorRewardPaid(
+                bucket_id.clone(),
+                operator_id,
+                operator_reward,
+            ));
+        }
+        assert_eq!(
+            DISTRIBUTION_WG_BUDGET.with(|val| *val.borrow()),
+            DEFAULT_WORKING_GROUP_BUDGET - bucket_reward
+        );
     });
 }
 
 #[test]
-fn create_dynamic_bag_failed_with_existing_bag() {
+fn operator_rewards_are_not_paid_with_default_rates() {
     build_test_externalities().execute_with(|| {
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        CreateDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .call_and_assert(Ok(()));
+        setup_storage_operator_rewards_test();
 
-        CreateDynamicBagFixture::default()
-            .with_bag_id(dynamic_bag_id.clone())
-            .call_and_assert(Err(Error::<Test>::DynamicBagExists.into()));
-    });
-}
+        run_to_block(OperatorRewardPeriod::get());
 
-fn create_dynamic_bag(dynamic_bag_id: &DynamicBagId<Test>) {
-    CreateDynamicBagFixture::default()
-        .with_bag_id(dynamic_bag_id.clone())
-        .call_and_assert(Ok(()));
+        assert_eq!(
+            STORAGE_WG_BUDGET.with(|val| *val.borrow()),
+            DEFAULT_WORKING_GROUP_BUDGET
+        );
+        assert_eq!(
+            DISTRIBUTION_WG_BUDGET.with(|val| *val.borrow()),
+            DEFAULT_WORKING_GROUP_BUDGET
+        );
+    });
 }
 
 #[test]
-fn test_storage_bucket_picking_for_bag_non_random() {
+fn storage_operator_rewards_round_is_spread_over_blocks() {
     build_test_externalities().execute_with(|| {
-        // Randomness disabled at the initial block.
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let initial_buckets_number = InitialStorageBucketsNumberForDynamicBag::get();
-        // No buckets
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
-        assert_eq!(bucket_ids, BTreeSet::new());
+        // Buckets without operators precede the rewarded bucket.
+        create_storage_buckets(MaxOperatorRewardBucketsPerBlock::get() - 5);
+        let (_, bucket_id) = setup_storage_operator_rewards_test();
 
-        // Less then initial buckets number
-        let buckets_number = initial_buckets_number - 1;
-        let created_buckets = create_storage_buckets(buckets_number);
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+        let rates = OperatorRewardRates {
+            per_megabyte: 0,
+            per_bag: 100,
+        };
 
-        assert_eq!(bucket_ids, created_buckets);
+        UpdateStorageOperatorRewardRatesFixture::default()
+            .with_rates(rates)
+            .call_and_assert(Ok(()));
 
-        // More then initial buckets number
-        let buckets_number = 5;
-        create_storage_buckets(buckets_number);
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+        // The rewards are summed up and the paying stage is started at the end of the period.
+        run_to_block(OperatorRewardPeriod::get());
 
+        assert!(Storage::storage_operator_rewards_round().is_some());
         assert_eq!(
-            bucket_ids,
-            BTreeSet::from_iter((0u64..initial_buckets_number).into_iter())
+            STORAGE_WG_BUDGET.with(|val| *val.borrow()),
+            DEFAULT_WORKING_GROUP_BUDGET
         );
 
-        // Check removed buckets
-        let removed_bucket_id = 1;
-        <crate::StorageBucketById<Test>>::remove(removed_bucket_id);
-
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
-
-        let mut expected_ids =
-            BTreeSet::from_iter((0u64..(initial_buckets_number + 1)).into_iter());
-        expected_ids.remove(&removed_bucket_id);
+        run_to_block(OperatorRewardPeriod::get() + 1);
 
-        assert_eq!(bucket_ids, expected_ids);
+        EventFixture::assert_last_crate_event(RawEvent::StorageOperatorRewardPaid(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            rates.per_bag,
+        ));
+        assert_eq!(
+            STORAGE_WG_BUDGET.with(|val| *val.borrow()),
+            DEFAULT_WORKING_GROUP_BUDGET - rates.per_bag
+        );
+        assert!(Storage::storage_operator_rewards_round().is_none());
+    });
+}
 
-        // Check disabled buckets
-        let disabled_bucket_id = 2;
-        <crate::StorageBucketById<Test>>::mutate(disabled_bucket_id, |bucket| {
-            bucket.accepting_new_bags = false;
-        });
+// Creates the distribution bucket family with a single bucket.
+fn setup_distribution_bucket_bags_size_test() -> DistributionBucketId<Test> {
+    set_default_distribution_buckets_per_bag_limit();
 
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+    let (_, bucket_ids) = create_distribution_bucket_family_with_buckets(1);
 
-        let mut expected_ids =
-            BTreeSet::from_iter((0u64..(initial_buckets_number + 2)).into_iter());
-        expected_ids.remove(&removed_bucket_id);
-        expected_ids.remove(&disabled_bucket_id);
+    bucket_ids[0].clone()
+}
 
-        assert_eq!(bucket_ids, expected_ids);
+fn update_distribution_bucket_for_council_bag(bucket_id: &DistributionBucketId<Test>, add: bool) {
+    let bucket_indices = BTreeSet::from_iter(vec![bucket_id.distribution_bucket_index]);
 
-        // No storage buckets required
-        crate::DynamicBagCreationPolicies::<Test>::insert(
-            DynamicBagType::Member,
-            DynamicBagCreationPolicy::default(),
-        );
+    let fixture = UpdateDistributionBucketForBagsFixture::default()
+        .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        .with_bag_id(BagId::<Test>::Static(StaticBagId::Council))
+        .with_family_id(bucket_id.distribution_bucket_family_id);
 
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
-        assert_eq!(bucket_ids, BTreeSet::new());
-    });
+    if add {
+        fixture.with_add_bucket_indices(bucket_indices)
+    } else {
+        fixture.with_remove_bucket_indices(bucket_indices)
+    }
+    .call_and_assert(Ok(()));
 }
 
 #[test]
-fn test_storage_bucket_picking_for_bag_with_randomness() {
+fn distribution_bucket_bags_size_is_tracked_with_bag_assignments_and_uploads() {
     build_test_externalities().execute_with(|| {
-        // Enable randomness (disabled at the initial block).
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let initial_buckets_number = InitialStorageBucketsNumberForDynamicBag::get();
-        // No buckets
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
-        assert_eq!(bucket_ids, BTreeSet::new());
-
-        // Less then initial buckets number
-        let buckets_number = initial_buckets_number - 1;
-        let created_buckets = create_storage_buckets(buckets_number);
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
-
-        assert_eq!(bucket_ids, created_buckets);
-
-        // More then initial buckets number
-        let buckets_number = 5;
-        create_storage_buckets(buckets_number);
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
-
-        let sequential_random_ids = BTreeSet::from_iter((0u64..initial_buckets_number).into_iter());
-
-        // Check number of generated IDs
-        assert_eq!(initial_buckets_number, bucket_ids.len() as u64);
-        // Verify that generated IDs differ from sequential ID enumeration.
-        assert_ne!(sequential_random_ids, bucket_ids);
-        // Check that IDs are within possible range.
-        assert!(bucket_ids
-            .iter()
-            .all(|id| { *id < Storage::next_storage_bucket_id() }));
-
-        // Check removed buckets
-        let removed_bucket_id = bucket_ids.iter().next().unwrap();
-        <crate::StorageBucketById<Test>>::remove(removed_bucket_id);
-
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
-        // Check number of generated IDs
-        assert_eq!(initial_buckets_number, bucket_ids.len() as u64);
-        // Check that IDs are within possible range.
-        assert!(bucket_ids
-            .iter()
-            .all(|id| { *id < Storage::next_storage_bucket_id() }));
-        // Check removed bucket
-        assert!(!bucket_ids.contains(removed_bucket_id));
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let bucket_id = setup_distribution_bucket_bags_size_test();
 
-        // Check disabled buckets
-        let disabled_bucket_id = 2;
-        <crate::StorageBucketById<Test>>::mutate(disabled_bucket_id, |bucket| {
-            bucket.accepting_new_bags = false;
-        });
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        upload_data_objects_to_bag(bag_id.clone(), create_data_object_candidates(1, 2), Ok(()));
 
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
+        assert_eq!(Storage::distribution_bucket_bags_size(&bucket_id), 0);
 
-        let mut expected_ids =
-            BTreeSet::from_iter((0u64..(initial_buckets_number + 2)).into_iter());
-        expected_ids.remove(&removed_bucket_id);
-        expected_ids.remove(&disabled_bucket_id);
+        // The bag size is added on the bag assignment.
+        update_distribution_bucket_for_council_bag(&bucket_id, true);
 
-        // Check number of generated IDs
-        assert_eq!(initial_buckets_number, bucket_ids.len() as u64);
-        // Check that IDs are within possible range.
-        assert!(bucket_ids
-            .iter()
-            .all(|id| { *id < Storage::next_storage_bucket_id() }));
-        // Check removed bucket
-        assert!(!bucket_ids.contains(removed_bucket_id));
+        assert_eq!(
+            Storage::distribution_bucket_bags_size(&bucket_id),
+            Storage::bag(&bag_id).objects_total_size
+        );
 
-        // No storage buckets required
-        crate::DynamicBagCreationPolicies::<Test>::insert(
-            DynamicBagType::Member,
-            DynamicBagCreationPolicy::default(),
+        // The uploaded objects are added to the assigned bucket.
+        upload_data_objects_to_bag(bag_id.clone(), create_data_object_candidates(3, 2), Ok(()));
+
+        assert_eq!(
+            Storage::distribution_bucket_bags_size(&bucket_id),
+            Storage::bag(&bag_id).objects_total_size
         );
 
-        let bucket_ids =
-            Storage::pick_storage_buckets_for_dynamic_bag(DynamicBagType::Member, None);
-        assert_eq!(bucket_ids, BTreeSet::new());
+        // The bag size is removed on the bag unassignment.
+        update_distribution_bucket_for_council_bag(&bucket_id, false);
+
+        assert_eq!(Storage::distribution_bucket_bags_size(&bucket_id), 0);
     });
 }
 
@@ -4581,57 +8886,340 @@ fn distribution_bucket_family_pick_during_dynamic_bag_creation_succeeded() {
         let (family_id5, bucket_id5) = create_distribution_bucket_family_with_buckets(1);
         let (family_id6, bucket_id6) = create_distribution_bucket_family_with_buckets(1);
 
-        let deleted_bucket_id = bucket_id5[0].clone();
-        DeleteDistributionBucketFixture::default()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_family_id(deleted_bucket_id.distribution_bucket_family_id)
-            .with_bucket_index(deleted_bucket_id.distribution_bucket_index)
+        let deleted_bucket_id = bucket_id5[0].clone();
+        DeleteDistributionBucketFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(deleted_bucket_id.distribution_bucket_family_id)
+            .with_bucket_index(deleted_bucket_id.distribution_bucket_index)
+            .call_and_assert(Ok(()));
+
+        let disabled_bucket_id = bucket_id6[0].clone();
+        UpdateDistributionBucketStatusFixture::default()
+            .with_new_status(false)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(disabled_bucket_id.distribution_bucket_family_id)
+            .with_bucket_index(disabled_bucket_id.distribution_bucket_index)
+            .call_and_assert(Ok(()));
+
+        let families = BTreeMap::from_iter(vec![
+            (family_id1, new_bucket_number),
+            (family_id2, new_bucket_number),
+            (family_id3, 0),
+            (family_id4, new_bucket_number),
+            (family_id5, new_bucket_number),
+            (family_id6, new_bucket_number),
+        ]);
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(families)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        let picked_bucket_ids =
+            Storage::pick_distribution_buckets_for_dynamic_bag(dynamic_bag_type);
+
+        println!("{:?}", picked_bucket_ids);
+
+        assert_eq!(picked_bucket_ids.len(), (new_bucket_number * 2) as usize); // buckets from two families
+
+        let total_ids1 = BTreeSet::from_iter(
+            bucket_ids1
+                .iter()
+                .cloned()
+                .chain(bucket_ids2.iter().cloned()),
+        );
+        let total_ids2 = BTreeSet::from_iter(
+            total_ids1
+                .iter()
+                .cloned()
+                .chain(picked_bucket_ids.iter().cloned()),
+        );
+
+        assert_eq!(total_ids1, total_ids2); // picked IDS are from total ID set.
+    });
+}
+
+#[test]
+fn update_regions_in_dynamic_bag_creation_policy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_default_distribution_buckets_per_bag_limit();
+
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let regions = BTreeSet::from_iter(vec![b"eu".to_vec(), b"us".to_vec()]);
+
+        UpdateRegionsInDynamicBagCreationPolicyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .with_regions(regions.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::RegionsInDynamicBagCreationPolicyUpdated(
+            dynamic_bag_type,
+            regions,
+        ));
+    });
+}
+
+#[test]
+fn update_regions_in_dynamic_bag_creation_policy_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateRegionsInDynamicBagCreationPolicyFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_regions_in_dynamic_bag_creation_policy_fails_with_invalid_region_tag() {
+    build_test_externalities().execute_with(|| {
+        set_default_distribution_buckets_per_bag_limit();
+
+        UpdateRegionsInDynamicBagCreationPolicyFixture::default()
+            .with_regions(BTreeSet::from_iter(vec![Vec::new()]))
+            .call_and_assert(Err(Error::<Test>::InvalidRegionTag.into()));
+
+        let too_long_region = vec![1u8; MaxRegionTagLength::get() as usize + 1];
+
+        UpdateRegionsInDynamicBagCreationPolicyFixture::default()
+            .with_regions(BTreeSet::from_iter(vec![too_long_region]))
+            .call_and_assert(Err(Error::<Test>::InvalidRegionTag.into()));
+    });
+}
+
+#[test]
+fn update_regions_in_dynamic_bag_creation_policy_fails_with_exceeded_regions_number() {
+    build_test_externalities().execute_with(|| {
+        set_default_distribution_buckets_per_bag_limit();
+
+        let regions_number = Storage::distribution_buckets_per_bag_limit() + 1;
+        let regions = (0..regions_number).map(|idx| vec![idx as u8]).collect();
+
+        UpdateRegionsInDynamicBagCreationPolicyFixture::default()
+            .with_regions(regions)
+            .call_and_assert(Err(
+                Error::<Test>::MaxRegionsNumberPerBagLimitExceeded.into()
+            ));
+    });
+}
+
+#[test]
+fn update_regions_in_dynamic_bag_creation_policy_fails_with_exceeded_family_buckets_number() {
+    build_test_externalities().execute_with(|| {
+        set_default_distribution_buckets_per_bag_limit();
+
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let (family_id, _) = create_distribution_bucket_family_with_buckets(0);
+        let family_buckets_number = Storage::distribution_buckets_per_bag_limit() as u32 - 1;
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(BTreeMap::from_iter(vec![(
+                family_id,
+                family_buckets_number,
+            )]))
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        UpdateRegionsInDynamicBagCreationPolicyFixture::default()
+            .with_regions(BTreeSet::from_iter(vec![b"eu".to_vec()]))
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        UpdateRegionsInDynamicBagCreationPolicyFixture::default()
+            .with_regions(BTreeSet::from_iter(vec![b"eu".to_vec(), b"us".to_vec()]))
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Err(
+                Error::<Test>::MaxRegionsNumberPerBagLimitExceeded.into()
+            ));
+
+        // The family buckets can't grow over the limit with the regions set.
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(BTreeMap::from_iter(vec![(
+                family_id,
+                family_buckets_number + 1,
+            )]))
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Err(
+                Error::<Test>::MaxRegionsNumberPerBagLimitExceeded.into()
+            ));
+    });
+}
+
+#[test]
+fn set_distribution_bucket_family_region_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (family_id, bucket_ids) = create_distribution_bucket_family_with_buckets(1);
+        let region = Some(b"eu".to_vec());
+
+        SetDistributionBucketFamilyRegionFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_region(region.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketFamilyRegionUpdated(
+            family_id,
+            region.clone(),
+        ));
+
+        // Family buckets inherit the family region.
+        assert_eq!(Storage::distribution_bucket_region(&bucket_ids[0]), region);
+    });
+}
+
+#[test]
+fn set_distribution_bucket_family_region_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        SetDistributionBucketFamilyRegionFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_distribution_bucket_family_region_fails_with_non_existing_family() {
+    build_test_externalities().execute_with(|| {
+        SetDistributionBucketFamilyRegionFixture::default()
+            .with_region(Some(b"eu".to_vec()))
+            .call_and_assert(Err(
+                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
+            ));
+    });
+}
+
+#[test]
+fn set_distribution_bucket_family_region_fails_with_invalid_region_tag() {
+    build_test_externalities().execute_with(|| {
+        let (family_id, _) = create_distribution_bucket_family_with_buckets(0);
+
+        SetDistributionBucketFamilyRegionFixture::default()
+            .with_family_id(family_id)
+            .with_region(Some(Vec::new()))
+            .call_and_assert(Err(Error::<Test>::InvalidRegionTag.into()));
+    });
+}
+
+#[test]
+fn set_distribution_bucket_region_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (family_id, bucket_ids) = create_distribution_bucket_family_with_buckets(1);
+        let bucket_id = bucket_ids[0].clone();
+        let region = Some(b"us".to_vec());
+
+        SetDistributionBucketFamilyRegionFixture::default()
+            .with_family_id(family_id)
+            .with_region(Some(b"eu".to_vec()))
+            .call_and_assert(Ok(()));
+
+        SetDistributionBucketRegionFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_id(bucket_id.clone())
+            .with_region(region.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketRegionUpdated(
+            bucket_id.clone(),
+            region.clone(),
+        ));
+
+        // The bucket region overrides the family region.
+        assert_eq!(Storage::distribution_bucket_region(&bucket_id), region);
+    });
+}
+
+#[test]
+fn set_distribution_bucket_region_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        SetDistributionBucketRegionFixture::default()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_distribution_bucket_region_fails_with_non_existing_bucket() {
+    build_test_externalities().execute_with(|| {
+        SetDistributionBucketRegionFixture::default()
+            .with_region(Some(b"us".to_vec()))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn distribution_bucket_pick_during_dynamic_bag_creation_covers_policy_regions() {
+    build_test_externalities().execute_with(|| {
+        // Enable randomness (disabled at the initial block).
+        let starting_block = 6;
+        run_to_block(starting_block);
+
+        set_default_distribution_buckets_per_bag_limit();
+
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let buckets_number = 5;
+
+        let (family_id1, bucket_ids1) =
+            create_distribution_bucket_family_with_buckets(buckets_number);
+        let (family_id2, bucket_ids2) =
+            create_distribution_bucket_family_with_buckets(buckets_number);
+
+        SetDistributionBucketFamilyRegionFixture::default()
+            .with_family_id(family_id1)
+            .with_region(Some(b"eu".to_vec()))
             .call_and_assert(Ok(()));
 
-        let disabled_bucket_id = bucket_id6[0].clone();
-        UpdateDistributionBucketStatusFixture::default()
-            .with_new_status(false)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_family_id(disabled_bucket_id.distribution_bucket_family_id)
-            .with_bucket_index(disabled_bucket_id.distribution_bucket_index)
-            .call_and_assert(Ok(()));
+        let us_bucket_id = bucket_ids2[buckets_number as usize - 1].clone();
 
-        let families = BTreeMap::from_iter(vec![
-            (family_id1, new_bucket_number),
-            (family_id2, new_bucket_number),
-            (family_id3, 0),
-            (family_id4, new_bucket_number),
-            (family_id5, new_bucket_number),
-            (family_id6, new_bucket_number),
-        ]);
+        SetDistributionBucketRegionFixture::default()
+            .with_bucket_id(us_bucket_id.clone())
+            .with_region(Some(b"us".to_vec()))
+            .call_and_assert(Ok(()));
 
         UpdateFamiliesInDynamicBagCreationPolicyFixture::default()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(families)
+            .with_families(BTreeMap::from_iter(vec![(family_id1, 1), (family_id2, 0)]))
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        // No buckets in the "asia" region: the region is skipped.
+        UpdateRegionsInDynamicBagCreationPolicyFixture::default()
+            .with_regions(BTreeSet::from_iter(vec![
+                b"eu".to_vec(),
+                b"us".to_vec(),
+                b"asia".to_vec(),
+            ]))
             .with_dynamic_bag_type(dynamic_bag_type)
             .call_and_assert(Ok(()));
 
         let picked_bucket_ids =
             Storage::pick_distribution_buckets_for_dynamic_bag(dynamic_bag_type);
 
-        println!("{:?}", picked_bucket_ids);
-
-        assert_eq!(picked_bucket_ids.len(), (new_bucket_number * 2) as usize); // buckets from two families
-
-        let total_ids1 = BTreeSet::from_iter(
-            bucket_ids1
-                .iter()
-                .cloned()
-                .chain(bucket_ids2.iter().cloned()),
-        );
-        let total_ids2 = BTreeSet::from_iter(
-            total_ids1
+        // A single "eu" bucket from the first family covers its region.
+        assert_eq!(picked_bucket_ids.len(), 2);
+        assert!(picked_bucket_ids.contains(&us_bucket_id));
+        assert_eq!(
+            picked_bucket_ids
                 .iter()
-                .cloned()
-                .chain(picked_bucket_ids.iter().cloned()),
+                .filter(|bucket_id| bucket_ids1.contains(bucket_id))
+                .count(),
+            1
         );
-
-        assert_eq!(total_ids1, total_ids2); // picked IDS are from total ID set.
     });
 }
 
@@ -5007,102 +9595,431 @@ fn accept_distribution_bucket_operator_invite_fails_with_non_invited_distributio
 }
 
 #[test]
-fn set_distribution_operator_metadata_succeeded() {
+fn set_distribution_operator_metadata_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+        let metadata = b"Metadata".to_vec();
+
+        let family_id = CreateDistributionBucketFamilyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::default()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        InviteDistributionBucketOperatorFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(provider_id)
+            .call_and_assert(Ok(()));
+
+        AcceptDistributionBucketInvitationFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(provider_id)
+            .call_and_assert(Ok(()));
+
+        SetDistributionBucketMetadataFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(provider_id)
+            .with_metadata(metadata.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketMetadataSet(
+            provider_id,
+            Storage::create_distribution_bucket_id(family_id, bucket_index),
+            metadata,
+        ));
+    });
+}
+
+#[test]
+fn set_distribution_operator_metadata_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let invalid_account_id = 11111;
+
+        SetDistributionBucketMetadataFixture::default()
+            .with_origin(RawOrigin::Signed(invalid_account_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_distribution_operator_metadata_fails_with_non_existing_distribution_bucket() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        SetDistributionBucketMetadataFixture::default()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn set_distribution_operator_metadata_fails_with_non_distribution_provider() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::default()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        SetDistributionBucketMetadataFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .call_and_assert(Err(
+                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
+            ));
+    });
+}
+
+// Creates the distribution buckets operated by the default distribution provider.
+fn setup_distribution_health_beacons_test(bucket_number: u64) -> Vec<DistributionBucketId<Test>> {
+    let (family_id, bucket_ids) = create_distribution_bucket_family_with_buckets(bucket_number);
+
+    for bucket_id in bucket_ids.iter() {
+        InviteDistributionBucketOperatorFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_id.distribution_bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
+            .call_and_assert(Ok(()));
+
+        AcceptDistributionBucketInvitationFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_id.distribution_bucket_index)
+            .with_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
+            .call_and_assert(Ok(()));
+    }
+
+    bucket_ids
+}
+
+#[test]
+fn report_distribution_operator_health_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_distribution_health_beacons_test(1)[0].clone();
+        let bytes_served = 1000;
+        let uptime = 3600;
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_bucket_id(bucket_id.clone())
+            .with_bytes_served(bytes_served)
+            .with_uptime(uptime)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionOperatorHealthReported(
+            bucket_id,
+            DEFAULT_DISTRIBUTION_PROVIDER_ID,
+            bytes_served,
+            uptime,
+        ));
+    });
+}
+
+#[test]
+fn report_distribution_operator_health_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let invalid_account_id = 11111;
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_origin(RawOrigin::Signed(invalid_account_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn report_distribution_operator_health_fails_with_non_existing_distribution_bucket() {
+    build_test_externalities().execute_with(|| {
+        ReportDistributionOperatorHealthFixture::default()
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn report_distribution_operator_health_fails_with_non_distribution_provider() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = setup_distribution_health_beacons_test(1)[0].clone();
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_bucket_id(bucket_id)
+            .with_worker_id(ANOTHER_DISTRIBUTION_PROVIDER_ID)
+            .call_and_assert(Err(
+                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
+            ));
+    });
+}
+
+#[test]
+fn report_distribution_operator_health_fails_with_already_reported_beacon() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_distribution_health_beacons_test(1)[0].clone();
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_bucket_id(bucket_id.clone())
+            .call_and_assert(Ok(()));
+
+        run_to_block(DistributionHealthBeaconPeriod::get() - 1);
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_bucket_id(bucket_id.clone())
+            .call_and_assert(Err(
+                Error::<Test>::DistributionHealthBeaconAlreadyReported.into()
+            ));
+
+        // The next health beacon period.
+        run_to_block(DistributionHealthBeaconPeriod::get());
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn report_distribution_operator_health_fails_with_exceeded_beacons_per_block_limit() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let max_beacons = MaxDistributionHealthBeaconsPerBlock::get();
+        let bucket_ids = setup_distribution_health_beacons_test(max_beacons + 1);
+
+        for bucket_id in bucket_ids.iter().take(max_beacons as usize) {
+            ReportDistributionOperatorHealthFixture::default()
+                .with_bucket_id(bucket_id.clone())
+                .call_and_assert(Ok(()));
+        }
+
+        let last_bucket_id = bucket_ids[max_beacons as usize].clone();
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_bucket_id(last_bucket_id.clone())
+            .call_and_assert(Err(
+                Error::<Test>::MaxDistributionHealthBeaconsPerBlockExceeded.into(),
+            ));
+
+        // The limit is per block.
+        run_to_block(starting_block + 1);
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_bucket_id(last_bucket_id)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn distribution_bucket_stops_distributing_on_missed_health_beacons() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = setup_distribution_health_beacons_test(1)[0].clone();
+        let period = DistributionHealthBeaconPeriod::get();
+        let threshold = MissedDistributionHealthBeaconsThreshold::get();
+
+        // The health tracking starts at the end of the first period.
+        run_to_block(period);
+        assert_eq!(
+            Storage::distribution_operator_health(&bucket_id, DEFAULT_DISTRIBUTION_PROVIDER_ID),
+            Some(Default::default())
+        );
+
+        for missed_beacons in 1..threshold {
+            run_to_block(period * (missed_beacons + 1));
+
+            EventFixture::assert_last_crate_event(RawEvent::DistributionHealthBeaconMissed(
+                bucket_id.clone(),
+                DEFAULT_DISTRIBUTION_PROVIDER_ID,
+                missed_beacons,
+            ));
+            assert!(
+                Storage::distribution_bucket_by_family_id_by_index(
+                    bucket_id.distribution_bucket_family_id,
+                    bucket_id.distribution_bucket_index
+                )
+                .distributing
+            );
+        }
+
+        run_to_block(period * (threshold + 1));
+
+        EventFixture::contains_crate_event(RawEvent::DistributionHealthBeaconMissed(
+            bucket_id.clone(),
+            DEFAULT_DISTRIBUTION_PROVIDER_ID,
+            threshold,
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketModeUpdated(
+            bucket_id.clone(),
+            false,
+        ));
+        assert!(
+            !Storage::distribution_bucket_by_family_id_by_index(
+                bucket_id.distribution_bucket_family_id,
+                bucket_id.distribution_bucket_index
+            )
+            .distributing
+        );
+        assert_eq!(
+            Storage::distribution_operator_health(&bucket_id, DEFAULT_DISTRIBUTION_PROVIDER_ID),
+            None
+        );
+    });
+}
+
+#[test]
+fn distribution_bucket_keeps_distributing_with_healthy_operator() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
-        let metadata = b"Metadata".to_vec();
-
-        let family_id = CreateDistributionBucketFamilyFixture::default()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        let bucket_index = CreateDistributionBucketFixture::default()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let bucket_id = setup_distribution_health_beacons_test(1)[0].clone();
+        let period = DistributionHealthBeaconPeriod::get();
+        let threshold = MissedDistributionHealthBeaconsThreshold::get();
 
         InviteDistributionBucketOperatorFixture::default()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(provider_id)
+            .with_bucket_index(bucket_id.distribution_bucket_index)
+            .with_family_id(bucket_id.distribution_bucket_family_id)
+            .with_operator_worker_id(ANOTHER_DISTRIBUTION_PROVIDER_ID)
             .call_and_assert(Ok(()));
 
         AcceptDistributionBucketInvitationFixture::default()
             .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .with_worker_id(provider_id)
+            .with_family_id(bucket_id.distribution_bucket_family_id)
+            .with_bucket_index(bucket_id.distribution_bucket_index)
+            .with_worker_id(ANOTHER_DISTRIBUTION_PROVIDER_ID)
             .call_and_assert(Ok(()));
 
-        SetDistributionBucketMetadataFixture::default()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .with_worker_id(provider_id)
-            .with_metadata(metadata.clone())
-            .call_and_assert(Ok(()));
+        // Only the default operator misses the health beacons.
+        for period_number in 1..(threshold + 2) {
+            ReportDistributionOperatorHealthFixture::default()
+                .with_bucket_id(bucket_id.clone())
+                .with_worker_id(ANOTHER_DISTRIBUTION_PROVIDER_ID)
+                .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketMetadataSet(
-            provider_id,
-            Storage::create_distribution_bucket_id(family_id, bucket_index),
-            metadata,
-        ));
+            run_to_block(period * period_number);
+        }
+
+        assert_eq!(
+            Storage::distribution_operator_health(&bucket_id, DEFAULT_DISTRIBUTION_PROVIDER_ID)
+                .map(|health| health.missed_beacons),
+            Some(threshold)
+        );
+        assert!(
+            Storage::distribution_bucket_by_family_id_by_index(
+                bucket_id.distribution_bucket_family_id,
+                bucket_id.distribution_bucket_index
+            )
+            .distributing
+        );
     });
 }
 
 #[test]
-fn set_distribution_operator_metadata_fails_with_non_leader_origin() {
+fn missed_health_beacons_round_is_spread_over_blocks() {
     build_test_externalities().execute_with(|| {
-        let invalid_account_id = 11111;
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        SetDistributionBucketMetadataFixture::default()
-            .with_origin(RawOrigin::Signed(invalid_account_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
-    });
-}
+        let max_buckets = MaxHealthBeaconBucketsPerBlock::get();
+        let bucket_ids = setup_distribution_health_beacons_test(max_buckets + 1);
+        let period = DistributionHealthBeaconPeriod::get();
 
-#[test]
-fn set_distribution_operator_metadata_fails_with_non_existing_distribution_bucket() {
-    build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::default()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        run_to_block(period);
 
-        SetDistributionBucketMetadataFixture::default()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+        for bucket_id in bucket_ids.iter().take(max_buckets as usize) {
+            assert!(Storage::distribution_operator_health(
+                bucket_id,
+                DEFAULT_DISTRIBUTION_PROVIDER_ID
+            )
+            .is_some());
+        }
+
+        let last_bucket_id = bucket_ids[max_buckets as usize].clone();
+
+        assert_eq!(
+            Storage::distribution_operator_health(
+                &last_bucket_id,
+                DEFAULT_DISTRIBUTION_PROVIDER_ID
+            ),
+            None
+        );
+        assert!(Storage::distribution_health_beacons_round().is_some());
+
+        run_to_block(period + 1);
+
+        assert!(Storage::distribution_operator_health(
+            &last_bucket_id,
+            DEFAULT_DISTRIBUTION_PROVIDER_ID
+        )
+        .is_some());
+        assert_eq!(Storage::distribution_health_beacons_round(), None);
     });
 }
 
 #[test]
-fn set_distribution_operator_metadata_fails_with_non_distribution_provider() {
+fn distribution_health_beacon_resets_missed_beacons() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::default()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let bucket_index = CreateDistributionBucketFixture::default()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let bucket_id = setup_distribution_health_beacons_test(1)[0].clone();
+        let period = DistributionHealthBeaconPeriod::get();
 
-        SetDistributionBucketMetadataFixture::default()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .call_and_assert(Err(
-                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
-            ));
+        run_to_block(2 * period);
+        assert_eq!(
+            Storage::distribution_operator_health(&bucket_id, DEFAULT_DISTRIBUTION_PROVIDER_ID)
+                .map(|health| health.missed_beacons),
+            Some(1)
+        );
+
+        ReportDistributionOperatorHealthFixture::default()
+            .with_bucket_id(bucket_id.clone())
+            .call_and_assert(Ok(()));
+
+        run_to_block(3 * period);
+
+        assert_eq!(
+            Storage::distribution_operator_health(&bucket_id, DEFAULT_DISTRIBUTION_PROVIDER_ID)
+                .map(|health| health.missed_beacons),
+            Some(0)
+        );
+        assert!(
+            Storage::distribution_bucket_by_family_id_by_index(
+                bucket_id.distribution_bucket_family_id,
+                bucket_id.distribution_bucket_index
+            )
+            .distributing
+        );
     });
 }
 
@@ -5411,6 +10328,9 @@ fn unsuccessful_dyn_bag_creation_with_zero_objects_size() {
             .map(|idx| DataObjectCreationParameters {
                 size: 0,
                 ipfs_content_id: vec![idx.try_into().unwrap()],
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
             })
             .collect();
 
@@ -5434,6 +10354,9 @@ fn unsuccessful_dyn_bag_creation_with_object_size_exceeding_max_obj_size() {
                 // set size high on purpose to trigger error
                 size: 1_000_000,
                 ipfs_content_id: vec![idx.try_into().unwrap()],
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
             })
             .collect();
 
@@ -5455,6 +10378,9 @@ fn unsuccessful_dyn_bag_creation_with_buckets_having_insufficient_size_available
             .map(|idx| DataObjectCreationParameters {
                 size: DEFAULT_STORAGE_BUCKET_SIZE_LIMIT + 1,
                 ipfs_content_id: vec![idx],
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
             })
             .collect();
 
@@ -5477,6 +10403,9 @@ fn unsuccessful_dyn_bag_creation_with_buckets_having_insufficient_objects_availa
             .map(|idx| DataObjectCreationParameters {
                 size: DEFAULT_DATA_OBJECTS_SIZE,
                 ipfs_content_id: vec![idx.try_into().unwrap()],
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
             })
             .collect();
 
@@ -5498,6 +10427,9 @@ fn unsuccessful_dyn_bag_creation_with_empty_ipfs_ids() {
             .map(|_| DataObjectCreationParameters {
                 size: DEFAULT_DATA_OBJECTS_SIZE,
                 ipfs_content_id: vec![],
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
             })
             .collect();
         CreateDynamicBagWithObjectsFixture::default()
@@ -5578,6 +10510,9 @@ fn unsuccessful_dyn_bag_creation_with_blacklisted_ipfs_id() {
             .map(|idx| DataObjectCreationParameters {
                 size: DEFAULT_DATA_OBJECTS_SIZE,
                 ipfs_content_id: vec![idx.try_into().unwrap()],
+                content_root: None,
+                parts: Vec::new(),
+                class: None,
             })
             .collect();
 
@@ -5640,3 +10575,310 @@ fn unsuccessful_dyn_bag_creation_with_no_bucket_accepting() {
             .call_and_assert(Err(Error::<Test>::StorageBucketIdCollectionsAreEmpty.into()));
     })
 }
+
+#[test]
+fn storage_bucket_bags_query_returns_pages_of_bucket_bags() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        // Dynamic bags are created before the buckets to avoid the automatic bucket assignment.
+        let dynamic_bag_ids = vec![
+            DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID),
+            DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID + 1),
+        ];
+        for dynamic_bag_id in dynamic_bag_ids.iter() {
+            create_dynamic_bag(dynamic_bag_id);
+        }
+
+        let council_bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let bucket_id = create_default_storage_bucket_and_assign_to_bag(council_bag_id.clone());
+        let unassigned_bucket_id = create_storage_buckets(1).into_iter().next().unwrap();
+
+        for dynamic_bag_id in dynamic_bag_ids.iter() {
+            UpdateStorageBucketForBagsFixture::default()
+                .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+                .with_bag_id(dynamic_bag_id.clone().into())
+                .with_add_bucket_ids(BTreeSet::from_iter(vec![bucket_id]))
+                .call_and_assert(Ok(()));
+        }
+
+        let first_page = Storage::storage_bucket_bags(&bucket_id, 0, 2);
+        let second_page = Storage::storage_bucket_bags(&bucket_id, 2, 2);
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(second_page.len(), 1);
+
+        let expected_bag_ids = BTreeSet::from_iter(
+            dynamic_bag_ids
+                .into_iter()
+                .map(BagId::<Test>::from)
+                .chain(vec![council_bag_id]),
+        );
+        let queried_bag_ids = BTreeSet::from_iter(first_page.into_iter().chain(second_page));
+        assert_eq!(queried_bag_ids, expected_bag_ids);
+
+        assert!(Storage::storage_bucket_bags(&bucket_id, 3, 2).is_empty());
+        assert!(Storage::storage_bucket_bags(&unassigned_bucket_id, 0, 10).is_empty());
+    });
+}
+
+#[test]
+fn distribution_bucket_bags_query_returns_bucket_bags() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (family_id, bucket_ids) = create_distribution_bucket_family_with_buckets(2);
+        let bucket_id = bucket_ids[0].clone();
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+
+        UpdateDistributionBucketForBagsFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_family_id(family_id)
+            .with_add_bucket_indices(BTreeSet::from_iter(vec![
+                bucket_id.distribution_bucket_index,
+            ]))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Storage::distribution_bucket_bags(&bucket_id, 0, 10),
+            vec![bag_id]
+        );
+        assert!(Storage::distribution_bucket_bags(&bucket_id, 1, 10).is_empty());
+        assert!(Storage::distribution_bucket_bags(&bucket_ids[1], 0, 10).is_empty());
+    });
+}
+
+#[test]
+fn distribution_bucket_bags_query_pages_bucket_bags_after_removal() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (family_id, bucket_ids) = create_distribution_bucket_family_with_buckets(1);
+        let bucket_id = bucket_ids[0].clone();
+        let bag_ids = vec![
+            BagId::<Test>::Static(StaticBagId::Council),
+            BagId::<Test>::Static(StaticBagId::WorkingGroup(WorkingGroup::Storage)),
+            BagId::<Test>::Static(StaticBagId::WorkingGroup(WorkingGroup::Content)),
+        ];
+
+        for bag_id in bag_ids.iter() {
+            UpdateDistributionBucketForBagsFixture::default()
+                .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+                .with_bag_id(bag_id.clone())
+                .with_family_id(family_id)
+                .with_add_bucket_indices(BTreeSet::from_iter(vec![
+                    bucket_id.distribution_bucket_index,
+                ]))
+                .call_and_assert(Ok(()));
+        }
+
+        assert_eq!(Storage::distribution_bucket_bags_number(&bucket_id), 3);
+        assert_eq!(
+            Storage::distribution_bucket_bags(&bucket_id, 0, 2),
+            bag_ids[..2].to_vec()
+        );
+        assert_eq!(
+            Storage::distribution_bucket_bags(&bucket_id, 2, 2),
+            bag_ids[2..].to_vec()
+        );
+
+        // The last bag takes the position of the removed bag.
+        UpdateDistributionBucketForBagsFixture::default()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_ids[0].clone())
+            .with_family_id(family_id)
+            .with_remove_bucket_indices(BTreeSet::from_iter(vec![
+                bucket_id.distribution_bucket_index,
+            ]))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Storage::distribution_bucket_bags_number(&bucket_id), 2);
+        assert_eq!(
+            Storage::distribution_bucket_bags(&bucket_id, 0, 10),
+            vec![bag_ids[2].clone(), bag_ids[1].clone()]
+        );
+    });
+}
+
+#[test]
+fn bag_data_objects_query_returns_pages_of_bag_data_objects() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let objects_number = 3;
+        let (bag_id, data_object_ids) =
+            setup_blacklisted_data_objects_test(vec![1], objects_number);
+
+        let first_page = Storage::bag_data_objects(&bag_id, 0, 2);
+        let second_page = Storage::bag_data_objects(&bag_id, 2, 2);
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(second_page.len(), 1);
+
+        let queried_data_objects = first_page
+            .into_iter()
+            .chain(second_page)
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(
+            BTreeSet::from_iter(queried_data_objects.keys().cloned()),
+            data_object_ids
+        );
+
+        for (data_object_id, data_object) in queried_data_objects.iter() {
+            assert_eq!(
+                *data_object,
+                Storage::ensure_data_object_exists(&bag_id, data_object_id).unwrap()
+            );
+            assert!(!data_object.accepted);
+        }
+
+        let empty_bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        assert!(Storage::bag_data_objects(&empty_bag_id, 0, 10).is_empty());
+    });
+}
+
+#[test]
+fn storage_bucket_voucher_query_returns_bucket_voucher() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let objects_limit = 10;
+        let size_limit = 1000;
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let bucket_id =
+            create_storage_bucket_and_assign_to_bag(bag_id, None, objects_limit, size_limit);
+
+        assert_eq!(
+            Storage::storage_bucket_voucher(&bucket_id),
+            Some(Storage::storage_bucket_by_id(bucket_id).voucher)
+        );
+        assert_eq!(
+            Storage::storage_bucket_voucher(&bucket_id).map(|voucher| voucher.objects_limit),
+            Some(objects_limit)
+        );
+        assert_eq!(Storage::storage_bucket_voucher(&(bucket_id + 1)), None);
+    });
+}
+
+#[test]
+fn upload_fee_quote_query_returns_upload_fees() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let data_size_fee = 10;
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_new_fee(data_size_fee)
+            .call_and_assert(Ok(()));
+
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+
+        // 1 MB + 1 byte is rounded up to 2 MB.
+        let object_creation_list = create_data_object_candidates(1, 2)
+            .into_iter()
+            .zip(vec![1_048_576, 1])
+            .map(|(object_params, size)| DataObjectCreationParameters {
+                size,
+                ..object_params
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            Storage::upload_fee_quote(&bag_id, &object_creation_list),
+            UploadFeeQuote {
+                data_size_fee: 2 * data_size_fee,
+                deletion_prize: 2 * DataObjectDeletionPrize::get(),
+                storage_rent: 0,
+            }
+        );
+        assert_eq!(
+            Storage::upload_fee_quote(&bag_id, &[]),
+            UploadFeeQuote::default()
+        );
+
+        // The bag category schedule is used when set.
+        let schedule_fee = 100;
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_category(DataSizeFeeCategory::Static(StaticBagId::Council))
+            .with_schedule(Some(DataSizeFeeSchedule {
+                per_megabyte_fee: schedule_fee,
+                object_class_fees: BTreeMap::new(),
+            }))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Storage::upload_fee_quote(&bag_id, &object_creation_list).data_size_fee,
+            2 * schedule_fee
+        );
+    });
+}
+
+#[test]
+fn upload_fee_quote_query_matches_upload_fees() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let rent = 2;
+        let dynamic_bag_id = setup_storage_rent_test(rent, 1);
+        let bag_id: BagId<Test> = dynamic_bag_id.into();
+
+        let per_megabyte_fee = 100;
+        let thumbnail_fee = 10;
+        UpdateDataSizeFeeScheduleFixture::default()
+            .with_category(DataSizeFeeCategory::Dynamic(DynamicBagType::Member))
+            .with_schedule(Some(DataSizeFeeSchedule {
+                per_megabyte_fee,
+                object_class_fees: vec![(DataObjectClass::Thumbnail, thumbnail_fee)]
+                    .into_iter()
+                    .collect(),
+            }))
+            .call_and_assert(Ok(()));
+
+        let block = starting_block + 10;
+        run_to_block(block);
+
+        let classes = vec![None, Some(DataObjectClass::Thumbnail)];
+        let object_creation_list = create_data_object_candidates(2, 2)
+            .into_iter()
+            .zip(classes)
+            .map(|(object_params, class)| DataObjectCreationParameters {
+                class,
+                ..object_params
+            })
+            .collect::<Vec<_>>();
+
+        // The data objects fit one megabyte for the rent.
+        let quote = Storage::upload_fee_quote(&bag_id, &object_creation_list);
+        assert_eq!(
+            quote,
+            UploadFeeQuote {
+                data_size_fee: per_megabyte_fee + thumbnail_fee,
+                deletion_prize: 2 * DataObjectDeletionPrize::get(),
+                storage_rent: rent * (starting_block + InitialBagRentPeriod::get() - block),
+            }
+        );
+
+        let balance = Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID);
+
+        let upload_params = UploadParameters::<Test> {
+            bag_id,
+            deletion_prize_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list,
+            expected_data_size_fee: quote.data_size_fee,
+        };
+
+        UploadFixture::default()
+            .with_params(upload_params)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            balance - quote.data_size_fee - quote.deletion_prize - quote.storage_rent
+        );
+    });
+}
@@ -363,6 +363,7 @@ impl membership::WeightInfo for Weights {
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
+    pub const MaxDeputyLeads: u32 = 10;
     pub const LockId1: [u8; 8] = [1; 8];
     pub const LockId2: [u8; 8] = [2; 8];
     pub const MinimumApplicationStake: u32 = 50;
@@ -373,6 +374,7 @@ pub struct WorkingGroupWeightInfo;
 impl working_group::Trait<ContentWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId1>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
@@ -450,11 +452,15 @@ impl working_group::WeightInfo for WorkingGroupWeightInfo {
     fn leave_role(_: u32) -> Weight {
         0
     }
+    fn set_deputy_lead() -> Weight {
+        0
+    }
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
@@ -468,6 +474,7 @@ impl working_group::Trait<StorageWorkingGroupInstance> for Test {
 impl working_group::Trait<ForumWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = staking_handler::StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
@@ -481,6 +488,7 @@ impl working_group::Trait<ForumWorkingGroupInstance> for Test {
 impl working_group::Trait<MembershipWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
@@ -494,6 +502,7 @@ impl working_group::Trait<MembershipWorkingGroupInstance> for Test {
 impl working_group::Trait<GatewayWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
@@ -507,6 +516,7 @@ impl working_group::Trait<GatewayWorkingGroupInstance> for Test {
 impl working_group::Trait<DistributionWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
@@ -520,6 +530,7 @@ impl working_group::Trait<DistributionWorkingGroupInstance> for Test {
 impl working_group::Trait<OperationsWorkingGroupInstanceAlpha> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
@@ -533,6 +544,7 @@ impl working_group::Trait<OperationsWorkingGroupInstanceAlpha> for Test {
 impl working_group::Trait<OperationsWorkingGroupInstanceBeta> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
@@ -546,6 +558,7 @@ impl working_group::Trait<OperationsWorkingGroupInstanceBeta> for Test {
 impl working_group::Trait<OperationsWorkingGroupInstanceGamma> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
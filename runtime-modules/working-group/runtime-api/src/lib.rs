@@ -0,0 +1,39 @@
+//! Runtime API definition for the working group pallet instances.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+// Internal Substrate warning (decl_runtime_apis).
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Working group state queries for off-chain services (e.g. council and group dashboards).
+    pub trait WorkingGroupApi<
+        WorkingGroup,
+        WorkerId,
+        WorkerSummary,
+        OpeningId,
+        OpeningSummary,
+        BudgetSummary,
+    >
+    where
+        WorkingGroup: Codec,
+        WorkerId: Codec,
+        WorkerSummary: Codec,
+        OpeningId: Codec,
+        OpeningSummary: Codec,
+        BudgetSummary: Codec,
+    {
+        /// Workers of the group (including the leader) with their reward, stake and missed reward.
+        fn workers(group: WorkingGroup) -> Vec<(WorkerId, WorkerSummary)>;
+
+        /// Open openings of the group with their active applications number.
+        fn openings(group: WorkingGroup) -> Vec<(OpeningId, OpeningSummary)>;
+
+        /// Group budget versus the committed rewards per block with the estimated number of blocks
+        /// the budget lasts.
+        fn budget(group: WorkingGroup) -> BudgetSummary;
+    }
+}
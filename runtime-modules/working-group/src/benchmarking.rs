@@ -922,6 +922,35 @@ benchmarks_instance! {
             "Worker hasn't started leaving"
         );
     }
+
+    set_deputy_lead {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let permissions: BTreeSet<DeputyLeadPermission> = vec![
+            DeputyLeadPermission::FillOpening,
+            DeputyLeadPermission::CancelOpening,
+            DeputyLeadPermission::UpdateRewardAmount,
+            DeputyLeadPermission::SpendFromBudget,
+            DeputyLeadPermission::SetStatusText,
+        ]
+        .into_iter()
+        .collect();
+    }: _ (RawOrigin::Signed(lead_id.clone()), worker_id, permissions.clone())
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::deputy_lead_permissions(worker_id),
+            permissions,
+            "Deputy lead not set"
+        );
+
+        assert_last_event::<T, I>(RawEvent::DeputyLeadSet(worker_id, permissions).into());
+    }
 }
 
 #[cfg(test)]
@@ -937,6 +966,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_set_deputy_lead() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_deputy_lead::<Test>());
+        });
+    }
+
     #[test]
     fn test_add_opening() {
         build_test_externalities().execute_with(|| {
@@ -1,6 +1,6 @@
 use crate::{
-    ApplicationId, BalanceOf, Instance, Opening, OpeningId, OpeningType, StakePolicy, Trait,
-    Worker, WorkerId,
+    ApplicationId, BalanceOf, DeputyLeadPermission, Instance, Opening, OpeningId, OpeningType,
+    StakePolicy, Trait, Worker, WorkerId,
 };
 
 use super::Error;
@@ -33,6 +33,28 @@ pub(crate) fn ensure_origin_for_opening_type<T: Trait<I>, I: Instance>(
     }
 }
 
+// Check opening: verifies origin and opening type compatibility. Allows deputy lead with
+// the provided permission for the regular opening. Returns the deputy lead worker id when
+// the deputy lead is the origin signer.
+pub(crate) fn ensure_origin_for_opening_type_or_deputy<T: Trait<I>, I: Instance>(
+    origin: T::Origin,
+    opening_type: OpeningType,
+    permission: DeputyLeadPermission,
+) -> Result<Option<WorkerId<T>>, DispatchError> {
+    match opening_type {
+        OpeningType::Regular => {
+            // Ensure lead or deputy lead is origin signer.
+            ensure_origin_is_leader_or_deputy::<T, I>(origin, permission)
+        }
+        OpeningType::Leader => {
+            // Council proposal.
+            ensure_root(origin)?;
+
+            Ok(None)
+        }
+    }
+}
+
 pub(crate) fn ensure_stake_for_opening_type<T: Trait<I>, I: Instance>(
     origin: T::Origin,
     opening_type: OpeningType,
@@ -159,6 +181,62 @@ pub(crate) fn ensure_origin_is_active_leader<T: Trait<I>, I: Instance>(
     ensure_is_lead_account::<T, I>(signer)
 }
 
+// Check leader: ensures origin is signed by the leader or it is the root (council proposal).
+pub(crate) fn ensure_origin_is_active_leader_or_root<T: Trait<I>, I: Instance>(
+    origin: T::Origin,
+) -> DispatchResult {
+    if ensure_root(origin.clone()).is_ok() {
+        return Ok(());
+    }
+
+    ensure_origin_is_active_leader::<T, I>(origin)
+}
+
+// Check deputy lead: returns the active deputy lead worker id and its permissions by the role account.
+pub(crate) fn find_deputy_lead_by_account<T: Trait<I>, I: Instance>(
+    account_id: &T::AccountId,
+) -> Option<(WorkerId<T>, BTreeSet<DeputyLeadPermission>)> {
+    let worker_id = <crate::DeputyLeadByRoleAccount<T, I>>::get(account_id)?;
+
+    let worker = ensure_worker_exists::<T, I>(&worker_id).ok()?;
+
+    if worker.is_leaving() {
+        return None;
+    }
+
+    Some((
+        worker_id,
+        <crate::DeputyLeadPermissions<T, I>>::get(worker_id),
+    ))
+}
+
+// Check leader: ensures origin is signed by the leader or by the deputy lead with the provided
+// permission. Returns the deputy lead worker id when the deputy lead is the origin signer.
+pub(crate) fn ensure_origin_is_leader_or_deputy<T: Trait<I>, I: Instance>(
+    origin: T::Origin,
+    permission: DeputyLeadPermission,
+) -> Result<Option<WorkerId<T>>, DispatchError> {
+    // Ensure is signed
+    let signer = ensure_signed(origin)?;
+
+    let lead_check_result = ensure_is_lead_account::<T, I>(signer.clone());
+    if lead_check_result.is_ok() {
+        return Ok(None);
+    }
+
+    if let Some((deputy_id, permissions)) = find_deputy_lead_by_account::<T, I>(&signer) {
+        ensure!(
+            permissions.contains(&permission),
+            Error::<T, I>::DeputyLeadPermissionMissing
+        );
+
+        return Ok(Some(deputy_id));
+    }
+
+    // Neither leader nor deputy lead: report the leader check error.
+    lead_check_result.map(|_| None)
+}
+
 // Check worker: ensures the worker was already created.
 pub(crate) fn ensure_worker_exists<T: Trait<I>, I: Instance>(
     worker_id: &WorkerId<T>,
@@ -211,6 +289,90 @@ pub(crate) fn ensure_origin_for_worker_operation<T: Trait<I>, I: Instance>(
     Ok(is_sudo)
 }
 
+// Check worker: verifies proper origin for the worker operation. Allows deputy lead with the
+// provided permission for the regular worker other than the deputy lead itself.
+// Returns the deputy lead worker id when the deputy lead is the origin signer.
+pub(crate) fn ensure_origin_for_worker_operation_or_deputy<T: Trait<I>, I: Instance>(
+    origin: T::Origin,
+    worker_id: WorkerId<T>,
+    permission: DeputyLeadPermission,
+) -> Result<Option<WorkerId<T>>, DispatchError> {
+    let worker_is_leader = <crate::CurrentLead<T, I>>::get() == Some(worker_id);
+
+    if worker_is_leader {
+        // Council proposal.
+        ensure_root(origin)?;
+
+        return Ok(None);
+    }
+
+    let deputy_id = ensure_origin_is_leader_or_deputy::<T, I>(origin, permission)?;
+
+    if deputy_id == Some(worker_id) {
+        let error = if permission == DeputyLeadPermission::UpdateRewardAmount {
+            Error::<T, I>::DeputyLeadCannotUpdateOwnReward
+        } else {
+            Error::<T, I>::DeputyLeadCannotOperateOnSelf
+        };
+
+        return Err(error.into());
+    }
+
+    Ok(deputy_id)
+}
+
+// Check deputy lead: verifies the worker can be appointed as a deputy lead (or get the deputy
+// lead permissions updated). Returns the worker.
+pub(crate) fn ensure_worker_can_be_deputy_lead<T: Trait<I>, I: Instance>(
+    worker_id: &WorkerId<T>,
+) -> Result<Worker<T>, DispatchError> {
+    let worker = ensure_worker_exists::<T, I>(worker_id)?;
+
+    ensure!(
+        <crate::CurrentLead<T, I>>::get() != Some(*worker_id),
+        Error::<T, I>::CannotAppointLeaderAsDeputyLead
+    );
+
+    ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
+
+    if !<crate::DeputyLeadPermissions<T, I>>::contains_key(worker_id) {
+        ensure!(
+            <crate::DeputyLeadCount<I>>::get() < T::MaxDeputyLeads::get(),
+            Error::<T, I>::MaxDeputyLeadsNumberExceeded
+        );
+    }
+
+    ensure_deputy_lead_role_account_is_free::<T, I>(worker_id, &worker.role_account_id)?;
+
+    Ok(worker)
+}
+
+// Check deputy lead: ensures the role account isn't used by another deputy lead.
+pub(crate) fn ensure_deputy_lead_role_account_is_free<T: Trait<I>, I: Instance>(
+    worker_id: &WorkerId<T>,
+    role_account_id: &T::AccountId,
+) -> DispatchResult {
+    ensure!(
+        <crate::DeputyLeadByRoleAccount<T, I>>::get(role_account_id)
+            .map_or(true, |deputy_id| deputy_id == *worker_id),
+        Error::<T, I>::DeputyLeadRoleAccountIsUsed
+    );
+
+    Ok(())
+}
+
+// Check deputy lead: ensures the worker is a deputy lead.
+pub(crate) fn ensure_worker_is_deputy_lead<T: Trait<I>, I: Instance>(
+    worker_id: &WorkerId<T>,
+) -> DispatchResult {
+    ensure!(
+        <crate::DeputyLeadPermissions<T, I>>::contains_key(worker_id),
+        Error::<T, I>::WorkerIsNotDeputyLead
+    );
+
+    Ok(())
+}
+
 // Check opening: verifies stake policy for the opening.
 pub(crate) fn ensure_valid_stake_policy<T: Trait<I>, I: Instance>(
     stake_policy: &StakePolicy<T::BlockNumber, BalanceOf<T>>,
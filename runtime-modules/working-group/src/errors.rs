@@ -89,5 +89,26 @@ decl_error! {
 
         /// Worker storage text is too long.
         WorkerStorageValueTooLong,
+
+        /// Leader cannot be appointed as a deputy lead.
+        CannotAppointLeaderAsDeputyLead,
+
+        /// Worker is not a deputy lead.
+        WorkerIsNotDeputyLead,
+
+        /// Deputy lead has no permission for this action.
+        DeputyLeadPermissionMissing,
+
+        /// Deputy lead cannot update their own reward.
+        DeputyLeadCannotUpdateOwnReward,
+
+        /// Max deputy leads number exceeded.
+        MaxDeputyLeadsNumberExceeded,
+
+        /// Role account is already used by another deputy lead.
+        DeputyLeadRoleAccountIsUsed,
+
+        /// Deputy lead cannot perform the worker operation on themselves.
+        DeputyLeadCannotOperateOnSelf,
     }
 }
@@ -22,6 +22,19 @@
 //! - [update_reward_amount](./struct.Module.html#method.update_reward_amount) -  Update the reward amount of the regular worker/lead.
 //! - [set_status_text](./struct.Module.html#method.set_status_text) - Sets the working group status.
 //! - [spend_from_budget](./struct.Module.html#method.spend_from_budget) - Spend tokens from the group budget.
+//! - [set_deputy_lead](./struct.Module.html#method.set_deputy_lead) - Appoints or removes a deputy lead with a set of delegated permissions.
+//!
+//! ## Deputy leads
+//!
+//! The leader (or the council via the root origin) can appoint regular workers as deputy leads.
+//! A deputy lead can perform the delegated subset of the leader actions ([DeputyLeadPermission])
+//! for regular workers and openings, e.g. when the leader is unavailable. The deputy leads number
+//! is limited by `MaxDeputyLeads`. The deputy lead signs with its role account: the role account
+//! can't be shared by the deputy leads.
+//!
+//! The deputy leads carry over the leader change: they keep acting while the leader position is
+//! vacant and stay appointed after the new leader is hired. The new leader (or the council)
+//! removes them with `set_deputy_lead` and empty permissions.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -38,6 +51,7 @@ pub mod benchmarking;
 
 mod checks;
 mod errors;
+mod queries;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -54,8 +68,10 @@ use sp_std::vec::Vec;
 
 pub use errors::Error;
 pub use types::{
-    Application, ApplicationId, ApplyOnOpeningParameters, BalanceOf, Opening, OpeningId,
-    OpeningType, RewardPaymentType, StakeParameters, StakePolicy, Worker, WorkerId,
+    Application, ApplicationId, ApplyOnOpeningParameters, BalanceOf, BudgetSummary,
+    BudgetSummaryRecord, DeputyLeadPermission, Opening, OpeningId, OpeningSummary,
+    OpeningSummaryRecord, OpeningType, RewardPaymentType, StakeParameters, StakePolicy, Worker,
+    WorkerId, WorkerSummary, WorkerSummaryRecord,
 };
 use types::{ApplicationInfo, WorkerInfo};
 
@@ -91,6 +107,7 @@ pub trait WeightInfo {
     fn set_budget() -> Weight;
     fn add_opening(i: u32) -> Weight;
     fn leave_role(i: u32) -> Weight;
+    fn set_deputy_lead() -> Weight;
 }
 
 /// The _Group_ main _Trait_
@@ -103,6 +120,9 @@ pub trait Trait<I: Instance = DefaultInstance>:
     /// Defines max workers number in the group.
     type MaxWorkerNumberLimit: Get<u32>;
 
+    /// Defines max deputy leads number in the group.
+    type MaxDeputyLeads: Get<u32>;
+
     /// Stakes and balance locks handler.
     type StakingHandler: StakingHandler<
         Self::AccountId,
@@ -288,6 +308,23 @@ decl_event!(
         /// - Id of the worker.
         /// - Raw storage field.
         WorkerStorageUpdated(WorkerId, Vec<u8>),
+
+        /// Emits on appointing the deputy lead or updating the deputy lead permissions.
+        /// Params:
+        /// - Id of the worker.
+        /// - Deputy lead permissions.
+        DeputyLeadSet(WorkerId, BTreeSet<DeputyLeadPermission>),
+
+        /// Emits on removing the deputy lead.
+        /// Params:
+        /// - Id of the worker.
+        DeputyLeadRemoved(WorkerId),
+
+        /// Emits on performing the privileged action by the deputy lead instead of the leader.
+        /// Params:
+        /// - Id of the deputy lead worker.
+        /// - Permission used for the action.
+        DeputyLeadActionPerformed(WorkerId, DeputyLeadPermission),
     }
 );
 
@@ -332,6 +369,17 @@ decl_storage! {
 
         /// Worker storage size upper bound.
         pub WorkerStorageSize get(fn worker_storage_size) : u16 = default_storage_size_constraint();
+
+        /// Maps deputy lead worker identifier to the set of delegated leader permissions.
+        pub DeputyLeadPermissions get(fn deputy_lead_permissions): map hasher(blake2_128_concat)
+            WorkerId<T> => BTreeSet<DeputyLeadPermission>;
+
+        /// Maps deputy lead role account to the deputy lead worker identifier.
+        pub DeputyLeadByRoleAccount get(fn deputy_lead_by_role_account):
+            map hasher(blake2_128_concat) T::AccountId => Option<WorkerId<T>>;
+
+        /// Count of deputy leads.
+        pub DeputyLeadCount get(fn deputy_lead_count): u32;
     }
 }
 
@@ -349,6 +397,9 @@ decl_module! {
         /// Max simultaneous active worker number.
         const MaxWorkerNumberLimit: u32 = T::MaxWorkerNumberLimit::get();
 
+        /// Max simultaneous deputy lead number.
+        const MaxDeputyLeads: u32 = T::MaxDeputyLeads::get();
+
         /// Defines min unstaking period in the group.
         const MinUnstakingPeriodLimit: T::BlockNumber = T::MinUnstakingPeriodLimit::get();
 
@@ -571,7 +622,11 @@ decl_module! {
             // Ensure job opening exists.
             let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
 
-            checks::ensure_origin_for_opening_type::<T, I>(origin, opening.opening_type)?;
+            let deputy_id = checks::ensure_origin_for_opening_type_or_deputy::<T, I>(
+                origin,
+                opening.opening_type,
+                DeputyLeadPermission::FillOpening
+            )?;
 
             // Ensure we're not exceeding the maximum worker number.
             let potential_worker_number =
@@ -614,14 +669,9 @@ decl_module! {
             //
 
             if opening.opening_type == OpeningType::Regular {
-                // Lead must be set for ensure_origin_for_openig_type in the
-                // case of regular.
-                let lead = Self::worker_by_id(checks::ensure_lead_is_set::<T, I>()?);
-                let current_stake = T::StakingHandler::current_stake(&lead.staking_account_id);
-                T::StakingHandler::set_stake(
-                    &lead.staking_account_id,
-                    current_stake.saturating_sub(opening.creation_stake)
-                )?;
+                // Lead can be unset in the case of the deputy lead origin: the stake of
+                // the removed leader is already unlocked.
+                Self::release_opening_creation_stake(&opening)?;
             }
 
             // Process successful applications
@@ -633,6 +683,8 @@ decl_module! {
             // Remove the opening.
             <OpeningById::<T, I>>::remove(opening_id);
 
+            Self::deposit_deputy_lead_action_event(deputy_id, DeputyLeadPermission::FillOpening);
+
             // Trigger event
             Self::deposit_event(RawEvent::OpeningFilled(
                     opening_id,
@@ -665,10 +717,25 @@ decl_module! {
             // Ensure the worker is active.
             ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
 
+            let is_deputy_lead = DeputyLeadPermissions::<T, I>::contains_key(worker_id);
+
+            if is_deputy_lead {
+                checks::ensure_deputy_lead_role_account_is_free::<T, I>(
+                    &worker_id,
+                    &new_role_account_id
+                )?;
+            }
+
             //
             // == MUTATION SAFE ==
             //
 
+            // Update the deputy lead role account index.
+            if is_deputy_lead {
+                DeputyLeadByRoleAccount::<T, I>::remove(&worker.role_account_id);
+                DeputyLeadByRoleAccount::<T, I>::insert(&new_role_account_id, worker_id);
+            }
+
             // Update role account
             WorkerById::<T, I>::mutate(worker_id, |worker| {
                 worker.role_account_id = new_role_account_id.clone()
@@ -940,7 +1007,11 @@ decl_module! {
             // Ensure job opening exists.
             let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
 
-            checks::ensure_origin_for_opening_type::<T, I>(origin, opening.opening_type)?;
+            let deputy_id = checks::ensure_origin_for_opening_type_or_deputy::<T, I>(
+                origin,
+                opening.opening_type,
+                DeputyLeadPermission::CancelOpening
+            )?;
 
             //
             // == MUTATION SAFE ==
@@ -948,19 +1019,16 @@ decl_module! {
 
             // Remove opening stake
             if opening.opening_type == OpeningType::Regular {
-                // Lead must be set for ensure_origin_for_openig_type in the
-                // case of regular.
-                let lead = Self::worker_by_id(checks::ensure_lead_is_set::<T, I>()?);
-                let current_stake = T::StakingHandler::current_stake(&lead.staking_account_id);
-                T::StakingHandler::set_stake(
-                    &lead.staking_account_id,
-                    current_stake.saturating_sub(opening.creation_stake)
-                )?;
+                // Lead can be unset in the case of the deputy lead origin: the stake of
+                // the removed leader is already unlocked.
+                Self::release_opening_creation_stake(&opening)?;
             }
 
             // Remove the opening.
             <OpeningById::<T, I>>::remove(opening_id);
 
+            Self::deposit_deputy_lead_action_event(deputy_id, DeputyLeadPermission::CancelOpening);
+
             // Trigger event
             Self::deposit_event(RawEvent::OpeningCanceled(opening_id));
         }
@@ -1029,6 +1097,8 @@ decl_module! {
 
         /// Update the reward per block for the active worker.
         /// Require signed leader origin or the root (to update leader's reward amount).
+        /// The deputy lead with the `UpdateRewardAmount` permission can update the reward amount
+        /// of other regular workers.
         ///
         /// # <weight>
         ///
@@ -1043,8 +1113,12 @@ decl_module! {
             worker_id: WorkerId<T>,
             reward_per_block: Option<BalanceOf<T>>
         ) {
-            // Ensure lead is set or it is the council setting the leader's reward.
-            checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
+            // Ensure lead (or deputy lead) is set or it is the council setting the leader's reward.
+            let deputy_id = checks::ensure_origin_for_worker_operation_or_deputy::<T,I>(
+                origin,
+                worker_id,
+                DeputyLeadPermission::UpdateRewardAmount
+            )?;
 
             // Ensuring worker actually exists
             checks::ensure_worker_exists::<T,I>(&worker_id)?;
@@ -1058,12 +1132,17 @@ decl_module! {
                 worker.reward_per_block = reward_per_block;
             });
 
+            Self::deposit_deputy_lead_action_event(
+                deputy_id,
+                DeputyLeadPermission::UpdateRewardAmount
+            );
+
             // Trigger event
             Self::deposit_event(RawEvent::WorkerRewardAmountUpdated(worker_id, reward_per_block));
         }
 
         /// Sets a new status text for the working group.
-        /// Requires root origin.
+        /// Requires leader origin or the deputy lead with the `SetStatusText` permission.
         ///
         /// # <weight>
         ///
@@ -1081,8 +1160,11 @@ decl_module! {
             origin,
             status_text: Option<Vec<u8>>,
         ) {
-            // Ensure group leader privilege.
-            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            // Ensure group leader or deputy lead privilege.
+            let deputy_id = checks::ensure_origin_is_leader_or_deputy::<T,I>(
+                origin,
+                DeputyLeadPermission::SetStatusText
+            )?;
 
             //
             // == MUTATION SAFE ==
@@ -1100,12 +1182,14 @@ decl_module! {
             // Update the status text hash.
             <StatusTextHash<I>>::put(status_text_hash.clone());
 
+            Self::deposit_deputy_lead_action_event(deputy_id, DeputyLeadPermission::SetStatusText);
+
             // Trigger event
             Self::deposit_event(RawEvent::StatusTextChanged(status_text_hash, status_text));
         }
 
         /// Transfers specified amount to any account.
-        /// Requires leader origin.
+        /// Requires leader origin or the deputy lead with the `SpendFromBudget` permission.
         ///
         /// # <weight>
         ///
@@ -1121,8 +1205,11 @@ decl_module! {
             amount: BalanceOf<T>,
             rationale: Option<Vec<u8>>,
         ) {
-            // Ensure group leader privilege.
-            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            // Ensure group leader or deputy lead privilege.
+            let deputy_id = checks::ensure_origin_is_leader_or_deputy::<T,I>(
+                origin,
+                DeputyLeadPermission::SpendFromBudget
+            )?;
 
             ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
 
@@ -1139,6 +1226,8 @@ decl_module! {
 
             Self::pay_from_budget(&account_id, amount);
 
+            Self::deposit_deputy_lead_action_event(deputy_id, DeputyLeadPermission::SpendFromBudget);
+
             // Trigger event
             Self::deposit_event(RawEvent::BudgetSpending(account_id, amount, rationale));
         }
@@ -1167,6 +1256,53 @@ decl_module! {
             // Trigger event
             Self::deposit_event(RawEvent::WorkerStorageUpdated(worker_id, storage));
         }
+
+        /// Appoints the regular worker as a deputy lead or updates the deputy lead permissions.
+        /// Removes the deputy lead when the permission set is empty.
+        /// Require signed leader origin or the root (council proposal).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::set_deputy_lead()]
+        pub fn set_deputy_lead(
+            origin,
+            worker_id: WorkerId<T>,
+            permissions: BTreeSet<DeputyLeadPermission>,
+        ) {
+            // Ensure group leader privilege or the council proposal.
+            checks::ensure_origin_is_active_leader_or_root::<T, I>(origin)?;
+
+            if permissions.is_empty() {
+                checks::ensure_worker_is_deputy_lead::<T, I>(&worker_id)?;
+
+                //
+                // == MUTATION SAFE ==
+                //
+
+                Self::remove_deputy_lead(&worker_id);
+            } else {
+                let worker = checks::ensure_worker_can_be_deputy_lead::<T, I>(&worker_id)?;
+
+                //
+                // == MUTATION SAFE ==
+                //
+
+                if !DeputyLeadPermissions::<T, I>::contains_key(worker_id) {
+                    DeputyLeadByRoleAccount::<T, I>::insert(&worker.role_account_id, worker_id);
+                    DeputyLeadCount::<I>::mutate(|count| *count = count.saturating_add(1));
+                }
+
+                DeputyLeadPermissions::<T, I>::insert(worker_id, permissions.clone());
+
+                // Trigger event
+                Self::deposit_event(RawEvent::DeputyLeadSet(worker_id, permissions));
+            }
+        }
     }
 }
 
@@ -1301,7 +1437,7 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         new_worker_id
     }
 
-    // Set worker id as a leader id.
+    // Set worker id as a leader id. The deputy leads appointed before are kept.
     pub(crate) fn set_lead(worker_id: WorkerId<T>) {
         // Update current lead
         <CurrentLead<T, I>>::put(worker_id);
@@ -1331,6 +1467,11 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             }
         }
 
+        // Remove the deputy lead permissions if any.
+        if DeputyLeadPermissions::<T, I>::contains_key(worker_id) {
+            Self::remove_deputy_lead(worker_id);
+        }
+
         Self::try_to_pay_missed_reward(worker_id, worker);
 
         // Remove the worker from the storage.
@@ -1342,6 +1483,44 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Self::deposit_event(event);
     }
 
+    // Removes the deputy lead permissions and the role account index. Deposits an event.
+    fn remove_deputy_lead(worker_id: &WorkerId<T>) {
+        let worker = Self::worker_by_id(worker_id);
+
+        DeputyLeadPermissions::<T, I>::remove(worker_id);
+        DeputyLeadByRoleAccount::<T, I>::remove(&worker.role_account_id);
+        DeputyLeadCount::<I>::mutate(|count| *count = count.saturating_sub(1));
+
+        Self::deposit_event(RawEvent::DeputyLeadRemoved(*worker_id));
+    }
+
+    // Deposits an event when the privileged action was performed by the deputy lead.
+    fn deposit_deputy_lead_action_event(
+        deputy_id: Option<WorkerId<T>>,
+        permission: DeputyLeadPermission,
+    ) {
+        if let Some(deputy_id) = deputy_id {
+            Self::deposit_event(RawEvent::DeputyLeadActionPerformed(deputy_id, permission));
+        }
+    }
+
+    // Releases the opening creation stake of the current leader. Does nothing when the leader is
+    // not set (the stake was unlocked on the leader removal).
+    fn release_opening_creation_stake(
+        opening: &Opening<T::BlockNumber, BalanceOf<T>>,
+    ) -> DispatchResult {
+        if let Ok(leader_worker_id) = checks::ensure_lead_is_set::<T, I>() {
+            let lead = Self::worker_by_id(leader_worker_id);
+            let current_stake = T::StakingHandler::current_stake(&lead.staking_account_id);
+            T::StakingHandler::set_stake(
+                &lead.staking_account_id,
+                current_stake.saturating_sub(opening.creation_stake),
+            )?;
+        }
+
+        Ok(())
+    }
+
     // Slash the stake.
     fn slash(
         worker_id: WorkerId<T>,
@@ -1532,6 +1711,47 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             .map(|(worker_id, _)| worker_id)
             .collect()
     }
+
+    /// Slashes the worker stake on behalf of another runtime module (ie.: for missed duties).
+    pub fn slash_worker_stake(
+        worker_id: &WorkerId<T>,
+        penalty: BalanceOf<T>,
+        rationale: Option<Vec<u8>>,
+    ) -> DispatchResult {
+        let worker = checks::ensure_worker_exists::<T, I>(worker_id)?;
+
+        ensure!(
+            penalty != <BalanceOf<T>>::zero(),
+            Error::<T, I>::StakeBalanceCannotBeZero
+        );
+
+        Self::slash(*worker_id, &worker.staking_account_id, penalty, rationale);
+
+        Ok(())
+    }
+
+    /// Pays the worker reward from the working group budget on behalf of another runtime module
+    /// (ie.: for the served load).
+    pub fn pay_worker_reward_from_budget(
+        worker_id: &WorkerId<T>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let worker = checks::ensure_worker_exists::<T, I>(worker_id)?;
+
+        ensure!(
+            Self::budget() >= amount,
+            Error::<T, I>::InsufficientBudgetForSpending
+        );
+
+        Self::pay_reward(
+            worker_id,
+            &worker.reward_account_id,
+            amount,
+            RewardPaymentType::RegularReward,
+        );
+
+        Ok(())
+    }
 }
 
 impl<T: Trait<I>, I: Instance> common::working_group::WorkingGroupAuthenticator<T>
@@ -0,0 +1,96 @@
+// Worker, opening and budget summaries served by the working group runtime API.
+
+use sp_arithmetic::traits::Zero;
+use sp_runtime::traits::{SaturatedConversion, Saturating};
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec::Vec;
+
+use crate::{
+    ApplicationById, BalanceOf, BudgetSummary, Instance, Module, OpeningById, OpeningId,
+    OpeningSummary, Trait, WorkerById, WorkerId, WorkerSummary,
+};
+use frame_support::IterableStorageMap;
+use staking_handler::StakingHandler;
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Returns the workers (including the leader) with their reward and stake summary.
+    pub fn workers_summary() -> Vec<(WorkerId<T>, WorkerSummary<T>)> {
+        let lead_id = Self::current_lead();
+
+        <WorkerById<T, I>>::iter()
+            .map(|(worker_id, worker)| {
+                let summary = WorkerSummary::<T> {
+                    member_id: worker.member_id,
+                    is_lead: lead_id == Some(worker_id),
+                    reward_per_block: worker.reward_per_block,
+                    stake: T::StakingHandler::current_stake(&worker.staking_account_id),
+                    missed_reward: worker.missed_reward,
+                };
+
+                (worker_id, summary)
+            })
+            .collect()
+    }
+
+    /// Returns the open openings with their active applications number.
+    pub fn openings_summary() -> Vec<(OpeningId, OpeningSummary<T>)> {
+        let mut applications_numbers = BTreeMap::<OpeningId, u32>::new();
+        for (_, application) in <ApplicationById<T, I>>::iter() {
+            let applications_number = applications_numbers
+                .entry(application.opening_id)
+                .or_default();
+
+            *applications_number = applications_number.saturating_add(1);
+        }
+
+        <OpeningById<T, I>>::iter()
+            .map(|(opening_id, opening)| {
+                let summary = OpeningSummary::<T> {
+                    opening_type: opening.opening_type,
+                    created: opening.created,
+                    stake_policy: opening.stake_policy,
+                    reward_per_block: opening.reward_per_block,
+                    applications_number: applications_numbers
+                        .get(&opening_id)
+                        .copied()
+                        .unwrap_or_default(),
+                };
+
+                (opening_id, summary)
+            })
+            .collect()
+    }
+
+    /// Returns the working group budget versus the committed worker rewards per block. Estimates
+    /// the number of blocks the budget lasts given the missed rewards are paid first.
+    pub fn budget_summary() -> BudgetSummary<T> {
+        let (committed_rewards_per_block, missed_rewards) = <WorkerById<T, I>>::iter().fold(
+            (Zero::zero(), Zero::zero()),
+            |(committed, missed): (BalanceOf<T>, BalanceOf<T>), (_, worker)| {
+                (
+                    committed.saturating_add(worker.reward_per_block.unwrap_or_else(Zero::zero)),
+                    missed.saturating_add(worker.missed_reward.unwrap_or_else(Zero::zero)),
+                )
+            },
+        );
+
+        let budget = Self::budget();
+
+        let budget_blocks_remaining = if committed_rewards_per_block.is_zero() {
+            None
+        } else {
+            let blocks: u128 = (budget.saturating_sub(missed_rewards)
+                / committed_rewards_per_block)
+                .saturated_into();
+
+            Some(blocks.saturated_into())
+        };
+
+        BudgetSummary::<T> {
+            budget,
+            committed_rewards_per_block,
+            missed_rewards,
+            budget_blocks_remaining,
+        }
+    }
+}
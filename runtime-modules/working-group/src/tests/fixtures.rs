@@ -12,8 +12,8 @@ use super::mock::{
 };
 use crate::types::StakeParameters;
 use crate::{
-    Application, ApplyOnOpeningParameters, DefaultInstance, Opening, OpeningType, RawEvent,
-    StakePolicy, Trait, Worker,
+    Application, ApplyOnOpeningParameters, DefaultInstance, DeputyLeadPermission, Opening,
+    OpeningType, RawEvent, StakePolicy, Trait, Worker,
 };
 
 pub struct EventFixture;
@@ -1243,3 +1243,61 @@ impl UpdateWorkerStorageFixture {
         }
     }
 }
+
+pub struct SetDeputyLeadFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    permissions: BTreeSet<DeputyLeadPermission>,
+}
+
+impl SetDeputyLeadFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+            permissions: vec![
+                DeputyLeadPermission::SpendFromBudget,
+                DeputyLeadPermission::SetStatusText,
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_permissions(self, permissions: Vec<DeputyLeadPermission>) -> Self {
+        Self {
+            permissions: permissions.into_iter().collect(),
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_permissions = TestWorkingGroup::deputy_lead_permissions(self.worker_id);
+
+        let actual_result = TestWorkingGroup::set_deputy_lead(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.permissions.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_permissions = TestWorkingGroup::deputy_lead_permissions(self.worker_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(new_permissions, self.permissions);
+            assert_eq!(
+                <crate::DeputyLeadPermissions<Test, DefaultInstance>>::contains_key(self.worker_id),
+                !self.permissions.is_empty()
+            );
+        } else {
+            assert_eq!(new_permissions, old_permissions);
+        }
+    }
+}
@@ -184,6 +184,7 @@ pub type System = frame_system::Module<Test>;
 parameter_types! {
     pub const RewardPeriod: u32 = 2;
     pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const MaxDeputyLeads: u32 = 2;
     pub const MinUnstakingPeriodLimit: u64 = 3;
     pub const MinimumApplicationStake: u64 = 50;
     pub const LockId: [u8; 8] = [1; 8];
@@ -193,6 +194,7 @@ parameter_types! {
 impl Trait for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = staking_handler::StakingManager<Self, LockId>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
@@ -276,6 +278,9 @@ impl crate::WeightInfo for () {
     fn leave_role(_: u32) -> Weight {
         0
     }
+    fn set_deputy_lead() -> Weight {
+        0
+    }
 }
 
 pub const ACTOR_ORIGIN_ERROR: &'static str = "Invalid membership";
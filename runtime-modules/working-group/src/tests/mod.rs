@@ -8,7 +8,7 @@ use frame_system::RawOrigin;
 
 use crate::tests::fixtures::{
     CancelOpeningFixture, DecreaseWorkerStakeFixture, IncreaseWorkerStakeFixture, SetBudgetFixture,
-    SetStatusTextFixture, SlashWorkerStakeFixture, SpendFromBudgetFixture,
+    SetDeputyLeadFixture, SetStatusTextFixture, SlashWorkerStakeFixture, SpendFromBudgetFixture,
     UpdateRewardAccountFixture, UpdateRewardAmountFixture, UpdateWorkerStorageFixture,
     WithdrawApplicationFixture,
 };
@@ -18,8 +18,9 @@ use crate::tests::mock::{
 };
 use crate::types::StakeParameters;
 use crate::{
-    default_storage_size_constraint, DefaultInstance, Error, OpeningType, RawEvent,
-    RewardPaymentType, StakePolicy, Trait, Worker,
+    default_storage_size_constraint, BudgetSummaryRecord, DefaultInstance, DeputyLeadPermission,
+    Error, OpeningType, RawEvent, RewardPaymentType, StakePolicy, Trait, Worker,
+    WorkerSummaryRecord,
 };
 use common::working_group::WorkingGroupAuthenticator;
 use fixtures::{
@@ -28,7 +29,7 @@ use fixtures::{
     LeaveWorkerRoleFixture, TerminateWorkerRoleFixture, UpdateWorkerRoleAccountFixture,
 };
 use frame_support::dispatch::DispatchError;
-use frame_support::StorageMap;
+use frame_support::{StorageMap, StorageValue};
 use mock::{run_to_block, Balances, RewardPeriod, TestWorkingGroup, ACTOR_ORIGIN_ERROR};
 use sp_runtime::traits::Hash;
 use sp_std::collections::btree_map::BTreeMap;
@@ -2611,3 +2612,485 @@ fn update_worker_storage_fails_with_too_long_text() {
         ));
     });
 }
+
+#[test]
+fn workers_summary_query_returns_worker_rewards_and_stakes() {
+    build_test_externalities().execute_with(|| {
+        let stake = 200;
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_initial_balance(300)
+            .with_stake_policy(StakePolicy {
+                stake_amount: stake,
+                leaving_unstaking_period: <Test as Trait>::MinUnstakingPeriodLimit::get() + 1,
+            })
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let lead_id = TestWorkingGroup::current_lead().unwrap();
+        let worker = TestWorkingGroup::worker_by_id(worker_id);
+
+        let summary = TestWorkingGroup::workers_summary()
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+
+        assert_eq!(summary.len(), 2);
+        assert!(summary[&lead_id].is_lead);
+        assert_eq!(
+            summary[&worker_id],
+            WorkerSummaryRecord {
+                member_id: worker.member_id,
+                is_lead: false,
+                reward_per_block: Some(reward_per_block),
+                stake,
+                missed_reward: None,
+            }
+        );
+    });
+}
+
+#[test]
+fn openings_summary_query_returns_application_numbers() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default()
+            .with_initial_balance(1000)
+            .hire_lead();
+
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let add_opening_fixture = AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_reward_per_block(Some(10));
+
+        let opening_id = add_opening_fixture.call().unwrap();
+        let empty_opening_id = add_opening_fixture.call().unwrap();
+
+        ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_initial_balance(<Test as Trait>::MinimumApplicationStake::get())
+            .call_and_assert(Ok(()));
+
+        let summary = TestWorkingGroup::openings_summary()
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[&opening_id].applications_number, 1);
+        assert_eq!(summary[&opening_id].reward_per_block, Some(10));
+        assert_eq!(summary[&opening_id].created, starting_block);
+        assert_eq!(summary[&empty_opening_id].applications_number, 0);
+    });
+}
+
+#[test]
+fn budget_summary_query_estimates_budget_blocks_remaining() {
+    build_test_externalities().execute_with(|| {
+        HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(20))
+            .hire();
+
+        SetBudgetFixture::default().with_budget(1000).execute();
+
+        assert_eq!(
+            TestWorkingGroup::budget_summary(),
+            BudgetSummaryRecord {
+                budget: 1000,
+                committed_rewards_per_block: 20,
+                missed_rewards: 0,
+                budget_blocks_remaining: Some(50),
+            }
+        );
+    });
+}
+
+#[test]
+fn budget_summary_query_returns_no_estimate_without_rewards() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SetBudgetFixture::default().with_budget(1000).execute();
+
+        let summary = TestWorkingGroup::budget_summary();
+
+        assert_eq!(summary.committed_rewards_per_block, 0);
+        assert_eq!(summary.budget_blocks_remaining, None);
+    });
+}
+
+#[test]
+fn set_deputy_lead_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let permissions = vec![
+            DeputyLeadPermission::SpendFromBudget,
+            DeputyLeadPermission::SetStatusText,
+        ];
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(permissions.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyLeadSet(
+            worker_id,
+            permissions.into_iter().collect(),
+        ));
+    });
+}
+
+#[test]
+fn set_deputy_lead_succeeded_with_root_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn set_deputy_lead_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn set_deputy_lead_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_worker_id = 12;
+
+        SetDeputyLeadFixture::default_for_worker_id(invalid_worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn set_deputy_lead_fails_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        SetDeputyLeadFixture::default_for_worker_id(lead_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::CannotAppointLeaderAsDeputyLead.into(),
+            ));
+    });
+}
+
+#[test]
+fn set_deputy_lead_fails_for_leaving_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::WorkerIsLeaving.into()));
+    });
+}
+
+#[test]
+fn set_deputy_lead_fails_with_exceeded_deputy_leads_limit() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        <crate::DeputyLeadCount<DefaultInstance>>::put(<Test as Trait>::MaxDeputyLeads::get());
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::MaxDeputyLeadsNumberExceeded.into(),
+        ));
+    });
+}
+
+#[test]
+fn deputy_lead_acts_with_updated_role_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let new_account_id = 10;
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(vec![DeputyLeadPermission::SetStatusText])
+            .call_and_assert(Ok(()));
+
+        UpdateWorkerRoleAccountFixture::default_with_ids(worker_id, new_account_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::deputy_lead_by_role_account(DEFAULT_WORKER_ACCOUNT_ID),
+            None
+        );
+        assert_eq!(
+            TestWorkingGroup::deputy_lead_by_role_account(new_account_id),
+            Some(worker_id)
+        );
+
+        SetStatusTextFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_status_text(Some(b"some".to_vec()))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+
+        SetStatusTextFixture::default()
+            .with_origin(RawOrigin::Signed(new_account_id))
+            .with_status_text(Some(b"some".to_vec()))
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn update_role_account_fails_with_role_account_of_another_deputy_lead() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let new_account_id = 10;
+        let another_deputy_id = 12;
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        <crate::DeputyLeadByRoleAccount<Test, DefaultInstance>>::insert(
+            new_account_id,
+            another_deputy_id,
+        );
+
+        UpdateWorkerRoleAccountFixture::default_with_ids(worker_id, new_account_id)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyLeadRoleAccountIsUsed.into(),
+            ));
+    });
+}
+
+#[test]
+fn remove_deputy_lead_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(Vec::new())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyLeadRemoved(worker_id));
+    });
+}
+
+#[test]
+fn remove_deputy_lead_fails_for_non_deputy_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(Vec::new())
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::WorkerIsNotDeputyLead.into()
+            ));
+    });
+}
+
+#[test]
+fn deputy_lead_is_removed_on_worker_termination() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!<crate::DeputyLeadPermissions<Test, DefaultInstance>>::contains_key(worker_id));
+        assert_eq!(TestWorkingGroup::deputy_lead_count(), 0);
+        EventFixture::contains_crate_event(RawEvent::DeputyLeadRemoved(worker_id));
+    });
+}
+
+#[test]
+fn deputy_lead_can_spend_from_budget_when_leader_is_unset() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let lead_id = TestWorkingGroup::current_lead().unwrap();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(vec![DeputyLeadPermission::SpendFromBudget])
+            .call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(lead_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        SetBudgetFixture::default().with_budget(1000).execute();
+
+        let account_id = 3;
+        let amount = 100;
+        SpendFromBudgetFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_account_id(account_id)
+            .with_amount(amount)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::DeputyLeadActionPerformed(
+            worker_id,
+            DeputyLeadPermission::SpendFromBudget,
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::BudgetSpending(account_id, amount, None));
+    });
+}
+
+#[test]
+fn deputy_lead_fails_without_permission() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(vec![DeputyLeadPermission::SpendFromBudget])
+            .call_and_assert(Ok(()));
+
+        SetStatusTextFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_status_text(Some(b"some".to_vec()))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyLeadPermissionMissing.into(),
+            ));
+    });
+}
+
+#[test]
+fn deputy_lead_can_fill_opening() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(vec![DeputyLeadPermission::FillOpening])
+            .call_and_assert(Ok(()));
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        let application_id = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3), 3)
+            .call()
+            .unwrap();
+
+        let initial_balance = Balances::usable_balance(&1);
+
+        let new_worker_id = FillOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call()
+            .unwrap();
+
+        // Leader opening stake is released.
+        assert_eq!(
+            Balances::usable_balance(&1),
+            initial_balance + <Test as Trait>::LeaderOpeningStake::get()
+        );
+
+        let mut result_map = BTreeMap::new();
+        result_map.insert(application_id, new_worker_id);
+
+        EventFixture::contains_crate_event(RawEvent::DeputyLeadActionPerformed(
+            worker_id,
+            DeputyLeadPermission::FillOpening,
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::OpeningFilled(
+            opening_id,
+            result_map,
+            vec![application_id].into_iter().collect(),
+        ));
+    });
+}
+
+#[test]
+fn deputy_lead_cannot_update_own_or_leader_reward() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let lead_id = TestWorkingGroup::current_lead().unwrap();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(vec![DeputyLeadPermission::UpdateRewardAmount])
+            .call_and_assert(Ok(()));
+
+        UpdateRewardAmountFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_reward_per_block(Some(100))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyLeadCannotUpdateOwnReward.into(),
+            ));
+
+        UpdateRewardAmountFixture::default_for_worker_id(lead_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_reward_per_block(Some(100))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn deputy_lead_cannot_perform_worker_operation_on_self() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(vec![DeputyLeadPermission::SetStatusText])
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            crate::checks::ensure_origin_for_worker_operation_or_deputy::<Test, DefaultInstance>(
+                RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID).into(),
+                worker_id,
+                DeputyLeadPermission::SetStatusText,
+            ),
+            Err(Error::<Test, DefaultInstance>::DeputyLeadCannotOperateOnSelf.into())
+        );
+    });
+}
+
+#[test]
+fn deputy_lead_carries_over_leader_change() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let lead_id = TestWorkingGroup::current_lead().unwrap();
+
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(vec![DeputyLeadPermission::SetStatusText])
+            .call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(lead_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let new_lead_id = HireLeadFixture::default().hire_lead();
+
+        assert_ne!(new_lead_id, lead_id);
+        assert_eq!(TestWorkingGroup::deputy_lead_count(), 1);
+        assert_eq!(
+            TestWorkingGroup::deputy_lead_by_role_account(DEFAULT_WORKER_ACCOUNT_ID),
+            Some(worker_id)
+        );
+
+        SetStatusTextFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_status_text(Some(b"some".to_vec()))
+            .call_and_assert(Ok(()));
+
+        // The new leader removes the deputy lead.
+        SetDeputyLeadFixture::default_for_worker_id(worker_id)
+            .with_permissions(Vec::new())
+            .call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::deputy_lead_count(), 0);
+    });
+}
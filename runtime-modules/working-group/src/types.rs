@@ -98,6 +98,26 @@ impl Default for OpeningType {
     }
 }
 
+/// Defines a privileged group action that the leader can delegate to a deputy lead.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
+pub enum DeputyLeadPermission {
+    /// Fill an opening for the regular worker position.
+    FillOpening,
+
+    /// Cancel an opening for the regular worker position.
+    CancelOpening,
+
+    /// Update the reward amount of the regular worker.
+    UpdateRewardAmount,
+
+    /// Spend tokens from the group budget.
+    SpendFromBudget,
+
+    /// Set the working group status text.
+    SetStatusText,
+}
+
 /// An application for the regular worker/lead role opening.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
@@ -271,3 +291,72 @@ pub enum RewardPaymentType {
     /// The reward was paid in time.
     RegularReward,
 }
+
+/// WorkerSummaryRecord type alias.
+pub type WorkerSummary<T> = WorkerSummaryRecord<MemberId<T>, BalanceOf<T>>;
+
+/// Reward and stake summary of the worker or leader (exposed by the runtime API).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerSummaryRecord<MemberId, Balance> {
+    /// Member id related to the worker/lead.
+    pub member_id: MemberId,
+
+    /// Defines whether the worker is the group leader.
+    pub is_lead: bool,
+
+    /// Optional reward setting for the worker.
+    pub reward_per_block: Option<Balance>,
+
+    /// Current stake of the worker staking account.
+    pub stake: Balance,
+
+    /// Total missed reward amount.
+    pub missed_reward: Option<Balance>,
+}
+
+/// OpeningSummaryRecord type alias.
+pub type OpeningSummary<T> =
+    OpeningSummaryRecord<<T as frame_system::Trait>::BlockNumber, BalanceOf<T>>;
+
+/// Summary of the open job opening (exposed by the runtime API).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpeningSummaryRecord<BlockNumber, Balance> {
+    /// Defines opening type: Leader or worker.
+    pub opening_type: OpeningType,
+
+    /// Block at which opening was added.
+    pub created: BlockNumber,
+
+    /// Stake policy for the job opening.
+    pub stake_policy: StakePolicy<BlockNumber, Balance>,
+
+    /// Reward per block for the job opening.
+    pub reward_per_block: Option<Balance>,
+
+    /// Number of the active applications on the opening.
+    pub applications_number: u32,
+}
+
+/// BudgetSummaryRecord type alias.
+pub type BudgetSummary<T> =
+    BudgetSummaryRecord<<T as frame_system::Trait>::BlockNumber, BalanceOf<T>>;
+
+/// Working group budget versus the committed worker rewards (exposed by the runtime API).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct BudgetSummaryRecord<BlockNumber, Balance> {
+    /// Current working group budget.
+    pub budget: Balance,
+
+    /// Sum of the worker rewards per block.
+    pub committed_rewards_per_block: Balance,
+
+    /// Sum of the missed worker rewards (paid before the regular rewards).
+    pub missed_rewards: Balance,
+
+    /// Estimated number of blocks the budget covers the committed rewards. `None` when there are
+    /// no committed rewards.
+    pub budget_blocks_remaining: Option<BlockNumber>,
+}
@@ -149,6 +149,18 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::VetoProposal(proposal_id) => {
                 Call::ProposalsEngine(proposals_engine::Call::veto_proposal(proposal_id))
             }
+            ProposalDetails::UpdateDataSizeFeeSchedule(category, schedule) => Call::Storage(
+                storage::Call::update_data_size_fee_schedule(category, schedule),
+            ),
+            ProposalDetails::StorageAdministrativeAction(action) => {
+                Call::Storage(storage::Call::execute_storage_administrative_action(action))
+            }
+            ProposalDetails::SetWorkingGroupDeputyLead(worker_id, permissions, working_group) => {
+                wrap_working_group_call!(
+                    working_group,
+                    Wg::create_set_deputy_lead_call(worker_id, permissions)
+                )
+            }
         };
 
         call.encode()
@@ -237,4 +249,12 @@ where
     ) -> working_group::Call<T, I> {
         working_group::Call::<T, I>::cancel_opening(opening_id)
     }
+
+    // Generic call constructor for the working group 'set deputy lead'.
+    fn create_set_deputy_lead_call(
+        worker_id: working_group::WorkerId<T>,
+        permissions: BTreeSet<working_group::DeputyLeadPermission>,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::set_deputy_lead(worker_id, permissions)
+    }
 }
@@ -25,6 +25,37 @@ pub fn wasm_binary_unwrap() -> &'static [u8] {
     )
 }
 
+// Dispatches the call to the working group instance. Defined before the modules declaration
+// to be available in all of them.
+macro_rules! call_wg {
+    ($working_group:ident<$wg_trait:ident>, $function:ident $(,$x:expr)*) => {{
+        match $working_group {
+            WorkingGroup::Content => <ContentWorkingGroup as $wg_trait<Runtime>>::$function($($x,)*),
+            WorkingGroup::Storage => <StorageWorkingGroup as $wg_trait<Runtime>>::$function($($x,)*),
+            WorkingGroup::Forum => <ForumWorkingGroup as $wg_trait<Runtime>>::$function($($x,)*),
+            WorkingGroup::Membership => <MembershipWorkingGroup as $wg_trait<Runtime>>::$function($($x,)*),
+            WorkingGroup::Gateway => <GatewayWorkingGroup as $wg_trait<Runtime>>::$function($($x,)*),
+            WorkingGroup::Distribution => <DistributionWorkingGroup as $wg_trait<Runtime>>::$function($($x,)*),
+            WorkingGroup::OperationsAlpha => <OperationsWorkingGroupAlpha as $wg_trait<Runtime>>::$function($($x,)*),
+            WorkingGroup::OperationsBeta => <OperationsWorkingGroupBeta as $wg_trait<Runtime>>::$function($($x,)*),
+            WorkingGroup::OperationsGamma => <OperationsWorkingGroupGamma as $wg_trait<Runtime>>::$function($($x,)*),
+        }
+    }};
+    ($working_group:ident, $function:ident $(,$x:expr)*) => {{
+        match $working_group {
+            WorkingGroup::Content => ContentWorkingGroup::$function($($x,)*),
+            WorkingGroup::Storage => StorageWorkingGroup::$function($($x,)*),
+            WorkingGroup::Forum => ForumWorkingGroup::$function($($x,)*),
+            WorkingGroup::Membership => MembershipWorkingGroup::$function($($x,)*),
+            WorkingGroup::Gateway => GatewayWorkingGroup::$function($($x,)*),
+            WorkingGroup::Distribution => DistributionWorkingGroup::$function($($x,)*),
+            WorkingGroup::OperationsAlpha => OperationsWorkingGroupAlpha::$function($($x,)*),
+            WorkingGroup::OperationsBeta => OperationsWorkingGroupBeta::$function($($x,)*),
+            WorkingGroup::OperationsGamma => OperationsWorkingGroupGamma::$function($($x,)*),
+        }
+    }};
+}
+
 mod constants;
 mod integration;
 pub mod primitives;
@@ -46,7 +77,7 @@ use frame_support::weights::{
     constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
     Weight,
 };
-use frame_support::{construct_runtime, parameter_types};
+use frame_support::{construct_runtime, parameter_types, StorageMap};
 use frame_system::{EnsureOneOf, EnsureRoot, EnsureSigned};
 use pallet_grandpa::{AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList};
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
@@ -58,6 +89,7 @@ use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT, IdentityLookup, OpaqueKeys, Saturating};
 use sp_runtime::{create_runtime_str, generic, impl_opaque_keys, ModuleId, Perbill};
 use sp_std::boxed::Box;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
@@ -70,6 +102,8 @@ pub use runtime_api::*;
 
 use integration::proposals::{CouncilManager, ExtrinsicProposalEncoder};
 
+use common::membership::MemberOriginValidator;
+use common::storage::StorageObjectOwner;
 use common::working_group::{WorkingGroup, WorkingGroupAuthenticator, WorkingGroupBudgetHandler};
 use council::ReferendumConnection;
 use referendum::{CastVote, OptionResult};
@@ -90,6 +124,7 @@ pub use working_group;
 
 pub use content;
 pub use content::MaxNumber;
+pub use storage;
 
 /// This runtime version.
 pub const VERSION: RuntimeVersion = RuntimeVersion {
@@ -453,6 +488,13 @@ parameter_types! {
     pub const BloatBondCap: u32 = 1000;  // TODO: update
     pub const VideosMigrationsEachBlock: u64 = 100;
     pub const ChannelsMigrationsEachBlock: u64 = 25;
+    // One data object removal per deletion, budgeted like the videos migrations.
+    pub const AssetsDeletionsEachBlock: u64 = 100;
+    // One read and two writes per expiration, subscriptions expire spread over the blocks.
+    pub const SubscriptionExpirationsEachBlock: u64 = 100;
+    // A deletion fails only on a storage inconsistency, retries don't recover it for long.
+    pub const MaxAssetsDeletionAttempts: u32 = 5;
+    pub const MaxVideoAssetSlotKeyLength: u32 = 32;
 }
 
 impl content::Trait for Runtime {
@@ -477,6 +519,10 @@ impl content::Trait for Runtime {
     type ModuleId = ContentModuleId;
     type VideosMigrationsEachBlock = VideosMigrationsEachBlock;
     type ChannelsMigrationsEachBlock = ChannelsMigrationsEachBlock;
+    type AssetsDeletionsEachBlock = AssetsDeletionsEachBlock;
+    type SubscriptionExpirationsEachBlock = SubscriptionExpirationsEachBlock;
+    type MaxAssetsDeletionAttempts = MaxAssetsDeletionAttempts;
+    type MaxVideoAssetSlotKeyLength = MaxVideoAssetSlotKeyLength;
 }
 
 // The referendum instance alias.
@@ -590,6 +636,10 @@ parameter_types! {
     pub const DataObjectDeletionPrize: Balance = 0; //TODO: Change during Olympia release
     pub const BlacklistSizeLimit: u64 = 10000; //TODO: adjust value
     pub const MaxRandomIterationNumber: u64 = 10; //TODO: adjust value
+    // Six candidates per storage bucket of the default dynamic bag, one read each.
+    pub const MaxStorageBucketPickerCandidates: u64 = 30;
+    // One read per candidate, matching the storage bucket picker bound.
+    pub const MaxDistributionBucketPickerCandidates: u64 = 30;
     pub const MaxNumberOfPendingInvitationsPerDistributionBucket: u64 = 20; //TODO: adjust value
     pub const StorageModuleId: ModuleId = ModuleId(*b"mstorage"); // module storage
     pub const StorageBucketsPerBagValueConstraint: storage::StorageBucketsPerBagValueConstraint =
@@ -599,6 +649,45 @@ parameter_types! {
     pub const DistributionBucketsPerBagValueConstraint: storage::DistributionBucketsPerBagValueConstraint =
         storage::DistributionBucketsPerBagValueConstraint {min: 1, max_min_diff: 100}; //TODO: adjust value
     pub const MaxDataObjectSize: u64 = 10 * 1024 * 1024 * 1024; // 10 GB
+    pub const MaxReReplicatedBagsPerBlock: u64 = 5;
+    // Daily rounds detect the lost data within days at a small per-block cost.
+    pub const StorageChallengePeriod: BlockNumber = DAYS;
+    // Up to eleven reads and four writes per bucket, a round over 1000 buckets takes 20 blocks.
+    pub const MaxStorageChallengesPerBlock: u64 = 50;
+    // The chunk is read from the local storage: an hour covers the operator node restarts.
+    pub const StorageChallengeResponsePeriod: BlockNumber = HOURS;
+    pub const ChallengeChunkSize: u64 = 1024 * 1024; // 1 MB
+    // Three missed daily challenges: a single node outage isn't slashed.
+    pub const MissedStorageChallengesSlashingThreshold: u64 = 3;
+    // Half of the storage worker application stake (MinimumApplicationStake).
+    pub const MissedStorageChallengesSlashingPenalty: Balance = 1000;
+    // A month of the prepaid rent leaves the new bag owner time to top up the bag.
+    pub const InitialBagRentPeriod: BlockNumber = 30 * DAYS;
+    // A week to top up the exhausted bag before its data objects are deleted.
+    pub const StorageRentGracePeriod: BlockNumber = 7 * DAYS;
+    // Three reads and three writes per data object plus the bag storage buckets updates.
+    pub const MaxGarbageCollectedDataObjectsPerBlock: u64 = 20;
+    // One read and two writes per expiration, the bags expire spread over the blocks.
+    pub const MaxBagRentExpirationsPerBlock: u64 = 50;
+    // Two reads and three writes per expiration, the entries expire spread over the blocks.
+    pub const MaxBlacklistExpirationsPerBlock: u64 = 50;
+    pub const MaxRegionTagLength: u32 = 32;
+    // Daily rewards follow the daily storage challenge rounds.
+    pub const OperatorRewardPeriod: BlockNumber = DAYS;
+    // Up to three reads and two writes per bucket besides the operator payment.
+    pub const MaxOperatorRewardBucketsPerBlock: u64 = 50;
+    // 10 MB parts of the max size data object, the part hashes take at most 32 KB.
+    pub const MaxDataObjectPartsNumber: u64 = 1000;
+    // Up to 44 reads and nine writes per bag with 30 replacement candidates checked.
+    pub const MaxDrainedBagsPerBlock: u64 = 5;
+    // Hourly beacons detect the stopped distributor within the missed beacons threshold hours.
+    pub const DistributionHealthBeaconPeriod: BlockNumber = HOURS;
+    // Beacons spread over the 600 blocks of the period: 12000 operators report hourly.
+    pub const MaxDistributionHealthBeaconsPerBlock: u64 = 20;
+    // Three hours without beacons from all bucket operators stop the bucket distribution.
+    pub const MissedDistributionHealthBeaconsThreshold: u64 = 3;
+    // Three reads per bucket plus one read and up to two writes per bucket operator.
+    pub const MaxHealthBeaconBucketsPerBlock: u64 = 50;
 }
 
 impl storage::Trait for Runtime {
@@ -617,14 +706,39 @@ impl storage::Trait for Runtime {
     type DefaultChannelDynamicBagNumberOfStorageBuckets =
         DefaultChannelDynamicBagNumberOfStorageBuckets;
     type Randomness = RandomnessCollectiveFlip;
+    type StorageBucketsSelectionPolicy = storage::CapacityAwareStorageBucketPicker<Runtime>;
     type MaxRandomIterationNumber = MaxRandomIterationNumber;
+    type MaxStorageBucketPickerCandidates = MaxStorageBucketPickerCandidates;
+    type MaxDistributionBucketPickerCandidates = MaxDistributionBucketPickerCandidates;
     type MaxDistributionBucketFamilyNumber = MaxDistributionBucketFamilyNumber;
     type DistributionBucketsPerBagValueConstraint = DistributionBucketsPerBagValueConstraint;
     type DistributionBucketOperatorId = DistributionBucketOperatorId;
     type MaxNumberOfPendingInvitationsPerDistributionBucket =
         MaxNumberOfPendingInvitationsPerDistributionBucket;
     type MaxDataObjectSize = MaxDataObjectSize;
+    type MaxReReplicatedBagsPerBlock = MaxReReplicatedBagsPerBlock;
+    type StorageChallengePeriod = StorageChallengePeriod;
+    type MaxStorageChallengesPerBlock = MaxStorageChallengesPerBlock;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type ChallengeChunkSize = ChallengeChunkSize;
+    type MissedStorageChallengesSlashingThreshold = MissedStorageChallengesSlashingThreshold;
+    type MissedStorageChallengesSlashingPenalty = MissedStorageChallengesSlashingPenalty;
+    type InitialBagRentPeriod = InitialBagRentPeriod;
+    type StorageRentGracePeriod = StorageRentGracePeriod;
+    type MaxGarbageCollectedDataObjectsPerBlock = MaxGarbageCollectedDataObjectsPerBlock;
+    type MaxBagRentExpirationsPerBlock = MaxBagRentExpirationsPerBlock;
+    type MaxBlacklistExpirationsPerBlock = MaxBlacklistExpirationsPerBlock;
+    type MaxRegionTagLength = MaxRegionTagLength;
+    type OperatorRewardPeriod = OperatorRewardPeriod;
+    type MaxOperatorRewardBucketsPerBlock = MaxOperatorRewardBucketsPerBlock;
+    type MaxDataObjectPartsNumber = MaxDataObjectPartsNumber;
+    type MaxDrainedBagsPerBlock = MaxDrainedBagsPerBlock;
+    type DistributionHealthBeaconPeriod = DistributionHealthBeaconPeriod;
+    type MaxDistributionHealthBeaconsPerBlock = MaxDistributionHealthBeaconsPerBlock;
+    type MissedDistributionHealthBeaconsThreshold = MissedDistributionHealthBeaconsThreshold;
+    type MaxHealthBeaconBucketsPerBlock = MaxHealthBeaconBucketsPerBlock;
     type ContentId = ContentId;
+    type WeightInfo = weights::storage::WeightInfo;
 
     fn ensure_storage_working_group_leader_origin(origin: Self::Origin) -> DispatchResult {
         StorageWorkingGroup::ensure_leader_origin(origin)
@@ -638,6 +752,28 @@ impl storage::Trait for Runtime {
         StorageWorkingGroup::ensure_worker_exists(&worker_id)
     }
 
+    fn slash_storage_worker_stake(worker_id: &ActorId, penalty: Balance) -> DispatchResult {
+        StorageWorkingGroup::slash_worker_stake(
+            worker_id,
+            penalty,
+            Some(b"Missed storage challenges".to_vec()),
+        )
+    }
+
+    fn storage_working_group_budget() -> Balance {
+        StorageWorkingGroup::budget()
+    }
+
+    fn pay_storage_worker_reward(worker_id: &ActorId, reward: Balance) -> DispatchResult {
+        StorageWorkingGroup::pay_worker_reward_from_budget(worker_id, reward)
+    }
+
+    fn fund_storage_working_group_budget(amount: Balance) {
+        let budget = StorageWorkingGroup::budget().saturating_add(amount);
+
+        <StorageWorkingGroup as WorkingGroupBudgetHandler<Runtime>>::set_budget(budget);
+    }
+
     fn ensure_distribution_working_group_leader_origin(origin: Self::Origin) -> DispatchResult {
         DistributionWorkingGroup::ensure_leader_origin(origin)
     }
@@ -652,6 +788,76 @@ impl storage::Trait for Runtime {
     fn ensure_distribution_worker_exists(worker_id: &ActorId) -> DispatchResult {
         DistributionWorkingGroup::ensure_worker_exists(&worker_id)
     }
+
+    fn distribution_working_group_budget() -> Balance {
+        DistributionWorkingGroup::budget()
+    }
+
+    fn pay_distribution_worker_reward(worker_id: &ActorId, reward: Balance) -> DispatchResult {
+        DistributionWorkingGroup::pay_worker_reward_from_budget(worker_id, reward)
+    }
+
+    fn is_storage_owner_account(
+        owner: &StorageObjectOwner<MemberId, ChannelId>,
+        account_id: &AccountId,
+    ) -> bool {
+        match owner {
+            StorageObjectOwner::Member(member_id) => {
+                Members::is_member_controller_account(member_id, account_id)
+            }
+            StorageObjectOwner::Channel(channel_id) => {
+                if !content::ChannelById::<Runtime>::contains_key(channel_id) {
+                    return false;
+                }
+
+                match content::ChannelById::<Runtime>::get(channel_id).owner {
+                    content::ChannelOwner::Member(member_id) => {
+                        Members::is_member_controller_account(&member_id, account_id)
+                    }
+                    content::ChannelOwner::CuratorGroup(_) => {
+                        ContentWorkingGroup::is_leader_account_id(account_id)
+                    }
+                }
+            }
+            StorageObjectOwner::Council => Council::council_members().iter().any(|councilor| {
+                Members::is_member_controller_account(councilor.member_id(), account_id)
+            }),
+            StorageObjectOwner::WorkingGroup(working_group) => {
+                let working_group = *working_group;
+
+                call_wg!(
+                    working_group<WorkingGroupAuthenticator>,
+                    is_leader_account_id,
+                    account_id
+                )
+            }
+        }
+    }
+
+    fn ensure_working_group_leader_origin(
+        origin: Self::Origin,
+        working_group: WorkingGroup,
+    ) -> DispatchResult {
+        call_wg!(
+            working_group<WorkingGroupAuthenticator>,
+            ensure_leader_origin,
+            origin
+        )
+    }
+
+    fn on_data_objects_removed(
+        _bag_id: &storage::BagId<Self>,
+        data_object_ids: &BTreeSet<DataObjectId>,
+    ) {
+        content::Module::<Runtime>::remove_video_assets(data_object_ids)
+    }
+
+    fn is_data_object_in_use(
+        _bag_id: &storage::BagId<Self>,
+        data_object_id: &DataObjectId,
+    ) -> bool {
+        content::VideoByAsset::<Runtime>::contains_key(data_object_id)
+    }
 }
 
 impl common::membership::MembershipTypes for Runtime {
@@ -732,6 +938,7 @@ impl LockComparator<<Runtime as pallet_balances::Trait>::Balance> for Runtime {
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
+    pub const MaxDeputyLeads: u32 = 10;
     pub const MinUnstakingPeriodLimit: u32 = 43200;
     pub const ForumWorkingGroupRewardPeriod: u32 = 14400 + 10;
     pub const StorageWorkingGroupRewardPeriod: u32 = 14400 + 20;
@@ -806,6 +1013,7 @@ pub type DistributionWorkingGroupInstance = working_group::Instance9;
 impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = ForumWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -819,6 +1027,7 @@ impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = StorageWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -832,6 +1041,7 @@ impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
 impl working_group::Trait<ContentWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = ContentWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -845,6 +1055,7 @@ impl working_group::Trait<ContentWorkingGroupInstance> for Runtime {
 impl working_group::Trait<MembershipWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = MembershipWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -858,6 +1069,7 @@ impl working_group::Trait<MembershipWorkingGroupInstance> for Runtime {
 impl working_group::Trait<OperationsWorkingGroupInstanceAlpha> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = OperationsWorkingGroupAlphaStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -871,6 +1083,7 @@ impl working_group::Trait<OperationsWorkingGroupInstanceAlpha> for Runtime {
 impl working_group::Trait<GatewayWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = GatewayWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -884,6 +1097,7 @@ impl working_group::Trait<GatewayWorkingGroupInstance> for Runtime {
 impl working_group::Trait<OperationsWorkingGroupInstanceBeta> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = OperationsWorkingGroupBetaStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -897,6 +1111,7 @@ impl working_group::Trait<OperationsWorkingGroupInstanceBeta> for Runtime {
 impl working_group::Trait<OperationsWorkingGroupInstanceGamma> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = OperationsWorkingGroupGammaStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -910,6 +1125,7 @@ impl working_group::Trait<OperationsWorkingGroupInstanceGamma> for Runtime {
 impl working_group::Trait<DistributionWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxDeputyLeads = MaxDeputyLeads;
     type StakingHandler = DistributionWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
@@ -960,22 +1176,6 @@ parameter_types! {
     pub const ForumPostLifeTime: BlockNumber = 3600;
 }
 
-macro_rules! call_wg {
-    ($working_group:ident, $function:ident $(,$x:expr)*) => {{
-        match $working_group {
-            WorkingGroup::Content => <ContentWorkingGroup as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-            WorkingGroup::Storage => <StorageWorkingGroup as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-            WorkingGroup::Forum => <ForumWorkingGroup as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-            WorkingGroup::Membership => <MembershipWorkingGroup as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-            WorkingGroup::Gateway => <GatewayWorkingGroup as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-            WorkingGroup::Distribution => <DistributionWorkingGroup as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-            WorkingGroup::OperationsAlpha => <OperationsWorkingGroupAlpha as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-            WorkingGroup::OperationsBeta => <OperationsWorkingGroupBeta as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-            WorkingGroup::OperationsGamma => <OperationsWorkingGroupGamma as WorkingGroupBudgetHandler<Runtime>>::$function($($x,)*),
-        }
-    }};
-}
-
 impl proposals_discussion::Trait for Runtime {
     type Event = Event;
     type AuthorOriginValidator = Members;
@@ -995,10 +1195,10 @@ impl joystream_utility::Trait for Runtime {
     type WeightInfo = weights::joystream_utility::WeightInfo;
 
     fn get_working_group_budget(working_group: WorkingGroup) -> Balance {
-        call_wg!(working_group, get_budget)
+        call_wg!(working_group<WorkingGroupBudgetHandler>, get_budget)
     }
     fn set_working_group_budget(working_group: WorkingGroup, budget: Balance) {
-        call_wg!(working_group, set_budget, budget)
+        call_wg!(working_group<WorkingGroupBudgetHandler>, set_budget, budget)
     }
 }
 
@@ -1041,6 +1241,10 @@ impl proposals_codex::Trait for Runtime {
     type LockBlogPostProposalParameters = LockBlogPostProposalParameters;
     type UnlockBlogPostProposalParameters = UnlockBlogPostProposalParameters;
     type VetoProposalProposalParameters = VetoProposalProposalParameters;
+    type UpdateDataSizeFeeScheduleProposalParameters = UpdateDataSizeFeeScheduleProposalParameters;
+    type StorageAdministrativeActionProposalParameters =
+        StorageAdministrativeActionProposalParameters;
+    type SetWorkingGroupDeputyLeadProposalParameters = SetWorkingGroupDeputyLeadProposalParameters;
     type WeightInfo = weights::proposals_codex::WeightInfo;
 }
 
@@ -1147,7 +1351,7 @@ construct_runtime!(
         Blog: blog::<Instance1>::{Module, Call, Storage, Event<T>},
         JoystreamUtility: joystream_utility::{Module, Call, Event<T>},
         Content: content::{Module, Call, Storage, Event<T>, Config<T>},
-        Storage: storage::{Module, Call, Storage, Event<T>},
+        Storage: storage::{Module, Call, Storage, Event<T>, Config},
         // --- Proposals
         ProposalsEngine: proposals_engine::{Module, Call, Storage, Event<T>},
         ProposalsDiscussion: proposals_discussion::{Module, Call, Storage, Event<T>},
@@ -73,8 +73,7 @@ pub(crate) fn create_working_group_lead_opening_proposal(
 }
 
 // Proposal parameters for the 'Fill Working Group Lead Opening' proposal
-pub(crate) fn fill_working_group_lead_opening_proposal() -> ProposalParameters<BlockNumber, Balance>
-{
+pub(crate) fn fill_working_group_lead_opening_proposal() -> ProposalParameters<BlockNumber, Balance> {
     ProposalParameters {
         voting_period: 43200,
         grace_period: 0,
@@ -230,8 +229,7 @@ pub(crate) fn set_councilor_reward_proposal() -> ProposalParameters<BlockNumber,
 }
 
 // Proposal parameters for the 'Set Initial Invitation Balance' proposal
-pub(crate) fn set_initial_invitation_balance_proposal() -> ProposalParameters<BlockNumber, Balance>
-{
+pub(crate) fn set_initial_invitation_balance_proposal() -> ProposalParameters<BlockNumber, Balance> {
     ProposalParameters {
         voting_period: 72000,
         grace_period: 43200,
@@ -357,3 +355,45 @@ pub(crate) fn veto_proposal_proposal() -> ProposalParameters<BlockNumber, Balanc
         constitutionality: 1,
     }
 }
+
+// Proposal parameters for the 'Update Data Size Fee Schedule' proposal
+pub(crate) fn update_data_size_fee_schedule_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 43200,
+        grace_period: 14400,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Storage Administrative Action' proposal
+pub(crate) fn storage_administrative_action_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 43200,
+        grace_period: 14400,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Set Working Group Deputy Lead' proposal
+pub(crate) fn set_working_group_deputy_lead_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 43200,
+        grace_period: 0,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50000),
+        constitutionality: 1,
+    }
+}
@@ -63,6 +63,12 @@ parameter_types! {
         ALL_PROPOSALS_PARAMETERS.unlock_blog_post_proposal;
     pub VetoProposalProposalParameters: ProposalParameters<BlockNumber, Balance> =
         ALL_PROPOSALS_PARAMETERS.veto_proposal_proposal;
+    pub UpdateDataSizeFeeScheduleProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        ALL_PROPOSALS_PARAMETERS.update_data_size_fee_schedule_proposal;
+    pub StorageAdministrativeActionProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        ALL_PROPOSALS_PARAMETERS.storage_administrative_action_proposal;
+    pub SetWorkingGroupDeputyLeadProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        ALL_PROPOSALS_PARAMETERS.set_working_group_deputy_lead_proposal;
 }
 
 ///////////
@@ -93,6 +99,9 @@ struct AllProposalsParameters {
     pub lock_blog_post_proposal: ProposalParameters<BlockNumber, Balance>,
     pub unlock_blog_post_proposal: ProposalParameters<BlockNumber, Balance>,
     pub veto_proposal_proposal: ProposalParameters<BlockNumber, Balance>,
+    pub update_data_size_fee_schedule_proposal: ProposalParameters<BlockNumber, Balance>,
+    pub storage_administrative_action_proposal: ProposalParameters<BlockNumber, Balance>,
+    pub set_working_group_deputy_lead_proposal: ProposalParameters<BlockNumber, Balance>,
 }
 
 // to initialize parameters only once.
@@ -193,7 +202,10 @@ fn convert_json_object_to_proposal_parameters(
         init_proposal_parameter_object!(params, jo.clone(), edit_blog_post_proposal);
         init_proposal_parameter_object!(params, jo.clone(), lock_blog_post_proposal);
         init_proposal_parameter_object!(params, jo.clone(), unlock_blog_post_proposal);
-        init_proposal_parameter_object!(params, jo, veto_proposal_proposal);
+        init_proposal_parameter_object!(params, jo.clone(), veto_proposal_proposal);
+        init_proposal_parameter_object!(params, jo.clone(), update_data_size_fee_schedule_proposal);
+        init_proposal_parameter_object!(params, jo.clone(), storage_administrative_action_proposal);
+        init_proposal_parameter_object!(params, jo, set_working_group_deputy_lead_proposal);
     }
 
     params
@@ -331,5 +343,8 @@ fn default_parameters() -> AllProposalsParameters {
         lock_blog_post_proposal: defaults::lock_blog_post_proposal(),
         unlock_blog_post_proposal: defaults::unlock_blog_post_proposal(),
         veto_proposal_proposal: defaults::veto_proposal_proposal(),
+        update_data_size_fee_schedule_proposal: defaults::update_data_size_fee_schedule_proposal(),
+        storage_administrative_action_proposal: defaults::storage_administrative_action_proposal(),
+        set_working_group_deputy_lead_proposal: defaults::set_working_group_deputy_lead_proposal(),
     }
 }
@@ -237,3 +237,30 @@ fn proposal_parameters_are_initialized_veto_proposal_proposal() {
 
     assert_eq!(default_proposal_parameters(), actual_params);
 }
+
+// Enable during the conditional compilation tests.
+#[test]
+#[ignore]
+fn proposal_parameters_are_initialized_update_data_size_fee_schedule_proposal() {
+    let actual_params = super::UpdateDataSizeFeeScheduleProposalParameters::get();
+
+    assert_eq!(default_proposal_parameters(), actual_params);
+}
+
+// Enable during the conditional compilation tests.
+#[test]
+#[ignore]
+fn proposal_parameters_are_initialized_storage_administrative_action_proposal() {
+    let actual_params = super::StorageAdministrativeActionProposalParameters::get();
+
+    assert_eq!(default_proposal_parameters(), actual_params);
+}
+
+// Enable during the conditional compilation tests.
+#[test]
+#[ignore]
+fn proposal_parameters_are_initialized_set_wg_deputy_lead() {
+    let actual_params = super::SetWorkingGroupDeputyLeadProposalParameters::get();
+
+    assert_eq!(default_proposal_parameters(), actual_params);
+}
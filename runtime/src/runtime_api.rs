@@ -13,13 +13,21 @@ use sp_std::vec::Vec;
 use crate::constants::PRIMARY_PROBABILITY;
 
 use crate::{
-    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration, GrandpaAuthorityList,
-    GrandpaId, Hash, Index, RuntimeVersion, Signature, VERSION,
+    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, ChannelId, DataObjectId, EpochDuration,
+    GrandpaAuthorityList, GrandpaId, Hash, Index, MemberId, RuntimeVersion, Signature,
+    StorageBucketId, VERSION,
 };
 use crate::{
     AllModules, AuthorityDiscovery, Babe, Balances, Call, Grandpa, Historical, InherentDataExt,
     ProposalsEngine, RandomnessCollectiveFlip, Runtime, SessionKeys, System, TransactionPayment,
 };
+use crate::{
+    ContentWorkingGroup, DistributionWorkingGroup, ForumWorkingGroup, GatewayWorkingGroup,
+    MembershipWorkingGroup, OperationsWorkingGroupAlpha, OperationsWorkingGroupBeta,
+    OperationsWorkingGroupGamma, StorageWorkingGroup,
+};
+
+use common::working_group::WorkingGroup;
 
 use frame_support::weights::Weight;
 
@@ -86,7 +94,9 @@ impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
         // initialize content module
         content::Module::<Runtime>::on_runtime_upgrade();
 
-        10_000_000 // TODO: adjust weight
+        let storage_migrations_weight = storage::Module::<Runtime>::on_runtime_upgrade();
+
+        storage_migrations_weight.saturating_add(10_000_000) // TODO: adjust weight
     }
 }
 
@@ -259,6 +269,116 @@ impl_runtime_apis! {
         }
     }
 
+    impl content_runtime_api::ContentApi<Block, ChannelId, MemberId> for Runtime {
+        fn is_subscribed(channel_id: ChannelId, member_id: MemberId) -> bool {
+            content::Module::<Runtime>::is_subscribed(&channel_id, &member_id)
+        }
+    }
+
+    impl storage_runtime_api::StorageApi<
+        Block,
+        storage::BagId<Runtime>,
+        storage::DynamicBagId<Runtime>,
+        DataObjectId,
+        storage::DataObject<Balance>,
+        StorageBucketId,
+        storage::DistributionBucketId<Runtime>,
+        storage::Voucher,
+        storage::DataObjectCreationParameters,
+        storage::UploadFeeQuote<Balance>,
+        BlockNumber,
+    > for Runtime
+    {
+        fn under_replicated_bags() -> Vec<storage::DynamicBagId<Runtime>> {
+            storage::Module::<Runtime>::under_replicated_bags()
+        }
+
+        fn bags_rent_paid_through() -> Vec<(storage::DynamicBagId<Runtime>, BlockNumber)> {
+            storage::Module::<Runtime>::bags_rent_paid_through()
+        }
+
+        fn blacklisted_data_objects(
+            cid: Vec<u8>,
+            bag_id: storage::BagId<Runtime>,
+        ) -> Vec<DataObjectId> {
+            storage::Module::<Runtime>::blacklisted_data_objects(&cid, &bag_id)
+        }
+
+        fn blacklisted_data_objects_page(
+            cid: Vec<u8>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(storage::BagId<Runtime>, DataObjectId)> {
+            storage::Module::<Runtime>::blacklisted_data_objects_page(&cid, offset, limit)
+        }
+
+        fn storage_bucket_bags(
+            storage_bucket_id: StorageBucketId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<storage::BagId<Runtime>> {
+            storage::Module::<Runtime>::storage_bucket_bags(&storage_bucket_id, offset, limit)
+        }
+
+        fn distribution_bucket_bags(
+            distribution_bucket_id: storage::DistributionBucketId<Runtime>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<storage::BagId<Runtime>> {
+            storage::Module::<Runtime>::distribution_bucket_bags(
+                &distribution_bucket_id,
+                offset,
+                limit,
+            )
+        }
+
+        fn bag_data_objects(
+            bag_id: storage::BagId<Runtime>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(DataObjectId, storage::DataObject<Balance>)> {
+            storage::Module::<Runtime>::bag_data_objects(&bag_id, offset, limit)
+        }
+
+        fn storage_bucket_voucher(storage_bucket_id: StorageBucketId) -> Option<storage::Voucher> {
+            storage::Module::<Runtime>::storage_bucket_voucher(&storage_bucket_id)
+        }
+
+        fn upload_fee_quote(
+            bag_id: storage::BagId<Runtime>,
+            object_creation_list: Vec<storage::DataObjectCreationParameters>,
+        ) -> storage::UploadFeeQuote<Balance> {
+            storage::Module::<Runtime>::upload_fee_quote(&bag_id, &object_creation_list)
+        }
+    }
+
+    impl working_group_runtime_api::WorkingGroupApi<
+        Block,
+        WorkingGroup,
+        working_group::WorkerId<Runtime>,
+        working_group::WorkerSummary<Runtime>,
+        working_group::OpeningId,
+        working_group::OpeningSummary<Runtime>,
+        working_group::BudgetSummary<Runtime>,
+    > for Runtime
+    {
+        fn workers(
+            group: WorkingGroup,
+        ) -> Vec<(working_group::WorkerId<Runtime>, working_group::WorkerSummary<Runtime>)> {
+            call_wg!(group, workers_summary)
+        }
+
+        fn openings(
+            group: WorkingGroup,
+        ) -> Vec<(working_group::OpeningId, working_group::OpeningSummary<Runtime>)> {
+            call_wg!(group, openings_summary)
+        }
+
+        fn budget(group: WorkingGroup) -> working_group::BudgetSummary<Runtime> {
+            call_wg!(group, budget_summary)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             SessionKeys::generate(seed)
@@ -297,7 +417,7 @@ impl_runtime_apis! {
             use crate::Blog;
             use crate::JoystreamUtility;
             use crate::Staking;
-            use crate::StorageV2;
+            use crate::Storage;
 
 
             // Trying to add benchmarks directly to the Session Pallet caused cyclic dependency issues.
@@ -391,7 +511,7 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, bounty, Bounty);
             add_benchmark!(params, batches, blog, Blog);
             add_benchmark!(params, batches, joystream_utility, JoystreamUtility);
-            add_benchmark!(params, batches, storage_v2, StorageV2);
+            add_benchmark!(params, batches, storage, Storage);
 
             if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
             Ok(batches)
@@ -1167,3 +1167,147 @@ fn proposal_reactivation_succeeds() {
         assert_eq!(CouncilManager::<Runtime>::total_voters_count(), 3);
     });
 }
+
+#[test]
+fn update_data_size_fee_schedule_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 10;
+        let account_id: [u8; 32] = [member_id; 32];
+        let category = storage::DataSizeFeeCategory::Dynamic(storage::DynamicBagType::Channel);
+        let schedule = storage::DataSizeFeeSchedule {
+            per_megabyte_fee: 100,
+            object_class_fees: vec![(storage::DataObjectClass::Thumbnail, 10)]
+                .into_iter()
+                .collect(),
+        };
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+                member_id: member_id.into(),
+                title: b"title".to_vec(),
+                description: b"body".to_vec(),
+                staking_account_id: Some(account_id.into()),
+                exact_execution_block: None,
+            };
+
+            ProposalCodex::create_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                general_proposal_parameters,
+                ProposalDetails::UpdateDataSizeFeeSchedule(
+                    category.clone(),
+                    Some(schedule.clone()),
+                ),
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        run_to_block(86410);
+
+        assert_eq!(
+            storage::Module::<Runtime>::data_size_fee_schedule(&category),
+            Some(schedule)
+        );
+    });
+}
+
+#[test]
+fn storage_administrative_action_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 10;
+        let account_id: [u8; 32] = [member_id; 32];
+        let dynamic_bag_id = storage::DynamicBagIdType::Member(member_id as u64);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+                member_id: member_id.into(),
+                title: b"title".to_vec(),
+                description: b"body".to_vec(),
+                staking_account_id: Some(account_id.into()),
+                exact_execution_block: None,
+            };
+
+            ProposalCodex::create_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                general_proposal_parameters,
+                ProposalDetails::StorageAdministrativeAction(
+                    storage::StorageAdministrativeActionRecord::CreateDynamicBag(
+                        dynamic_bag_id.clone(),
+                        None,
+                    ),
+                ),
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        run_to_block(86410);
+
+        assert!(
+            <storage::Module<Runtime> as storage::DataObjectStorage<Runtime>>::ensure_bag_exists(
+                &dynamic_bag_id.into()
+            )
+            .is_ok()
+        );
+    });
+}
+
+#[test]
+fn set_working_group_deputy_lead_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 10;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let worker_id = MembershipWorkingGroup::next_worker_id();
+        let worker_account_id: AccountId32 = [11; 32].into();
+        <working_group::WorkerById<Runtime, MembershipWorkingGroupInstance>>::insert(
+            worker_id,
+            working_group::Worker::<Runtime>::new(
+                &11,
+                &worker_account_id,
+                &worker_account_id,
+                &worker_account_id,
+                1000,
+                None,
+                0,
+            ),
+        );
+
+        let permissions: BTreeSet<working_group::DeputyLeadPermission> =
+            vec![working_group::DeputyLeadPermission::SpendFromBudget]
+                .into_iter()
+                .collect();
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+                member_id: member_id.into(),
+                title: b"title".to_vec(),
+                description: b"body".to_vec(),
+                staking_account_id: Some(account_id.into()),
+                exact_execution_block: None,
+            };
+
+            ProposalCodex::create_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                general_proposal_parameters,
+                ProposalDetails::SetWorkingGroupDeputyLead(
+                    worker_id,
+                    permissions.clone(),
+                    common::working_group::WorkingGroup::Membership,
+                ),
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        run_to_block(86410);
+
+        assert_eq!(
+            MembershipWorkingGroup::deputy_lead_permissions(worker_id),
+            permissions
+        );
+    });
+}
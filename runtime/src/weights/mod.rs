@@ -38,4 +38,5 @@ pub mod proposals_codex;
 pub mod proposals_discussion;
 pub mod proposals_engine;
 pub mod referendum;
+pub mod storage;
 pub mod working_group;
@@ -191,4 +191,25 @@ impl proposals_codex::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().reads(6 as Weight))
             .saturating_add(DbWeight::get().writes(9 as Weight))
     }
+    fn create_proposal_update_data_size_fee_schedule(t: u32, d: u32) -> Weight {
+        (728_310_000 as Weight)
+            .saturating_add((541_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((237_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_storage_administrative_action(t: u32, d: u32) -> Weight {
+        (731_860_000 as Weight)
+            .saturating_add((538_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((241_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_set_working_group_deputy_lead(t: u32, d: u32) -> Weight {
+        (706_154_000 as Weight)
+            .saturating_add((543_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((232_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
 }
@@ -0,0 +1,382 @@
+//! Storage pallet weights: PLACEHOLDERS, NOT GENERATED BY THE BENCHMARK CLI.
+//!
+//! The execution times are hand estimates rounded up to one significant digit to stay on the
+//! conservative side. The database reads and writes are counted from the extrinsic
+//! implementations. Replace the whole file with the output of the storage pallet benchmarks
+//! (`scripts/generate-weights.sh`) instead of editing the values.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl storage::WeightInfo for WeightInfo {
+    fn delete_storage_bucket() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn update_uploading_blocked_status() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_data_size_fee() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_storage_buckets_per_bag_limit() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_storage_buckets_voucher_max_limits() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_blacklist(i: u32, j: u32, k: u32) -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add((4_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((5_000_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add((2_000 as Weight).saturating_mul(k as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(j as Weight)))
+    }
+    fn create_storage_bucket() -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn update_storage_buckets_for_bag(i: u32, j: u32) -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add((70_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((60_000_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(j as Weight)))
+    }
+    fn cancel_storage_bucket_operator_invite() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn invite_storage_bucket_operator() -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_storage_bucket_operator() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_storage_bucket_status() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_storage_bucket_voucher_limits() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn accept_storage_bucket_invitation() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_storage_operator_metadata(i: u32) -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+    }
+    fn accept_pending_data_objects(i: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
+    }
+    fn create_distribution_bucket_family() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn delete_distribution_bucket_family() -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn create_distribution_bucket() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn update_distribution_bucket_status() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn delete_distribution_bucket() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_distribution_buckets_for_bag(i: u32, j: u32) -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((50_000_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(j as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(j as Weight)))
+    }
+    fn update_distribution_buckets_per_bag_limit() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_distribution_bucket_mode() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_families_in_dynamic_bag_creation_policy(i: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((30_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn invite_distribution_bucket_operator() -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_distribution_bucket_operator_invite() -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_distribution_bucket_operator() -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_distribution_bucket_family_metadata(i: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+    }
+    fn accept_distribution_bucket_invitation() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_distribution_operator_metadata(i: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+    }
+    fn upload_data_objects(i: u32) -> Weight {
+        (2_000_000_000 as Weight)
+            .saturating_add((200_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(25 as Weight))
+            .saturating_add(DbWeight::get().writes(26 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+    }
+    fn move_data_objects(i: u32) -> Weight {
+        (2_000_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(44 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(44 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
+    }
+    fn delete_data_objects(i: u32) -> Weight {
+        (800_000_000 as Weight)
+            .saturating_add((50_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(24 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(23 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+    }
+    fn create_dynamic_bag(i: u32, j: u32) -> Weight {
+        (500_000_000 as Weight)
+            .saturating_add((100_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((70_000_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(j as Weight)))
+    }
+    fn delete_dynamic_bag(i: u32, j: u32) -> Weight {
+        (400_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((60_000_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(j as Weight)))
+    }
+    fn re_replicate_bags(i: u32) -> Weight {
+        (20_000_000 as Weight)
+            .saturating_add((400_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads((9 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((8 as Weight).saturating_mul(i as Weight)))
+    }
+    fn update_storage_rent() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn top_up_bag_rent() -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn update_data_object_reference_fee() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_dynamic_bag_quota() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_bag_quota_override() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_regions_in_dynamic_bag_creation_policy(i: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((2_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_distribution_bucket_family_region(i: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_distribution_bucket_region(i: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn respond_to_storage_challenge(i: u32, j: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((5_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((2_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_storage_operator_reward_rates() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_distribution_operator_reward_rates() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_blacklist_entry(i: u32) -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn purge_blacklisted_data_objects(i: u32) -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add((100_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
+    }
+    fn accept_pending_data_object_parts(i: u32) -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add((400_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn move_bag_data_objects(i: u32) -> Weight {
+        (2_000_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(49 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(45 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
+    }
+    fn start_storage_bucket_draining() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn cancel_storage_bucket_draining() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn drain_storage_buckets(i: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((500_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((14 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().writes((9 as Weight).saturating_mul(i as Weight)))
+    }
+    fn update_data_size_fee_schedule() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn report_distribution_operator_health() -> Weight {
+        (300_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn upload_working_group_data_objects(i: u32) -> Weight {
+        (2_000_000_000 as Weight)
+            .saturating_add((200_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(26 as Weight))
+            .saturating_add(DbWeight::get().writes(26 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+    }
+    fn delete_working_group_data_objects(i: u32) -> Weight {
+        (800_000_000 as Weight)
+            .saturating_add((50_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(25 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(23 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+    }
+}
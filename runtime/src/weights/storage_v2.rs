@@ -1,15 +0,0 @@
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.1
-
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-
-use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
-
-pub struct WeightInfo;
-impl storage_v2::WeightInfo for WeightInfo {
-	fn create_storage_bucket() -> Weight {
-		(157_000_000 as Weight)
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
-	}
-}
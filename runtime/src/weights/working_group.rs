@@ -58,8 +58,8 @@ impl working_group::WeightInfo for WeightInfo {
     }
     fn update_role_account() -> Weight {
         (278_339_000 as Weight)
-            .saturating_add(DbWeight::get().reads(2 as Weight))
-            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
     }
     fn cancel_opening() -> Weight {
         (201_764_000 as Weight)
@@ -135,4 +135,9 @@ impl working_group::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().reads(2 as Weight))
             .saturating_add(DbWeight::get().writes(1 as Weight))
     }
+    fn set_deputy_lead() -> Weight {
+        (198_417_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
 }
@@ -44,6 +44,8 @@ export default class DevUpload extends ApiCommandBase {
 
     logger.info(`Current data fee: ${dataFee}`)
 
-    await uploadDataObjects(api, objectSize, objectCid, dataFee.toNumber())
+    const dataSizeFee = dataFee.muln(Math.ceil(objectSize / 1024 / 1024))
+
+    await uploadDataObjects(api, objectSize, objectCid, dataSizeFee.toNumber())
   }
 }
@@ -111,7 +111,7 @@ export async function updateStorageBucketsForBag(
  * @param account - KeyringPair instance
  * @param objectSize - object size in bytes
  * @param objectCid - object CID (Content ID - multihash)
- * @param dataFee - expected 'DataObjectPerMegabyteFee' runtime value
+ * @param dataFee - expected data size fee of the upload
  * @returns promise with a success flag.
  */
 export async function uploadDataObjects(
@@ -134,7 +134,7 @@ export async function uploadDataObjects(
       expectedDataSizeFee: dataFee,
     })
 
-    const tx = api.tx.storage.sudoUploadDataObjects(data)
+    const tx = api.tx.storage.executeStorageAdministrativeAction({ UploadDataObjects: data })
 
     return sendAndFollowSudoNamedTx(api, alice, tx)
   })
@@ -3,7 +3,7 @@
 
 import type { BTreeMap, BTreeSet, Bytes, Enum, GenericAccountId, Option, Struct, Text, Vec, bool, u128, u16, u32, u64, u8 } from '@polkadot/types';
 import type { ITuple } from '@polkadot/types/types';
-import type { AccountId, Balance, Hash } from '@polkadot/types/interfaces/runtime';
+import type { AccountId, Balance, BlockNumber, Hash } from '@polkadot/types/interfaces/runtime';
 import type { AccountInfoWithRefCount } from '@polkadot/types/interfaces/system';
 
 /** @name AccountInfo */
@@ -56,6 +56,14 @@ export interface Approved extends Enum {
   readonly isPendingConstitutionality: boolean;
 }
 
+/** @name AssetsDeletion */
+export interface AssetsDeletion extends Struct {
+  readonly channel_id: ChannelId;
+  readonly deletion_prize_account_id: GenericAccountId;
+  readonly assets: BTreeSet<DataObjectId>;
+  readonly delete_bag: bool;
+}
+
 /** @name AssuranceContractType */
 export interface AssuranceContractType extends Enum {
   readonly isOpen: boolean;
@@ -117,6 +125,12 @@ export interface BagIdType extends Enum {
   readonly asDynamic: Dynamic;
 }
 
+/** @name BagQuota */
+export interface BagQuota extends Struct {
+  readonly objects_limit: u64;
+  readonly size_limit: u64;
+}
+
 /** @name BalanceKind */
 export interface BalanceKind extends Enum {
   readonly isPositive: boolean;
@@ -131,6 +145,12 @@ export interface Bid extends Struct {
   readonly made_at_block: u32;
 }
 
+/** @name BlacklistEntry */
+export interface BlacklistEntry extends Struct {
+  readonly rationale: Bytes;
+  readonly expires_at: Option<BlockNumber>;
+}
+
 /** @name BlockAndTime */
 export interface BlockAndTime extends Struct {
   readonly block: u32;
@@ -159,6 +179,14 @@ export interface BountyCreationParameters extends Struct {
 /** @name BountyId */
 export interface BountyId extends u32 {}
 
+/** @name BudgetSummary */
+export interface BudgetSummary extends Struct {
+  readonly budget: u128;
+  readonly committed_rewards_per_block: u128;
+  readonly missed_rewards: u128;
+  readonly budget_blocks_remaining: Option<u32>;
+}
+
 /** @name BuyMembershipParameters */
 export interface BuyMembershipParameters extends Struct {
   readonly root_account: AccountId;
@@ -207,7 +235,7 @@ export interface Channel extends Struct {
   readonly num_videos: u64;
   readonly is_censored: bool;
   readonly reward_account: Option<GenericAccountId>;
-  readonly collaborators: BTreeSet<MemberId>;
+  readonly collaborators: BTreeMap<MemberId, ChannelCollaboratorPermissions>;
   readonly moderators: BTreeSet<MemberId>;
   readonly cumulative_payout_earned: u128;
 }
@@ -228,12 +256,25 @@ export interface ChannelCategoryUpdateParameters extends Struct {
   readonly new_meta: Bytes;
 }
 
+/** @name ChannelCollaboratorPermission */
+export interface ChannelCollaboratorPermission extends Enum {
+  readonly isAddVideo: boolean;
+  readonly isUpdateMetadata: boolean;
+  readonly isManageAssets: boolean;
+  readonly isDeleteVideo: boolean;
+  readonly isIssueNft: boolean;
+  readonly isManageVideoPosts: boolean;
+}
+
+/** @name ChannelCollaboratorPermissions */
+export interface ChannelCollaboratorPermissions extends BTreeSet<ChannelCollaboratorPermission> {}
+
 /** @name ChannelCreationParameters */
 export interface ChannelCreationParameters extends Struct {
   readonly assets: Option<StorageAssets>;
   readonly meta: Option<Bytes>;
   readonly reward_account: Option<GenericAccountId>;
-  readonly collaborators: BTreeSet<MemberId>;
+  readonly collaborators: BTreeMap<MemberId, ChannelCollaboratorPermissions>;
   readonly moderators: BTreeSet<MemberId>;
 }
 
@@ -271,7 +312,7 @@ export interface ChannelUpdateParameters extends Struct {
   readonly new_meta: Option<Bytes>;
   readonly reward_account: Option<Option<GenericAccountId>>;
   readonly assets_to_remove: BTreeSet<DataObjectId>;
-  readonly collaborators: Option<BTreeSet<MemberId>>;
+  readonly collaborators: Option<BTreeMap<MemberId, ChannelCollaboratorPermissions>>;
 }
 
 /** @name Cid */
@@ -291,9 +332,20 @@ export interface ContentActor extends Enum {
   readonly isLead: boolean;
 }
 
+/** @name ContentHash */
+export interface ContentHash extends Hash {}
+
 /** @name ContentIdSet */
 export interface ContentIdSet extends BTreeSet<Cid> {}
 
+/** @name ContentStorageVersion */
+export interface ContentStorageVersion extends Enum {
+  readonly isV0: boolean;
+  readonly isV1: boolean;
+  readonly isV2: boolean;
+  readonly isV3: boolean;
+}
+
 /** @name CouncilMemberOf */
 export interface CouncilMemberOf extends Struct {
   readonly staking_account_id: AccountId;
@@ -360,11 +412,23 @@ export interface DataObject extends Struct {
   readonly accepted: bool;
   readonly deletion_prize: u128;
   readonly ipfsContentId: Bytes;
+  readonly contentRoot: Option<ContentHash>;
+  readonly parts: Vec<ContentHash>;
+}
+
+/** @name DataObjectClass */
+export interface DataObjectClass extends Enum {
+  readonly isMedia: boolean;
+  readonly isThumbnail: boolean;
+  readonly isMetadata: boolean;
 }
 
 /** @name DataObjectCreationParameters */
 export interface DataObjectCreationParameters extends Struct {
   readonly ipfsContentId: Bytes;
+  readonly contentRoot: Option<ContentHash>;
+  readonly parts: Vec<ContentHash>;
+  readonly class: Option<DataObjectClass>;
 }
 
 /** @name DataObjectId */
@@ -376,6 +440,32 @@ export interface DataObjectIdMap extends BTreeMap<DataObjectId, DataObject> {}
 /** @name DataObjectIdSet */
 export interface DataObjectIdSet extends BTreeSet<DataObjectId> {}
 
+/** @name DataSizeFeeCategory */
+export interface DataSizeFeeCategory extends Enum {
+  readonly isStatic: boolean;
+  readonly asStatic: StaticBagId;
+  readonly isDynamic: boolean;
+  readonly asDynamic: DynamicBagType;
+}
+
+/** @name DataSizeFeeSchedule */
+export interface DataSizeFeeSchedule extends Struct {
+  readonly per_megabyte_fee: u128;
+  readonly object_class_fees: BTreeMap<DataObjectClass, u128>;
+}
+
+/** @name DeputyLeadPermission */
+export interface DeputyLeadPermission extends Enum {
+  readonly isFillOpening: boolean;
+  readonly isCancelOpening: boolean;
+  readonly isUpdateRewardAmount: boolean;
+  readonly isSpendFromBudget: boolean;
+  readonly isSetStatusText: boolean;
+}
+
+/** @name DeputyLeadPermissionSet */
+export interface DeputyLeadPermissionSet extends BTreeSet<DeputyLeadPermission> {}
+
 /** @name DiscussionPost */
 export interface DiscussionPost extends Struct {
   readonly author_id: u64;
@@ -395,11 +485,13 @@ export interface DistributionBucket extends Struct {
   readonly pending_invitations: BTreeSet<WorkerId>;
   readonly operators: BTreeSet<WorkerId>;
   readonly assigned_bags: u64;
+  readonly region: Option<RegionTag>;
 }
 
 /** @name DistributionBucketFamily */
 export interface DistributionBucketFamily extends Struct {
   readonly next_distribution_bucket_index: DistributionBucketIndex;
+  readonly region: Option<RegionTag>;
 }
 
 /** @name DistributionBucketFamilyId */
@@ -417,6 +509,14 @@ export interface DistributionBucketIndex extends u64 {}
 /** @name DistributionBucketIndexSet */
 export interface DistributionBucketIndexSet extends BTreeSet<DistributionBucketIndex> {}
 
+/** @name DistributionOperatorHealth */
+export interface DistributionOperatorHealth extends Struct {
+  readonly last_beacon_at: Option<BlockNumber>;
+  readonly bytes_served: u64;
+  readonly uptime: u64;
+  readonly missed_beacons: u64;
+}
+
 /** @name Dynamic */
 export interface Dynamic extends Enum {
   readonly isMember: boolean;
@@ -429,6 +529,7 @@ export interface Dynamic extends Enum {
 export interface DynamicBagCreationPolicy extends Struct {
   readonly numberOfStorageBuckets: u64;
   readonly families: BTreeMap<DistributionBucketFamilyId, u32>;
+  readonly regions: BTreeSet<RegionTag>;
 }
 
 /** @name DynamicBagCreationPolicyDistributorFamiliesMap */
@@ -623,12 +724,27 @@ export interface Opening extends Struct {
 /** @name OpeningId */
 export interface OpeningId extends u64 {}
 
+/** @name OpeningSummary */
+export interface OpeningSummary extends Struct {
+  readonly opening_type: OpeningType;
+  readonly created: u32;
+  readonly stake_policy: StakePolicy;
+  readonly reward_per_block: Option<u128>;
+  readonly applications_number: u32;
+}
+
 /** @name OpeningType */
 export interface OpeningType extends Enum {
   readonly isLeader: boolean;
   readonly isRegular: boolean;
 }
 
+/** @name OperatorRewardRates */
+export interface OperatorRewardRates extends Struct {
+  readonly per_megabyte: u128;
+  readonly per_bag: u128;
+}
+
 /** @name OptionResult */
 export interface OptionResult extends Struct {
   readonly option_id: MemberId;
@@ -830,6 +946,12 @@ export interface ProposalDetails extends Enum {
   readonly asUnlockBlogPost: PostId;
   readonly isVetoProposal: boolean;
   readonly asVetoProposal: ProposalId;
+  readonly isUpdateDataSizeFeeSchedule: boolean;
+  readonly asUpdateDataSizeFeeSchedule: ITuple<[DataSizeFeeCategory, Option<DataSizeFeeSchedule>]>;
+  readonly isStorageAdministrativeAction: boolean;
+  readonly asStorageAdministrativeAction: StorageAdministrativeAction;
+  readonly isSetWorkingGroupDeputyLead: boolean;
+  readonly asSetWorkingGroupDeputyLead: ITuple<[WorkerId, DeputyLeadPermissionSet, WorkingGroup]>;
 }
 
 /** @name ProposalDetailsOf */
@@ -884,6 +1006,12 @@ export interface ProposalDetailsOf extends Enum {
   readonly asUnlockBlogPost: PostId;
   readonly isVetoProposal: boolean;
   readonly asVetoProposal: ProposalId;
+  readonly isUpdateDataSizeFeeSchedule: boolean;
+  readonly asUpdateDataSizeFeeSchedule: ITuple<[DataSizeFeeCategory, Option<DataSizeFeeSchedule>]>;
+  readonly isStorageAdministrativeAction: boolean;
+  readonly asStorageAdministrativeAction: StorageAdministrativeAction;
+  readonly isSetWorkingGroupDeputyLead: boolean;
+  readonly asSetWorkingGroupDeputyLead: ITuple<[WorkerId, DeputyLeadPermissionSet, WorkingGroup]>;
 }
 
 /** @name ProposalId */
@@ -955,6 +1083,9 @@ export interface ReferendumStageVoting extends Struct {
   readonly current_cycle_id: u64;
 }
 
+/** @name RegionTag */
+export interface RegionTag extends Bytes {}
+
 /** @name Reply */
 export interface Reply extends Struct {
   readonly text_hash: Hash;
@@ -1012,6 +1143,12 @@ export interface SeriesParameters extends Struct {
 /** @name SetLeadParams */
 export interface SetLeadParams extends ITuple<[MemberId, AccountId]> {}
 
+/** @name SharedDataObjectRecord */
+export interface SharedDataObjectRecord extends Struct {
+  readonly size: u64;
+  readonly references: u64;
+}
+
 /** @name Side */
 export interface Side extends Enum {
   readonly isLeft: boolean;
@@ -1050,6 +1187,14 @@ export interface StaticBagId extends Enum {
   readonly asWorkingGroup: WorkingGroup;
 }
 
+/** @name StorageAdministrativeAction */
+export interface StorageAdministrativeAction extends Enum {
+  readonly isUploadDataObjects: boolean;
+  readonly asUploadDataObjects: UploadParameters;
+  readonly isCreateDynamicBag: boolean;
+  readonly asCreateDynamicBag: ITuple<[DynamicBagId, Option<DynamicBagDeletionPrize>]>;
+}
+
 /** @name StorageAssets */
 export interface StorageAssets extends Struct {
   readonly object_creation_list: Vec<DataObjectCreationParameters>;
@@ -1085,9 +1230,33 @@ export interface StorageBucketsPerBagValueConstraint extends Struct {
   readonly max_min_diff: u64;
 }
 
+/** @name StorageChallenge */
+export interface StorageChallenge extends Struct {
+  readonly worker_id: WorkerId;
+  readonly bag_id: BagId;
+  readonly data_object_id: DataObjectId;
+  readonly chunk_index: u64;
+  readonly expires_at: BlockNumber;
+}
+
+/** @name StorageLayoutVersion */
+export interface StorageLayoutVersion extends Enum {
+  readonly isV0: boolean;
+  readonly isV1: boolean;
+  readonly isV2: boolean;
+  readonly isV3: boolean;
+  readonly isV4: boolean;
+}
+
 /** @name StorageProviderId */
 export interface StorageProviderId extends u64 {}
 
+/** @name SubscriptionPlan */
+export interface SubscriptionPlan extends Struct {
+  readonly price: u128;
+  readonly period: u32;
+}
+
 /** @name TerminateRoleParameters */
 export interface TerminateRoleParameters extends Struct {
   readonly worker_id: WorkerId;
@@ -1143,6 +1312,13 @@ export interface UpdatedBody extends Option<Text> {}
 /** @name UpdatedTitle */
 export interface UpdatedTitle extends Option<Text> {}
 
+/** @name UploadFeeQuote */
+export interface UploadFeeQuote extends Struct {
+  readonly data_size_fee: u128;
+  readonly deletion_prize: u128;
+  readonly storage_rent: u128;
+}
+
 /** @name UploadParameters */
 export interface UploadParameters extends Struct {
   readonly bagId: BagId;
@@ -1162,6 +1338,17 @@ export interface Video extends Struct {
   readonly enable_comments: bool;
   readonly video_post_id: Option<VideoPostId>;
   readonly nft_status: Option<OwnedNFT>;
+  readonly assets: BTreeMap<VideoAssetSlot, DataObjectId>;
+}
+
+/** @name VideoAssetSlot */
+export interface VideoAssetSlot extends Enum {
+  readonly isMedia: boolean;
+  readonly isThumbnail: boolean;
+  readonly isSubtitle: boolean;
+  readonly asSubtitle: Bytes;
+  readonly isRendition: boolean;
+  readonly asRendition: Bytes;
 }
 
 /** @name VideoCategory */
@@ -1185,6 +1372,7 @@ export interface VideoCreationParameters extends Struct {
   readonly assets: Option<StorageAssets>;
   readonly meta: Option<Bytes>;
   readonly enable_comments: bool;
+  readonly asset_slots: Vec<VideoAssetSlot>;
 }
 
 /** @name VideoId */
@@ -1233,6 +1421,7 @@ export interface VideoUpdateParameters extends Struct {
   readonly new_meta: Option<Bytes>;
   readonly assets_to_remove: BTreeSet<DataObjectId>;
   readonly enable_comments: Option<bool>;
+  readonly asset_slots: Vec<VideoAssetSlot>;
 }
 
 /** @name VoteKind */
@@ -1262,6 +1451,12 @@ export interface Voucher extends Struct {
   readonly objectsUsed: u64;
 }
 
+/** @name VoucherUpdate */
+export interface VoucherUpdate extends Struct {
+  readonly objects_number: u64;
+  readonly objects_total_size: u64;
+}
+
 /** @name Worker */
 export interface Worker extends Struct {
   readonly member_id: MemberId;
@@ -1284,6 +1479,15 @@ export interface WorkerInfo extends Struct {
   readonly worker: Worker;
 }
 
+/** @name WorkerSummary */
+export interface WorkerSummary extends Struct {
+  readonly member_id: MemberId;
+  readonly is_lead: bool;
+  readonly reward_per_block: Option<u128>;
+  readonly stake: u128;
+  readonly missed_reward: Option<u128>;
+}
+
 /** @name WorkingGroup */
 export interface WorkingGroup extends Enum {
   readonly isForum: boolean;
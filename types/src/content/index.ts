@@ -1,4 +1,4 @@
-import { Vec, Option, Tuple, BTreeSet, UInt } from '@polkadot/types'
+import { Vec, Option, Tuple, BTreeSet, BTreeMap, UInt } from '@polkadot/types'
 import { bool, u64, u32, Null, Bytes } from '@polkadot/types/primitive'
 import { JoyStructDecorated, JoyEnum, ChannelId, MemberId, Balance, Hash, BlockNumber, BalanceOf } from '../common'
 
@@ -107,12 +107,23 @@ export class ChannelOwner extends JoyEnum({
   Curators: CuratorGroupId,
 }) {}
 
+export class ChannelCollaboratorPermission extends JoyEnum({
+  AddVideo: Null,
+  UpdateMetadata: Null,
+  ManageAssets: Null,
+  DeleteVideo: Null,
+  IssueNft: Null,
+  ManageVideoPosts: Null,
+}) {}
+
+export class ChannelCollaboratorPermissions extends BTreeSet.with(ChannelCollaboratorPermission) {}
+
 export class Channel extends JoyStructDecorated({
   owner: ChannelOwner,
   num_videos: u64,
   is_censored: bool,
   reward_account: Option.with(AccountId),
-  collaborators: BTreeSet.with(MemberId),
+  collaborators: BTreeMap.with(MemberId, ChannelCollaboratorPermissions),
   moderators: BTreeSet.with(MemberId),
   cumulative_payout_earned: Balance,
 }) {}
@@ -121,7 +132,7 @@ export class ChannelCreationParameters extends JoyStructDecorated({
   assets: Option.with(StorageAssets),
   meta: Option.with(Bytes),
   reward_account: Option.with(AccountId),
-  collaborators: BTreeSet.with(MemberId),
+  collaborators: BTreeMap.with(MemberId, ChannelCollaboratorPermissions),
   moderators: BTreeSet.with(MemberId),
 }) {}
 
@@ -130,7 +141,7 @@ export class ChannelUpdateParameters extends JoyStructDecorated({
   new_meta: Option.with(Bytes),
   reward_account: Option.with(Option.with(AccountId)),
   assets_to_remove: BTreeSet.with(DataObjectId),
-  collaborators: Option.with(BTreeSet.with(MemberId)),
+  collaborators: Option.with(BTreeMap.with(MemberId, ChannelCollaboratorPermissions)),
 }) {}
 
 export class ChannelOwnershipTransferRequest extends JoyStructDecorated({
@@ -164,6 +175,13 @@ export class VideoCategoryUpdateParameters extends JoyStructDecorated({
   new_meta: Bytes,
 }) {}
 
+export class VideoAssetSlot extends JoyEnum({
+  Media: Null,
+  Thumbnail: Null,
+  Subtitle: Bytes,
+  Rendition: Bytes,
+}) {}
+
 export class Video extends JoyStructDecorated({
   in_channel: ChannelId,
   in_series: Option.with(SeriesId),
@@ -171,12 +189,14 @@ export class Video extends JoyStructDecorated({
   enable_comments: bool,
   video_post_id: Option.with(VideoPostId),
   nft_status: Option.with(OwnedNFT),
+  assets: BTreeMap.with(VideoAssetSlot, DataObjectId),
 }) {}
 
 export class VideoCreationParameters extends JoyStructDecorated({
   assets: Option.with(StorageAssets),
   meta: Option.with(Bytes),
   enable_comments: bool,
+  asset_slots: Vec.with(VideoAssetSlot),
 }) {}
 
 export class VideoUpdateParameters extends JoyStructDecorated({
@@ -184,6 +204,7 @@ export class VideoUpdateParameters extends JoyStructDecorated({
   new_meta: Option.with(Bytes),
   assets_to_remove: BTreeSet.with(DataObjectId),
   enable_comments: Option.with(bool),
+  asset_slots: Vec.with(VideoAssetSlot),
 }) {}
 
 export class Playlist extends JoyStructDecorated({
@@ -257,6 +278,20 @@ export class ChannelMigrationConfig extends JoyStructDecorated({
   final_id: ChannelId,
 }) {}
 
+export class ContentStorageVersion extends JoyEnum({
+  V0: Null,
+  V1: Null,
+  V2: Null,
+  V3: Null,
+}) {}
+
+export class AssetsDeletion extends JoyStructDecorated({
+  channel_id: ChannelId,
+  deletion_prize_account_id: AccountId,
+  assets: BTreeSet.with(DataObjectId),
+  delete_bag: bool,
+}) {}
+
 export class VideoPostType extends JoyEnum({
   Description: Null,
   Comment: VideoPostId,
@@ -298,6 +333,11 @@ export class PullPayment extends JoyStructDecorated({
 
 export class ModeratorSet extends BTreeSet.with(MemberId) {}
 
+export class SubscriptionPlan extends JoyStructDecorated({
+  price: Balance,
+  period: BlockNumber,
+}) {}
+
 export const contentTypes = {
   CuratorId,
   CuratorGroupId,
@@ -315,6 +355,7 @@ export const contentTypes = {
   ChannelOwnershipTransferRequestId,
   ChannelOwnershipTransferRequest,
   Video,
+  VideoAssetSlot,
   VideoId,
   VideoCategoryId,
   VideoCategory,
@@ -342,6 +383,8 @@ export const contentTypes = {
   IsCensored,
   VideoMigrationConfig,
   ChannelMigrationConfig,
+  ContentStorageVersion,
+  AssetsDeletion,
   // Added in Olympia:
   VideoPostId,
   ReactionId,
@@ -353,6 +396,9 @@ export const contentTypes = {
   VideoPostDeletionParameters,
   PullPayment,
   ModeratorSet,
+  SubscriptionPlan,
+  ChannelCollaboratorPermission,
+  ChannelCollaboratorPermissions,
   // NFT
   Royalty,
   IsExtended,
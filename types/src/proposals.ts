@@ -4,7 +4,8 @@ import { Text, u32, Tuple, u8, u128, Vec, Option, Null, Bytes } from '@polkadot/
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import { Constructor, ITuple } from '@polkadot/types/types'
 import { MemberId, WorkingGroup, JoyEnum, JoyStructDecorated, BalanceKind, PostId, AccountId } from './common'
-import { ApplicationId, OpeningId, StakePolicy, WorkerId } from './working-group'
+import { ApplicationId, DeputyLeadPermissionSet, OpeningId, StakePolicy, WorkerId } from './working-group'
+import { DataSizeFeeCategory, DataSizeFeeSchedule, StorageAdministrativeAction } from './storage'
 
 export type IVotingResults = {
   abstensions: u32
@@ -271,6 +272,15 @@ const CreateBlogPost = (Tuple.with([Text, Text]) as unknown) as Constructor<ITup
 const EditBlogPost = (Tuple.with([PostId, 'Option<Text>', 'Option<Text>']) as unknown) as Constructor<
   ITuple<[PostId, Option<Text>, Option<Text>]>
 >
+const UpdateDataSizeFeeSchedule = (Tuple.with([
+  DataSizeFeeCategory,
+  'Option<DataSizeFeeSchedule>',
+]) as unknown) as Constructor<ITuple<[DataSizeFeeCategory, Option<DataSizeFeeSchedule>]>>
+const SetWorkingGroupDeputyLead = (Tuple.with([
+  WorkerId,
+  DeputyLeadPermissionSet,
+  WorkingGroup,
+]) as unknown) as Constructor<ITuple<[WorkerId, DeputyLeadPermissionSet, WorkingGroup]>>
 
 export class ProposalDetails extends JoyEnum({
   Signal: Text,
@@ -298,6 +308,9 @@ export class ProposalDetails extends JoyEnum({
   LockBlogPost: PostId,
   UnlockBlogPost: PostId,
   VetoProposal: ProposalId,
+  UpdateDataSizeFeeSchedule,
+  StorageAdministrativeAction,
+  SetWorkingGroupDeputyLead,
 } as const) {}
 
 // Discussions
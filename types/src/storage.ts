@@ -14,11 +14,13 @@ import {
 } from '@polkadot/types'
 import { Balance } from '@polkadot/types/interfaces'
 import { RegistryTypes } from '@polkadot/types/types'
-import { JoyEnum, JoyStructDecorated, WorkingGroup, BalanceOf, MemberId } from './common'
+import { JoyEnum, JoyStructDecorated, WorkingGroup, BalanceOf, MemberId, Hash, BlockNumber } from './common'
 import { WorkerId } from './working-group'
 
 export class DataObjectId extends u64 {}
 export class StorageBucketId extends u64 {}
+export class ContentHash extends Hash {}
+export class RegionTag extends Bytes {}
 
 export type IStorageBucketsPerBagValueConstraint = {
   min: u64
@@ -37,6 +39,8 @@ export type IDataObject = {
   deletion_prize: BalanceOf
   size: u64
   ipfsContentId: Bytes
+  contentRoot: Option<ContentHash>
+  parts: Vec<ContentHash>
 }
 
 export class DataObject
@@ -45,6 +49,8 @@ export class DataObject
     deletion_prize: BalanceOf,
     size: u64,
     ipfsContentId: Bytes,
+    contentRoot: Option.with(ContentHash),
+    parts: Vec.with(ContentHash),
   })
   implements IDataObject {}
 
@@ -102,12 +108,14 @@ export class Bag
 export type IDynamicBagCreationPolicy = {
   numberOfStorageBuckets: u64
   families: BTreeMap<DistributionBucketFamilyId, u32>
+  regions: BTreeSet<RegionTag>
 }
 
 export class DynamicBagCreationPolicy
   extends JoyStructDecorated({
     numberOfStorageBuckets: u64,
     families: BTreeMap.with(DistributionBucketFamilyId, u32),
+    regions: BTreeSet.with(RegionTag),
   })
   implements IDynamicBagCreationPolicy {}
 
@@ -183,15 +191,29 @@ export class StorageBucket
   })
   implements IStorageBucket {}
 
+export const DataObjectClassDef = {
+  Media: Null,
+  Thumbnail: Null,
+  Metadata: Null,
+} as const
+export type DataObjectClassKey = keyof typeof DataObjectClassDef
+export class DataObjectClass extends JoyEnum(DataObjectClassDef) {}
+
 export type IDataObjectCreationParameters = {
   size: u64
   ipfsContentId: Bytes
+  contentRoot: Option<ContentHash>
+  parts: Vec<ContentHash>
+  class: Option<DataObjectClass>
 }
 
 export class DataObjectCreationParameters
   extends JoyStructDecorated({
     size: u64,
     ipfsContentId: Bytes,
+    contentRoot: Option.with(ContentHash),
+    parts: Vec.with(ContentHash),
+    class: Option.with(DataObjectClass),
   })
   implements IDataObjectCreationParameters {}
 
@@ -211,6 +233,12 @@ export class UploadParameters
   })
   implements IUploadParameters {}
 
+export const StorageAdministrativeActionDef = {
+  UploadDataObjects: UploadParameters,
+  CreateDynamicBag: Tuple.with([DynamicBagId, Option.with(DynamicBagDeletionPrize)]),
+} as const
+export class StorageAdministrativeAction extends JoyEnum(StorageAdministrativeActionDef) {}
+
 export class Cid extends Bytes {}
 export class ContentIdSet extends BTreeSet.with(Cid) {}
 
@@ -220,6 +248,7 @@ export type IDistributionBucket = {
   pending_invitations: BTreeSet<WorkerId>
   operators: BTreeSet<WorkerId>
   assigned_bags: u64
+  region: Option<RegionTag>
 }
 
 export class DistributionBucket
@@ -229,19 +258,156 @@ export class DistributionBucket
     pending_invitations: BTreeSet.with(WorkerId),
     operators: BTreeSet.with(WorkerId),
     assigned_bags: u64,
+    region: Option.with(RegionTag),
   })
   implements IDistributionBucket {}
 
 export type IDistributionBucketFamily = {
   next_distribution_bucket_index: DistributionBucketIndex
+  region: Option<RegionTag>
 }
 
 export class DistributionBucketFamily
   extends JoyStructDecorated({
     next_distribution_bucket_index: DistributionBucketIndex,
+    region: Option.with(RegionTag),
   })
   implements IDistributionBucketFamily {}
 
+export type IStorageChallenge = {
+  worker_id: WorkerId
+  bag_id: BagId
+  data_object_id: DataObjectId
+  chunk_index: u64
+  expires_at: BlockNumber
+}
+
+export class StorageChallenge
+  extends JoyStructDecorated({
+    worker_id: WorkerId,
+    bag_id: BagId,
+    data_object_id: DataObjectId,
+    chunk_index: u64,
+    expires_at: BlockNumber,
+  })
+  implements IStorageChallenge {}
+
+export type ISharedDataObjectRecord = {
+  size: u64
+  references: u64
+}
+
+export class SharedDataObjectRecord
+  extends JoyStructDecorated({
+    size: u64,
+    references: u64,
+  })
+  implements ISharedDataObjectRecord {}
+
+export type IBagQuota = {
+  objects_limit: u64
+  size_limit: u64
+}
+
+export class BagQuota
+  extends JoyStructDecorated({
+    objects_limit: u64,
+    size_limit: u64,
+  })
+  implements IBagQuota {}
+
+export type IBlacklistEntry = {
+  rationale: Bytes
+  expires_at: Option<BlockNumber>
+}
+
+export class BlacklistEntry
+  extends JoyStructDecorated({
+    rationale: Bytes,
+    expires_at: Option.with(BlockNumber),
+  })
+  implements IBlacklistEntry {}
+
+export type IUploadFeeQuote = {
+  data_size_fee: BalanceOf
+  deletion_prize: BalanceOf
+  storage_rent: BalanceOf
+}
+
+export class UploadFeeQuote
+  extends JoyStructDecorated({
+    data_size_fee: BalanceOf,
+    deletion_prize: BalanceOf,
+    storage_rent: BalanceOf,
+  })
+  implements IUploadFeeQuote {}
+
+export type IVoucherUpdate = {
+  objects_number: u64
+  objects_total_size: u64
+}
+
+export class VoucherUpdate
+  extends JoyStructDecorated({
+    objects_number: u64,
+    objects_total_size: u64,
+  })
+  implements IVoucherUpdate {}
+
+export const DataSizeFeeCategoryDef = {
+  Static: StaticBagId,
+  Dynamic: DynamicBagType,
+} as const
+export class DataSizeFeeCategory extends JoyEnum(DataSizeFeeCategoryDef) {}
+
+export type IDataSizeFeeSchedule = {
+  per_megabyte_fee: BalanceOf
+  object_class_fees: BTreeMap<DataObjectClass, BalanceOf>
+}
+
+export class DataSizeFeeSchedule
+  extends JoyStructDecorated({
+    per_megabyte_fee: BalanceOf,
+    object_class_fees: BTreeMap.with(DataObjectClass, BalanceOf),
+  })
+  implements IDataSizeFeeSchedule {}
+
+export type IDistributionOperatorHealth = {
+  last_beacon_at: Option<BlockNumber>
+  bytes_served: u64
+  uptime: u64
+  missed_beacons: u64
+}
+
+export class DistributionOperatorHealth
+  extends JoyStructDecorated({
+    last_beacon_at: Option.with(BlockNumber),
+    bytes_served: u64,
+    uptime: u64,
+    missed_beacons: u64,
+  })
+  implements IDistributionOperatorHealth {}
+
+export class StorageLayoutVersion extends JoyEnum({
+  V0: Null,
+  V1: Null,
+  V2: Null,
+  V3: Null,
+  V4: Null,
+}) {}
+
+export type IOperatorRewardRates = {
+  per_megabyte: BalanceOf
+  per_bag: BalanceOf
+}
+
+export class OperatorRewardRates
+  extends JoyStructDecorated({
+    per_megabyte: BalanceOf,
+    per_bag: BalanceOf,
+  })
+  implements IOperatorRewardRates {}
+
 export class DynamicBagCreationPolicyDistributorFamiliesMap extends BTreeMap.with(DistributionBucketFamilyId, u32) {}
 
 export const storageTypes: RegistryTypes = {
@@ -263,6 +429,7 @@ export const storageTypes: RegistryTypes = {
   DataObjectCreationParameters,
   BagIdType,
   UploadParameters,
+  StorageAdministrativeAction,
   StorageBucketIdSet,
   DataObjectIdSet,
   ContentIdSet,
@@ -274,6 +441,20 @@ export const storageTypes: RegistryTypes = {
   DistributionBucketFamilyId,
   DistributionBucket,
   DistributionBucketFamily,
+  ContentHash,
+  RegionTag,
+  StorageChallenge,
+  SharedDataObjectRecord,
+  BagQuota,
+  OperatorRewardRates,
+  BlacklistEntry,
+  UploadFeeQuote,
+  VoucherUpdate,
+  DataObjectClass,
+  DataSizeFeeCategory,
+  DataSizeFeeSchedule,
+  DistributionOperatorHealth,
+  StorageLayoutVersion,
   // Utility types:
   DataObjectIdMap,
   DistributionBucketIndexSet,
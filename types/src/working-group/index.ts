@@ -1,4 +1,4 @@
-import { Bytes, BTreeMap, Option, Text, BTreeSet } from '@polkadot/types'
+import { Bytes, BTreeMap, Option, Text, BTreeSet, bool } from '@polkadot/types'
 import { Null, u32, u64, u128 } from '@polkadot/types/primitive'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import { ActorId, MemberId, JoyEnum, JoyStructDecorated, AccountId } from '../common'
@@ -177,6 +177,69 @@ export class RewardPaymentType extends JoyEnum({
   RegularReward: Null,
 }) {}
 
+// Leader permission delegated to the deputy lead.
+export class DeputyLeadPermission extends JoyEnum({
+  FillOpening: Null,
+  CancelOpening: Null,
+  UpdateRewardAmount: Null,
+  SpendFromBudget: Null,
+  SetStatusText: Null,
+}) {}
+
+export class DeputyLeadPermissionSet extends BTreeSet.with(DeputyLeadPermission) {}
+
+export type IWorkerSummary = {
+  member_id: MemberId
+  is_lead: bool
+  reward_per_block: Option<Balance>
+  stake: Balance
+  missed_reward: Option<Balance>
+}
+
+export class WorkerSummary
+  extends JoyStructDecorated({
+    member_id: MemberId,
+    is_lead: bool,
+    reward_per_block: Option.with(u128),
+    stake: u128,
+    missed_reward: Option.with(u128),
+  })
+  implements IWorkerSummary {}
+
+export type IOpeningSummary = {
+  opening_type: OpeningType
+  created: BlockNumber
+  stake_policy: StakePolicy
+  reward_per_block: Option<Balance>
+  applications_number: u32
+}
+
+export class OpeningSummary
+  extends JoyStructDecorated({
+    opening_type: OpeningType,
+    created: u32,
+    stake_policy: StakePolicy,
+    reward_per_block: Option.with(u128),
+    applications_number: u32,
+  })
+  implements IOpeningSummary {}
+
+export type IBudgetSummary = {
+  budget: Balance
+  committed_rewards_per_block: Balance
+  missed_rewards: Balance
+  budget_blocks_remaining: Option<BlockNumber>
+}
+
+export class BudgetSummary
+  extends JoyStructDecorated({
+    budget: u128,
+    committed_rewards_per_block: u128,
+    missed_rewards: u128,
+    budget_blocks_remaining: Option.with(u32),
+  })
+  implements IBudgetSummary {}
+
 export const workingGroupTypes: RegistryTypes = {
   ApplicationId,
   Application,
@@ -195,6 +258,11 @@ export const workingGroupTypes: RegistryTypes = {
   ApplyOnOpeningParameters,
   Penalty,
   RewardPaymentType,
+  DeputyLeadPermission,
+  DeputyLeadPermissionSet,
+  WorkerSummary,
+  OpeningSummary,
+  BudgetSummary,
 }
 
 export default workingGroupTypes
@@ -106,17 +106,20 @@ export class AssetsManager {
     return path.join(this.config.dataDir, contentHash)
   }
 
-  public calcDataObjectsFee(params: DataObjectCreationParameters[]): BN {
-    const { dataObjectFeePerMB, api } = this
-    const deletionPrize = api.consts.storage.dataObjectDeletionPrize
+  public calcDataSizeFee(params: DataObjectCreationParameters[]): BN {
+    const { dataObjectFeePerMB } = this
     const totalSize = params
       .reduce((a, b) => {
         return a.add(b.getField('size'))
       }, new BN(0))
       .toNumber()
-    const totalStorageFee = dataObjectFeePerMB.muln(Math.ceil(totalSize / 1024 / 1024))
+    return dataObjectFeePerMB.muln(Math.ceil(totalSize / 1024 / 1024))
+  }
+
+  public calcDataObjectsFee(params: DataObjectCreationParameters[]): BN {
+    const deletionPrize = this.api.consts.storage.dataObjectDeletionPrize
     const totalDeletionPrize = deletionPrize.muln(params.length)
-    return totalStorageFee.add(totalDeletionPrize)
+    return this.calcDataSizeFee(params).add(totalDeletionPrize)
   }
 
   private async prepareAsset(
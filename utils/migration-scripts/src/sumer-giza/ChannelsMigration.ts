@@ -160,7 +160,7 @@ export class ChannelMigration extends AssetsMigration {
         assets: assetsParams.length
           ? {
               object_creation_list: assetsParams,
-              expected_data_size_fee: this.assetsManager.dataObjectFeePerMB,
+              expected_data_size_fee: this.assetsManager.calcDataSizeFee(assetsParams),
             }
           : null,
         meta: `0x${Buffer.from(ChannelMetadata.encode(meta).finish()).toString('hex')}`,
@@ -185,7 +185,7 @@ export class VideosMigration extends AssetsMigration {
         assets: assetsParams.length
           ? {
               object_creation_list: assetsParams,
-              expected_data_size_fee: this.assetsManager.dataObjectFeePerMB,
+              expected_data_size_fee: this.assetsManager.calcDataSizeFee(assetsParams),
             }
           : null,
         meta: `0x${Buffer.from(VideoMetadata.encode(meta).finish()).toString('hex')}`,